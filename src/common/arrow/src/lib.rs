@@ -22,6 +22,7 @@ pub use arrow;
 pub use arrow_format;
 pub use native;
 pub use parquet2 as parquet;
+pub use parquet_read::infer_schema_with_extension;
 pub use parquet_read::read_columns_async;
 pub use parquet_read::read_columns_many_async;
 pub use parquet_write::write_parquet_file;
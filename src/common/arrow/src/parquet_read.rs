@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use arrow::datatypes::DataType;
 use arrow::datatypes::Field;
+use arrow::datatypes::Schema;
 use arrow::error::Result;
+use arrow::io::parquet::read::infer_schema;
 use arrow::io::parquet::read::to_deserializer;
 use arrow::io::parquet::read::ArrayIter;
 use futures::AsyncRead;
@@ -21,6 +24,7 @@ use futures::AsyncReadExt;
 use futures::AsyncSeek;
 use futures::AsyncSeekExt;
 use parquet2::metadata::ColumnChunkMetaData;
+use parquet2::metadata::FileMetaData;
 use parquet2::metadata::RowGroupMetaData;
 
 fn get_field_columns<'a>(
@@ -80,3 +84,32 @@ pub async fn read_columns_many_async<'a, R: AsyncRead + AsyncSeek + Send + Unpin
     }
     Ok(arrays)
 }
+
+/// Infer the arrow schema of a parquet file, restoring the extension types
+/// recorded in the key-value metadata by [`crate::write_parquet_file`].
+///
+/// Types such as Variant, Bitmap and Binary are all stored as plain BYTE_ARRAY
+/// columns, so the physical type alone is not enough to tell them from String.
+pub fn infer_schema_with_extension(meta: &FileMetaData) -> Result<Schema> {
+    let arrow_schema = infer_schema(meta)?;
+    if let Some(metas) = meta.key_value_metadata() {
+        let mut new_fields = arrow_schema.fields.clone();
+        for (i, field) in arrow_schema.fields.iter().enumerate() {
+            for meta in metas {
+                if field.name == meta.key {
+                    let data_type = DataType::Extension(
+                        meta.value.clone().unwrap(),
+                        Box::new(field.data_type.clone()),
+                        None,
+                    );
+                    let new_field = Field::new(field.name.clone(), data_type, field.is_nullable);
+                    new_fields[i] = new_field;
+                    break;
+                }
+            }
+        }
+        Ok(new_fields.into())
+    } else {
+        Ok(arrow_schema)
+    }
+}
@@ -145,6 +145,7 @@ build_exceptions! {
     ColumnReferencedByComputedColumn(1117),
     // The table is not a clustered table.
     UnclusteredTable(1118),
+    UnknownPreparedStatement(1119),
 
     // Data Related Errors
 
@@ -181,10 +182,23 @@ build_exceptions! {
 
     BackgroundJobAlreadyExists(1501),
     UnknownBackgroundJob(1502),
+    InvalidCronExpression(1503),
 
     // Index related errors.
     UnsupportedIndex(1601),
     RefreshIndexError(1602),
+
+    // Transaction related errors.
+
+    /// CurrentTransactionIsAborted is used when a statement of the transaction failed,
+    /// and the following statements are rejected until the transaction is rolled back.
+    CurrentTransactionIsAborted(1701),
+
+    // Stream related errors.
+
+    /// IllegalStream is used when the table of a stream is dropped, recreated or altered,
+    /// the stream needs to be recreated.
+    IllegalStream(1801),
 }
 
 // Meta service errors [2001, 3000].
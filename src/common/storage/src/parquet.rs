@@ -12,10 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_arrow::arrow::datatypes::DataType as ArrowType;
-use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::read as pread;
+pub use common_arrow::infer_schema_with_extension;
 use common_arrow::parquet::metadata::FileMetaData;
 use common_base::runtime::execute_futures_in_parallel;
 use common_base::runtime::GLOBAL_MEM_STAT;
@@ -30,35 +29,7 @@ pub async fn read_parquet_schema_async(operator: &Operator, path: &str) -> Resul
         ErrorCode::Internal(format!("Read parquet file '{}''s meta error: {}", path, e))
     })?;
 
-    infer_schema_with_extension(&meta)
-}
-
-pub fn infer_schema_with_extension(meta: &FileMetaData) -> Result<ArrowSchema> {
-    let arrow_schema = pread::infer_schema(meta)?;
-    // Convert data types to extension types using meta information.
-    // Mainly used for types such as Variant and Bitmap,
-    // as they have the same physical type as String.
-    if let Some(metas) = meta.key_value_metadata() {
-        let mut new_fields = arrow_schema.fields.clone();
-        for (i, field) in arrow_schema.fields.iter().enumerate() {
-            for meta in metas {
-                if field.name == meta.key {
-                    let data_type = ArrowType::Extension(
-                        meta.value.clone().unwrap(),
-                        Box::new(field.data_type.clone()),
-                        None,
-                    );
-                    let new_field =
-                        ArrowField::new(field.name.clone(), data_type, field.is_nullable);
-                    new_fields[i] = new_field;
-                    break;
-                }
-            }
-        }
-        Ok(new_fields.into())
-    } else {
-        Ok(arrow_schema)
-    }
+    Ok(infer_schema_with_extension(&meta)?)
 }
 
 async fn read_parquet_metas_batch(
@@ -63,6 +63,7 @@ use crate::send_txn;
 use crate::serialize_struct;
 use crate::serialize_u64;
 use crate::txn_cond_seq;
+use crate::txn_op_del;
 use crate::txn_op_put;
 use crate::util::deserialize_u64;
 use crate::util::txn_trials;
@@ -141,12 +142,52 @@ impl<KV: kvapi::KVApi<Error = MetaError>> BackgroundApi for KV {
         Ok(CreateBackgroundJobReply { id })
     }
 
-    // TODO(zhihanz): needs to drop both background job and related background tasks, also needs to gracefully shutdown running queries
+    // Related background tasks are left to expire, and a running task is not interrupted.
     async fn drop_background_job(
         &self,
-        _req: DeleteBackgroundJobReq,
+        req: DeleteBackgroundJobReq,
     ) -> Result<DeleteBackgroundJobReply, KVAppError> {
-        todo!()
+        debug!(req = debug(&req), "BackgroundApi: {}", func_name!());
+
+        let name_key = &req.name;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (id_seq, id) = get_u64_value(self, name_key).await?;
+            background_job_has_to_exist(id_seq, name_key)?;
+            let id_key = BackgroundJobId { id };
+            let (job_seq, _): (_, Option<BackgroundJobInfo>) = get_pb_value(self, &id_key).await?;
+
+            let condition = vec![
+                txn_cond_seq(name_key, Eq, id_seq),
+                txn_cond_seq(&id_key, Eq, job_seq),
+            ];
+            let if_then = vec![txn_op_del(name_key), txn_op_del(&id_key)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(name_key),
+                id = debug(&id_key),
+                succ = display(succ),
+                "drop_background_job"
+            );
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DeleteBackgroundJobReply {})
     }
 
     async fn update_background_job_status(
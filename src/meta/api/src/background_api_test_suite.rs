@@ -26,6 +26,7 @@ use common_meta_app::background::BackgroundTaskIdent;
 use common_meta_app::background::BackgroundTaskInfo;
 use common_meta_app::background::BackgroundTaskState;
 use common_meta_app::background::CreateBackgroundJobReq;
+use common_meta_app::background::DeleteBackgroundJobReq;
 use common_meta_app::background::GetBackgroundJobReq;
 use common_meta_app::background::GetBackgroundTaskReq;
 use common_meta_app::background::ListBackgroundJobsReq;
@@ -70,6 +71,8 @@ fn new_background_job(state: BackgroundJobState, created_at: DateTime<Utc>) -> B
         last_updated: None,
         task_type: Default::default(),
         message: "".to_string(),
+        sql: None,
+        owner_role: None,
         creator: None,
         created_at,
         job_status: Some(BackgroundJobStatus {
@@ -353,6 +356,35 @@ impl BackgroundApiTestSuite {
                 "first state is started"
             );
         }
+
+        info!("--- drop a background job");
+        {
+            let req = DeleteBackgroundJobReq {
+                name: job_ident.clone(),
+            };
+            mt.drop_background_job(req).await?;
+
+            let res = mt
+                .get_background_job(GetBackgroundJobReq {
+                    name: job_ident.clone(),
+                })
+                .await;
+            assert!(res.is_err());
+
+            let res = mt
+                .list_background_jobs(ListBackgroundJobsReq {
+                    tenant: tenant.to_string(),
+                })
+                .await?;
+            assert!(res.is_empty());
+
+            let res = mt
+                .drop_background_job(DeleteBackgroundJobReq {
+                    name: job_ident.clone(),
+                })
+                .await;
+            assert!(res.is_err(), "drop a dropped job");
+        }
         Ok(())
     }
 }
@@ -122,6 +122,13 @@ impl BackgroundJobParams {
         }
     }
 
+    /// Returns an error message if the cron expression can not be scheduled.
+    pub fn check_cron_expression(cron: &str) -> Result<(), String> {
+        Schedule::from_str(cron)
+            .map(|_| ())
+            .map_err(|e| format!("invalid cron expression {}: {}", cron, e))
+    }
+
     pub fn get_next_running_time(&self, last_run_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
         match self.job_type {
             BackgroundJobType::ONESHOT => None,
@@ -225,6 +232,10 @@ pub struct BackgroundJobInfo {
     pub last_updated: Option<DateTime<Utc>>,
     pub message: String,
 
+    // The statement run by a job of task type SQL, and the role it runs under.
+    pub sql: Option<String>,
+    pub owner_role: Option<String>,
+
     // Audit
     pub creator: Option<UserIdentity>,
     pub created_at: DateTime<Utc>,
@@ -238,6 +249,27 @@ impl BackgroundJobInfo {
             task_type: BackgroundTaskType::COMPACTION,
             last_updated: Some(Utc::now()),
             message: "".to_string(),
+            sql: None,
+            owner_role: None,
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn new_sql_task_job(
+        job_params: BackgroundJobParams,
+        sql: String,
+        owner_role: Option<String>,
+        creator: UserIdentity,
+    ) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::SQL,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            sql: Some(sql),
+            owner_role,
             creator: Some(creator),
             created_at: Utc::now(),
         }
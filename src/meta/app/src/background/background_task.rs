@@ -62,6 +62,7 @@ pub enum BackgroundTaskType {
     #[default]
     COMPACTION = 0,
     VACUUM = 1,
+    SQL = 2,
 }
 
 impl Display for BackgroundTaskType {
@@ -151,6 +152,24 @@ impl BackgroundTaskInfo {
             created_at: now,
         }
     }
+
+    pub fn new_sql_task(
+        creator: BackgroundJobIdent,
+        manual_trigger: Option<ManualTriggerParams>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            last_updated: Some(now),
+            task_type: BackgroundTaskType::SQL,
+            task_state: BackgroundTaskState::STARTED,
+            message: "".to_string(),
+            compaction_task_stats: None,
+            vacuum_stats: None,
+            manual_trigger,
+            creator: Some(creator),
+            created_at: now,
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
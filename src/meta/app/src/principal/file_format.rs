@@ -96,6 +96,9 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
+    Arrow(ArrowFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -107,6 +110,9 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Arrow(_) => StageFileFormatType::Arrow,
         }
     }
 
@@ -124,6 +130,13 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Arrow => {
+                Ok(FileFormatParams::Arrow(ArrowFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -139,6 +152,9 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(_) => StageFileCompression::None,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Arrow(_) => StageFileCompression::None,
         }
     }
 
@@ -164,6 +180,15 @@ impl FileFormatParams {
                 FileFormatParams::NdJson(NdJsonFileFormatParams { compression })
             }
             StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Avro => {
+                let compression = ast.take_compression()?;
+                FileFormatParams::Avro(AvroFileFormatParams { compression })
+            }
+            StageFileFormatType::Orc => {
+                let compression = ast.take_compression()?;
+                FileFormatParams::Orc(OrcFileFormatParams { compression })
+            }
+            StageFileFormatType::Arrow => FileFormatParams::Arrow(ArrowFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -403,6 +428,40 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+/// Avro container files carry their own schema and block codec.
+/// `compression` is the block codec used when unloading.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub compression: StageFileCompression,
+}
+
+impl Default for AvroFileFormatParams {
+    fn default() -> Self {
+        AvroFileFormatParams {
+            compression: StageFileCompression::None,
+        }
+    }
+}
+
+/// ORC files carry their own schema, compression and stripe statistics.
+/// `compression` is the stream compression used when unloading.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {
+    pub compression: StageFileCompression,
+}
+
+impl Default for OrcFileFormatParams {
+    fn default() -> Self {
+        OrcFileFormatParams {
+            compression: StageFileCompression::None,
+        }
+    }
+}
+
+/// Arrow IPC files or streams, the schema is carried in the data.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrowFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -448,6 +507,15 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Avro(params) => {
+                write!(f, "TYPE = AVRO COMPRESSION = {:?}", params.compression)
+            }
+            FileFormatParams::Orc(params) => {
+                write!(f, "TYPE = ORC COMPRESSION = {:?}", params.compression)
+            }
+            FileFormatParams::Arrow(_) => {
+                write!(f, "TYPE = ARROW")
+            }
         }
     }
 }
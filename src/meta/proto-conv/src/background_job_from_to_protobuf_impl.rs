@@ -54,6 +54,8 @@ impl FromToProto for mt::background::BackgroundJobInfo {
                 .last_updated
                 .and_then(|t| DateTime::<Utc>::from_pb(t).ok()),
             message: p.message,
+            sql: p.sql,
+            owner_role: p.owner_role,
             creator: match p.creator {
                 Some(c) => Some(mt::principal::UserIdentity::from_pb(c)?),
                 None => None,
@@ -71,6 +73,8 @@ impl FromToProto for mt::background::BackgroundJobInfo {
             task_type: self.task_type.clone() as i32,
             last_updated: self.last_updated.and_then(|t| t.to_pb().ok()),
             message: self.message.clone(),
+            sql: self.sql.clone(),
+            owner_role: self.owner_role.clone(),
             creator: self.creator.clone().and_then(|c| c.to_pb().ok()),
             created_at: self.created_at.to_pb()?,
         };
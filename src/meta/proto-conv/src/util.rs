@@ -77,7 +77,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (45, "2023-06-06: Add: background_tasks.proto and background_jobs.proto", ),
     (46, "2023-06-28: Add: index.proto/IndexMeta::updated_on", ),
     (47, "2023-07-03: Add: catalog.proto/CatalogMeta",),
-    (48, "2023-07-04: Add: ManualTriggerParams on background_job", )
+    (48, "2023-07-04: Add: ManualTriggerParams on background_job", ),
+    (49, "2023-07-10: Add: background.proto/BackgroundJobInfo::sql and owner_role, BackgroundTaskType::SQL", ),
+    (50, "2023-07-12: Add: datatype.proto/DataType::interval_t", ),
+    (51, "2023-07-13: Add: datatype.proto/DataType::binary_t", ),
+    (52, "2023-07-14: Add: file_format.proto/FileFormatParams::avro", ),
+    (53, "2023-07-17: Add: file_format.proto/FileFormatParams::orc", ),
+    (54, "2023-07-19: Add: file_format.proto/FileFormatParams::arrow, StageFileFormatType::Arrow", ),
+    (55, "2023-07-21: Add: file_format.proto/AvroFileFormatParams::compression, OrcFileFormatParams::compression", ),
+    (56, "2023-07-24: Add: table.proto/TableMeta::indexes, TableIndex", )
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -53,3 +53,11 @@ mod v045_background;
 mod v046_index_meta;
 mod v047_catalog_meta;
 mod v048_background;
+mod v049_background;
+mod v050_schema;
+mod v051_schema;
+mod v052_file_format_params;
+mod v053_file_format_params;
+mod v054_file_format_params;
+mod v055_file_format_params;
+mod v056_table_meta;
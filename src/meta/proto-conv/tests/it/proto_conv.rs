@@ -203,6 +203,11 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        indexes: btreemap! {s("idx1") => mt::TableIndex {
+            name: s("idx1"),
+            column_ids: vec![1, 2],
+            version: s("abc"),
+        }},
     }
 }
 
@@ -129,6 +129,8 @@ fn test_decode_v48_background_job() -> anyhow::Result<()> {
         last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
         task_type: BackgroundTaskType::COMPACTION,
         message: "".to_string(),
+        sql: None,
+        owner_role: None,
         creator: None,
         created_at: Default::default(),
         job_status: Some(BackgroundJobStatus {
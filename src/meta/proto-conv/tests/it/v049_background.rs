@@ -0,0 +1,106 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobState;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundJobType;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::principal::UserIdentity;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v49_background_task() -> anyhow::Result<()> {
+    let bytes = vec![
+        26, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 32, 2, 40, 2, 50, 11, 116, 97, 115, 107, 32, 102, 97, 105, 108, 101, 100, 210, 5,
+        20, 10, 5, 116, 101, 115, 116, 49, 18, 5, 116, 97, 115, 107, 49, 160, 6, 49, 168, 6, 24,
+        218, 5, 23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32,
+        85, 84, 67, 160, 6, 49, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::background::BackgroundTaskInfo {
+        last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        task_type: BackgroundTaskType::SQL,
+        task_state: BackgroundTaskState::FAILED,
+        message: "task failed".to_string(),
+        compaction_task_stats: None,
+        vacuum_stats: None,
+        manual_trigger: None,
+        creator: Some(BackgroundJobIdent {
+            tenant: "test1".to_string(),
+            name: "task1".to_string(),
+        }),
+        created_at: Default::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 49, want())
+}
+
+#[test]
+fn test_decode_v49_background_job() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 23, 8, 2, 26, 13, 48, 32, 42, 47, 53, 32, 42, 32, 42, 32, 42, 32, 42, 160, 6, 49, 168,
+        6, 24, 18, 39, 16, 2, 34, 4, 97, 49, 98, 50, 42, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+        56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 49, 168, 6, 24, 24, 2, 42,
+        23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+        67, 50, 31, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32, 116, 49, 32, 83, 69, 76, 69,
+        67, 84, 32, 42, 32, 70, 82, 79, 77, 32, 115, 49, 58, 5, 114, 111, 108, 101, 49, 210, 5, 15,
+        10, 4, 116, 101, 115, 116, 18, 1, 37, 160, 6, 49, 168, 6, 24, 218, 5, 23, 49, 57, 55, 48,
+        45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 49,
+        168, 6, 24,
+    ];
+
+    let want = || common_meta_app::background::BackgroundJobInfo {
+        job_params: Some(BackgroundJobParams {
+            job_type: BackgroundJobType::CRON,
+            scheduled_job_interval: std::time::Duration::from_secs(0),
+            scheduled_job_cron: "0 */5 * * * *".to_string(),
+            scheduled_job_timezone: None,
+            manual_trigger_params: None,
+        }),
+        job_status: Some(BackgroundJobStatus {
+            job_state: BackgroundJobState::SUSPENDED,
+            last_task_id: Some("a1b2".to_string()),
+            last_task_run_at: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+            next_task_scheduled_time: None,
+        }),
+        task_type: BackgroundTaskType::SQL,
+        last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        message: "".to_string(),
+        sql: Some("INSERT INTO t1 SELECT * FROM s1".to_string()),
+        owner_role: Some("role1".to_string()),
+        creator: Some(UserIdentity::new("test", "%")),
+        created_at: Default::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 49, want())
+}
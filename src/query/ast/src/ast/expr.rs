@@ -235,6 +235,8 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// Parameter placeholder `?` of a prepared statement
+    Placeholder { span: Span },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -294,7 +296,9 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Interval,
     String,
+    Binary,
     Array(Box<TypeName>),
     Map {
         key_type: Box<TypeName>,
@@ -502,7 +506,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::Placeholder { span } => *span,
         }
     }
 }
@@ -706,9 +711,15 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
             TypeName::Array(ty) => {
                 write!(f, "ARRAY({})", ty)?;
             }
@@ -1141,6 +1152,9 @@ impl Display for Expr {
             Expr::DateTrunc { unit, date, .. } => {
                 write!(f, "DATE_TRUNC({unit}, {date})")?;
             }
+            Expr::Placeholder { .. } => {
+                write!(f, "?")?;
+            }
         }
 
         Ok(())
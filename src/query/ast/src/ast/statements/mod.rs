@@ -23,13 +23,17 @@ mod hint;
 mod index;
 mod insert;
 mod kill;
+mod merge_into;
+mod prepare;
 mod presign;
 mod replace;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
+mod task;
 mod unset;
 mod update;
 mod user;
@@ -47,13 +51,17 @@ pub use hint::*;
 pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use merge_into::*;
+pub use prepare::*;
 pub use presign::*;
 pub use replace::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
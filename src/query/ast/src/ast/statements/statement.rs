@@ -42,6 +42,11 @@ pub enum Statement {
     Copy(CopyStmt),
     Call(CallStmt),
 
+    // Prepared statements
+    Prepare(PrepareStmt),
+    Execute(ExecuteStmt),
+    Deallocate(DeallocateStmt),
+
     ShowSettings {
         like: Option<String>,
     },
@@ -69,6 +74,10 @@ pub enum Statement {
 
     UnSetVariable(UnSetStmt),
 
+    Begin,
+    Commit,
+    Rollback,
+
     SetRole {
         is_default: bool,
         role_name: String,
@@ -85,6 +94,8 @@ pub enum Statement {
 
     Update(UpdateStmt),
 
+    MergeInto(MergeIntoStmt),
+
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
     ShowCreateCatalog(ShowCreateCatalogStmt),
@@ -128,10 +139,29 @@ pub enum Statement {
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Materialized views
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
+
+    // Tasks
+    CreateTask(CreateTaskStmt),
+    AlterTask(AlterTaskStmt),
+    DropTask(DropTaskStmt),
+    ShowTasks {
+        limit: Option<ShowLimit>,
+    },
+    ExecuteTask(ExecuteTaskStmt),
+
     // Indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumns(CreateVirtualColumnsStmt),
@@ -305,6 +335,7 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -339,6 +370,9 @@ impl Display for Statement {
                 }
                 write!(f, " '{object_id}'")?;
             }
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Rollback => write!(f, "ROLLBACK")?,
             Statement::SetVariable {
                 is_global,
                 variable,
@@ -394,9 +428,25 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTask(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowTasks { limit } => {
+                write!(f, "SHOW TASKS")?;
+                if let Some(limit) = limit {
+                    write!(f, " {limit}")?;
+                }
+            }
+            Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumns(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumns(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumns(stmt) => write!(f, "{stmt}")?,
@@ -528,6 +578,9 @@ impl Display for Statement {
             }
             Statement::ShowFileFormats => write!(f, "SHOW FILE FORMATS")?,
             Statement::Call(stmt) => write!(f, "{stmt}")?,
+            Statement::Prepare(stmt) => write!(f, "{stmt}")?,
+            Statement::Execute(stmt) => write!(f, "{stmt}")?,
+            Statement::Deallocate(stmt) => write!(f, "{stmt}")?,
             Statement::Presign(stmt) => write!(f, "{stmt}")?,
             Statement::CreateShareEndpoint(stmt) => write!(f, "{stmt}")?,
             Statement::ShowShareEndpoint(stmt) => write!(f, "{stmt}")?,
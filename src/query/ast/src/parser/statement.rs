@@ -147,6 +147,37 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge = map(
+        rule! {
+            MERGE ~ #hint? ~ INTO ~ #period_separated_idents_1_to_3 ~ #table_alias?
+            ~ USING ~ #merge_source
+            ~ ON ~ ^#expr ~ (#merge_option)+
+        },
+        |(
+            _,
+            opt_hints,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            Statement::MergeInto(MergeIntoStmt {
+                hints: opt_hints,
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                merge_options,
+            })
+        },
+    );
+
     let delete = map(
         rule! {
             DELETE ~ #hint? ~ FROM ~ #table_reference_only
@@ -212,6 +243,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let rollback = value(Statement::Rollback, rule! { ROLLBACK });
+
     let set_variable = map(
         rule! {
             SET ~ (GLOBAL)? ~ #ident ~ "=" ~ #subexpr(0)
@@ -503,7 +538,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #period_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
-            ~ ( #uri_location )?
+            ~ ( LOCATION ~ ^"=" )? ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
@@ -516,6 +551,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             (catalog, database, table),
             source,
             engine,
+            _,
             uri_location,
             opt_cluster_by,
             opt_table_options,
@@ -712,6 +748,125 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+    let create_task = map(
+        rule! {
+            CREATE ~ TASK ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ SCHEDULE ~ "=" ~ #literal_string
+            ~ AS ~ #statement
+        },
+        |(_, _, opt_if_not_exists, name, _, _, schedule, _, statement)| {
+            Statement::CreateTask(CreateTaskStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                schedule,
+                sql: Box::new(statement.stmt),
+            })
+        },
+    );
+    let alter_task = map(
+        rule! {
+            ALTER ~ TASK ~ ( IF ~ EXISTS )? ~ #ident ~ #alter_task_action
+        },
+        |(_, _, opt_if_exists, name, action)| {
+            Statement::AlterTask(AlterTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+                action,
+            })
+        },
+    );
+    let drop_task = map(
+        rule! {
+            DROP ~ TASK ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTask(DropTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let show_tasks = map(
+        rule! {
+            SHOW ~ TASKS ~ #show_limit?
+        },
+        |(_, _, limit)| Statement::ShowTasks { limit },
+    );
+    let execute_task = map(
+        rule! {
+            EXECUTE ~ TASK ~ #ident
+        },
+        |(_, _, name)| Statement::ExecuteTask(ExecuteTaskStmt { name }),
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -772,6 +927,40 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, opt_if_not_exists, index_name, _, (catalog, database, table), _, columns, _)| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_columns = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMNS ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #period_separated_idents_1_to_3
@@ -1103,6 +1292,30 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let prepare = map(
+        rule! {
+            PREPARE ~ #ident ~ FROM ~ #literal_string
+        },
+        |(_, name, _, sql)| Statement::Prepare(PrepareStmt { name, sql }),
+    );
+    let execute = map(
+        rule! {
+            EXECUTE ~ #ident ~ ( USING ~ #comma_separated_list1(expr) )?
+        },
+        |(_, name, opt_params)| {
+            Statement::Execute(ExecuteStmt {
+                name,
+                params: opt_params.map(|(_, params)| params).unwrap_or_default(),
+            })
+        },
+    );
+    let deallocate = map(
+        rule! {
+            DEALLOCATE ~ PREPARE? ~ #ident
+        },
+        |(_, _, name)| Statement::Deallocate(DeallocateStmt { name }),
+    );
+
     let call = map(
         rule! {
             CALL ~ #ident ~ "(" ~ #comma_separated_list0(parameter_to_string) ~ ")"
@@ -1346,10 +1559,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge : "`MERGE INTO <target_table> [AS <alias>] USING <source> ON <join_expr> { WHEN MATCHED [AND <condition>] THEN { UPDATE SET <column> = <expr> [, ...] | DELETE } | WHEN NOT MATCHED [AND <condition>] THEN INSERT [(<column>, ...)] VALUES (<expr>, ...) } [...]`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
+            | #rollback : "`ROLLBACK`"
         ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
@@ -1377,11 +1594,24 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+            | #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [COMMENT = '<comment>']`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+        ),
+        rule!(
+            #create_task : "`CREATE TASK [IF NOT EXISTS] <task> SCHEDULE = '<cron_expr>' AS <statement>`"
+            | #alter_task : "`ALTER TASK [IF EXISTS] <task> {SUSPEND | RESUME}`"
+            | #drop_task : "`DROP TASK [IF EXISTS] <task>`"
+            | #show_tasks : "`SHOW TASKS [<show_limit>]`"
+            | #execute_task : "`EXECUTE TASK <task>`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...)`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_columns: "`CREATE VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
@@ -1463,6 +1693,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         | #create_catalog: "`CREATE CATALOG [IF NOT EXISTS] <catalog> TYPE=<catalog_type> CONNECTION=<catalog_options>`"
         | #drop_catalog: "`DROP CATALOG [IF EXISTS] <catalog>`"
         ),
+        // prepared statement, must be after `EXECUTE TASK`
+        rule!(
+            #prepare : "`PREPARE <name> FROM '<sql>'`"
+            | #execute : "`EXECUTE <name> [USING <expr>, ...]`"
+            | #deallocate : "`DEALLOCATE [PREPARE] <name>`"
+        ),
     ));
 
     map(
@@ -1861,6 +2097,13 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
     )(i)
 }
 
+pub fn alter_task_action(i: Input) -> IResult<AlterTaskAction> {
+    alt((
+        value(AlterTaskAction::Suspend, rule! { SUSPEND }),
+        value(AlterTaskAction::Resume, rule! { RESUME }),
+    ))(i)
+}
+
 pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     let rename_table = map(
         rule! {
@@ -2123,6 +2366,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::Delta, rule! { DELTA }),
     ));
 
     map(
@@ -2323,3 +2567,84 @@ pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
         UpdateExpr { name, expr }
     })(i)
 }
+
+pub fn merge_update_expr(i: Input) -> IResult<MergeUpdateExpr> {
+    map(rule! { ( #ident ~ "=" ~ ^#expr ) }, |(name, _, expr)| {
+        MergeUpdateExpr { name, expr }
+    })(i)
+}
+
+pub fn merge_source(i: Input) -> IResult<TableReference> {
+    let source_table = map(
+        consumed(rule! {
+            #period_separated_idents_1_to_3 ~ #table_alias?
+        }),
+        |(span, ((catalog, database, table), alias))| TableReference::Table {
+            span: transform_span(span.0),
+            catalog,
+            database,
+            table,
+            alias,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        },
+    );
+    let source_subquery = map(
+        consumed(rule! {
+            "(" ~ #query ~ ")" ~ #table_alias?
+        }),
+        |(span, (_, subquery, _, alias))| TableReference::Subquery {
+            span: transform_span(span.0),
+            subquery: Box::new(subquery),
+            alias,
+        },
+    );
+
+    rule!(
+        #source_subquery
+        | #source_table
+    )(i)
+}
+
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let match_operation = alt((
+        value(MatchOperation::Delete, rule! { DELETE }),
+        map(
+            rule! { UPDATE ~ SET ~ ^#comma_separated_list1(merge_update_expr) },
+            |(_, _, update_list)| MatchOperation::Update { update_list },
+        ),
+    ));
+    let matched_clause = map(
+        rule! {
+            WHEN ~ MATCHED ~ (AND ~ ^#expr)? ~ THEN ~ #match_operation
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched_clause = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ (AND ~ ^#expr)? ~ THEN ~ INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                insert_operation: InsertOperation {
+                    columns: opt_columns.map(|(_, columns, _)| columns),
+                    values,
+                },
+            })
+        },
+    );
+
+    rule!(
+        #matched_clause
+        | #unmatched_clause
+    )(i)
+}
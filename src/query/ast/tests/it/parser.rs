@@ -78,6 +78,10 @@ fn test_statement() {
         r#"show columns in t from ctl.db"#,
         r#"show full columns from t from db like 'id%'"#,
         r#"show processlist;"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"commit;"#,
+        r#"rollback;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
@@ -96,6 +100,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
              location_prefix = 'db';"#,
+        r#"create table t engine = delta location = 's3://testbucket/delta/';"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
@@ -112,6 +117,16 @@ fn test_statement() {
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
         r#"drop view v;"#,
+        r#"create materialized view if not exists db.mv as select a from t;"#,
+        r#"refresh materialized view mv;"#,
+        r#"create stream if not exists test.s on table test.t comment = 'this is a stream';"#,
+        r#"drop stream if exists s;"#,
+        r#"create task if not exists t1 schedule = '0 */5 * * * *' as truncate table a;"#,
+        r#"alter task t1 suspend;"#,
+        r#"alter task if exists t1 resume;"#,
+        r#"drop task if exists t1;"#,
+        r#"show tasks;"#,
+        r#"execute task t1;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
         r#"rename table d.t to e.s;"#,
@@ -359,6 +374,9 @@ fn test_statement() {
         //         size_limit=10;"#,
         r#"CALL system$test(a)"#,
         r#"CALL system$test('a')"#,
+        r#"PREPARE s1 FROM 'SELECT * FROM t WHERE a = ?'"#,
+        r#"EXECUTE s1 USING 1, 'a'"#,
+        r#"DEALLOCATE PREPARE s1"#,
         r#"show settings like 'enable%'"#,
         r#"PRESIGN @my_stage"#,
         r#"PRESIGN @my_stage/path/to/dir/"#,
@@ -415,6 +433,8 @@ fn test_statement() {
         r#"ALTER VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMNS FOR t"#,
         r#"GENERATE VIRTUAL COLUMNS FOR t"#,
+        r#"CREATE INVERTED INDEX idx ON t (msg, content)"#,
+        r#"DROP INVERTED INDEX IF EXISTS idx ON db.t"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- xxxxx\n  select 34343;",
     ];
@@ -621,6 +641,7 @@ fn test_expr() {
         r#"substring(a from b for c)"#,
         r#"substring(a, b, c)"#,
         r#"col1::UInt8"#,
+        r#"INTERVAL '3 days 2 hours'"#,
         r#"(arr[0]:a).b"#,
         r#"arr[4]["k"]"#,
         r#"a rlike '^11'"#,
@@ -653,6 +674,8 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        // prepared statement placeholder
+        r#"a = ?"#,
     ];
 
     for case in cases {
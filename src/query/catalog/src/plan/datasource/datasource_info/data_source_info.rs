@@ -20,6 +20,7 @@ use common_meta_app::schema::TableInfo;
 use crate::plan::ParquetTableInfo;
 use crate::plan::ResultScanTableInfo;
 use crate::plan::StageTableInfo;
+use crate::plan::StreamTableInfo;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub enum DataSourceInfo {
@@ -31,6 +32,8 @@ pub enum DataSourceInfo {
     ParquetSource(ParquetTableInfo),
     // Table Function Result_Scan
     ResultScanSource(ResultScanTableInfo),
+    // The changes of a fuse table read by a stream.
+    StreamSource(StreamTableInfo),
 }
 
 impl DataSourceInfo {
@@ -40,6 +43,7 @@ impl DataSourceInfo {
             DataSourceInfo::StageSource(table_info) => table_info.schema(),
             DataSourceInfo::ParquetSource(table_info) => table_info.schema(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.schema(),
+            DataSourceInfo::StreamSource(table_info) => table_info.schema(),
         }
     }
 
@@ -49,6 +53,7 @@ impl DataSourceInfo {
             DataSourceInfo::StageSource(table_info) => table_info.desc(),
             DataSourceInfo::ParquetSource(table_info) => table_info.desc(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.desc(),
+            DataSourceInfo::StreamSource(table_info) => table_info.desc(),
         }
     }
 }
@@ -17,9 +17,11 @@ mod parquet;
 mod parquet_read_options;
 mod result_scan;
 mod stage;
+mod stream;
 
 pub use data_source_info::DataSourceInfo;
 pub use parquet::ParquetTableInfo;
 pub use parquet_read_options::ParquetReadOptions;
 pub use result_scan::ResultScanTableInfo;
 pub use stage::StageTableInfo;
+pub use stream::StreamTableInfo;
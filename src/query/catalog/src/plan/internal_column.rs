@@ -24,12 +24,15 @@ use common_expression::BlockEntry;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
+use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::FromData;
 use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::Value;
 use common_expression::BLOCK_NAME_COLUMN_ID;
+use common_expression::CHANGE_ACTION_COLUMN_ID;
+use common_expression::CHANGE_ROW_ID_COLUMN_ID;
 use common_expression::ROW_ID_COLUMN_ID;
 use common_expression::SEGMENT_NAME_COLUMN_ID;
 use common_expression::SNAPSHOT_NAME_COLUMN_ID;
@@ -90,6 +93,10 @@ pub struct InternalColumnMeta {
     pub snapshot_location: String,
     /// The row offsets in the block.
     pub offsets: Option<Vec<usize>>,
+    /// The block is removed from the table, used by the change columns of a stream.
+    pub is_deleted: bool,
+    /// The ids of the changed rows, generated when the changes of a stream are read.
+    pub change_row_ids: Option<Vec<String>>,
 }
 
 #[typetag::serde(name = "internal_column_meta")]
@@ -127,6 +134,8 @@ pub enum InternalColumnType {
     BlockName,
     SegmentName,
     SnapshotName,
+    ChangeAction,
+    ChangeRowId,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -153,6 +162,8 @@ impl InternalColumn {
             InternalColumnType::BlockName => TableDataType::String,
             InternalColumnType::SegmentName => TableDataType::String,
             InternalColumnType::SnapshotName => TableDataType::String,
+            InternalColumnType::ChangeAction => TableDataType::String,
+            InternalColumnType::ChangeRowId => TableDataType::String,
         }
     }
 
@@ -171,6 +182,8 @@ impl InternalColumn {
             InternalColumnType::BlockName => BLOCK_NAME_COLUMN_ID,
             InternalColumnType::SegmentName => SEGMENT_NAME_COLUMN_ID,
             InternalColumnType::SnapshotName => SNAPSHOT_NAME_COLUMN_ID,
+            InternalColumnType::ChangeAction => CHANGE_ACTION_COLUMN_ID,
+            InternalColumnType::ChangeRowId => CHANGE_ROW_ID_COLUMN_ID,
         }
     }
 
@@ -227,6 +240,28 @@ impl InternalColumn {
                     Value::Scalar(Scalar::String(builder.build_scalar())),
                 )
             }
+            InternalColumnType::ChangeAction => {
+                let action = if meta.is_deleted { "DELETE" } else { "INSERT" };
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(action.as_bytes().to_vec())),
+                )
+            }
+            InternalColumnType::ChangeRowId => {
+                let row_ids = meta.change_row_ids.as_deref().unwrap_or_default();
+                let mut builder = StringColumnBuilder::with_capacity(
+                    num_rows,
+                    row_ids.iter().map(|row_id| row_id.len()).sum(),
+                );
+                for row_id in row_ids {
+                    builder.put_str(row_id);
+                    builder.commit_row();
+                }
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Column(Column::String(builder.build())),
+                )
+            }
         }
     }
 }
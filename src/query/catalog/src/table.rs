@@ -40,6 +40,7 @@ use common_storage::StorageMetrics;
 
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
+use crate::plan::InternalColumn;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
@@ -124,6 +125,11 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// The change columns of the table, like `change$action`, only streams have them.
+    fn change_columns(&self) -> Vec<InternalColumn> {
+        vec![]
+    }
+
     #[async_backtrace::framed]
     async fn alter_table_cluster_keys(
         &self,
@@ -391,6 +397,11 @@ pub trait TableExt: Table {
             tenant: "".to_owned(),
             db_type: DatabaseType::NormalDB,
         };
+        let table_info = ctx
+            .txn_mgr()
+            .lock()
+            .apply_table_update(&table_info)
+            .unwrap_or(table_info);
         catalog.get_table_by_info(&table_info)
     }
 }
@@ -29,6 +29,7 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_pipeline_core::InputError;
 use common_profile::QueryProfileManager;
 use common_settings::ChangeValue;
@@ -44,6 +45,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -143,6 +145,15 @@ pub trait TableContext: Send + Sync {
     // Get the storage data accessor operator from the session manager.
     fn get_data_operator(&self) -> Result<DataOperator>;
 
+    // Get the transaction state of the session.
+    fn txn_mgr(&self) -> TxnManagerRef;
+
+    // The new offsets of the streams consumed by the query, they are advanced together
+    // with the table the query commits to.
+    fn add_stream_update(&self, req: UpdateTableMetaReq);
+    fn get_stream_updates(&self) -> Vec<UpdateTableMetaReq>;
+    fn clear_stream_updates(&self);
+
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxnState {
+    /// Each statement commits its own changes.
+    #[default]
+    AutoCommit,
+    /// Inside `BEGIN`, the table meta updates are buffered until `COMMIT`.
+    Active,
+    /// A statement of the transaction failed, the transaction can only be rolled back.
+    Fail,
+}
+
+/// The transaction state of a session.
+///
+/// While a transaction is active, the table meta updates produced by the statements are
+/// buffered here instead of being sent to the meta service, and `COMMIT` updates all of them
+/// in one meta transaction.
+#[derive(Default)]
+pub struct TxnManager {
+    state: TxnState,
+    /// Buffered table meta updates, keyed by the table id.
+    table_updates: BTreeMap<u64, TxnTableUpdate>,
+    /// Tables truncated in the transaction, keyed by the table id.
+    truncated_tables: BTreeMap<u64, TxnTableTruncate>,
+}
+
+/// The latest table meta of a table updated in the transaction.
+#[derive(Clone, Debug)]
+pub struct TxnTableUpdate {
+    pub catalog: String,
+    pub req: UpdateTableMetaReq,
+}
+
+/// A table truncated in the transaction, its copied files are removed after `COMMIT`.
+#[derive(Clone, Debug)]
+pub struct TxnTableTruncate {
+    pub catalog: String,
+    pub table_info: TableInfo,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    /// End the transaction and drop the buffered updates.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.table_updates.clear();
+        self.truncated_tables.clear();
+    }
+
+    /// Buffer the update of a table. The version to match stays the one the transaction
+    /// first read, so that the updates of others are detected on commit.
+    pub fn update_table_meta(&mut self, catalog: &str, req: UpdateTableMetaReq) {
+        match self.table_updates.get_mut(&req.table_id) {
+            None => {
+                self.table_updates.insert(req.table_id, TxnTableUpdate {
+                    catalog: catalog.to_string(),
+                    req,
+                });
+            }
+            Some(update) => {
+                update.req.new_table_meta = req.new_table_meta;
+                if let Some(copied_files) = req.copied_files {
+                    match &mut update.req.copied_files {
+                        None => update.req.copied_files = Some(copied_files),
+                        Some(buffered) => {
+                            buffered.file_info.extend(copied_files.file_info);
+                            buffered.expire_at = copied_files.expire_at;
+                            buffered.fail_if_duplicated |= copied_files.fail_if_duplicated;
+                        }
+                    }
+                }
+                if req.deduplicated_label.is_some() {
+                    update.req.deduplicated_label = req.deduplicated_label;
+                }
+            }
+        }
+    }
+
+    /// Record that the table is truncated, the meta update itself is buffered by
+    /// [`TxnManager::update_table_meta`].
+    pub fn truncate_table(&mut self, catalog: &str, table_info: &TableInfo) {
+        self.truncated_tables
+            .entry(table_info.ident.table_id)
+            .or_insert_with(|| TxnTableTruncate {
+                catalog: catalog.to_string(),
+                table_info: table_info.clone(),
+            });
+    }
+
+    /// Apply the buffered update of the table, so that the statements in the transaction
+    /// can see the changes of the previous ones.
+    pub fn apply_table_update(&self, table_info: &TableInfo) -> Option<TableInfo> {
+        self.table_updates
+            .get(&table_info.ident.table_id)
+            .map(|update| {
+                let mut table_info = table_info.clone();
+                table_info.meta = update.req.new_table_meta.clone();
+                table_info
+            })
+    }
+
+    pub fn table_updates(&self) -> Vec<TxnTableUpdate> {
+        self.table_updates.values().cloned().collect()
+    }
+
+    pub fn truncated_tables(&self) -> Vec<TxnTableTruncate> {
+        self.truncated_tables.values().cloned().collect()
+    }
+}
@@ -31,6 +31,7 @@ use common_meta_app::background::BackgroundJobIdent;
 use common_meta_app::background::BackgroundJobInfo;
 use common_meta_app::background::BackgroundJobParams;
 use common_meta_app::background::BackgroundJobState;
+use common_meta_app::background::BackgroundJobType;
 use common_meta_app::background::CreateBackgroundJobReq;
 use common_meta_app::background::GetBackgroundJobReq;
 use common_meta_app::background::ManualTriggerParams;
@@ -51,6 +52,7 @@ use tracing::warn;
 use crate::background_service::session::create_session;
 use crate::background_service::CompactionJob;
 use crate::background_service::JobScheduler;
+use crate::background_service::SqlTaskLoader;
 
 pub struct RealBackgroundService {
     conf: InnerConfig,
@@ -164,6 +166,15 @@ impl RealBackgroundService {
             .await?;
             scheduler.add_job(compactor_job).await?;
         }
+        // tasks created by `CREATE TASK` are loaded from the meta store on every tick,
+        // except for a one shot background service which exits once its jobs finish.
+        let one_shot = conf.background.compaction.enable
+            && conf.background.compaction.params.job_type == BackgroundJobType::ONESHOT;
+        if !one_shot {
+            scheduler.set_job_loader(Arc::new(SqlTaskLoader::create(
+                conf.query.tenant_id.clone(),
+            )));
+        }
 
         let rm = RealBackgroundService {
             conf: conf.clone(),
@@ -49,6 +49,13 @@ pub trait Job: JobClone {
     async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()>;
 }
 
+/// Loads the jobs that are defined at runtime, such as the tasks created by `CREATE TASK`.
+#[async_trait]
+pub trait JobLoader: Send + Sync {
+    /// Returns all the jobs currently defined, the scheduler drops the jobs not returned.
+    async fn load_jobs(&self) -> Result<Vec<BoxedJob>>;
+}
+
 pub trait JobClone {
     fn box_clone(&self) -> BoxedJob;
 }
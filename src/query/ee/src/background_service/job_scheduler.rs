@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -28,13 +29,18 @@ use common_meta_app::background::BackgroundJobState;
 use common_meta_app::background::BackgroundJobType;
 use dashmap::DashMap;
 use tracing::info;
+use tracing::warn;
 
 use crate::background_service::job::BoxedJob;
 use crate::background_service::job::Job;
+use crate::background_service::job::JobLoader;
 
 pub struct JobScheduler {
     one_shot_jobs: DashMap<String, BoxedJob>,
     scheduled_jobs: DashMap<String, BoxedJob>,
+    // Jobs from the job loader, reloaded on every tick.
+    loaded_jobs: DashMap<String, BoxedJob>,
+    job_loader: Option<Arc<dyn JobLoader>>,
     pub job_tick_interval: Duration,
     pub finish_tx: Arc<Mutex<Sender<u64>>>,
     pub finish_rx: Arc<Mutex<Receiver<u64>>>,
@@ -56,6 +62,8 @@ impl JobScheduler {
         Self {
             one_shot_jobs: DashMap::new(),
             scheduled_jobs: DashMap::new(),
+            loaded_jobs: DashMap::new(),
+            job_loader: None,
             job_tick_interval: Duration::from_secs(5),
             finish_tx: Arc::new(Mutex::new(finish_tx)),
             finish_rx: Arc::new(Mutex::new(finish_rx)),
@@ -68,9 +76,15 @@ impl JobScheduler {
     pub fn get_scheduled_job(&self, job_name: &str) -> Option<BoxedJob> {
         self.scheduled_jobs
             .get(job_name)
+            .or_else(|| self.loaded_jobs.get(job_name))
             .map(|job| job.value().box_clone())
     }
 
+    /// Sets the loader of the jobs scheduled besides the ones added by [add_job](Self::add_job).
+    pub fn set_job_loader(&mut self, loader: Arc<dyn JobLoader>) {
+        self.job_loader = Some(loader);
+    }
+
     pub async fn add_job(&mut self, job: impl Job + Send + Sync + Clone + 'static) -> Result<()> {
         let info = &job.get_info().await?;
         if info.job_params.is_none() {
@@ -110,7 +124,7 @@ impl JobScheduler {
 
     pub async fn start_scheduled_jobs(&self, tick_duration: std::time::Duration) -> Result<()> {
         let scheduled_jobs = &self.scheduled_jobs;
-        if scheduled_jobs.is_empty() {
+        if scheduled_jobs.is_empty() && self.job_loader.is_none() {
             return Ok(());
         }
         let mut job_interval = tokio::time::interval(tick_duration);
@@ -131,9 +145,33 @@ impl JobScheduler {
             }
             job_interval.tick().await;
             Self::check_and_run_jobs(scheduled_jobs).await;
+            self.reload_jobs().await;
+            Self::check_and_run_jobs(&self.loaded_jobs).await;
         }
         Ok(())
     }
+
+    async fn reload_jobs(&self) {
+        let loader = match &self.job_loader {
+            Some(loader) => loader,
+            None => return,
+        };
+        match loader.load_jobs().await {
+            Ok(jobs) => {
+                let names = jobs
+                    .iter()
+                    .map(|job| job.get_name().name)
+                    .collect::<HashSet<_>>();
+                self.loaded_jobs.retain(|name, _| names.contains(name));
+                for job in jobs {
+                    self.loaded_jobs.insert(job.get_name().name, job);
+                }
+            }
+            Err(e) => {
+                warn!(background = true, "failed to load jobs: {:?}", e);
+            }
+        }
+    }
     async fn check_and_run_jobs(jobs: &DashMap<String, BoxedJob>) {
         let job_futures = jobs
             .iter()
@@ -143,7 +181,10 @@ impl JobScheduler {
             })
             .collect::<Vec<_>>();
         for job in job_futures {
-            job.await.unwrap();
+            // a job may be dropped after it is loaded
+            if let Err(e) = job.await {
+                warn!(background = true, "failed to check and run job: {:?}", e);
+            }
         }
     }
     // Checks and runs a single [Job](crate::Job)
@@ -184,6 +225,10 @@ impl JobScheduler {
 
         let job_params = &job_info.job_params.clone().unwrap();
         let job_status = &job_info.job_status.clone().unwrap();
+        // a pending manual trigger runs the job once, even if it is suspended
+        if job_params.manual_trigger_params.is_some() {
+            return true;
+        }
         if job_status.job_state == BackgroundJobState::FAILED
             || job_status.job_state == BackgroundJobState::SUSPENDED
         {
@@ -17,9 +17,13 @@ mod compaction_job;
 mod job;
 mod job_scheduler;
 mod session;
+mod sql_task_job;
 
 pub use background_service_handler::RealBackgroundService;
 pub use compaction_job::should_continue_compaction;
 pub use compaction_job::CompactionJob;
 pub use job::Job;
+pub use job::JobLoader;
 pub use job_scheduler::JobScheduler;
+pub use sql_task_job::SqlTaskJob;
+pub use sql_task_job::SqlTaskLoader;
@@ -0,0 +1,234 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::uuid::Uuid;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundTaskIdent;
+use common_meta_app::background::BackgroundTaskInfo;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::ListBackgroundJobsReq;
+use common_meta_app::background::UpdateBackgroundJobParamsReq;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_meta_app::background::UpdateBackgroundTaskReq;
+use common_meta_store::MetaStore;
+use common_users::UserApiProvider;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+use futures_util::TryStreamExt;
+use tracing::error;
+use tracing::info;
+
+use crate::background_service::job::BoxedJob;
+use crate::background_service::job::Job;
+use crate::background_service::job::JobLoader;
+use crate::background_service::session::create_session;
+
+const EXPIRE_SEC: u64 = 60 * 60 * 24 * 7; // 7 days
+
+/// A job that runs the statement of a task created by `CREATE TASK`.
+///
+/// Each run is recorded as a background task, and the statement runs as the
+/// creator of the task under the role the task was created with.
+#[derive(Clone)]
+pub struct SqlTaskJob {
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+}
+
+#[async_trait::async_trait]
+impl Job for SqlTaskJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name = ?self.creator.clone(), "SQL task started");
+        if let Err(e) = self.do_sql_task().await {
+            error!(background = true, job_name = ?self.creator.clone(), "SQL task failed: {:?}", e);
+        }
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl SqlTaskJob {
+    pub fn create(tenant: String, name: String) -> Self {
+        let creator = BackgroundJobIdent { tenant, name };
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Self { meta_api, creator }
+    }
+
+    async fn do_sql_task(&mut self) -> Result<()> {
+        let job_info = self.get_info().await?;
+
+        // consume the manual trigger, so that it runs the task only once
+        let mut params = job_info.job_params.clone().unwrap_or_default();
+        let manual = params.manual_trigger_params.take();
+        self.update_job_params(params).await?;
+
+        let id = Uuid::new_v4().to_string();
+        let mut status = job_info.job_status.clone().unwrap_or_default();
+        status.last_task_id = Some(id.clone());
+        status.last_task_run_at = Some(Utc::now());
+        self.update_job_status(status).await?;
+
+        let task_name = BackgroundTaskIdent {
+            tenant: self.creator.tenant.clone(),
+            task_id: id.clone(),
+        };
+        let mut info = BackgroundTaskInfo::new_sql_task(self.creator.clone(), manual);
+        self.update_task(&task_name, &info).await?;
+
+        info!(
+            job = "sql_task",
+            background = true,
+            id = id.clone(),
+            "start task"
+        );
+        let res = self.execute_sql(&job_info).await;
+        match &res {
+            Ok(_) => {
+                info.task_state = BackgroundTaskState::DONE;
+                info!(
+                    job = "sql_task",
+                    background = true,
+                    id = id.clone(),
+                    "finish task"
+                );
+            }
+            Err(e) => {
+                info.task_state = BackgroundTaskState::FAILED;
+                info.message = format!("task failed: {}", e);
+            }
+        }
+        info.last_updated = Some(Utc::now());
+        self.update_task(&task_name, &info).await?;
+        res
+    }
+
+    async fn execute_sql(&self, job_info: &BackgroundJobInfo) -> Result<()> {
+        let (sql, creator) = match (&job_info.sql, &job_info.creator) {
+            (Some(sql), Some(creator)) => (sql.clone(), creator.clone()),
+            _ => {
+                return Err(ErrorCode::UnknownBackgroundJob(format!(
+                    "background job {} is not a task",
+                    self.creator
+                )));
+            }
+        };
+
+        let user = UserApiProvider::instance()
+            .get_user(&self.creator.tenant, creator)
+            .await?;
+        let session = create_session().await?;
+        session
+            .set_authed_user(user, job_info.owner_role.clone())
+            .await?;
+
+        let ctx = session.create_query_context().await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, plan_extras) = planner.plan_sql(sql.as_str()).await?;
+        ctx.attach_query_str(plan.to_string(), plan_extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        let _: Vec<DataBlock> = stream.try_collect().await?;
+        Ok(())
+    }
+
+    async fn update_task(
+        &self,
+        name: &BackgroundTaskIdent,
+        info: &BackgroundTaskInfo,
+    ) -> Result<()> {
+        self.meta_api
+            .update_background_task(UpdateBackgroundTaskReq {
+                task_name: name.clone(),
+                task_info: info.clone(),
+                expire_at: Utc::now().timestamp() as u64 + EXPIRE_SEC,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+/// Loads a [SqlTaskJob] for every task of the tenant.
+pub struct SqlTaskLoader {
+    tenant: String,
+    meta_api: Arc<MetaStore>,
+}
+
+impl SqlTaskLoader {
+    pub fn create(tenant: String) -> Self {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Self { tenant, meta_api }
+    }
+}
+
+#[async_trait::async_trait]
+impl JobLoader for SqlTaskLoader {
+    async fn load_jobs(&self) -> Result<Vec<BoxedJob>> {
+        let jobs = self
+            .meta_api
+            .list_background_jobs(ListBackgroundJobsReq {
+                tenant: self.tenant.clone(),
+            })
+            .await?;
+        Ok(jobs
+            .into_iter()
+            .filter(|(_, _, info)| info.task_type == BackgroundTaskType::SQL)
+            .map(|(_, name, _)| Box::new(SqlTaskJob::create(self.tenant.clone(), name)) as BoxedJob)
+            .collect())
+    }
+}
@@ -26,10 +26,13 @@ use common_exception::Result;
 use common_meta_app::background::BackgroundJobIdent;
 use common_meta_app::background::BackgroundJobInfo;
 use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobState;
 use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::ManualTriggerParams;
 use common_meta_app::principal::UserIdentity;
 use databend_query::test_kits::TestFixture;
 use enterprise_query::background_service::Job;
+use enterprise_query::background_service::JobLoader;
 use enterprise_query::background_service::JobScheduler;
 
 #[derive(Clone)]
@@ -46,6 +49,8 @@ fn new_info(params: BackgroundJobParams, status: BackgroundJobStatus) -> Backgro
         task_type: Default::default(),
         last_updated: None,
         message: "".to_string(),
+        sql: None,
+        owner_role: None,
         creator: None,
         created_at: Default::default(),
     }
@@ -129,6 +134,45 @@ async fn test_interval_job() -> Result<()> {
     Ok(())
 }
 
+struct TestJobLoader {
+    job: TestJob,
+}
+
+#[async_trait::async_trait]
+impl JobLoader for TestJobLoader {
+    async fn load_jobs(&self) -> Result<Vec<Box<dyn Job + Send + Sync>>> {
+        Ok(vec![Box::new(self.job.clone())])
+    }
+}
+
+// test jobs from a job loader are scheduled without any job added
+#[tokio::test(flavor = "multi_thread")]
+async fn test_loaded_job() -> Result<()> {
+    let _ = TestFixture::new().await;
+
+    let mut scheduler = JobScheduler::new();
+    scheduler.job_tick_interval = Duration::from_millis(5);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let job = TestJob {
+        counter: counter.clone(),
+        info: BackgroundJobInfo::new_compactor_job(
+            BackgroundJobParams::new_interval_job(Duration::from_millis(10)),
+            UserIdentity::default(),
+        ),
+        finish_tx: scheduler.finish_tx.clone(),
+    };
+    scheduler.set_job_loader(Arc::new(TestJobLoader { job }));
+    let suspend_tx = scheduler.suspend_tx.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let _ = suspend_tx.lock().await.send(()).await;
+    });
+    scheduler.start().await?;
+    assert!(scheduler.get_scheduled_job("test").is_some());
+    assert!(counter.load(std::sync::atomic::Ordering::Relaxed) > 5);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_should_run_job() -> Result<()> {
     let current_time = chrono::Utc::now();
@@ -163,6 +207,24 @@ async fn test_should_run_job() -> Result<()> {
         false
     ));
 
+    // a manual trigger runs the job before its scheduled time, even if it is suspended
+    let mut params = BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(1000));
+    params.manual_trigger_params = Some(ManualTriggerParams::new(
+        "id".to_string(),
+        UserIdentity::default(),
+    ));
+    let triggered_job = new_info(params, BackgroundJobStatus {
+        job_state: BackgroundJobState::SUSPENDED,
+        last_task_id: None,
+        last_task_run_at: None,
+        next_task_scheduled_time: Some(current_time + chrono::Duration::seconds(1)),
+    });
+    assert!(JobScheduler::should_run_job(
+        &triggered_job,
+        current_time,
+        false
+    ));
+
     Ok(())
 }
 
@@ -29,6 +29,7 @@ use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::DataField;
 use crate::DataSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
@@ -40,7 +41,7 @@ impl From<&DataType> for ArrowDataType {
         match ty {
             DataType::Null => ArrowDataType::Null,
             DataType::Boolean => ArrowDataType::Boolean,
-            DataType::String | DataType::Bitmap => ArrowDataType::LargeBinary,
+            DataType::String | DataType::Bitmap | DataType::Binary => ArrowDataType::LargeBinary,
             DataType::Number(ty) => with_number_type!(|TYPE| match ty {
                 NumberDataType::TYPE => ArrowDataType::TYPE,
             }),
@@ -52,6 +53,7 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Duration(TimeUnit::Microsecond),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -144,6 +146,9 @@ impl From<&DataField> for ArrowField {
             DataType::Bitmap => {
                 metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BITMAP.to_string());
             }
+            DataType::Binary => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BINARY.to_string());
+            }
             _ => Default::default(),
         };
         match ty {
@@ -201,6 +206,7 @@ impl TryFrom<&ArrowField> for DataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(DataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(DataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(DataType::Bitmap),
+            Some(ARROW_EXT_TYPE_BINARY) => Some(DataType::Binary),
             _ => None,
         };
 
@@ -230,6 +236,8 @@ impl TryFrom<&ArrowField> for DataType {
             ArrowDataType::Float64 => DataType::Number(NumberDataType::Float64),
             ArrowDataType::Timestamp(_unit, _tz) => DataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => DataType::Date,
+            // The values of the other units are converted to microseconds by `Column::from_arrow`.
+            ArrowDataType::Duration(_unit) => DataType::Interval,
             ArrowDataType::Utf8
             | ArrowDataType::LargeUtf8
             | ArrowDataType::Binary
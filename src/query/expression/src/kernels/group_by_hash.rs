@@ -642,6 +642,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
         }
         Column::Timestamp(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Date(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::Interval(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             BinaryWrite::write_uvarint(vec, data.len() as u64).unwrap();
@@ -649,7 +650,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
                 serialize_column_binary(&data, i, vec);
             }
         }
-        Column::Bitmap(v) => {
+        Column::Bitmap(v) | Column::Binary(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
         Column::Nullable(c) => {
@@ -795,7 +796,7 @@ pub fn fixed_hash(
                 }
             }
         }
-        Column::Timestamp(c) => {
+        Column::Timestamp(c) | Column::Interval(c) => {
             let mut ptr = ptr;
             match nulls {
                 Some((offsize, Some(bitmap))) => {
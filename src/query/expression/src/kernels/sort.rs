@@ -222,6 +222,22 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_binary(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Binary)
+        .as_binary()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Binary)
+        .as_binary()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| {
+        let l = unsafe { left.index_unchecked(i) };
+        let r = unsafe { right.index_unchecked(j) };
+        l.cmp(r)
+    }))
+}
+
 fn compare_decimal256(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     let left = left
         .as_any()
@@ -242,6 +258,8 @@ fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparat
         ArrowType::Extension(name, _, _) => {
             if name == "Variant" {
                 compare_variant(left, right)
+            } else if name == "Binary" {
+                compare_binary(left, right)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
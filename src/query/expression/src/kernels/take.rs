@@ -17,6 +17,7 @@ use itertools::Itertools;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
 use crate::types::map::KvColumnBuilder;
@@ -107,6 +108,14 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::take_arg_types::<NumberType<i64>, _>(column, indices)
+                    .into_number()
+                    .unwrap()
+                    .into_int64()
+                    .unwrap();
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -133,6 +142,7 @@ impl Column {
                 Self::take_value_types::<MapType<AnyType, AnyType>, _>(&column, builder, indices)
             }
             Column::Bitmap(column) => Self::take_arg_types::<BitmapType, _>(column, indices),
+            Column::Binary(column) => Self::take_arg_types::<BinaryType, _>(column, indices),
             Column::Nullable(c) => {
                 let column = c.column.take(indices);
                 let validity = Self::take_arg_types::<BooleanType, _>(&c.validity, indices);
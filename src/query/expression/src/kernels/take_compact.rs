@@ -16,6 +16,7 @@ use common_exception::Result;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
@@ -132,6 +133,14 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::take_compacted_arg_types::<NumberType<i64>>(column, indices, row_num)
+                    .into_number()
+                    .unwrap()
+                    .into_int64()
+                    .unwrap();
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(row_num + 1);
                 offsets.push(0);
@@ -162,6 +171,9 @@ impl Column {
             Column::Bitmap(column) => {
                 Self::take_compacted_arg_types::<BitmapType>(column, indices, row_num)
             }
+            Column::Binary(column) => {
+                Self::take_compacted_arg_types::<BinaryType>(column, indices, row_num)
+            }
             Column::Nullable(c) => {
                 let column = c.column.take_compacted_indices(indices, row_num);
                 let validity =
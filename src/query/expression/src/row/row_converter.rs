@@ -117,7 +117,7 @@ impl RowConverter {
                         .iter_mut()
                         .for_each(|x| *x += i256::ENCODED_LEN as u64),
                 },
-                DataType::Timestamp => lengths
+                DataType::Timestamp | DataType::Interval => lengths
                     .iter_mut()
                     .for_each(|x| *x += i64::ENCODED_LEN as u64),
                 DataType::Date => lengths
@@ -146,6 +146,29 @@ impl RowConverter {
                             })
                     }
                 }
+                DataType::Binary => {
+                    let col = col.remove_nullable();
+                    if all_null {
+                        lengths.iter_mut().for_each(|x| *x += 1)
+                    } else if let Some(validity) = validity {
+                        col.as_binary()
+                            .unwrap()
+                            .iter()
+                            .zip(validity.iter())
+                            .zip(lengths.iter_mut())
+                            .for_each(|((bytes, v), length)| {
+                                *length += variable::encoded_len(bytes, !v) as u64
+                            })
+                    } else {
+                        col.as_binary()
+                            .unwrap()
+                            .iter()
+                            .zip(lengths.iter_mut())
+                            .for_each(|(bytes, length)| {
+                                *length += variable::encoded_len(bytes, false) as u64
+                            })
+                    }
+                }
                 DataType::Variant => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -231,7 +254,9 @@ fn encode_column(out: &mut StringColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::String(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
+        Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::Variant(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         _ => unimplemented!(),
     }
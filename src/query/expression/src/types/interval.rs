@@ -0,0 +1,312 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_MILLI;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+pub const MICROS_IN_A_WEEK: i64 = 7 * MICROS_IN_A_DAY;
+
+/// An interval is a fixed-length duration represented by a number of microseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = i64;
+    type ScalarRef<'a> = i64;
+    type Column = Buffer<i64>;
+    type Domain = SimpleDomain<i64>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i64>>;
+    type ColumnBuilder = Vec<i64>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i64) -> i64 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i64>> {
+        domain.as_interval().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i64>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: i64::MIN,
+            max: i64::MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parse an interval string such as `3 days 2 hours`, `-1 hour 30 minutes` or `1 day 02:00:00`.
+///
+/// Units of variable length (months and years) are rejected because an interval is stored
+/// as a fixed number of microseconds.
+pub fn string_to_interval(interval_str: impl AsRef<[u8]>) -> Result<i64, String> {
+    let s = std::str::from_utf8(interval_str.as_ref())
+        .map_err(|_| "invalid utf-8 in interval string".to_string())?;
+    let mut tokens = s.split_whitespace().peekable();
+    if tokens.peek().is_none() {
+        return Err(format!("invalid interval string: '{s}'"));
+    }
+
+    let mut micros: i64 = 0;
+    while let Some(token) = tokens.next() {
+        let value = if token.contains(':') {
+            parse_time_part(token)
+        } else {
+            let unit = match tokens.next() {
+                Some(unit) => unit,
+                None => return Err(format!("missing unit in interval string: '{s}'")),
+            };
+            parse_unit_part(token, unit)
+        }
+        .map_err(|e| format!("invalid interval string '{s}': {e}"))?;
+        micros = micros
+            .checked_add(value)
+            .ok_or_else(|| "interval is out of range".to_string())?;
+    }
+    Ok(micros)
+}
+
+fn parse_unit_part(number: &str, unit: &str) -> Result<i64, String> {
+    let factor = match unit.to_ascii_lowercase().as_str() {
+        "microsecond" | "microseconds" | "us" => 1,
+        "millisecond" | "milliseconds" | "ms" => MICROS_IN_A_MILLI,
+        "second" | "seconds" | "sec" | "secs" | "s" => MICROS_IN_A_SEC,
+        "minute" | "minutes" | "min" | "mins" | "m" => MICROS_IN_A_MINUTE,
+        "hour" | "hours" | "hr" | "hrs" | "h" => MICROS_IN_AN_HOUR,
+        "day" | "days" | "d" => MICROS_IN_A_DAY,
+        "week" | "weeks" | "w" => MICROS_IN_A_WEEK,
+        "month" | "months" | "mon" | "mons" | "year" | "years" | "y" => {
+            return Err(format!("unit '{unit}' has no fixed length"));
+        }
+        _ => return Err(format!("unknown unit '{unit}'")),
+    };
+    match number.parse::<i64>() {
+        Ok(n) => n
+            .checked_mul(factor)
+            .ok_or_else(|| "interval is out of range".to_string()),
+        Err(_) => {
+            let n = number
+                .parse::<f64>()
+                .map_err(|_| format!("invalid number '{number}'"))?;
+            let value = (n * factor as f64).round();
+            if value.is_finite() && value >= i64::MIN as f64 && value <= i64::MAX as f64 {
+                Ok(value as i64)
+            } else {
+                Err("interval is out of range".to_string())
+            }
+        }
+    }
+}
+
+fn parse_time_part(time: &str) -> Result<i64, String> {
+    let (negative, time) = match time.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, time.strip_prefix('+').unwrap_or(time)),
+    };
+    let parts = time.split(':').collect::<Vec<_>>();
+    if parts.len() != 2 && parts.len() != 3 {
+        return Err(format!("invalid time '{time}'"));
+    }
+    let hours = parts[0]
+        .parse::<i64>()
+        .map_err(|_| format!("invalid time '{time}'"))?;
+    let minutes = parts[1]
+        .parse::<i64>()
+        .map_err(|_| format!("invalid time '{time}'"))?;
+    let seconds = match parts.get(2) {
+        Some(seconds) => {
+            let seconds = seconds
+                .parse::<f64>()
+                .map_err(|_| format!("invalid time '{time}'"))?;
+            (seconds * MICROS_IN_A_SEC as f64).round() as i64
+        }
+        None => 0,
+    };
+    if !(0..60).contains(&minutes) || !(0..60 * MICROS_IN_A_SEC).contains(&seconds) {
+        return Err(format!("invalid time '{time}'"));
+    }
+    let micros = hours
+        .checked_mul(MICROS_IN_AN_HOUR)
+        .and_then(|v| v.checked_add(minutes * MICROS_IN_A_MINUTE + seconds))
+        .ok_or_else(|| "interval is out of range".to_string())?;
+    Ok(if negative { -micros } else { micros })
+}
+
+/// Format an interval as `[-]N days [-]HH:MM:SS[.ffffff]`, which can be parsed back by
+/// [`string_to_interval`].
+pub fn interval_to_string(micros: i64) -> String {
+    let negative = micros < 0;
+    let abs = micros.unsigned_abs();
+    let days = abs / MICROS_IN_A_DAY as u64;
+    let rest = abs % MICROS_IN_A_DAY as u64;
+    let hours = rest / MICROS_IN_AN_HOUR as u64;
+    let minutes = rest % MICROS_IN_AN_HOUR as u64 / MICROS_IN_A_MINUTE as u64;
+    let seconds = rest % MICROS_IN_A_MINUTE as u64 / MICROS_IN_A_SEC as u64;
+    let fraction = rest % MICROS_IN_A_SEC as u64;
+
+    let sign = if negative { "-" } else { "" };
+    let mut buf = String::new();
+    if days > 0 {
+        let unit = if days == 1 { "day" } else { "days" };
+        write!(buf, "{sign}{days} {unit}").unwrap();
+        if rest == 0 {
+            return buf;
+        }
+        buf.push(' ');
+    }
+    write!(buf, "{sign}{hours:02}:{minutes:02}:{seconds:02}").unwrap();
+    if fraction > 0 {
+        write!(buf, ".{fraction:06}").unwrap();
+    }
+    buf
+}
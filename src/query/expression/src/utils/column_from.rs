@@ -57,6 +57,7 @@ macro_rules! for_common_scalar_values {
             { StringType },
             { DateType },
             { TimestampType },
+            { IntervalType },
             { VariantType },
             { BitmapType }
         }
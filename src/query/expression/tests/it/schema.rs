@@ -14,9 +14,15 @@
 
 use std::collections::BTreeMap;
 
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::TimeUnit;
 use common_exception::Result;
 use common_expression::create_test_complex_schema;
+use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
+use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -566,3 +572,24 @@ fn test_leaf_columns_of() -> Result<()> {
     assert_eq!(schema.leaf_columns_of("d"), vec![5,6]);
     assert_eq!(schema.leaf_columns_of("e"), vec![7]);
 }
+
+#[test]
+fn test_interval_from_arrow_duration() -> Result<()> {
+    let cases = vec![
+        (TimeUnit::Second, vec![1, -2], vec![1_000_000, -2_000_000]),
+        (TimeUnit::Millisecond, vec![1, -2], vec![1_000, -2_000]),
+        (TimeUnit::Microsecond, vec![1, -2], vec![1, -2]),
+        (TimeUnit::Nanosecond, vec![1_000, -2_000], vec![1, -2]),
+    ];
+    for (unit, values, expected) in cases {
+        let arrow_type = ArrowDataType::Duration(unit);
+        let field = ArrowField::new("a", arrow_type.clone(), false);
+        assert_eq!(TableDataType::from(&field), TableDataType::Interval);
+
+        let array = PrimitiveArray::<i64>::from_vec(values).to(arrow_type);
+        let column = Column::from_arrow(&array, &DataType::Interval);
+        assert_eq!(column.as_interval().unwrap().to_vec(), expected);
+    }
+
+    Ok(())
+}
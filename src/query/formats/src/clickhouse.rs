@@ -23,6 +23,7 @@ const SUFFIX_WITH_NAMES: &str = "withnames";
 const SUFFIX_COMPACT: &str = "compact";
 const SUFFIX_STRINGS: &str = "strings";
 const SUFFIX_EACHROW: &str = "eachrow";
+const SUFFIX_STREAM: &str = "stream";
 
 #[derive(Default, Clone)]
 pub struct ClickhouseTypeSuffixJson {
@@ -35,6 +36,7 @@ pub struct ClickhouseTypeSuffixJson {
 pub struct ClickhouseSuffix {
     pub headers: usize,
     pub json: Option<ClickhouseTypeSuffixJson>,
+    pub stream: bool,
 }
 
 #[derive(Default, Clone)]
@@ -85,6 +87,10 @@ impl ClickhouseFormatType {
             }
         }
 
+        if base.starts_with("arrow") {
+            (base, suffixes.stream) = try_remove_suffix(base, SUFFIX_STREAM);
+        }
+
         let format_type = StageFileFormatType::from_str(base).map_err(ErrorCode::UnknownFormat)?;
 
         Ok(ClickhouseFormatType {
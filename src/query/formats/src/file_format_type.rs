@@ -20,11 +20,14 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_settings::Settings;
 
+use crate::output_format::ArrowOutputFormat;
+use crate::output_format::AvroOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
 use crate::output_format::JSONOutputFormat;
 use crate::output_format::NDJSONOutputFormatBase;
+use crate::output_format::OrcOutputFormat;
 use crate::output_format::OutputFormat;
 use crate::output_format::ParquetOutputFormat;
 use crate::output_format::TSVOutputFormat;
@@ -45,6 +48,7 @@ pub struct FileFormatOptionsExt {
     pub disable_variant_check: bool,
     pub timezone: Tz,
     pub is_select: bool,
+    pub arrow_stream: bool,
 }
 
 impl FileFormatOptionsExt {
@@ -61,6 +65,7 @@ impl FileFormatOptionsExt {
             disable_variant_check: false,
             timezone,
             is_select,
+            arrow_stream: false,
         };
         Ok(options)
     }
@@ -78,9 +83,11 @@ impl FileFormatOptionsExt {
             disable_variant_check: false,
             timezone,
             is_select: false,
+            arrow_stream: false,
         };
         let suf = &clickhouse_type.suffixes;
         options.headers = suf.headers;
+        options.arrow_stream = suf.stream;
         if let Some(json) = &suf.json {
             options.json_compact = json.is_compact;
             options.json_strings = json.is_strings;
@@ -157,6 +164,13 @@ impl FileFormatOptionsExt {
             }
             FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
+            FileFormatParams::Arrow(_) => Box::new(ArrowOutputFormat::create(schema, self)),
+            FileFormatParams::Avro(params) => {
+                Box::new(AvroOutputFormat::try_create(schema, params, self)?)
+            }
+            FileFormatParams::Orc(params) => {
+                Box::new(OrcOutputFormat::try_create(schema, params, self)?)
+            }
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
                     "Unsupported output file format:{:?}",
@@ -173,7 +187,7 @@ impl FileFormatTypeExt for StageFileFormatType {
         match self {
             StageFileFormatType::Tsv => "text/tab-separated-values; charset=UTF-8",
             StageFileFormatType::Csv => "text/csv; charset=UTF-8",
-            StageFileFormatType::Parquet => "application/octet-stream",
+            StageFileFormatType::Parquet | StageFileFormatType::Arrow => "application/octet-stream",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
             _ => "text/plain; charset=UTF-8",
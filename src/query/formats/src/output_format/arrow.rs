@@ -0,0 +1,143 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Arrow IPC output.
+///
+/// The file format (Feather V2) needs a footer with the offsets of all record batches,
+/// so blocks are buffered until `finalize`, like parquet.
+/// The stream format writes out each block as soon as it is serialized.
+pub struct ArrowOutputFormat {
+    schema: ArrowSchema,
+    stream: Option<ArrowStreamState>,
+    data_blocks: Vec<DataBlock>,
+}
+
+struct ArrowStreamState {
+    buffer: SharedBuffer,
+    writer: StreamWriter<SharedBuffer>,
+    started: bool,
+}
+
+impl ArrowOutputFormat {
+    pub fn create(schema: TableSchemaRef, options: &FileFormatOptionsExt) -> Self {
+        let stream = if options.arrow_stream {
+            let buffer = SharedBuffer::default();
+            let writer = StreamWriter::new(buffer.clone(), WriteOptions { compression: None });
+            Some(ArrowStreamState {
+                buffer,
+                writer,
+                started: false,
+            })
+        } else {
+            None
+        };
+        Self {
+            schema: schema.to_arrow(),
+            stream,
+            data_blocks: vec![],
+        }
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        match &mut self.stream {
+            Some(state) => {
+                state.start(&self.schema)?;
+                state.writer.write(&Chunk::try_from(block.clone())?, None)?;
+                Ok(state.buffer.take())
+            }
+            None => {
+                self.data_blocks.push(block.clone());
+                Ok(vec![])
+            }
+        }
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        if let Some(state) = &mut self.stream {
+            state.start(&self.schema)?;
+            state.writer.finish()?;
+            return Ok(state.buffer.take());
+        }
+
+        let blocks = std::mem::take(&mut self.data_blocks);
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+        let mut writer = FileWriter::new(&mut buf, self.schema.clone(), None, WriteOptions {
+            compression: None,
+        });
+        writer.start()?;
+        for block in blocks {
+            writer.write(&Chunk::try_from(block)?, None)?;
+        }
+        writer.finish()?;
+        Ok(buf)
+    }
+}
+
+impl ArrowStreamState {
+    /// The schema message is written lazily, so an empty result is still a valid stream.
+    fn start(&mut self, schema: &ArrowSchema) -> Result<()> {
+        if !self.started {
+            self.writer.start(schema, None)?;
+            self.started = true;
+        }
+        Ok(())
+    }
+}
+
+/// Lets the bytes written by `StreamWriter` be taken out after each message.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use apache_avro::types::Value;
+use apache_avro::Codec;
+use apache_avro::Decimal;
+use apache_avro::Schema;
+use apache_avro::Writer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberColumn;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::AvroFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Avro object container output.
+///
+/// Each call of `finalize` produces a complete container file holding the blocks
+/// serialized since the last call.
+pub struct AvroOutputFormat {
+    table_schema: TableSchemaRef,
+    schema: Schema,
+    field_names: Vec<String>,
+    codec: Codec,
+    data_blocks: Vec<DataBlock>,
+}
+
+impl AvroOutputFormat {
+    pub fn try_create(
+        table_schema: TableSchemaRef,
+        params: &AvroFileFormatParams,
+        _options: &FileFormatOptionsExt,
+    ) -> Result<Self> {
+        let codec = avro_codec(params.compression)?;
+        let mut builder = AvroSchemaBuilder::default();
+        let mut field_names = Vec::with_capacity(table_schema.num_fields());
+        let mut fields = Vec::with_capacity(table_schema.num_fields());
+        for field in table_schema.fields() {
+            let name = avro_name(field.name());
+            fields.push(builder.field(&name, field.data_type())?);
+            field_names.push(name);
+        }
+        let json = json!({
+            "type": "record",
+            "name": "record",
+            "fields": fields,
+        });
+        let schema = Schema::parse_str(&json.to_string()).map_err(|e| {
+            ErrorCode::Internal(format!("fail to build avro schema for unloading: {e}"))
+        })?;
+        Ok(Self {
+            table_schema,
+            schema,
+            field_names,
+            codec,
+            data_blocks: vec![],
+        })
+    }
+}
+
+impl OutputFormat for AvroOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        self.data_blocks.push(block.clone());
+        Ok(vec![])
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let blocks = std::mem::take(&mut self.data_blocks);
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut writer = Writer::with_codec(&self.schema, Vec::new(), self.codec);
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let mut columns = Vec::with_capacity(block.num_columns());
+            for (entry, field) in block.columns().iter().zip(self.table_schema.fields()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                columns.push(column_to_values(&column, field.data_type())?.into_iter());
+            }
+            for _ in 0..num_rows {
+                let record = self
+                    .field_names
+                    .iter()
+                    .zip(columns.iter_mut())
+                    .map(|(name, values)| (name.clone(), values.next().unwrap()))
+                    .collect::<Vec<_>>();
+                writer
+                    .append(Value::Record(record))
+                    .map_err(|e| ErrorCode::Internal(format!("fail to write avro record: {e}")))?;
+            }
+        }
+        writer
+            .into_inner()
+            .map_err(|e| ErrorCode::Internal(format!("fail to write avro file: {e}")))
+    }
+}
+
+fn avro_codec(compression: StageFileCompression) -> Result<Codec> {
+    match compression {
+        StageFileCompression::None | StageFileCompression::Auto => Ok(Codec::Null),
+        StageFileCompression::Deflate
+        | StageFileCompression::RawDeflate
+        | StageFileCompression::Gzip => Ok(Codec::Deflate),
+        StageFileCompression::Snappy => Ok(Codec::Snappy),
+        StageFileCompression::Zstd => Ok(Codec::Zstandard),
+        StageFileCompression::Bz2 => Ok(Codec::Bzip2),
+        StageFileCompression::Xz => Ok(Codec::Xz),
+        other => Err(ErrorCode::InvalidArgument(format!(
+            "compression {:?} is not supported by avro, \
+            expect one of NONE, DEFLATE, SNAPPY, ZSTD, BZ2 and XZ",
+            other
+        ))),
+    }
+}
+
+/// Avro names must match `[A-Za-z_][A-Za-z0-9_]*`.
+fn avro_name(name: &str) -> String {
+    let mut res = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if !res.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        res.insert(0, '_');
+    }
+    res
+}
+
+/// Builds the json representation of the avro schema.
+///
+/// Named types (records of tuples) must be unique in a schema, so they are numbered.
+#[derive(Default)]
+struct AvroSchemaBuilder {
+    num_records: usize,
+}
+
+impl AvroSchemaBuilder {
+    fn field(&mut self, name: &str, data_type: &TableDataType) -> Result<JsonValue> {
+        let typ = self.build(data_type)?;
+        if data_type.is_nullable() {
+            Ok(json!({"name": name, "type": typ, "default": null}))
+        } else {
+            Ok(json!({"name": name, "type": typ}))
+        }
+    }
+
+    fn build(&mut self, data_type: &TableDataType) -> Result<JsonValue> {
+        let typ = match data_type {
+            TableDataType::Nullable(inner) => {
+                let inner = self.build(inner)?;
+                json!(["null", inner])
+            }
+            TableDataType::Null => json!("null"),
+            TableDataType::EmptyArray => json!({"type": "array", "items": "null"}),
+            TableDataType::EmptyMap => json!({"type": "map", "values": "null"}),
+            TableDataType::Boolean => json!("boolean"),
+            TableDataType::Number(n) => match n {
+                NumberDataType::Int8
+                | NumberDataType::Int16
+                | NumberDataType::Int32
+                | NumberDataType::UInt8
+                | NumberDataType::UInt16 => json!("int"),
+                NumberDataType::Int64 | NumberDataType::UInt32 | NumberDataType::UInt64 => {
+                    json!("long")
+                }
+                NumberDataType::Float32 => json!("float"),
+                NumberDataType::Float64 => json!("double"),
+            },
+            TableDataType::Decimal(d) => {
+                let size = match d {
+                    DecimalDataType::Decimal128(size) | DecimalDataType::Decimal256(size) => size,
+                };
+                json!({
+                    "type": "bytes",
+                    "logicalType": "decimal",
+                    "precision": size.precision,
+                    "scale": size.scale,
+                })
+            }
+            TableDataType::String | TableDataType::Variant => json!("string"),
+            TableDataType::Binary | TableDataType::Bitmap => json!("bytes"),
+            TableDataType::Date => json!({"type": "int", "logicalType": "date"}),
+            TableDataType::Timestamp => json!({"type": "long", "logicalType": "timestamp-micros"}),
+            TableDataType::Interval => json!("long"),
+            TableDataType::Array(inner) => {
+                let items = self.build(inner)?;
+                json!({"type": "array", "items": items})
+            }
+            TableDataType::Map(inner) => match inner.as_ref() {
+                TableDataType::Tuple { fields_type, .. }
+                    if fields_type[0].remove_nullable() == TableDataType::String =>
+                {
+                    let values = self.build(&fields_type[1])?;
+                    json!({"type": "map", "values": values})
+                }
+                // avro maps only have string keys, other maps are written as arrays of entries.
+                inner => {
+                    let items = self.build(inner)?;
+                    json!({"type": "array", "items": items})
+                }
+            },
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            } => {
+                self.num_records += 1;
+                let name = format!("record_{}", self.num_records);
+                let fields = fields_name
+                    .iter()
+                    .zip(fields_type)
+                    .map(|(name, ty)| self.field(&avro_name(name), ty))
+                    .collect::<Result<Vec<_>>>()?;
+                json!({"type": "record", "name": name, "fields": fields})
+            }
+        };
+        Ok(typ)
+    }
+}
+
+/// Converts a column to avro values, row by row.
+fn column_to_values(column: &Column, data_type: &TableDataType) -> Result<Vec<Value>> {
+    let values = match (column, data_type) {
+        (Column::Nullable(c), TableDataType::Nullable(inner)) => {
+            let values = column_to_values(&c.column, inner)?;
+            values
+                .into_iter()
+                .zip(c.validity.iter())
+                .map(|(v, valid)| {
+                    if valid {
+                        Value::Union(1, Box::new(v))
+                    } else {
+                        Value::Union(0, Box::new(Value::Null))
+                    }
+                })
+                .collect()
+        }
+        (Column::Null { len }, _) => vec![Value::Null; *len],
+        (Column::EmptyArray { len }, _) => vec![Value::Array(vec![]); *len],
+        (Column::EmptyMap { len }, _) => vec![Value::Map(HashMap::new()); *len],
+        (Column::Boolean(c), _) => c.iter().map(Value::Boolean).collect(),
+        (Column::Number(c), _) => match c {
+            NumberColumn::UInt8(c) => c.iter().map(|v| Value::Int(*v as i32)).collect(),
+            NumberColumn::UInt16(c) => c.iter().map(|v| Value::Int(*v as i32)).collect(),
+            NumberColumn::UInt32(c) => c.iter().map(|v| Value::Long(*v as i64)).collect(),
+            NumberColumn::UInt64(c) => c.iter().map(|v| Value::Long(*v as i64)).collect(),
+            NumberColumn::Int8(c) => c.iter().map(|v| Value::Int(*v as i32)).collect(),
+            NumberColumn::Int16(c) => c.iter().map(|v| Value::Int(*v as i32)).collect(),
+            NumberColumn::Int32(c) => c.iter().map(|v| Value::Int(*v)).collect(),
+            NumberColumn::Int64(c) => c.iter().map(|v| Value::Long(*v)).collect(),
+            NumberColumn::Float32(c) => c.iter().map(|v| Value::Float(v.0)).collect(),
+            NumberColumn::Float64(c) => c.iter().map(|v| Value::Double(v.0)).collect(),
+        },
+        (Column::Decimal(c), _) => match c {
+            DecimalColumn::Decimal128(c, _) => c
+                .iter()
+                .map(|v| Value::Decimal(Decimal::from(v.to_be_bytes().to_vec())))
+                .collect(),
+            DecimalColumn::Decimal256(c, _) => c
+                .iter()
+                .map(|v| Value::Decimal(Decimal::from(v.to_be_bytes().to_vec())))
+                .collect(),
+        },
+        (Column::String(c), _) => c
+            .iter()
+            .map(|v| Value::String(String::from_utf8_lossy(v).into_owned()))
+            .collect(),
+        (Column::Binary(c) | Column::Bitmap(c), _) => {
+            c.iter().map(|v| Value::Bytes(v.to_vec())).collect()
+        }
+        (Column::Variant(c), _) => c
+            .iter()
+            .map(|v| Value::String(jsonb::to_string(v)))
+            .collect(),
+        (Column::Date(c), _) => c.iter().map(|v| Value::Date(*v)).collect(),
+        (Column::Timestamp(c), _) => c.iter().map(|v| Value::TimestampMicros(*v)).collect(),
+        (Column::Interval(c), _) => c.iter().map(|v| Value::Long(*v)).collect(),
+        (Column::Array(c), TableDataType::Array(inner)) => {
+            let mut values = column_to_values(&c.values, inner)?.into_iter();
+            c.offsets
+                .windows(2)
+                .map(|w| Value::Array(values.by_ref().take((w[1] - w[0]) as usize).collect()))
+                .collect()
+        }
+        (Column::Map(c), TableDataType::Map(inner)) => match (&c.values, inner.as_ref()) {
+            (Column::Tuple(kv), TableDataType::Tuple { fields_type, .. })
+                if fields_type[0].remove_nullable() == TableDataType::String =>
+            {
+                let keys = kv[0].remove_nullable();
+                let keys = keys.as_string().unwrap();
+                let mut values = column_to_values(&kv[1], &fields_type[1])?.into_iter();
+                c.offsets
+                    .windows(2)
+                    .map(|w| {
+                        let map = (w[0] as usize..w[1] as usize)
+                            .map(|i| {
+                                let key = keys.index(i).unwrap_or_default();
+                                let key = String::from_utf8_lossy(key).into_owned();
+                                (key, values.next().unwrap())
+                            })
+                            .collect::<HashMap<_, _>>();
+                        Value::Map(map)
+                    })
+                    .collect()
+            }
+            (entries, inner) => {
+                let mut values = column_to_values(entries, inner)?.into_iter();
+                c.offsets
+                    .windows(2)
+                    .map(|w| Value::Array(values.by_ref().take((w[1] - w[0]) as usize).collect()))
+                    .collect()
+            }
+        },
+        (
+            Column::Tuple(fields),
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            },
+        ) => {
+            let num_rows = fields.first().map(|c| c.len()).unwrap_or_default();
+            let mut fields = fields
+                .iter()
+                .zip(fields_type)
+                .map(|(c, ty)| Ok(column_to_values(c, ty)?.into_iter()))
+                .collect::<Result<Vec<_>>>()?;
+            let names = fields_name.iter().map(|n| avro_name(n)).collect::<Vec<_>>();
+            (0..num_rows)
+                .map(|_| {
+                    let record = names
+                        .iter()
+                        .zip(fields.iter_mut())
+                        .map(|(name, values)| (name.clone(), values.next().unwrap()))
+                        .collect();
+                    Value::Record(record)
+                })
+                .collect()
+        }
+        (c, ty) => {
+            return Err(ErrorCode::Internal(format!(
+                "column {:?} does not match type {:?} when writing avro",
+                c.data_type(),
+                ty
+            )));
+        }
+    };
+    Ok(values)
+}
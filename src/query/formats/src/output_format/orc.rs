@@ -0,0 +1,774 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::io::orc::format::proto;
+use common_arrow::arrow::io::orc::format::proto::column_encoding;
+use common_arrow::arrow::io::orc::format::proto::stream;
+use common_arrow::arrow::io::orc::format::proto::r#type;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberColumn;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::OrcFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use prost::Message;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+const ORC_MAGIC: &[u8] = b"ORC";
+/// Blocks are grouped into stripes of about this memory size.
+const STRIPE_SIZE: usize = 64 * 1024 * 1024;
+const COMPRESSION_BLOCK_SIZE: usize = 256 * 1024;
+/// Timestamps are stored as seconds relative to 2015-01-01 00:00:00 UTC.
+const TIMESTAMP_BASE_SECONDS: i64 = 1420070400;
+/// ORC_135, timestamp statistics are in UTC.
+const WRITER_VERSION: u32 = 6;
+
+/// ORC output.
+///
+/// Columns are written with the DIRECT(_V2) encodings only, each stripe carries
+/// min/max statistics of integer, float and string columns, which can be used to
+/// prune stripes when the files are read back.
+///
+/// Unsigned 64-bit integers are stored as ORC `long`, values above `i64::MAX` wrap around.
+pub struct OrcOutputFormat {
+    schema: TableSchemaRef,
+    compression: proto::CompressionKind,
+    data_blocks: Vec<DataBlock>,
+}
+
+impl OrcOutputFormat {
+    pub fn try_create(
+        schema: TableSchemaRef,
+        params: &OrcFileFormatParams,
+        _options: &FileFormatOptionsExt,
+    ) -> Result<Self> {
+        let compression = match params.compression {
+            StageFileCompression::None | StageFileCompression::Auto => proto::CompressionKind::None,
+            StageFileCompression::Deflate
+            | StageFileCompression::RawDeflate
+            | StageFileCompression::Gzip => proto::CompressionKind::Zlib,
+            StageFileCompression::Snappy => proto::CompressionKind::Snappy,
+            StageFileCompression::Zstd => proto::CompressionKind::Zstd,
+            other => {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "compression {:?} is not supported by orc, \
+                    expect one of NONE, DEFLATE, SNAPPY and ZSTD",
+                    other
+                )));
+            }
+        };
+        // fail early on the types orc can not hold.
+        build_types(&schema)?;
+        Ok(Self {
+            schema,
+            compression,
+            data_blocks: vec![],
+        })
+    }
+}
+
+impl OutputFormat for OrcOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        self.data_blocks.push(block.clone());
+        Ok(vec![])
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let blocks = std::mem::take(&mut self.data_blocks);
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut writer = OrcFileWriter::try_create(&self.schema, self.compression)?;
+        let mut stripe = vec![];
+        let mut stripe_size = 0;
+        for block in blocks {
+            stripe_size += block.memory_size();
+            stripe.push(block);
+            if stripe_size >= STRIPE_SIZE {
+                writer.write_stripe(&DataBlock::concat(&stripe)?)?;
+                stripe.clear();
+                stripe_size = 0;
+            }
+        }
+        if !stripe.is_empty() {
+            writer.write_stripe(&DataBlock::concat(&stripe)?)?;
+        }
+        writer.finish()
+    }
+}
+
+/// Flattens the schema into ORC types, the root struct is column 0 and
+/// the other columns follow in pre-order.
+fn build_types(schema: &TableSchemaRef) -> Result<Vec<proto::Type>> {
+    let mut types = vec![proto::Type {
+        kind: Some(r#type::Kind::Struct as i32),
+        ..Default::default()
+    }];
+    for field in schema.fields() {
+        let id = push_type(&mut types, field.data_type())?;
+        types[0].subtypes.push(id);
+        types[0].field_names.push(field.name().clone());
+    }
+    Ok(types)
+}
+
+fn push_type(types: &mut Vec<proto::Type>, data_type: &TableDataType) -> Result<u32> {
+    let id = types.len();
+    types.push(proto::Type::default());
+    let mut typ = proto::Type::default();
+    let kind = match data_type.remove_nullable() {
+        TableDataType::Boolean => r#type::Kind::Boolean,
+        TableDataType::Number(n) => match n {
+            NumberDataType::Int8 => r#type::Kind::Byte,
+            NumberDataType::UInt8 | NumberDataType::Int16 => r#type::Kind::Short,
+            NumberDataType::UInt16 | NumberDataType::Int32 => r#type::Kind::Int,
+            NumberDataType::UInt32 | NumberDataType::UInt64 | NumberDataType::Int64 => {
+                r#type::Kind::Long
+            }
+            NumberDataType::Float32 => r#type::Kind::Float,
+            NumberDataType::Float64 => r#type::Kind::Double,
+        },
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            typ.precision = Some(size.precision as u32);
+            typ.scale = Some(size.scale as u32);
+            r#type::Kind::Decimal
+        }
+        TableDataType::String | TableDataType::Variant => r#type::Kind::String,
+        TableDataType::Binary | TableDataType::Bitmap => r#type::Kind::Binary,
+        TableDataType::Date => r#type::Kind::Date,
+        TableDataType::Timestamp => r#type::Kind::Timestamp,
+        TableDataType::Interval => r#type::Kind::Long,
+        TableDataType::Array(inner) => {
+            typ.subtypes.push(push_type(types, &inner)?);
+            r#type::Kind::List
+        }
+        TableDataType::Map(inner) => match *inner {
+            TableDataType::Tuple { fields_type, .. } => {
+                typ.subtypes.push(push_type(types, &fields_type[0])?);
+                typ.subtypes.push(push_type(types, &fields_type[1])?);
+                r#type::Kind::Map
+            }
+            _ => unreachable!("map type must be a tuple of key and value"),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            for ty in fields_type.iter() {
+                typ.subtypes.push(push_type(types, ty)?);
+            }
+            typ.field_names = fields_name;
+            r#type::Kind::Struct
+        }
+        other => {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "type {} is not supported by orc",
+                other
+            )));
+        }
+    };
+    typ.kind = Some(kind as i32);
+    types[id] = typ;
+    Ok(id as u32)
+}
+
+struct OrcFileWriter {
+    schema: TableSchemaRef,
+    types: Vec<proto::Type>,
+    compression: proto::CompressionKind,
+    buf: Vec<u8>,
+    stripes: Vec<proto::StripeInformation>,
+    stripe_stats: Vec<proto::StripeStatistics>,
+    file_stats: Vec<ColumnStats>,
+    num_rows: u64,
+}
+
+impl OrcFileWriter {
+    fn try_create(schema: &TableSchemaRef, compression: proto::CompressionKind) -> Result<Self> {
+        let types = build_types(schema)?;
+        let file_stats = vec![ColumnStats::default(); types.len()];
+        Ok(Self {
+            schema: schema.clone(),
+            types,
+            compression,
+            buf: ORC_MAGIC.to_vec(),
+            stripes: vec![],
+            stripe_stats: vec![],
+            file_stats,
+            num_rows: 0,
+        })
+    }
+
+    fn write_stripe(&mut self, block: &DataBlock) -> Result<()> {
+        let num_rows = block.num_rows();
+        let mut encoder = StripeEncoder::new(self.types.len());
+        encoder.stats[0].num_values = num_rows as u64;
+        encoder.encodings[0] = direct();
+        for (entry, field) in block.columns().iter().zip(self.schema.fields()) {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            encoder.encode(&column, field.data_type())?;
+        }
+
+        let offset = self.buf.len() as u64;
+        let mut streams = Vec::with_capacity(encoder.streams.len());
+        for (kind, column, data) in encoder.streams {
+            let data = self.compress(&data)?;
+            streams.push(proto::Stream {
+                kind: Some(kind as i32),
+                column: Some(column),
+                length: Some(data.len() as u64),
+                ..Default::default()
+            });
+            self.buf.extend_from_slice(&data);
+        }
+        let data_length = self.buf.len() as u64 - offset;
+        let footer = proto::StripeFooter {
+            streams,
+            columns: encoder.encodings,
+            writer_timezone: Some("UTC".to_string()),
+            ..Default::default()
+        };
+        let footer = self.compress(&footer.encode_to_vec())?;
+        self.buf.extend_from_slice(&footer);
+
+        self.stripes.push(proto::StripeInformation {
+            offset: Some(offset),
+            index_length: Some(0),
+            data_length: Some(data_length),
+            footer_length: Some(footer.len() as u64),
+            number_of_rows: Some(num_rows as u64),
+            ..Default::default()
+        });
+        for (file_stats, stats) in self.file_stats.iter_mut().zip(encoder.stats.iter()) {
+            file_stats.merge(stats);
+        }
+        self.stripe_stats.push(proto::StripeStatistics {
+            col_stats: encoder.stats.iter().map(|s| s.to_pb()).collect(),
+        });
+        self.num_rows += num_rows as u64;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Vec<u8>> {
+        let metadata = proto::Metadata {
+            stripe_stats: std::mem::take(&mut self.stripe_stats),
+        };
+        let metadata = self.compress(&metadata.encode_to_vec())?;
+        self.buf.extend_from_slice(&metadata);
+
+        let footer = proto::Footer {
+            header_length: Some(ORC_MAGIC.len() as u64),
+            content_length: Some((self.buf.len() - ORC_MAGIC.len() - metadata.len()) as u64),
+            stripes: std::mem::take(&mut self.stripes),
+            types: std::mem::take(&mut self.types),
+            number_of_rows: Some(self.num_rows),
+            statistics: self.file_stats.iter().map(|s| s.to_pb()).collect(),
+            row_index_stride: Some(0),
+            ..Default::default()
+        };
+        let footer = self.compress(&footer.encode_to_vec())?;
+        self.buf.extend_from_slice(&footer);
+
+        let compression_block_size = match self.compression {
+            proto::CompressionKind::None => None,
+            _ => Some(COMPRESSION_BLOCK_SIZE as u64),
+        };
+        let postscript = proto::PostScript {
+            footer_length: Some(footer.len() as u64),
+            compression: Some(self.compression as i32),
+            compression_block_size,
+            version: vec![0, 12],
+            metadata_length: Some(metadata.len() as u64),
+            writer_version: Some(WRITER_VERSION),
+            magic: Some(String::from_utf8_lossy(ORC_MAGIC).to_string()),
+            ..Default::default()
+        };
+        let postscript = postscript.encode_to_vec();
+        self.buf.extend_from_slice(&postscript);
+        self.buf.push(postscript.len() as u8);
+        Ok(self.buf)
+    }
+
+    /// Splits the data into chunks, each with a 3 bytes header of `length << 1 | is_original`.
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>> {
+        if self.compression == proto::CompressionKind::None {
+            return Ok(data.to_vec());
+        }
+        let mut out = Vec::with_capacity(data.len());
+        for chunk in data.chunks(COMPRESSION_BLOCK_SIZE) {
+            let compressed = match self.compression {
+                proto::CompressionKind::Zlib => {
+                    let mut encoder = flate2::write::DeflateEncoder::new(
+                        Vec::new(),
+                        flate2::Compression::default(),
+                    );
+                    encoder.write_all(chunk)?;
+                    encoder.finish()?
+                }
+                proto::CompressionKind::Snappy => snap::raw::Encoder::new()
+                    .compress_vec(chunk)
+                    .map_err(|e| ErrorCode::Internal(format!("fail to compress orc: {e}")))?,
+                proto::CompressionKind::Zstd => zstd::bulk::compress(chunk, 0)?,
+                _ => unreachable!(),
+            };
+            let (header, body) = if compressed.len() < chunk.len() {
+                (compressed.len() << 1, compressed.as_slice())
+            } else {
+                ((chunk.len() << 1) | 1, chunk)
+            };
+            out.extend_from_slice(&(header as u32).to_le_bytes()[..3]);
+            out.extend_from_slice(body);
+        }
+        Ok(out)
+    }
+}
+
+fn direct() -> proto::ColumnEncoding {
+    proto::ColumnEncoding {
+        kind: Some(column_encoding::Kind::Direct as i32),
+        ..Default::default()
+    }
+}
+
+fn direct_v2() -> proto::ColumnEncoding {
+    proto::ColumnEncoding {
+        kind: Some(column_encoding::Kind::DirectV2 as i32),
+        ..Default::default()
+    }
+}
+
+/// Min/max statistics of the non-null values of a column.
+#[derive(Clone, Default)]
+struct ColumnStats {
+    num_values: u64,
+    has_null: bool,
+    int: Option<(i64, i64)>,
+    double: Option<(f64, f64)>,
+    string: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl ColumnStats {
+    fn update_int(&mut self, v: i64) {
+        self.int = Some(match self.int {
+            Some((min, max)) => (min.min(v), max.max(v)),
+            None => (v, v),
+        });
+    }
+
+    fn update_double(&mut self, v: f64) {
+        if v.is_nan() {
+            return;
+        }
+        self.double = Some(match self.double {
+            Some((min, max)) => (min.min(v), max.max(v)),
+            None => (v, v),
+        });
+    }
+
+    fn update_string(&mut self, v: &[u8]) {
+        match &mut self.string {
+            Some((min, max)) => {
+                if v < min.as_slice() {
+                    *min = v.to_vec();
+                }
+                if v > max.as_slice() {
+                    *max = v.to_vec();
+                }
+            }
+            None => self.string = Some((v.to_vec(), v.to_vec())),
+        }
+    }
+
+    fn merge(&mut self, other: &ColumnStats) {
+        self.num_values += other.num_values;
+        self.has_null |= other.has_null;
+        if let Some((min, max)) = other.int {
+            self.update_int(min);
+            self.update_int(max);
+        }
+        if let Some((min, max)) = other.double {
+            self.update_double(min);
+            self.update_double(max);
+        }
+        if let Some((min, max)) = &other.string {
+            self.update_string(min);
+            self.update_string(max);
+        }
+    }
+
+    fn to_pb(&self) -> proto::ColumnStatistics {
+        let string_statistics = match &self.string {
+            Some((min, max)) => match (
+                String::from_utf8(min.clone()),
+                String::from_utf8(max.clone()),
+            ) {
+                (Ok(min), Ok(max)) => Some(proto::StringStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                    ..Default::default()
+                }),
+                _ => None,
+            },
+            None => None,
+        };
+        proto::ColumnStatistics {
+            number_of_values: Some(self.num_values),
+            has_null: Some(self.has_null),
+            int_statistics: self.int.map(|(min, max)| proto::IntegerStatistics {
+                minimum: Some(min),
+                maximum: Some(max),
+                ..Default::default()
+            }),
+            double_statistics: self.double.map(|(min, max)| proto::DoubleStatistics {
+                minimum: Some(min),
+                maximum: Some(max),
+                ..Default::default()
+            }),
+            string_statistics,
+            ..Default::default()
+        }
+    }
+}
+
+/// Encodes the columns of a stripe into streams, column ids are assigned in the
+/// same pre-order as `build_types`.
+struct StripeEncoder {
+    streams: Vec<(stream::Kind, u32, Vec<u8>)>,
+    encodings: Vec<proto::ColumnEncoding>,
+    stats: Vec<ColumnStats>,
+    next_id: u32,
+}
+
+impl StripeEncoder {
+    fn new(num_columns: usize) -> Self {
+        Self {
+            streams: vec![],
+            encodings: vec![direct(); num_columns],
+            stats: vec![ColumnStats::default(); num_columns],
+            next_id: 1,
+        }
+    }
+
+    fn encode(&mut self, column: &Column, data_type: &TableDataType) -> Result<()> {
+        if let TableDataType::Nullable(inner) = data_type {
+            return match column {
+                Column::Nullable(c) if c.validity.unset_bits() > 0 => {
+                    let id = self.next_id;
+                    self.stats[id as usize].has_null = true;
+                    self.streams
+                        .push((stream::Kind::Present, id, encode_bool_rle(&c.validity)));
+                    self.encode(&c.column.filter(&c.validity), inner)
+                }
+                Column::Nullable(c) => self.encode(&c.column, inner),
+                c => self.encode(c, inner),
+            };
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let stats = &mut self.stats[id as usize];
+        stats.num_values += column.len() as u64;
+        match (column, data_type) {
+            (Column::Boolean(c), _) => {
+                self.streams
+                    .push((stream::Kind::Data, id, encode_bool_rle(c)));
+            }
+            (Column::Number(NumberColumn::Int8(c)), _) => {
+                c.iter().for_each(|v| stats.update_int(*v as i64));
+                let bytes = c.iter().map(|v| *v as u8).collect::<Vec<_>>();
+                self.streams
+                    .push((stream::Kind::Data, id, encode_byte_rle(&bytes)));
+            }
+            (Column::Number(NumberColumn::Float32(c)), _) => {
+                let mut data = Vec::with_capacity(c.len() * 4);
+                for v in c.iter() {
+                    stats.update_double(v.0 as f64);
+                    data.extend_from_slice(&v.0.to_le_bytes());
+                }
+                self.streams.push((stream::Kind::Data, id, data));
+            }
+            (Column::Number(NumberColumn::Float64(c)), _) => {
+                let mut data = Vec::with_capacity(c.len() * 8);
+                for v in c.iter() {
+                    stats.update_double(v.0);
+                    data.extend_from_slice(&v.0.to_le_bytes());
+                }
+                self.streams.push((stream::Kind::Data, id, data));
+            }
+            (Column::Number(c), _) => {
+                let values = integers(c);
+                values.iter().for_each(|v| stats.update_int(*v));
+                self.encodings[id as usize] = direct_v2();
+                self.streams
+                    .push((stream::Kind::Data, id, encode_signed_rle_v2(&values)));
+            }
+            (Column::Interval(c), _) => {
+                c.iter().for_each(|v| stats.update_int(*v));
+                self.encodings[id as usize] = direct_v2();
+                self.streams
+                    .push((stream::Kind::Data, id, encode_signed_rle_v2(c)));
+            }
+            (Column::Date(c), _) => {
+                let values = c.iter().map(|v| *v as i64).collect::<Vec<_>>();
+                self.encodings[id as usize] = direct_v2();
+                self.streams
+                    .push((stream::Kind::Data, id, encode_signed_rle_v2(&values)));
+            }
+            (Column::Timestamp(c), _) => {
+                let mut seconds = Vec::with_capacity(c.len());
+                let mut nanos = Vec::with_capacity(c.len());
+                for v in c.iter() {
+                    seconds.push(v.div_euclid(1_000_000) - TIMESTAMP_BASE_SECONDS);
+                    nanos.push(encode_nanos(v.rem_euclid(1_000_000) as u64 * 1000));
+                }
+                self.encodings[id as usize] = direct_v2();
+                self.streams
+                    .push((stream::Kind::Data, id, encode_signed_rle_v2(&seconds)));
+                self.streams
+                    .push((stream::Kind::Secondary, id, encode_unsigned_rle_v2(&nanos)));
+            }
+            (Column::Decimal(DecimalColumn::Decimal128(c, size)), _) => {
+                let mut data = vec![];
+                for v in c.iter() {
+                    write_varint(&mut data, ((v << 1) ^ (v >> 127)) as u128);
+                }
+                let scales = vec![size.scale as i64; c.len()];
+                self.encodings[id as usize] = direct_v2();
+                self.streams.push((stream::Kind::Data, id, data));
+                self.streams
+                    .push((stream::Kind::Secondary, id, encode_signed_rle_v2(&scales)));
+            }
+            (Column::String(c), _) => {
+                c.iter().for_each(|v| stats.update_string(v));
+                self.encode_bytes(id, c.data().to_vec(), c.offsets());
+            }
+            (Column::Binary(c) | Column::Bitmap(c), _) => {
+                self.encode_bytes(id, c.data().to_vec(), c.offsets());
+            }
+            (Column::Variant(c), _) => {
+                let mut data = vec![];
+                let mut offsets = vec![0u64];
+                for v in c.iter() {
+                    data.extend_from_slice(jsonb::to_string(v).as_bytes());
+                    offsets.push(data.len() as u64);
+                }
+                self.encode_bytes(id, data, &offsets);
+            }
+            (Column::Array(c), TableDataType::Array(inner)) => {
+                self.encode_lengths(id, &c.offsets);
+                self.encode(&c.values, inner)?;
+            }
+            (Column::Map(c), TableDataType::Map(inner)) => {
+                self.encode_lengths(id, &c.offsets);
+                match (&c.values, inner.as_ref()) {
+                    (Column::Tuple(kv), TableDataType::Tuple { fields_type, .. }) => {
+                        self.encode(&kv[0], &fields_type[0])?;
+                        self.encode(&kv[1], &fields_type[1])?;
+                    }
+                    _ => unreachable!("map column must be a tuple of key and value"),
+                }
+            }
+            (Column::Tuple(fields), TableDataType::Tuple { fields_type, .. }) => {
+                for (c, ty) in fields.iter().zip(fields_type) {
+                    self.encode(c, ty)?;
+                }
+            }
+            (c, ty) => {
+                return Err(ErrorCode::Internal(format!(
+                    "column {:?} does not match type {:?} when writing orc",
+                    c.data_type(),
+                    ty
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_bytes(&mut self, id: u32, data: Vec<u8>, offsets: &[u64]) {
+        self.encodings[id as usize] = direct_v2();
+        self.streams.push((stream::Kind::Data, id, data));
+        self.encode_lengths(id, offsets);
+    }
+
+    fn encode_lengths(&mut self, id: u32, offsets: &[u64]) {
+        let lengths = offsets.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+        self.encodings[id as usize] = direct_v2();
+        self.streams
+            .push((stream::Kind::Length, id, encode_unsigned_rle_v2(&lengths)));
+    }
+}
+
+fn integers(column: &NumberColumn) -> Vec<i64> {
+    match column {
+        NumberColumn::UInt8(c) => c.iter().map(|v| *v as i64).collect(),
+        NumberColumn::UInt16(c) => c.iter().map(|v| *v as i64).collect(),
+        NumberColumn::UInt32(c) => c.iter().map(|v| *v as i64).collect(),
+        NumberColumn::UInt64(c) => c.iter().map(|v| *v as i64).collect(),
+        NumberColumn::Int8(c) => c.iter().map(|v| *v as i64).collect(),
+        NumberColumn::Int16(c) => c.iter().map(|v| *v as i64).collect(),
+        NumberColumn::Int32(c) => c.iter().map(|v| *v as i64).collect(),
+        NumberColumn::Int64(c) => c.to_vec(),
+        NumberColumn::Float32(_) | NumberColumn::Float64(_) => unreachable!(),
+    }
+}
+
+/// Nanoseconds with trailing decimal zeros stripped, the low 3 bits hold the number of
+/// stripped zeros minus one.
+fn encode_nanos(nanos: u64) -> u64 {
+    if nanos == 0 {
+        return 0;
+    }
+    if nanos % 100 != 0 {
+        return nanos << 3;
+    }
+    let mut nanos = nanos / 100;
+    let mut zeros = 1;
+    while nanos % 10 == 0 && zeros < 7 {
+        nanos /= 10;
+        zeros += 1;
+    }
+    (nanos << 3) | zeros
+}
+
+fn write_varint(out: &mut Vec<u8>, mut v: u128) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Runs of 3 to 130 equal bytes, or up to 128 literal bytes.
+fn encode_byte_rle(bytes: &[u8]) -> Vec<u8> {
+    let mut out = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut run = 1;
+        while i + run < bytes.len() && run < 130 && bytes[i + run] == bytes[i] {
+            run += 1;
+        }
+        if run >= 3 {
+            out.push((run - 3) as u8);
+            out.push(bytes[i]);
+            i += run;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && i - start < 128 {
+            if i + 2 < bytes.len() && bytes[i] == bytes[i + 1] && bytes[i] == bytes[i + 2] {
+                break;
+            }
+            i += 1;
+        }
+        out.push((-((i - start) as i16)) as u8);
+        out.extend_from_slice(&bytes[start..i]);
+    }
+    out
+}
+
+/// Bits are packed from the most significant bit, then byte RLE encoded.
+fn encode_bool_rle(bitmap: &Bitmap) -> Vec<u8> {
+    let mut bytes = vec![0u8; (bitmap.len() + 7) / 8];
+    for (i, v) in bitmap.iter().enumerate() {
+        if v {
+            bytes[i / 8] |= 0x80 >> (i % 8);
+        }
+    }
+    encode_byte_rle(&bytes)
+}
+
+fn encode_signed_rle_v2(values: &[i64]) -> Vec<u8> {
+    let values = values
+        .iter()
+        .map(|v| ((v << 1) ^ (v >> 63)) as u64)
+        .collect::<Vec<_>>();
+    encode_unsigned_rle_v2(&values)
+}
+
+/// Integer RLE v2 with the DIRECT sub-encoding, each run holds up to 512 values
+/// bit-packed with the smallest fixed width that fits them all.
+fn encode_unsigned_rle_v2(values: &[u64]) -> Vec<u8> {
+    let mut out = vec![];
+    for chunk in values.chunks(512) {
+        let max = chunk.iter().max().copied().unwrap_or_default();
+        let width = closest_fixed_bits(64 - max.leading_zeros());
+        let len = chunk.len() - 1;
+        out.push(0b0100_0000 | (encode_bit_width(width) << 1) as u8 | (len >> 8) as u8);
+        out.push(len as u8);
+
+        let mut current = 0u8;
+        let mut num_bits = 0;
+        for v in chunk {
+            for bit in (0..width).rev() {
+                current = (current << 1) | ((v >> bit) & 1) as u8;
+                num_bits += 1;
+                if num_bits == 8 {
+                    out.push(current);
+                    current = 0;
+                    num_bits = 0;
+                }
+            }
+        }
+        if num_bits > 0 {
+            out.push(current << (8 - num_bits));
+        }
+    }
+    out
+}
+
+fn closest_fixed_bits(n: u32) -> u32 {
+    match n {
+        0 => 1,
+        1..=24 => n,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn encode_bit_width(width: u32) -> u32 {
+    match width {
+        1..=24 => width - 1,
+        26 => 24,
+        28 => 25,
+        30 => 26,
+        32 => 27,
+        40 => 28,
+        48 => 29,
+        56 => 30,
+        _ => 31,
+    }
+}
@@ -17,10 +17,14 @@ use common_expression::TableSchemaRef;
 use common_formats::output_format::OutputFormat;
 use common_formats::ClickhouseFormatType;
 use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
 use common_settings::Settings;
 
 mod field_encoder;
+mod output_format_arrow;
+mod output_format_avro;
 mod output_format_json_each_row;
+mod output_format_orc;
 mod output_format_tcsv;
 mod output_format_utils;
 
@@ -32,3 +36,12 @@ fn get_output_format_clickhouse(
     let settings = Settings::create("default".to_string());
     FileFormatOptionsExt::get_output_format_from_clickhouse_format(format, schema, &settings)
 }
+
+fn get_output_format(
+    params: FileFormatParams,
+    schema: TableSchemaRef,
+) -> Result<Box<dyn OutputFormat>> {
+    let settings = Settings::create("default".to_string());
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    options.get_output_format(schema, params)
+}
@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use pretty_assertions::assert_eq;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_arrow_file() -> Result<()> {
+    for is_nullable in [false, true] {
+        let (schema, block) = get_simple_block(is_nullable);
+        let mut formatter = get_output_format_clickhouse("arrow", schema.clone())?;
+        assert!(formatter.serialize_block(&block)?.is_empty());
+        assert!(formatter.serialize_block(&block)?.is_empty());
+        let buffer = formatter.finalize()?;
+
+        let mut reader = Cursor::new(buffer);
+        let metadata = read_file_metadata(&mut reader)?;
+        assert_eq!(metadata.schema, schema.to_arrow());
+        let data_schema = DataSchema::from(&schema);
+        for chunk in FileReader::new(reader, metadata, None, None) {
+            let result = DataBlock::from_arrow_chunk(&chunk?, &data_schema)?;
+            assert_eq!(format!("{:?}", result), format!("{:?}", block));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_arrow_stream() -> Result<()> {
+    let (schema, block) = get_simple_block(true);
+    let mut formatter = get_output_format_clickhouse("arrowstream", schema.clone())?;
+    let mut buffer = formatter.serialize_prefix()?;
+    buffer.extend(formatter.serialize_block(&block)?);
+    buffer.extend(formatter.serialize_block(&block)?);
+    buffer.extend(formatter.finalize()?);
+
+    let mut reader = Cursor::new(buffer);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema.to_arrow());
+    let data_schema = DataSchema::from(&schema);
+    let mut num_chunks = 0;
+    for state in StreamReader::new(reader, metadata, None) {
+        if let StreamState::Some(chunk) = state? {
+            let result = DataBlock::from_arrow_chunk(&chunk, &data_schema)?;
+            assert_eq!(format!("{:?}", result), format!("{:?}", block));
+            num_chunks += 1;
+        }
+    }
+    assert_eq!(num_chunks, 2);
+    Ok(())
+}
+
+#[test]
+fn test_arrow_stream_empty() -> Result<()> {
+    let (schema, _) = get_simple_block(false);
+    let mut formatter = get_output_format_clickhouse("arrowstream", schema.clone())?;
+    let buffer = formatter.finalize()?;
+
+    let mut reader = Cursor::new(buffer);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema.to_arrow());
+    assert_eq!(StreamReader::new(reader, metadata, None).count(), 0);
+    Ok(())
+}
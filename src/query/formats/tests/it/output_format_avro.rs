@@ -0,0 +1,90 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use common_exception::Result;
+use common_meta_app::principal::AvroFileFormatParams;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use pretty_assertions::assert_eq;
+
+use crate::get_output_format;
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_avro() -> Result<()> {
+    for compression in [
+        StageFileCompression::None,
+        StageFileCompression::Deflate,
+        StageFileCompression::Snappy,
+        StageFileCompression::Zstd,
+    ] {
+        for is_nullable in [false, true] {
+            let (schema, block) = get_simple_block(is_nullable);
+            let params = FileFormatParams::Avro(AvroFileFormatParams { compression });
+            let mut formatter = get_output_format(params, schema)?;
+            assert!(formatter.serialize_block(&block)?.is_empty());
+            assert!(formatter.serialize_block(&block)?.is_empty());
+            let buffer = formatter.finalize()?;
+
+            let reader = Reader::new(buffer.as_slice()).unwrap();
+            match reader.writer_schema() {
+                Schema::Record { fields, .. } => {
+                    let names = fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+                    assert_eq!(names, vec!["c1", "c2", "c3", "c4", "c5"]);
+                }
+                other => panic!("unexpected schema {:?}", other),
+            }
+            let records = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+            assert_eq!(records.len(), 6);
+
+            let wrap = |v: Value| {
+                if is_nullable {
+                    Value::Union(1, Box::new(v))
+                } else {
+                    v
+                }
+            };
+            let expected = Value::Record(vec![
+                ("c1".to_string(), wrap(Value::Int(2))),
+                ("c2".to_string(), wrap(Value::String("b\"".to_string()))),
+                ("c3".to_string(), wrap(Value::Boolean(true))),
+                ("c4".to_string(), wrap(Value::Double(2.2))),
+                ("c5".to_string(), wrap(Value::Date(2))),
+            ]);
+            assert_eq!(records[4], expected);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_avro_empty() -> Result<()> {
+    let (schema, _) = get_simple_block(false);
+    let params = FileFormatParams::Avro(AvroFileFormatParams::default());
+    let mut formatter = get_output_format(params, schema)?;
+    assert!(formatter.finalize()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_avro_unsupported_compression() -> Result<()> {
+    let (schema, _) = get_simple_block(false);
+    let params = FileFormatParams::Avro(AvroFileFormatParams {
+        compression: StageFileCompression::Lzo,
+    });
+    assert!(get_output_format(params, schema).is_err());
+    Ok(())
+}
@@ -0,0 +1,95 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Int32Array;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::error::Error as ArrowError;
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::format::read::read_stripe_column;
+use common_arrow::arrow::io::orc::format::read::read_stripe_footer;
+use common_arrow::arrow::io::orc::read::deserialize;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OrcFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use pretty_assertions::assert_eq;
+
+use crate::get_output_format;
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_orc() -> Result<()> {
+    for compression in [
+        StageFileCompression::None,
+        StageFileCompression::Deflate,
+        StageFileCompression::Snappy,
+        StageFileCompression::Zstd,
+    ] {
+        let (schema, block) = get_simple_block(true);
+        let params = FileFormatParams::Orc(OrcFileFormatParams { compression });
+        let mut formatter = get_output_format(params, schema)?;
+        assert!(formatter.serialize_block(&block)?.is_empty());
+        assert!(formatter.serialize_block(&block)?.is_empty());
+        let buffer = formatter.finalize()?;
+
+        let mut reader = Cursor::new(buffer);
+        let meta = read_metadata(&mut reader).map_err(ArrowError::from)?;
+        assert_eq!(meta.footer.number_of_rows, Some(6));
+        assert_eq!(meta.footer.stripes.len(), 1);
+        assert_eq!(meta.footer.types.len(), 6);
+
+        // column 1 is c1, column 2 is c2
+        let stats = &meta.metadata.stripe_stats[0].col_stats;
+        let int_stats = stats[1].int_statistics.as_ref().unwrap();
+        assert_eq!((int_stats.minimum, int_stats.maximum), (Some(1), Some(3)));
+        let string_stats = stats[2].string_statistics.as_ref().unwrap();
+        assert_eq!(string_stats.minimum.as_deref(), Some("a"));
+        assert_eq!(string_stats.maximum.as_deref(), Some("c'"));
+
+        let footer =
+            read_stripe_footer(&mut reader, &meta, 0, &mut vec![]).map_err(ArrowError::from)?;
+        let column = read_stripe_column(&mut reader, &meta, 0, footer.clone(), 1, vec![])
+            .map_err(ArrowError::from)?;
+        let array = deserialize(ArrowDataType::Int32, &column)?;
+        let expected = Int32Array::from_slice([1, 2, 3, 1, 2, 3]);
+        assert_eq!(array.as_any().downcast_ref::<Int32Array>(), Some(&expected));
+
+        let column = read_stripe_column(&mut reader, &meta, 0, footer, 2, vec![])
+            .map_err(ArrowError::from)?;
+        let array = deserialize(ArrowDataType::Utf8, &column)?;
+        let expected = Utf8Array::<i32>::from_slice(["a", "b\"", "c'", "a", "b\"", "c'"]);
+        assert_eq!(
+            array.as_any().downcast_ref::<Utf8Array<i32>>(),
+            Some(&expected)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_orc_unsupported_type() -> Result<()> {
+    let (schema, _) = get_simple_block(false);
+    let schema = TableSchemaRefExt::create(vec![
+        schema.fields()[0].clone(),
+        TableField::new("n", TableDataType::Null),
+    ]);
+    let params = FileFormatParams::Orc(OrcFileFormatParams::default());
+    assert!(get_output_format(params, schema).is_err());
+    Ok(())
+}
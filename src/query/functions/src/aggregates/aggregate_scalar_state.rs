@@ -35,12 +35,14 @@ macro_rules! with_simple_no_number_mapped_type {
         match_template::match_template! {
             $t = [
                 String => StringType,
+                Binary => BinaryType,
                 Boolean => BooleanType,
                 Timestamp => TimestampType,
                 Null => NullType,
                 EmptyArray => EmptyArrayType,
                 EmptyMap => EmptyMapType,
                 Date => DateType,
+                Interval => IntervalType,
             ],
             $($tail)*
         }
@@ -250,7 +252,7 @@ where
 
 pub fn need_manual_drop_state(data_type: &DataType) -> bool {
     match data_type {
-        DataType::String | DataType::Variant => true,
+        DataType::String | DataType::Binary | DataType::Variant => true,
         DataType::Nullable(t) | DataType::Array(t) | DataType::Map(t) => need_manual_drop_state(t),
         DataType::Tuple(ts) => ts.iter().any(need_manual_drop_state),
         _ => false,
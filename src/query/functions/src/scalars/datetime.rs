@@ -25,6 +25,9 @@ use common_expression::types::date::date_to_string;
 use common_expression::types::date::string_to_date;
 use common_expression::types::date::DATE_MAX;
 use common_expression::types::date::DATE_MIN;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MICROS_IN_A_DAY;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::Int64Type;
@@ -41,6 +44,7 @@ use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DateType;
 use common_expression::types::Int32Type;
+use common_expression::types::IntervalType;
 use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -72,8 +76,12 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_timestamp_to_date(registry);
     register_number_to_date(registry);
 
-    // cast([date | timestamp] AS string)
-    // to_string([date | timestamp])
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast([date | timestamp | interval] AS string)
+    // to_string([date | timestamp | interval])
     register_to_string(registry);
 
     // cast([date | timestamp] AS [uint8 | int8 | ...])
@@ -97,6 +105,9 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // [date | timestamp | interval] +/- interval, timestamp - timestamp
+    register_interval_add_sub(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -407,6 +418,37 @@ fn register_number_to_date(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(i) => output.push(i),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`: {e}"),
+                    );
+                    output.push(0);
+                }
+            }
+        })(val, ctx)
+    }
+}
+
 fn register_to_string(registry: &mut FunctionRegistry) {
     registry.register_aliases("to_string", &["date_format"]);
     registry.register_combine_nullable_2_arg::<TimestampType, StringType, StringType, _, _>(
@@ -456,6 +498,15 @@ fn register_to_string(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            output.put_str(&interval_to_string(val));
+            output.commit_row();
+        }),
+    );
+
     registry.register_combine_nullable_1_arg::<DateType, StringType, _, _>(
         "try_to_string",
         |_, _| {
@@ -1144,7 +1195,7 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         |a, b, _| a - b,
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
         |_, lhs, rhs| {
             (|| {
@@ -1164,6 +1215,128 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
     );
 }
 
+fn register_interval_add_sub(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    push_timestamp(ts.checked_add(interval), output, ctx);
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| {
+                    push_timestamp(ts.checked_add(interval), output, ctx);
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    push_timestamp(ts.checked_sub(interval), output, ctx);
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                push_timestamp(ts.checked_add(interval), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                push_timestamp(ts.checked_add(interval), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                push_timestamp(ts.checked_sub(interval), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| {
+                push_interval(lhs.checked_add(rhs), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| {
+                push_interval(lhs.checked_sub(rhs), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            push_interval(val.checked_neg(), output, ctx);
+        }),
+    );
+
+    fn push_timestamp(ts: Option<i64>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+        match ts.ok_or_else(|| "timestamp is out of range".to_string()) {
+            Ok(ts) => match check_timestamp(ts) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+            Err(e) => {
+                ctx.set_error(output.len(), e);
+                output.push(0);
+            }
+        }
+    }
+
+    fn push_interval(interval: Option<i64>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+        match interval {
+            Some(interval) => output.push(interval),
+            None => {
+                ctx.set_error(output.len(), "interval is out of range");
+                output.push(0);
+            }
+        }
+    }
+}
+
 fn register_rounder_functions(registry: &mut FunctionRegistry) {
     // timestamp -> timestamp
     registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampType, _, _>(
@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_expression::types::BooleanType;
+use common_expression::types::Float64Type;
+use common_expression::types::StringType;
+use common_expression::types::F64;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+/// The term frequency saturation parameter of BM25.
+const BM25_K1: f64 = 1.2;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // match(doc, query) is true if the doc contains all the terms of the query.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |doc, query, output, _| {
+                let terms = tokenize(query);
+                let doc_terms = tokenize(doc).into_iter().collect::<HashSet<_>>();
+                output.push(!terms.is_empty() && terms.iter().all(|t| doc_terms.contains(t)));
+            },
+        ),
+    );
+
+    // score(doc, query) is the relevance of the doc to the query.
+    // Only the term frequency part of BM25 is used, the corpus statistics (IDF and the average
+    // doc length) are not known when a single row is evaluated.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "score",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, Float64Type>(
+            |doc, query, output, _| {
+                let mut freqs = HashMap::new();
+                for term in tokenize(doc) {
+                    *freqs.entry(term).or_insert(0u32) += 1;
+                }
+                let mut terms = tokenize(query);
+                terms.sort();
+                terms.dedup();
+                let score = terms
+                    .iter()
+                    .filter_map(|t| freqs.get(t))
+                    .map(|freq| {
+                        let freq = *freq as f64;
+                        freq * (BM25_K1 + 1.0) / (freq + BM25_K1)
+                    })
+                    .sum::<f64>();
+                output.push(F64::from(score));
+            },
+        ),
+    );
+}
+
+/// Split the text into lowercase terms at each non alphanumeric character.
+///
+/// This is the analyzer shared by the `match` function and the inverted index,
+/// they must agree on the terms for the index pruning to be correct.
+pub fn tokenize(text: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
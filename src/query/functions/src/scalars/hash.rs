@@ -23,8 +23,10 @@ use common_expression::types::number::NumberScalar;
 use common_expression::types::number::F32;
 use common_expression::types::number::F64;
 use common_expression::types::ArgType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DateType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
@@ -56,8 +58,10 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     register_simple_domain_type_hash::<VariantType>(registry);
     register_simple_domain_type_hash::<StringType>(registry);
+    register_simple_domain_type_hash::<BinaryType>(registry);
     register_simple_domain_type_hash::<DateType>(registry);
     register_simple_domain_type_hash::<TimestampType>(registry);
+    register_simple_domain_type_hash::<IntervalType>(registry);
     register_simple_domain_type_hash::<BooleanType>(registry);
 
     for ty in ALL_NUMBER_CLASSES {
@@ -23,6 +23,7 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod full_text;
 mod geo;
 mod hash;
 mod map;
@@ -36,8 +37,10 @@ mod vector;
 
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
+pub use comparison::like;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use full_text::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -58,4 +61,5 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    full_text::register(registry);
 }
@@ -18,13 +18,16 @@ use std::io::Write;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use bstr::ByteSlice;
+use common_expression::error_to_null;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::string::StringDomain;
+use common_expression::types::BinaryType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
+use common_expression::vectorize_1_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::vectorize_with_builder_3_arg;
@@ -312,20 +315,6 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
-        "from_base64",
-        |_, _| FunctionDomain::MayThrow,
-        vectorize_string_to_string(
-            |col| col.data().len() * 4 / 3 + col.len() * 4,
-            |val, output, ctx| {
-                if let Err(err) = general_purpose::STANDARD.decode_vec(val, &mut output.data) {
-                    ctx.set_error(output.len(), err.to_string());
-                }
-                output.commit_row();
-            },
-        ),
-    );
-
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "quote",
         |_, _| FunctionDomain::Full,
@@ -709,6 +698,104 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         }),
     );
+
+    register_binary(registry);
+}
+
+fn register_binary(registry: &mut FunctionRegistry) {
+    registry.register_aliases("hex", &["to_hex"]);
+
+    // String and Binary share the same physical layout, so the casts
+    // between them only reinterpret the bytes.
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_, _| FunctionDomain::Full,
+        eval_string_to_binary,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_binary),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_binary_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_binary_to_string),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, NumberType<u64>, _, _>(
+        "length",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<BinaryType, NumberType<u64>>(|val, _| val.len() as u64),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "hex",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            let old_len = output.data.len();
+            let extra_len = val.len() * 2;
+            output.data.resize(old_len + extra_len, 0);
+            hex::encode_to_slice(val, &mut output.data[old_len..]).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_hex",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            let old_len = output.data.len();
+            let extra_len = val.len() / 2;
+            output.data.resize(old_len + extra_len, 0);
+            if let Err(err) = hex::decode_to_slice(val, &mut output.data[old_len..]) {
+                ctx.set_error(output.len(), err.to_string());
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_base64",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            if let Err(err) = general_purpose::STANDARD.decode_vec(val, &mut output.data) {
+                ctx.set_error(output.len(), err.to_string());
+            }
+            output.commit_row();
+        }),
+    );
+
+    fn eval_string_to_binary(val: ValueRef<StringType>, _: &mut EvalContext) -> Value<BinaryType> {
+        match val {
+            ValueRef::Scalar(s) => Value::Scalar(s.to_vec()),
+            ValueRef::Column(c) => Value::Column(c),
+        }
+    }
+
+    fn eval_binary_to_string(val: ValueRef<BinaryType>, _: &mut EvalContext) -> Value<StringType> {
+        match val {
+            ValueRef::Scalar(s) => Value::Scalar(s.to_vec()),
+            ValueRef::Column(c) => Value::Column(c),
+        }
+    }
 }
 
 pub(crate) mod soundex {
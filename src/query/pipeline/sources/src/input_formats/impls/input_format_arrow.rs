@@ -0,0 +1,326 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::aggregate::estimated_bytes_size;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::BlockMetaInfo;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::impls::input_format_orc::adjust_column;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Files in the IPC file format (Feather V2) start with this magic, streams do not.
+const ARROW_FILE_MAGIC: &[u8; 6] = b"ARROW1";
+
+pub struct InputFormatArrow {}
+
+impl InputFormatArrow {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatArrow {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // the record batches of a stream can only be located by reading from the start,
+        // so each file is a single split.
+        let mut infos = vec![];
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct ArrowFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for ArrowFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = ArrowRecordBatches;
+    type AligningState = ArrowAligningState;
+    type BlockBuilder = ArrowBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<ArrowAligningState> {
+        Ok(ArrowAligningState {
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<ArrowBlockBuilder> {
+        Ok(ArrowBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+/// The decoded record batches of a whole file.
+pub struct ArrowRecordBatches {
+    pub split_info: String,
+    pub schema: ArrowSchema,
+    pub chunks: Vec<Chunk<Box<dyn Array>>>,
+}
+
+impl Debug for ArrowRecordBatches {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ArrowRecordBatches")
+    }
+}
+
+impl serde::Serialize for ArrowRecordBatches {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        Err(serde::ser::Error::custom(
+            "ArrowRecordBatches is not serializable",
+        ))
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for ArrowRecordBatches {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom(
+            "ArrowRecordBatches is not deserializable",
+        ))
+    }
+}
+
+impl RowBatchTrait for ArrowRecordBatches {
+    fn size(&self) -> usize {
+        self.chunks
+            .iter()
+            .flat_map(|c| c.arrays())
+            .map(|a| estimated_bytes_size(a.as_ref()))
+            .sum()
+    }
+
+    fn rows(&self) -> usize {
+        self.chunks.iter().map(|c| c.len()).sum()
+    }
+}
+
+#[typetag::serde(name = "row_batch_arrow")]
+impl BlockMetaInfo for ArrowRecordBatches {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("ArrowRecordBatches as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("ArrowRecordBatches as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+/// Decode all record batches, both the file format and the stream format are accepted.
+fn read_chunks(data: &[u8]) -> Result<(ArrowSchema, Vec<Chunk<Box<dyn Array>>>)> {
+    let mut reader = Cursor::new(data);
+    if data.starts_with(ARROW_FILE_MAGIC) {
+        let metadata = read_file_metadata(&mut reader)?;
+        let schema = metadata.schema.clone();
+        let chunks = FileReader::new(reader, metadata, None, None)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok((schema, chunks))
+    } else {
+        let metadata = read_stream_metadata(&mut reader)?;
+        let schema = metadata.schema.clone();
+        let mut chunks = vec![];
+        for state in StreamReader::new(reader, metadata, None) {
+            match state? {
+                StreamState::Some(chunk) => chunks.push(chunk),
+                StreamState::Waiting => break,
+            }
+        }
+        Ok((schema, chunks))
+    }
+}
+
+pub struct ArrowBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl ArrowBlockBuilder {
+    /// Columns are matched by name, and the type in the file must be the same as the column
+    /// except the nullability.
+    fn read_chunk(
+        &self,
+        batches: &ArrowRecordBatches,
+        chunk: &Chunk<Box<dyn Array>>,
+    ) -> Result<DataBlock> {
+        let schema = &batches.schema;
+        let num_rows = chunk.len();
+        let case_sensitive = self.ctx.file_format_options_ext.ident_case_sensitive;
+        let mut columns = Vec::with_capacity(self.ctx.schema.num_fields());
+        for (i, field) in self.ctx.schema.fields().iter().enumerate() {
+            if let Some(projection) = &self.ctx.projection {
+                if !projection.contains(&i) {
+                    columns.push(Column::String(
+                        StringColumnBuilder {
+                            need_estimated: false,
+                            data: vec![],
+                            offsets: vec![0; num_rows + 1],
+                        }
+                        .build(),
+                    ));
+                    continue;
+                }
+            }
+            let data_type = DataType::from(field.data_type());
+            let index = schema.fields.iter().position(|f| {
+                if case_sensitive {
+                    &f.name == field.name()
+                } else {
+                    f.name.to_lowercase() == field.name().to_lowercase()
+                }
+            });
+            let column = match index {
+                Some(index) => {
+                    let source_type = DataType::from(&TableDataType::from(&schema.fields[index]));
+                    let column = Column::from_arrow(chunk.arrays()[index].as_ref(), &source_type);
+                    adjust_column(column, field.name(), &data_type)?
+                }
+                None if data_type.is_nullable() => {
+                    ColumnBuilder::repeat(&ScalarRef::Null, num_rows, &data_type).build()
+                }
+                None => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "column {} not found in arrow file {}",
+                        field.name(),
+                        batches.split_info
+                    )));
+                }
+            };
+            columns.push(column);
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+impl BlockBuilderTrait for ArrowBlockBuilder {
+    type Pipe = ArrowFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<ArrowRecordBatches>) -> Result<Vec<DataBlock>> {
+        let batches = match batch {
+            Some(batches) => batches,
+            None => return Ok(vec![]),
+        };
+        let mut blocks = vec![];
+        for chunk in batches.chunks.iter().filter(|c| !c.is_empty()) {
+            blocks.push(self.read_chunk(&batches, chunk)?);
+        }
+        Ok(blocks)
+    }
+}
+
+pub struct ArrowAligningState {
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for ArrowAligningState {
+    type Pipe = ArrowFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<ArrowRecordBatches>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+        let data = self.buffers.concat();
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+        let split_info = self.split_info.to_string();
+        let (schema, chunks) = read_chunks(&data).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to read arrow file {}: {}",
+                split_info,
+                e.message()
+            ))
+        })?;
+        Ok(vec![ArrowRecordBatches {
+            split_info,
+            schema,
+            chunks,
+        }])
+    }
+}
@@ -0,0 +1,987 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use apache_avro::from_avro_datum;
+use apache_avro::schema::Name;
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Codec;
+use apache_avro::Schema;
+use bstr::ByteSlice;
+use common_base::base::tokio::sync::mpsc::Sender;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::BlockMetaInfo;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_formats::FieldJsonAstDecoder;
+use common_io::display_decimal_256;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::InputError;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use ethnum::i256;
+use futures_util::AsyncReadExt;
+use opendal::Operator;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+use crate::input_formats::input_pipeline::read_full;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::split_by_size;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+const SYNC_SIZE: usize = 16;
+const HEADER_READ_BATCH_SIZE: usize = 64 * 1024;
+
+macro_rules! try_ready {
+    ($e:expr) => {
+        match $e? {
+            Some(v) => v,
+            None => return Ok(None),
+        }
+    };
+}
+
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatAvro {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        stage_info: &StageInfo,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let split_size = stage_info.copy_options.split_size;
+        let mut infos = vec![];
+        for info in file_infos {
+            let size = info.size as usize;
+            let path = info.path.clone();
+            // Avro compresses each block with the codec in the file header,
+            // so the file itself is never compressed as a whole.
+            if split_size > 0 && size > split_size {
+                let header = Arc::new(read_avro_header_async(op, &path).await?);
+                let split_offsets = split_by_size(size, split_size);
+                let num_file_splits = split_offsets.len();
+                tracing::debug!(
+                    "split avro file {} of size {} to {} {} bytes splits",
+                    path,
+                    size,
+                    num_file_splits,
+                    split_size
+                );
+                let file = Arc::new(FileInfo {
+                    path,
+                    size,
+                    num_splits: num_file_splits,
+                    compress_alg: None,
+                });
+                let format_info: Arc<dyn DynData> = Arc::new(AvroSplitMeta { header });
+                for (i, (offset, size)) in split_offsets.into_iter().enumerate() {
+                    infos.push(Arc::new(SplitInfo {
+                        file: file.clone(),
+                        seq_in_file: i,
+                        offset,
+                        size,
+                        num_file_splits,
+                        format_info: Some(format_info.clone()),
+                    }));
+                }
+            } else {
+                let file = Arc::new(FileInfo {
+                    path,
+                    size,
+                    num_splits: 1,
+                    compress_alg: None,
+                });
+                infos.push(Arc::new(SplitInfo {
+                    file,
+                    seq_in_file: 0,
+                    offset: 0,
+                    size,
+                    num_file_splits: 1,
+                    format_info: None,
+                }));
+            }
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct AvroFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for AvroFormatPipe {
+    type SplitMeta = AvroSplitMeta;
+    type ReadBatch = Vec<u8>;
+    type RowBatch = AvroRowBatch;
+    type AligningState = AvroAligningState;
+    type BlockBuilder = AvroBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<AvroAligningState> {
+        Ok(AvroAligningState::create(
+            split_info,
+            Self::get_split_meta(split_info),
+        ))
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<AvroBlockBuilder> {
+        Ok(AvroBlockBuilder::create(ctx.clone()))
+    }
+
+    /// Unlike text formats, a split of an avro file does not end at a record delimiter,
+    /// so the reader keeps going past the end of the split until the sync marker
+    /// closing the last block which starts inside the split.
+    #[async_backtrace::framed]
+    async fn copy_reader_with_aligner(
+        ctx: Arc<InputContext>,
+        split_info: Arc<SplitInfo>,
+        batch_tx: Sender<Result<Vec<u8>>>,
+    ) -> Result<()> {
+        tracing::debug!("started");
+        let operator = ctx.source.get_operator()?;
+        let end = split_info.offset + split_info.size;
+        let sync = match Self::get_split_meta(&split_info) {
+            Some(meta) if split_info.seq_in_file + 1 < split_info.num_file_splits => {
+                Some(meta.header.sync)
+            }
+            _ => None,
+        };
+        let mut reader = operator
+            .range_reader(
+                &split_info.file.path,
+                split_info.offset as u64..split_info.file.size as u64,
+            )
+            .await?;
+        let mut pos = split_info.offset;
+        // bytes beyond the end of the split, searched for the closing sync marker
+        let mut tail = vec![];
+        loop {
+            let mut batch = vec![0u8; ctx.read_batch_size];
+            let n = read_full(&mut reader, &mut batch[0..]).await?;
+            if n == 0 {
+                break;
+            }
+            batch.truncate(n);
+            let batch_start = pos;
+            pos += n;
+
+            let mut finished = false;
+            if let Some(sync) = &sync {
+                if pos > end {
+                    tail.extend_from_slice(&batch[end.saturating_sub(batch_start)..]);
+                    finished = tail.find(sync).is_some();
+                    let keep = tail.len().min(SYNC_SIZE - 1);
+                    tail.drain(..tail.len() - keep);
+                }
+            }
+            tracing::debug!("read {} bytes", n);
+            if let Err(e) = batch_tx.send(Ok(batch)).await {
+                tracing::warn!("fail to send ReadBatch: {}", e);
+                break;
+            }
+            if finished {
+                break;
+            }
+        }
+        tracing::debug!("finished");
+        Ok(())
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct AvroHeader {
+    /// writer schema in JSON
+    pub schema: String,
+    pub codec: String,
+    pub sync: [u8; SYNC_SIZE],
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct AvroSplitMeta {
+    pub header: Arc<AvroHeader>,
+}
+
+#[typetag::serde(name = "avro_split")]
+impl DynData for AvroSplitMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// One decompressed data block of an avro container file.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AvroRowBatch {
+    pub split_info: String,
+    pub header: Arc<AvroHeader>,
+    // offset of the block in the file, for error info
+    pub offset: usize,
+    pub num_rows: usize,
+    pub data: Vec<u8>,
+}
+
+impl Debug for AvroRowBatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AvroRowBatch")
+    }
+}
+
+impl RowBatchTrait for AvroRowBatch {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.num_rows
+    }
+}
+
+#[typetag::serde(name = "row_batch_avro")]
+impl BlockMetaInfo for AvroRowBatch {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("AvroRowBatch as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("AvroRowBatch as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct AvroAligningState {
+    split_info: Arc<SplitInfo>,
+    header: Option<Arc<AvroHeader>>,
+    buf: Vec<u8>,
+    // position of buf[0] in the file
+    offset: usize,
+    // whether buf starts at the beginning of a block
+    synced: bool,
+    // only blocks after a sync marker starting before this position belong to the split
+    end: Option<usize>,
+    finished: bool,
+}
+
+impl AvroAligningState {
+    fn create(split_info: &Arc<SplitInfo>, meta: Option<&AvroSplitMeta>) -> Self {
+        let end = if split_info.num_file_splits > 1 {
+            Some(split_info.offset + split_info.size)
+        } else {
+            None
+        };
+        Self {
+            split_info: split_info.clone(),
+            header: meta.map(|m| m.header.clone()),
+            buf: vec![],
+            offset: split_info.offset,
+            synced: false,
+            end,
+            finished: false,
+        }
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.buf.drain(..n);
+        self.offset += n;
+    }
+
+    /// The header is already known when the file is split, then each split
+    /// starts from the first sync marker in it, including the one ending the header.
+    fn sync(&mut self) -> Result<()> {
+        match &self.header {
+            None => {
+                if let Some((header, len)) = parse_header(&self.buf)? {
+                    self.header = Some(Arc::new(header));
+                    self.consume(len);
+                    self.synced = true;
+                }
+            }
+            Some(header) => {
+                let sync = header.sync;
+                if let Some(i) = self.buf.find(sync) {
+                    self.consume(i + SYNC_SIZE);
+                    self.synced = true;
+                } else {
+                    let n = self.buf.len().saturating_sub(SYNC_SIZE - 1);
+                    self.consume(n);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_block(&mut self) -> Result<Option<AvroRowBatch>> {
+        let header = self
+            .header
+            .clone()
+            .ok_or_else(|| self.error("block before the file header"))?;
+        let mut pos = 0;
+        let num_rows = try_ready!(read_long(&self.buf, &mut pos));
+        let size = try_ready!(read_long(&self.buf, &mut pos));
+        if num_rows < 0 || size < 0 {
+            return Err(self.error("invalid block header"));
+        }
+        let data_end = pos + size as usize;
+        if self.buf.len() < data_end + SYNC_SIZE {
+            return Ok(None);
+        }
+        if self.buf[data_end..data_end + SYNC_SIZE] != header.sync {
+            return Err(self.error("sync marker mismatch"));
+        }
+        let mut data = self.buf[pos..data_end].to_vec();
+        let codec = Codec::from_str(&header.codec)
+            .map_err(|_| self.error(&format!("unsupported codec '{}'", header.codec)))?;
+        codec
+            .decompress(&mut data)
+            .map_err(|e| self.error(&format!("fail to decompress block: {e}")))?;
+        let batch = AvroRowBatch {
+            split_info: self.split_info.to_string(),
+            header,
+            offset: self.offset,
+            num_rows: num_rows as usize,
+            data,
+        };
+        self.consume(data_end + SYNC_SIZE);
+        Ok(Some(batch))
+    }
+
+    fn error(&self, msg: &str) -> ErrorCode {
+        ErrorCode::BadBytes(format!(
+            "invalid avro file {} at offset {}: {}",
+            self.split_info.file.path, self.offset, msg
+        ))
+    }
+}
+
+impl AligningStateTrait for AvroAligningState {
+    type Pipe = AvroFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<AvroRowBatch>> {
+        if self.finished {
+            return Ok(vec![]);
+        }
+        match read_batch {
+            Some(data) => {
+                self.buf.extend_from_slice(&data);
+                if !self.synced {
+                    self.sync()?;
+                }
+                let mut row_batches = vec![];
+                while self.synced {
+                    // the sync marker before the block starts beyond the split
+                    if matches!(self.end, Some(end) if self.offset - SYNC_SIZE >= end) {
+                        self.finished = true;
+                        self.buf = vec![];
+                        break;
+                    }
+                    match self.read_block()? {
+                        Some(b) => row_batches.push(b),
+                        None => break,
+                    }
+                }
+                Ok(row_batches)
+            }
+            None => {
+                if !self.buf.is_empty() && (self.synced || self.header.is_none()) {
+                    return Err(self.error(&format!(
+                        "unexpected end of file with {} bytes left",
+                        self.buf.len()
+                    )));
+                }
+                Ok(vec![])
+            }
+        }
+    }
+}
+
+struct AvroSchema {
+    schema: Schema,
+    names: HashMap<Name, Schema>,
+}
+
+pub struct AvroBlockBuilder {
+    ctx: Arc<InputContext>,
+    field_decoder: FieldJsonAstDecoder,
+    mutable_columns: Vec<ColumnBuilder>,
+    num_rows: usize,
+    projection: Option<Vec<usize>>,
+    // parsed writer schemas, keyed by the JSON in file headers
+    schemas: HashMap<String, Arc<AvroSchema>>,
+}
+
+impl AvroBlockBuilder {
+    fn create(ctx: Arc<InputContext>) -> Self {
+        let mutable_columns = ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity_hint(&f.data_type().into(), 1024, false))
+            .collect();
+        let field_decoder = FieldJsonAstDecoder::create(&ctx.file_format_options_ext);
+        let projection = ctx.projection.clone();
+        AvroBlockBuilder {
+            ctx,
+            field_decoder,
+            mutable_columns,
+            num_rows: 0,
+            projection,
+            schemas: HashMap::new(),
+        }
+    }
+
+    fn get_schema(&mut self, json: &str) -> Result<Arc<AvroSchema>> {
+        if let Some(schema) = self.schemas.get(json) {
+            return Ok(schema.clone());
+        }
+        let schema = parse_schema(json)?;
+        let mut names = HashMap::new();
+        collect_named_schemas(&schema, &mut names);
+        let schema = Arc::new(AvroSchema { schema, names });
+        self.schemas.insert(json.to_string(), schema.clone());
+        Ok(schema)
+    }
+
+    fn read_row(&mut self, value: &AvroValue, schema: &AvroSchema) -> Result<()> {
+        let json = avro_to_json(value, Some(&schema.schema), &schema.names)?;
+        let columns = &mut self.mutable_columns;
+        if self.field_decoder.is_select {
+            return self.field_decoder.read_field(&mut columns[0], &json);
+        }
+        let mut record = match json {
+            JsonValue::Object(record) => record,
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "the top-level schema of avro file must be a record",
+                ));
+            }
+        };
+        if !self.field_decoder.ident_case_sensitive {
+            record = record
+                .into_iter()
+                .map(|(k, v)| (k.to_lowercase(), v))
+                .collect();
+        }
+        for (f, column) in self.ctx.schema.fields().iter().zip(columns.iter_mut()) {
+            let name = if self.field_decoder.ident_case_sensitive {
+                f.name().to_owned()
+            } else {
+                f.name().to_lowercase()
+            };
+            let value = record.get(&name).unwrap_or(&JsonValue::Null);
+            self.field_decoder
+                .read_field(column, value)
+                .map_err(|e| ErrorCode::BadBytes(format!("{}. column={}", e, f.name())))?;
+        }
+        Ok(())
+    }
+
+    fn read_batch(&mut self, batch: &AvroRowBatch) -> Result<HashMap<u16, InputError>> {
+        let schema = self.get_schema(&batch.header.schema)?;
+        let mut reader = &batch.data[..];
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        for row in 0..batch.num_rows {
+            let value = from_avro_datum(&schema.schema, &mut reader, None).map_err(|e| {
+                ErrorCode::BadBytes(format!(
+                    "fail to decode avro datum: {}, file={}, block offset={}, row={}",
+                    e, batch.split_info, batch.offset, row
+                ))
+            })?;
+            if let Err(e) = self.read_row(&value, &schema) {
+                self.ctx
+                    .on_error(
+                        e,
+                        Some((&mut self.mutable_columns, self.num_rows)),
+                        Some(&mut error_map),
+                    )
+                    .map_err(|e| {
+                        e.add_message_back(format!(
+                            " (file={}, block offset={}, row={})",
+                            batch.split_info, batch.offset, row
+                        ))
+                    })?;
+            } else {
+                self.num_rows += 1;
+            }
+        }
+        Ok(error_map)
+    }
+
+    fn flush(&mut self) -> Result<Vec<DataBlock>> {
+        let columns: Vec<Column> = self
+            .mutable_columns
+            .iter_mut()
+            .map(|col| {
+                let empty_builder =
+                    ColumnBuilder::with_capacity_hint(&col.data_type(), 1024, false);
+                std::mem::replace(col, empty_builder).build()
+            })
+            .collect();
+        let columns = match &self.projection {
+            Some(projection) => columns
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    if projection.contains(&i) {
+                        c
+                    } else {
+                        Column::String(
+                            StringColumnBuilder {
+                                need_estimated: false,
+                                data: vec![],
+                                offsets: vec![0; self.num_rows + 1],
+                            }
+                            .build(),
+                        )
+                    }
+                })
+                .collect::<Vec<_>>(),
+            None => columns,
+        };
+        self.num_rows = 0;
+
+        if columns.is_empty() || columns[0].len() == 0 {
+            Ok(vec![])
+        } else {
+            Ok(vec![DataBlock::new_from_columns(columns)])
+        }
+    }
+
+    fn memory_size(&self) -> usize {
+        self.mutable_columns.iter().map(|x| x.memory_size()).sum()
+    }
+
+    fn merge_map(&self, error_map: HashMap<u16, InputError>, file_name: String) {
+        if let Some(ref on_error_map) = self.ctx.on_error_map {
+            on_error_map
+                .entry(file_name)
+                .and_modify(|x| {
+                    for (k, v) in error_map.clone() {
+                        x.entry(k).and_modify(|y| y.num += v.num).or_insert(v);
+                    }
+                })
+                .or_insert(error_map);
+        }
+    }
+}
+
+impl BlockBuilderTrait for AvroBlockBuilder {
+    type Pipe = AvroFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<AvroRowBatch>) -> Result<Vec<DataBlock>> {
+        if let Some(b) = batch {
+            let error_map = self.read_batch(&b)?;
+            self.merge_map(error_map, b.split_info.clone());
+            let mem = self.memory_size();
+            if self.num_rows >= self.ctx.block_compact_thresholds.min_rows_per_block
+                || mem > self.ctx.block_compact_thresholds.max_bytes_per_block
+            {
+                self.flush()
+            } else {
+                Ok(vec![])
+            }
+        } else {
+            self.flush()
+        }
+    }
+}
+
+/// Read the header of an avro container file and infer the table schema from the writer schema.
+#[async_backtrace::framed]
+pub async fn read_avro_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let header = read_avro_header_async(operator, path).await?;
+    let schema = parse_schema(&header.schema)?;
+    match &schema {
+        Schema::Record { fields, .. } => {
+            let fields = fields
+                .iter()
+                .map(|f| {
+                    Ok(TableField::new(
+                        &f.name,
+                        avro_type_to_table_type(&f.schema)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TableSchema::new(fields))
+        }
+        _ => Err(ErrorCode::BadBytes(format!(
+            "the top-level schema of avro file {} must be a record",
+            path
+        ))),
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_avro_header_async(operator: &Operator, path: &str) -> Result<AvroHeader> {
+    let mut reader = operator.reader(path).await?;
+    let mut buf = vec![];
+    let mut batch = vec![0u8; HEADER_READ_BATCH_SIZE];
+    loop {
+        let n = reader.read(&mut batch[..]).await?;
+        if n == 0 {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid avro file {}: incomplete header",
+                path
+            )));
+        }
+        buf.extend_from_slice(&batch[..n]);
+        if let Some((header, _)) = parse_header(&buf)? {
+            return Ok(header);
+        }
+    }
+}
+
+fn parse_schema(json: &str) -> Result<Schema> {
+    Schema::parse_str(json)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid avro schema: {e}, schema={json}")))
+}
+
+/// Read a zigzag encoded long, return `None` if the buffer ends before it.
+fn read_long(buf: &[u8], pos: &mut usize) -> Result<Option<i64>> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    let mut i = *pos;
+    loop {
+        if i >= buf.len() {
+            return Ok(None);
+        }
+        let b = buf[i];
+        i += 1;
+        value |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift > 63 {
+            return Err(ErrorCode::BadBytes("invalid avro long: varint overflow"));
+        }
+    }
+    *pos = i;
+    Ok(Some((value >> 1) as i64 ^ -((value & 1) as i64)))
+}
+
+fn read_bytes<'a>(buf: &'a [u8], pos: &mut usize) -> Result<Option<&'a [u8]>> {
+    let mut i = *pos;
+    let len = try_ready!(read_long(buf, &mut i));
+    if len < 0 {
+        return Err(ErrorCode::BadBytes("invalid avro bytes: negative length"));
+    }
+    let end = i + len as usize;
+    if buf.len() < end {
+        return Ok(None);
+    }
+    *pos = end;
+    Ok(Some(&buf[i..end]))
+}
+
+/// Parse the file header, return it with its length, or `None` if the buffer is not long enough.
+fn parse_header(buf: &[u8]) -> Result<Option<(AvroHeader, usize)>> {
+    if buf.len() < AVRO_MAGIC.len() {
+        return Ok(None);
+    }
+    if &buf[..AVRO_MAGIC.len()] != AVRO_MAGIC {
+        return Err(ErrorCode::BadBytes(
+            "invalid avro file: not an object container file",
+        ));
+    }
+    let mut pos = AVRO_MAGIC.len();
+    let mut schema = None;
+    let mut codec = None;
+    loop {
+        let mut count = try_ready!(read_long(buf, &mut pos));
+        if count == 0 {
+            break;
+        }
+        if count < 0 {
+            // followed by the size of the block in bytes
+            try_ready!(read_long(buf, &mut pos));
+            count = -count;
+        }
+        for _ in 0..count {
+            let key = try_ready!(read_bytes(buf, &mut pos));
+            let value = try_ready!(read_bytes(buf, &mut pos));
+            match key {
+                b"avro.schema" => schema = Some(String::from_utf8(value.to_vec())?),
+                b"avro.codec" => codec = Some(String::from_utf8(value.to_vec())?),
+                _ => {}
+            }
+        }
+    }
+    if buf.len() < pos + SYNC_SIZE {
+        return Ok(None);
+    }
+    let mut sync = [0u8; SYNC_SIZE];
+    sync.copy_from_slice(&buf[pos..pos + SYNC_SIZE]);
+    let schema = schema.ok_or_else(|| {
+        ErrorCode::BadBytes("invalid avro file: no avro.schema in the file header")
+    })?;
+    let header = AvroHeader {
+        schema,
+        codec: codec.unwrap_or_else(|| "null".to_string()),
+        sync,
+    };
+    Ok(Some((header, pos + SYNC_SIZE)))
+}
+
+fn collect_named_schemas(schema: &Schema, names: &mut HashMap<Name, Schema>) {
+    match schema {
+        Schema::Record { name, fields, .. } => {
+            names.insert(name.clone(), schema.clone());
+            for f in fields {
+                collect_named_schemas(&f.schema, names);
+            }
+        }
+        Schema::Enum { name, .. } | Schema::Fixed { name, .. } => {
+            names.insert(name.clone(), schema.clone());
+        }
+        Schema::Array(inner) | Schema::Map(inner) => collect_named_schemas(inner, names),
+        Schema::Union(union) => {
+            for s in union.variants() {
+                collect_named_schemas(s, names);
+            }
+        }
+        Schema::Decimal { inner, .. } => collect_named_schemas(inner, names),
+        _ => {}
+    }
+}
+
+fn avro_type_to_table_type(schema: &Schema) -> Result<TableDataType> {
+    let ty = match schema {
+        Schema::Null => TableDataType::Null,
+        Schema::Boolean => TableDataType::Boolean,
+        Schema::Int => TableDataType::Number(NumberDataType::Int32),
+        Schema::Long => TableDataType::Number(NumberDataType::Int64),
+        Schema::Float => TableDataType::Number(NumberDataType::Float32),
+        Schema::Double => TableDataType::Number(NumberDataType::Float64),
+        Schema::Bytes | Schema::Fixed { .. } => TableDataType::Binary,
+        Schema::String | Schema::Enum { .. } | Schema::Uuid => TableDataType::String,
+        Schema::Date => TableDataType::Date,
+        Schema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        Schema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        Schema::TimestampMillis | Schema::TimestampMicros => TableDataType::Timestamp,
+        Schema::Duration => TableDataType::Interval,
+        Schema::Decimal {
+            precision, scale, ..
+        } => {
+            let size = DecimalSize {
+                precision: *precision as u8,
+                scale: *scale as u8,
+            };
+            if *precision > 76 || scale > precision {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unsupported avro decimal({}, {})",
+                    precision, scale
+                )));
+            } else if *precision > 38 {
+                TableDataType::Decimal(DecimalDataType::Decimal256(size))
+            } else {
+                TableDataType::Decimal(DecimalDataType::Decimal128(size))
+            }
+        }
+        Schema::Array(inner) => TableDataType::Array(Box::new(avro_type_to_table_type(inner)?)),
+        Schema::Map(inner) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_type_to_table_type(inner)?],
+        })),
+        Schema::Record { fields, .. } => TableDataType::Tuple {
+            fields_name: fields.iter().map(|f| f.name.clone()).collect(),
+            fields_type: fields
+                .iter()
+                .map(|f| avro_type_to_table_type(&f.schema))
+                .collect::<Result<Vec<_>>>()?,
+        },
+        Schema::Union(union) => {
+            let variants = union
+                .variants()
+                .iter()
+                .filter(|s| !matches!(s, Schema::Null))
+                .collect::<Vec<_>>();
+            let ty = match variants.as_slice() {
+                [] => return Ok(TableDataType::Null),
+                [s] => avro_type_to_table_type(s)?,
+                _ => TableDataType::Variant,
+            };
+            if variants.len() < union.variants().len() {
+                ty.wrap_nullable()
+            } else {
+                ty
+            }
+        }
+        // recursive types can not be mapped to a fixed table type
+        Schema::Ref { .. } => TableDataType::Variant,
+    };
+    Ok(ty)
+}
+
+/// Convert an avro value to JSON which `FieldJsonAstDecoder` accepts,
+/// the schema is needed for the scale of decimals.
+fn avro_to_json(
+    value: &AvroValue,
+    schema: Option<&Schema>,
+    names: &HashMap<Name, Schema>,
+) -> Result<JsonValue> {
+    if let Some(Schema::Ref { name }) = schema {
+        return avro_to_json(value, names.get(name), names);
+    }
+    let json = match value {
+        AvroValue::Null => JsonValue::Null,
+        AvroValue::Boolean(v) => JsonValue::Bool(*v),
+        AvroValue::Int(v) | AvroValue::Date(v) | AvroValue::TimeMillis(v) => JsonValue::from(*v),
+        AvroValue::Long(v) | AvroValue::TimeMicros(v) | AvroValue::TimestampMicros(v) => {
+            JsonValue::from(*v)
+        }
+        AvroValue::TimestampMillis(v) => {
+            JsonValue::from(v.checked_mul(1000).ok_or_else(|| {
+                ErrorCode::BadBytes(format!("timestamp-millis {} out of range", v))
+            })?)
+        }
+        AvroValue::Float(v) => JsonValue::from(*v as f64),
+        AvroValue::Double(v) => JsonValue::from(*v),
+        AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => JsonValue::String(hex::encode(v)),
+        AvroValue::String(v) | AvroValue::Enum(_, v) => JsonValue::String(v.clone()),
+        AvroValue::Uuid(v) => JsonValue::String(v.to_string()),
+        AvroValue::Duration(v) => {
+            let months = u32::from(v.months());
+            if months != 0 {
+                return Err(ErrorCode::BadBytes(
+                    "avro duration with months can not be converted to interval",
+                ));
+            }
+            let days = u32::from(v.days()) as i64;
+            let millis = u32::from(v.millis()) as i64;
+            JsonValue::from((days * 86_400_000 + millis) * 1000)
+        }
+        AvroValue::Decimal(v) => match schema {
+            Some(Schema::Decimal { scale, .. }) => {
+                let bytes = Vec::<u8>::try_from(v)
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid avro decimal: {e}")))?;
+                JsonValue::String(display_decimal_256(
+                    decimal_from_be_bytes(&bytes)?,
+                    *scale as u8,
+                ))
+            }
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "can not find the scale of avro decimal",
+                ));
+            }
+        },
+        AvroValue::Union(i, v) => {
+            let schema = match schema {
+                Some(Schema::Union(union)) => union.variants().get(*i as usize),
+                _ => None,
+            };
+            avro_to_json(v, schema, names)?
+        }
+        AvroValue::Array(values) => {
+            let schema = match schema {
+                Some(Schema::Array(inner)) => Some(inner.as_ref()),
+                _ => None,
+            };
+            JsonValue::Array(
+                values
+                    .iter()
+                    .map(|v| avro_to_json(v, schema, names))
+                    .collect::<Result<Vec<_>>>()?,
+            )
+        }
+        AvroValue::Map(values) => {
+            let schema = match schema {
+                Some(Schema::Map(inner)) => Some(inner.as_ref()),
+                _ => None,
+            };
+            let mut map = JsonMap::with_capacity(values.len());
+            for (k, v) in values {
+                map.insert(k.clone(), avro_to_json(v, schema, names)?);
+            }
+            JsonValue::Object(map)
+        }
+        AvroValue::Record(values) => {
+            let fields = match schema {
+                Some(Schema::Record { fields, .. }) => Some(fields),
+                _ => None,
+            };
+            let mut map = JsonMap::with_capacity(values.len());
+            for (i, (k, v)) in values.iter().enumerate() {
+                let schema = fields.and_then(|f| f.get(i)).map(|f| &f.schema);
+                map.insert(k.clone(), avro_to_json(v, schema, names)?);
+            }
+            JsonValue::Object(map)
+        }
+    };
+    Ok(json)
+}
+
+/// Decode the big-endian two's-complement unscaled value of a decimal.
+fn decimal_from_be_bytes(bytes: &[u8]) -> Result<i256> {
+    let negative = bytes.first().map(|b| b & 0x80 != 0).unwrap_or(false);
+    let sign = if negative { 0xff } else { 0 };
+    // redundant sign bytes are allowed
+    let bytes = match bytes.iter().position(|b| *b != sign) {
+        Some(i) if i > 0 && bytes.len() - i < 32 => &bytes[i - 1..],
+        _ => bytes,
+    };
+    if bytes.len() > 32 {
+        return Err(ErrorCode::BadBytes(
+            "avro decimal out of range of decimal256",
+        ));
+    }
+    let mut buf = [sign; 32];
+    buf[32 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i256::from_be_bytes(buf))
+}
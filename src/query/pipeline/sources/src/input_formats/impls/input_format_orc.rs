@@ -0,0 +1,612 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::error::Error as ArrowError;
+use common_arrow::arrow::io::orc::format::proto::ColumnStatistics;
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::format::read::read_stripe_column;
+use common_arrow::arrow::io::orc::format::read::read_stripe_footer;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_arrow::arrow::io::orc::read::deserialize;
+use common_arrow::arrow::io::orc::read::infer_schema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::BlockMetaInfo;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+const TAIL_READ_SIZE: usize = 64 * 1024;
+
+pub struct InputFormatOrc {}
+
+impl InputFormatOrc {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let mut infos = vec![];
+        for info in file_infos {
+            let size = info.size as usize;
+            let file_meta = Arc::new(read_orc_meta_async(op, &info.path, size).await?);
+            let stripes = &file_meta.meta.footer.stripes;
+            let num_file_splits = stripes.len();
+            tracing::debug!(
+                "split orc file {} of size {} to {} stripes",
+                info.path,
+                size,
+                num_file_splits,
+            );
+            let file = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size,
+                num_splits: num_file_splits,
+                compress_alg: None,
+            });
+            for (i, stripe) in stripes.iter().enumerate() {
+                let stripe_size =
+                    stripe.index_length() + stripe.data_length() + stripe.footer_length();
+                infos.push(Arc::new(SplitInfo {
+                    file: file.clone(),
+                    seq_in_file: i,
+                    offset: stripe.offset() as usize,
+                    size: stripe_size as usize,
+                    num_file_splits,
+                    format_info: Some(Arc::new(OrcSplitMeta {
+                        file: file_meta.clone(),
+                        stripe: i,
+                    })),
+                }));
+            }
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = OrcSplitMeta;
+    type ReadBatch = Vec<u8>;
+    type RowBatch = StripeInMemory;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+pub struct OrcFileMeta {
+    pub meta: FileMetadata,
+    // all fields in the orc file
+    pub schema: ArrowSchema,
+}
+
+impl OrcFileMeta {
+    fn try_create(meta: FileMetadata) -> Result<Self> {
+        let schema = infer_schema(&meta.footer)?;
+        Ok(Self { meta, schema })
+    }
+}
+
+impl Debug for OrcFileMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "orc file meta")
+    }
+}
+
+impl serde::Serialize for OrcFileMeta {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        Err(serde::ser::Error::custom("OrcFileMeta is not serializable"))
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for OrcFileMeta {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom(
+            "OrcFileMeta is not deserializable",
+        ))
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct OrcSplitMeta {
+    pub file: Arc<OrcFileMeta>,
+    pub stripe: usize,
+}
+
+#[typetag::serde(name = "orc_split")]
+impl DynData for OrcSplitMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Statistics of a top-level column in a stripe, converted to the type of the column.
+pub struct OrcColumnStatistics {
+    pub min: Scalar,
+    pub max: Scalar,
+    pub null_count: u64,
+}
+
+impl OrcSplitMeta {
+    /// Returns `None` if the column is not found, or its type does not support statistics.
+    ///
+    /// The column is matched by name like [`OrcBlockBuilder`] does.
+    pub fn column_statistics(
+        &self,
+        name: &str,
+        case_sensitive: bool,
+    ) -> Option<OrcColumnStatistics> {
+        let index = field_index_of(&self.file.schema, name, case_sensitive)?;
+        let field = &self.file.schema.fields[index];
+        let column_id = column_id_of(&self.file.meta, index)?;
+        let stats = self
+            .file
+            .meta
+            .metadata
+            .stripe_stats
+            .get(self.stripe)?
+            .col_stats
+            .get(column_id as usize)?;
+        let (min, max) = stats_to_scalars(&field.data_type, stats)?;
+        let num_rows = self.file.meta.footer.stripes[self.stripe].number_of_rows();
+        let null_count = match (stats.has_null, stats.number_of_values) {
+            (Some(false), _) => 0,
+            (_, Some(n)) => num_rows.saturating_sub(n),
+            _ => num_rows,
+        };
+        Some(OrcColumnStatistics {
+            min,
+            max,
+            null_count,
+        })
+    }
+}
+
+/// All bytes of a stripe, `offset` is the position of the stripe in the file.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct StripeInMemory {
+    pub split_info: String,
+    pub file: Arc<OrcFileMeta>,
+    pub stripe: usize,
+    pub offset: u64,
+    pub data: Vec<u8>,
+}
+
+impl Debug for StripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StripeInMemory")
+    }
+}
+
+impl RowBatchTrait for StripeInMemory {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.file.meta.footer.stripes[self.stripe].number_of_rows() as usize
+    }
+}
+
+#[typetag::serde(name = "row_batch_orc")]
+impl BlockMetaInfo for StripeInMemory {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("StripeInMemory as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("StripeInMemory as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+impl StripeInMemory {
+    fn read_column(&self, index: usize, scratch: Vec<u8>) -> Result<(Column, Vec<u8>)> {
+        let meta = &self.file.meta;
+        let field = &self.file.schema.fields[index];
+        let column_id = column_id_of(meta, index)
+            .ok_or_else(|| ErrorCode::BadBytes(format!("no column {} in orc file", field.name)))?;
+        let mut reader = OffsetCursor::new(&self.data, self.offset, None);
+        let mut footer_scratch = vec![];
+        let footer = read_stripe_footer(&mut reader, meta, self.stripe, &mut footer_scratch)
+            .map_err(ArrowError::from)?;
+        let column = read_stripe_column(&mut reader, meta, self.stripe, footer, column_id, scratch)
+            .map_err(ArrowError::from)?;
+        let array = deserialize(field.data_type.clone(), &column)?;
+        let data_type = DataType::from(&TableDataType::from(field));
+        let (_, scratch) = column.into_inner();
+        Ok((Column::from_arrow(array.as_ref(), &data_type), scratch))
+    }
+}
+
+pub struct OrcBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl OrcBlockBuilder {
+    /// Columns are matched by name, and the type in the file must be the same as the column
+    /// except the nullability.
+    fn read_stripe(&self, stripe: &StripeInMemory) -> Result<DataBlock> {
+        let num_rows = stripe.rows();
+        let case_sensitive = self.ctx.file_format_options_ext.ident_case_sensitive;
+        let mut scratch = vec![];
+        let mut columns = Vec::with_capacity(self.ctx.schema.num_fields());
+        for (i, field) in self.ctx.schema.fields().iter().enumerate() {
+            if let Some(projection) = &self.ctx.projection {
+                if !projection.contains(&i) {
+                    columns.push(Column::String(
+                        StringColumnBuilder {
+                            need_estimated: false,
+                            data: vec![],
+                            offsets: vec![0; num_rows + 1],
+                        }
+                        .build(),
+                    ));
+                    continue;
+                }
+            }
+            let data_type = DataType::from(field.data_type());
+            let index = field_index_of(&stripe.file.schema, field.name(), case_sensitive);
+            let column = match index {
+                Some(index) => {
+                    let (column, s) = stripe.read_column(index, scratch)?;
+                    scratch = s;
+                    adjust_column(column, field.name(), &data_type)?
+                }
+                None if data_type.is_nullable() => {
+                    ColumnBuilder::repeat(&ScalarRef::Null, num_rows, &data_type).build()
+                }
+                None => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "column {} not found in orc file {}",
+                        field.name(),
+                        stripe.split_info
+                    )));
+                }
+            };
+            columns.push(column);
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<StripeInMemory>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(stripe) => Ok(vec![self.read_stripe(&stripe)?]),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+pub struct OrcAligningState {
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<StripeInMemory>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+        let data = self.buffers.concat();
+        let split_info = self.split_info.to_string();
+        match OrcFormatPipe::get_split_meta(&self.split_info) {
+            Some(meta) => Ok(vec![StripeInMemory {
+                split_info,
+                file: meta.file.clone(),
+                stripe: meta.stripe,
+                offset: self.split_info.offset as u64,
+                data,
+            }]),
+            None => {
+                // streaming load, the whole file is in memory
+                let size = data.len();
+                let mut cursor = OffsetCursor::new(&data, 0, None);
+                let meta = read_metadata(&mut cursor).map_err(ArrowError::from)?;
+                let file = Arc::new(OrcFileMeta::try_create(meta)?);
+                let stripes = &file.meta.footer.stripes;
+                tracing::info!(
+                    "align orc file {} of {} bytes to {} stripes",
+                    self.split_info.file.path,
+                    size,
+                    stripes.len()
+                );
+                let mut row_batches = Vec::with_capacity(stripes.len());
+                for (i, stripe) in stripes.iter().enumerate() {
+                    let start = stripe.offset() as usize;
+                    let end = start
+                        + (stripe.index_length() + stripe.data_length() + stripe.footer_length())
+                            as usize;
+                    if end > size {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "invalid orc file {}: stripe {} out of range",
+                            self.split_info.file.path, i
+                        )));
+                    }
+                    row_batches.push(StripeInMemory {
+                        split_info: split_info.clone(),
+                        file: file.clone(),
+                        stripe: i,
+                        offset: start as u64,
+                        data: data[start..end].to_vec(),
+                    });
+                }
+                Ok(row_batches)
+            }
+        }
+    }
+}
+
+/// Read and seek in a range of a file, positions are relative to the start of the file.
+struct OffsetCursor<'a> {
+    data: &'a [u8],
+    offset: u64,
+    file_size: u64,
+    pos: u64,
+}
+
+impl<'a> OffsetCursor<'a> {
+    fn new(data: &'a [u8], offset: u64, file_size: Option<u64>) -> Self {
+        Self {
+            data,
+            offset,
+            file_size: file_size.unwrap_or(offset + data.len() as u64),
+            pos: offset,
+        }
+    }
+}
+
+impl<'a> Read for OffsetCursor<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let start = (self.pos - self.offset) as usize;
+        if start >= self.data.len() {
+            return Ok(0);
+        }
+        let n = buf.len().min(self.data.len() - start);
+        buf[..n].copy_from_slice(&self.data[start..start + n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<'a> Seek for OffsetCursor<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(p) => p as i64,
+            SeekFrom::End(p) => self.file_size as i64 + p,
+            SeekFrom::Current(p) => self.pos as i64 + p,
+        };
+        if pos < self.offset as i64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("seek to {} before the loaded range", pos),
+            ));
+        }
+        self.pos = pos as u64;
+        Ok(self.pos)
+    }
+}
+
+/// Read the metadata at the tail of the file, the tail is enlarged until it covers the metadata.
+#[async_backtrace::framed]
+async fn read_orc_meta_async(operator: &Operator, path: &str, size: usize) -> Result<OrcFileMeta> {
+    let mut tail_size = TAIL_READ_SIZE.min(size);
+    loop {
+        let offset = (size - tail_size) as u64;
+        let tail = operator.range_read(path, offset..size as u64).await?;
+        let mut cursor = OffsetCursor::new(&tail, offset, Some(size as u64));
+        match read_metadata(&mut cursor) {
+            Ok(meta) => return OrcFileMeta::try_create(meta),
+            Err(e) if tail_size == size => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "fail to read meta of orc file {}: {:?}",
+                    path, e
+                )));
+            }
+            Err(_) => tail_size = (tail_size * 4).min(size),
+        }
+    }
+}
+
+/// Read the footer of an orc file and infer the table schema from it.
+#[async_backtrace::framed]
+pub async fn read_orc_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let size = operator.stat(path).await?.content_length() as usize;
+    let meta = read_orc_meta_async(operator, path, size).await?;
+    Ok(TableSchema::from(&meta.schema))
+}
+
+/// The index of the field named `name` in the file, the name is compared case-insensitively
+/// unless `case_sensitive`.
+fn field_index_of(schema: &ArrowSchema, name: &str, case_sensitive: bool) -> Option<usize> {
+    schema.fields.iter().position(|f| {
+        if case_sensitive {
+            f.name == name
+        } else {
+            f.name.to_lowercase() == name.to_lowercase()
+        }
+    })
+}
+
+/// Column 0 is the root struct, the ids of top-level fields are its subtypes.
+fn column_id_of(meta: &FileMetadata, index: usize) -> Option<u32> {
+    meta.footer.types.first()?.subtypes.get(index).copied()
+}
+
+/// Check the type of a column read from the file and fit its nullability to the table column.
+pub(crate) fn adjust_column(column: Column, name: &str, data_type: &DataType) -> Result<Column> {
+    let source_type = column.data_type();
+    if source_type.remove_nullable() != data_type.remove_nullable() {
+        return Err(ErrorCode::BadBytes(format!(
+            "column {} in file is {}, but {} is expected",
+            name, source_type, data_type
+        )));
+    }
+    match column {
+        Column::Nullable(c) if !data_type.is_nullable() => {
+            if c.validity.unset_bits() > 0 {
+                Err(ErrorCode::BadBytes(format!(
+                    "null values in file can not be loaded into non-nullable column {}",
+                    name
+                )))
+            } else {
+                Ok(c.column)
+            }
+        }
+        Column::Nullable(c) => Ok(Column::Nullable(c)),
+        c if data_type.is_nullable() => Ok(c.wrap_nullable(None)),
+        c => Ok(c),
+    }
+}
+
+fn stats_to_scalars(
+    data_type: &ArrowDataType,
+    stats: &ColumnStatistics,
+) -> Option<(Scalar, Scalar)> {
+    if stats.number_of_values == Some(0) {
+        return None;
+    }
+    let int_range = || {
+        let s = stats.int_statistics.as_ref()?;
+        Some((s.minimum?, s.maximum?))
+    };
+    let float_range = || {
+        let s = stats.double_statistics.as_ref()?;
+        Some((s.minimum?, s.maximum?))
+    };
+    let range = match data_type {
+        ArrowDataType::Int8 => {
+            let (min, max) = int_range()?;
+            (NumberScalar::Int8(min as i8), NumberScalar::Int8(max as i8))
+        }
+        ArrowDataType::Int16 => {
+            let (min, max) = int_range()?;
+            (
+                NumberScalar::Int16(min as i16),
+                NumberScalar::Int16(max as i16),
+            )
+        }
+        ArrowDataType::Int32 => {
+            let (min, max) = int_range()?;
+            (
+                NumberScalar::Int32(min as i32),
+                NumberScalar::Int32(max as i32),
+            )
+        }
+        ArrowDataType::Int64 => {
+            let (min, max) = int_range()?;
+            (NumberScalar::Int64(min), NumberScalar::Int64(max))
+        }
+        ArrowDataType::Float32 => {
+            let (min, max) = float_range()?;
+            (
+                NumberScalar::Float32((min as f32).into()),
+                NumberScalar::Float32((max as f32).into()),
+            )
+        }
+        ArrowDataType::Float64 => {
+            let (min, max) = float_range()?;
+            (
+                NumberScalar::Float64(min.into()),
+                NumberScalar::Float64(max.into()),
+            )
+        }
+        ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => {
+            let s = stats.string_statistics.as_ref()?;
+            let min = s.minimum.as_ref()?.as_bytes().to_vec();
+            let max = s.maximum.as_ref()?.as_bytes().to_vec();
+            return Some((Scalar::String(min), Scalar::String(max)));
+        }
+        _ => return None,
+    };
+    Some((Scalar::Number(range.0), Scalar::Number(range.1)))
+}
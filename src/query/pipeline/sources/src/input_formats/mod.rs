@@ -23,6 +23,9 @@ mod source_aligner;
 mod transform_deserializer;
 
 pub use beyond_end_reader::BeyondEndReader;
+pub use impls::read_avro_schema_async;
+pub use impls::read_orc_schema_async;
+pub use impls::OrcSplitMeta;
 pub use input_context::InputContext;
 pub use input_context::InputPlan;
 pub use input_context::StreamPlan;
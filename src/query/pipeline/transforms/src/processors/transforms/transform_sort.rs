@@ -22,6 +22,8 @@ use common_profile::SharedProcessorProfiles;
 use super::transform_multi_sort_merge::try_add_multi_sort_merge;
 use super::transform_sort_merge::try_create_transform_sort_merge;
 use super::transform_sort_merge_limit::try_create_transform_sort_merge_limit;
+use super::transform_sort_spill::try_create_transform_sort_spill;
+use super::SortSpillParams;
 use super::TransformSortPartial;
 use crate::processors::profile_wrapper::ProcessorProfileWrapper;
 
@@ -35,6 +37,7 @@ pub fn build_full_sort_pipeline(
     final_block_size: usize,
     prof_info: Option<(u32, SharedProcessorProfiles)>,
     after_exchange: bool,
+    spill_params: Option<SortSpillParams>,
 ) -> Result<()> {
     // Partial sort
     if limit.is_none() || !after_exchange {
@@ -57,8 +60,9 @@ pub fn build_full_sort_pipeline(
     // Merge sort
     let need_multi_merge = pipeline.output_len() > 1;
     pipeline.add_transform(|input, output| {
-        let transform = match limit {
-            Some(limit) => try_create_transform_sort_merge_limit(
+        let transform = match (limit, &spill_params) {
+            // Sort with limit only keeps `limit` rows in memory, no need to spill.
+            (Some(limit), _) => try_create_transform_sort_merge_limit(
                 input,
                 output,
                 input_schema.clone(),
@@ -67,7 +71,16 @@ pub fn build_full_sort_pipeline(
                 limit,
                 need_multi_merge,
             )?,
-            _ => try_create_transform_sort_merge(
+            (None, Some(spill_params)) => try_create_transform_sort_spill(
+                input,
+                output,
+                input_schema.clone(),
+                partial_block_size,
+                sort_desc.clone(),
+                need_multi_merge,
+                spill_params.clone(),
+            )?,
+            (None, None) => try_create_transform_sort_merge(
                 input,
                 output,
                 input_schema.clone(),
@@ -0,0 +1,608 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::row::RowConverter as CommonRowConverter;
+use common_expression::types::string::StringColumn;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::with_number_mapped_type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use futures_util::future::BoxFuture;
+use opendal::Operator;
+use tracing::warn;
+
+use super::sort::Cursor;
+use super::sort::RowConverter;
+use super::sort::Rows;
+use super::sort::SimpleRowConverter;
+use super::sort::SimpleRows;
+use super::transform_sort_merge::SortMergeCompactor;
+use super::Compactor;
+
+/// Parameters of the external sort.
+#[derive(Clone)]
+pub struct SortSpillParams {
+    pub operator: Operator,
+    pub location_prefix: String,
+    /// Sorted runs are spilled once the blocks buffered by a processor exceed this size.
+    pub spilling_bytes_threshold: usize,
+}
+
+struct SpilledBlock {
+    data_range: Range<u64>,
+    num_rows: usize,
+    columns_layout: Vec<usize>,
+}
+
+/// A sorted run, each block of the run is sorted and the blocks are in order.
+enum SortedRun {
+    Memory(VecDeque<DataBlock>),
+    Spilled {
+        location: String,
+        blocks: VecDeque<SpilledBlock>,
+    },
+}
+
+/// External merge sort.
+///
+/// Works like [`super::transform_sort_merge::SortMergeCompactor`] until the buffered blocks
+/// exceed the memory threshold, then they are merged into a sorted run which is written to
+/// storage. Once the input is finished, all the runs are merged by a k-way streaming merge,
+/// only one block of each run is kept in memory at a time.
+///
+/// The file of a spilled run is removed once the run is read, or when the processor is dropped.
+pub struct TransformSortSpill<R, Converter>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+{
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    block_size: usize,
+    order_by_cols: Vec<usize>,
+    gen_order_col: bool,
+    params: SortSpillParams,
+    compactor: SortMergeCompactor<R, Converter>,
+    row_converter: Converter,
+
+    blocks: Vec<DataBlock>,
+    memory_size: usize,
+    need_spill: bool,
+    input_finished: bool,
+    collect_finished: bool,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+    output_blocks: VecDeque<DataBlock>,
+
+    runs: Vec<SortedRun>,
+    /// Runs whose current block is drained and need to load the next one.
+    pending_runs: VecDeque<usize>,
+    /// Blocks of the runs referenced by the cursors and `in_progress_rows`.
+    merging_blocks: Vec<DataBlock>,
+    /// The index in `merging_blocks` of the current block of each run.
+    current_blocks: Vec<Option<usize>>,
+    /// The accumulated rows for the next output block, format: (merging_block_index, row_index).
+    in_progress_rows: Vec<(usize, usize)>,
+    heap: BinaryHeap<Reverse<Cursor<R>>>,
+    merge_finished: bool,
+}
+
+impl<R, Converter> TransformSortSpill<R, Converter>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+{
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_desc: Vec<SortColumnDescription>,
+        gen_order_col: bool,
+        params: SortSpillParams,
+    ) -> Result<Box<dyn Processor>>
+    where
+        R: Send + 'static,
+        Converter: Send + 'static,
+    {
+        let order_by_cols = sort_desc.iter().map(|i| i.offset).collect::<Vec<_>>();
+        let row_converter = Converter::create(sort_desc.clone(), schema.clone())?;
+        let compactor = SortMergeCompactor::try_create(schema, block_size, sort_desc, false)?;
+        Ok(Box::new(TransformSortSpill {
+            input,
+            output,
+            block_size,
+            order_by_cols,
+            gen_order_col,
+            params,
+            compactor,
+            row_converter,
+            blocks: vec![],
+            memory_size: 0,
+            need_spill: false,
+            input_finished: false,
+            collect_finished: false,
+            spilling_future: None,
+            output_blocks: VecDeque::new(),
+            runs: vec![],
+            pending_runs: VecDeque::new(),
+            merging_blocks: vec![],
+            current_blocks: vec![],
+            in_progress_rows: vec![],
+            heap: BinaryHeap::new(),
+            merge_finished: false,
+        }))
+    }
+
+    fn convert_rows(&mut self, block: &DataBlock) -> Result<R> {
+        let columns = self
+            .order_by_cols
+            .iter()
+            .map(|i| block.get_by_offset(*i).clone())
+            .collect::<Vec<_>>();
+        self.row_converter.convert(&columns, block.num_rows())
+    }
+
+    fn add_order_column(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        if self.gen_order_col {
+            let order_col = self.convert_rows(&block)?.to_column();
+            block.add_column(BlockEntry {
+                data_type: order_col.data_type(),
+                value: Value::Column(order_col),
+            });
+        }
+        Ok(block)
+    }
+
+    fn spill_run(&mut self, sorted_blocks: Vec<DataBlock>) -> BoxFuture<'static, Result<()>> {
+        let location = format!(
+            "{}/{}",
+            self.params.location_prefix,
+            GlobalUniqName::unique()
+        );
+
+        let mut write_size = 0;
+        let mut write_data = Vec::with_capacity(sorted_blocks.len());
+        let mut blocks = VecDeque::with_capacity(sorted_blocks.len());
+        for block in sorted_blocks.into_iter() {
+            let begin = write_size;
+            let num_rows = block.num_rows();
+            let mut columns_layout = Vec::with_capacity(block.num_columns());
+            for entry in block.convert_to_full().columns() {
+                let column_data = serialize_column(entry.value.as_column().unwrap());
+                write_size += column_data.len() as u64;
+                columns_layout.push(column_data.len());
+                write_data.push(column_data);
+            }
+            blocks.push_back(SpilledBlock {
+                data_range: begin..write_size,
+                num_rows,
+                columns_layout,
+            });
+        }
+
+        self.runs.push(SortedRun::Spilled {
+            location: location.clone(),
+            blocks,
+        });
+
+        let operator = self.params.operator.clone();
+        Box::pin(async move {
+            let mut writer = operator.writer(&location).await?;
+            for data in write_data.into_iter() {
+                writer.write(data).await?;
+            }
+            writer.close().await?;
+            Ok(())
+        })
+    }
+
+    fn start_merge(&mut self, sorted_blocks: Vec<DataBlock>) {
+        if !sorted_blocks.is_empty() {
+            self.runs.push(SortedRun::Memory(sorted_blocks.into()));
+        }
+        self.current_blocks = vec![None; self.runs.len()];
+        self.pending_runs = (0..self.runs.len()).collect();
+    }
+
+    fn push_run_block(&mut self, run: usize, block: DataBlock) -> Result<()> {
+        if block.is_empty() {
+            self.pending_runs.push_back(run);
+            return Ok(());
+        }
+
+        let rows = self.convert_rows(&block)?;
+        self.merging_blocks.push(block);
+        self.current_blocks[run] = Some(self.merging_blocks.len() - 1);
+        self.heap.push(Reverse(Cursor::new(run, rows)));
+        Ok(())
+    }
+
+    fn need_read(&self) -> bool {
+        match self.pending_runs.front() {
+            Some(run) => matches!(self.runs[*run], SortedRun::Spilled { .. }),
+            None => false,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn read_run_block(&mut self) -> Result<()> {
+        let run = self.pending_runs.pop_front().unwrap();
+        if let SortedRun::Spilled { location, blocks } = &mut self.runs[run] {
+            if let Some(spilled_block) = blocks.pop_front() {
+                let data = self
+                    .params
+                    .operator
+                    .range_read(location, spilled_block.data_range)
+                    .await?;
+
+                let mut begin = 0;
+                let mut columns = Vec::with_capacity(spilled_block.columns_layout.len());
+                for column_layout in spilled_block.columns_layout.iter() {
+                    let column = deserialize_column(&data[begin..begin + column_layout])
+                        .ok_or_else(|| {
+                            ErrorCode::Internal(format!(
+                                "Failed to deserialize sort spill {}",
+                                location
+                            ))
+                        })?;
+                    columns.push(BlockEntry {
+                        data_type: column.data_type(),
+                        value: Value::Column(column),
+                    });
+                    begin += column_layout;
+                }
+
+                // The whole run is read, its file is not needed anymore.
+                if blocks.is_empty() {
+                    self.params.operator.delete(location).await?;
+                    self.runs[run] = SortedRun::Memory(VecDeque::new());
+                }
+
+                let block = DataBlock::new(columns, spilled_block.num_rows);
+                self.push_run_block(run, block)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge the runs until an output block is generated or a spilled run needs to be read.
+    fn merge(&mut self) -> Result<()> {
+        loop {
+            while let Some(run) = self.pending_runs.front().copied() {
+                match &mut self.runs[run] {
+                    SortedRun::Memory(blocks) => {
+                        self.pending_runs.pop_front();
+                        if let Some(block) = blocks.pop_front() {
+                            self.push_run_block(run, block)?;
+                        }
+                    }
+                    SortedRun::Spilled { .. } => return Ok(()),
+                }
+            }
+
+            if self.in_progress_rows.len() >= self.block_size {
+                return self.build_block();
+            }
+
+            match self.heap.pop() {
+                Some(Reverse(mut cursor)) => {
+                    let run = cursor.input_index;
+                    let block_index = self.current_blocks[run].unwrap();
+                    loop {
+                        self.in_progress_rows.push((block_index, cursor.advance()));
+                        if cursor.is_finished() || self.in_progress_rows.len() >= self.block_size {
+                            break;
+                        }
+                        if let Some(Reverse(next_cursor)) = self.heap.peek() {
+                            if cursor.gt(next_cursor) {
+                                break;
+                            }
+                        }
+                    }
+
+                    match cursor.is_finished() {
+                        // The next block of the run may contain smaller rows than the others,
+                        // load it before popping from the heap again.
+                        true => self.pending_runs.push_back(run),
+                        false => self.heap.push(Reverse(cursor)),
+                    }
+                }
+                None => {
+                    self.merge_finished = true;
+                    if !self.in_progress_rows.is_empty() {
+                        return self.build_block();
+                    }
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn build_block(&mut self) -> Result<()> {
+        let mut merge_slices: Vec<(usize, usize, usize)> =
+            Vec::with_capacity(self.in_progress_rows.len());
+        for (block_index, row_index) in self.in_progress_rows.iter() {
+            match merge_slices.last_mut() {
+                Some(last) if last.0 == *block_index && last.1 + last.2 == *row_index => {
+                    last.2 += 1;
+                }
+                _ => merge_slices.push((*block_index, *row_index, 1)),
+            }
+        }
+
+        let block =
+            DataBlock::take_by_slices_limit_from_blocks(&self.merging_blocks, &merge_slices, None);
+        let block = self.add_order_column(block)?;
+        self.output_blocks.push_back(block);
+        self.in_progress_rows.clear();
+
+        // Only the current block of each run is still referenced.
+        let mut merging_blocks = Vec::with_capacity(self.current_blocks.len());
+        for current_block in self.current_blocks.iter_mut() {
+            if let Some(index) = current_block {
+                merging_blocks.push(self.merging_blocks[*index].clone());
+                *index = merging_blocks.len() - 1;
+            }
+        }
+        self.merging_blocks = merging_blocks;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<R, Converter> Processor for TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + 'static,
+    Converter: RowConverter<R> + Send + 'static,
+{
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.spilling_future.is_some() {
+            return Ok(Event::Async);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.collect_finished {
+            if self.merge_finished || self.runs.is_empty() {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+
+            return match self.need_read() {
+                true => Ok(Event::Async),
+                false => Ok(Event::Sync),
+            };
+        }
+
+        if self.need_spill {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            let block = self.input.pull_data().unwrap()?;
+            self.memory_size += block.memory_size();
+            self.blocks.push(block);
+            if self.memory_size > self.params.spilling_bytes_threshold {
+                self.need_spill = true;
+                return Ok(Event::Sync);
+            }
+        }
+
+        if self.input.is_finished() {
+            self.input_finished = true;
+            return Ok(Event::Sync);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn interrupt(&self) {
+        self.compactor.interrupt();
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if self.need_spill {
+            self.need_spill = false;
+            self.memory_size = 0;
+            let blocks = std::mem::take(&mut self.blocks);
+            let sorted_blocks = self.compactor.compact_final(blocks)?;
+            if !sorted_blocks.is_empty() {
+                self.spilling_future = Some(self.spill_run(sorted_blocks));
+            }
+            return Ok(());
+        }
+
+        if self.collect_finished {
+            return self.merge();
+        }
+
+        if self.input_finished {
+            self.collect_finished = true;
+            self.memory_size = 0;
+            let blocks = std::mem::take(&mut self.blocks);
+            let sorted_blocks = self.compactor.compact_final(blocks)?;
+            if self.runs.is_empty() {
+                // Nothing has been spilled, the blocks are sorted in memory.
+                for block in sorted_blocks.into_iter() {
+                    let block = self.add_order_column(block)?;
+                    self.output_blocks.push_back(block);
+                }
+            } else {
+                self.start_merge(sorted_blocks);
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_future) = self.spilling_future.take() {
+            return spilling_future.await;
+        }
+
+        self.read_run_block().await
+    }
+}
+
+impl<R, Converter> Drop for TransformSortSpill<R, Converter>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+{
+    fn drop(&mut self) {
+        for run in self.runs.iter() {
+            if let SortedRun::Spilled { location, .. } = run {
+                let operator = self.params.operator.clone();
+                let location = location.clone();
+                GlobalIORuntime::instance().spawn(async move {
+                    if let Err(cause) = operator.delete(&location).await {
+                        warn!("Failed to remove sort spill {}: {:?}", location, cause);
+                    }
+                });
+            }
+        }
+    }
+}
+
+type SimpleDateSortSpill = TransformSortSpill<SimpleRows<DateType>, SimpleRowConverter<DateType>>;
+type SimpleTimestampSortSpill =
+    TransformSortSpill<SimpleRows<TimestampType>, SimpleRowConverter<TimestampType>>;
+type SimpleStringSortSpill =
+    TransformSortSpill<SimpleRows<StringType>, SimpleRowConverter<StringType>>;
+type CommonSortSpill = TransformSortSpill<StringColumn, CommonRowConverter>;
+
+pub fn try_create_transform_sort_spill(
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    output_schema: DataSchemaRef,
+    block_size: usize,
+    sort_desc: Vec<SortColumnDescription>,
+    gen_order_col: bool,
+    params: SortSpillParams,
+) -> Result<Box<dyn Processor>> {
+    if sort_desc.len() == 1 {
+        let sort_type = output_schema.field(sort_desc[0].offset).data_type();
+        match sort_type {
+            DataType::Number(num_ty) => with_number_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => TransformSortSpill::<
+                    SimpleRows<NumberType<NUM_TYPE>>,
+                    SimpleRowConverter<NumberType<NUM_TYPE>>,
+                >::try_create(
+                    input,
+                    output,
+                    output_schema,
+                    block_size,
+                    sort_desc,
+                    gen_order_col,
+                    params
+                ),
+            }),
+            DataType::Date => SimpleDateSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                params,
+            ),
+            DataType::Timestamp => SimpleTimestampSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                params,
+            ),
+            DataType::String => SimpleStringSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                params,
+            ),
+            _ => CommonSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                params,
+            ),
+        }
+    } else {
+        CommonSortSpill::try_create(
+            input,
+            output,
+            output_schema,
+            block_size,
+            sort_desc,
+            gen_order_col,
+            params,
+        )
+    }
+}
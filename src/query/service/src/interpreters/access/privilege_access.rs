@@ -62,7 +62,9 @@ impl AccessChecker for PrivilegeAccess {
                 };
                 let metadata = metadata.read().clone();
                 for table in metadata.tables() {
-                    if table.is_source_of_view() {
+                    // The indexes and materialized views used to answer the query are
+                    // covered by the privileges of the tables they are built from.
+                    if table.is_source_of_view() || table.is_source_of_index() {
                         continue;
                     }
                     session
@@ -135,6 +137,32 @@ impl AccessChecker for PrivilegeAccess {
                 };
             }
 
+            // Inverted Index.
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+
             // Virtual Column.
             Plan::CreateVirtualColumns(plan) => {
                 session
@@ -459,6 +487,22 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![
+                            UserPrivilegeType::Insert,
+                            UserPrivilegeType::Update,
+                            UserPrivilegeType::Delete,
+                        ],
+                    )
+                    .await?;
+            }
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -483,6 +527,57 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+            Plan::CreateTask(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
+                    .await?;
+            }
+            Plan::AlterTask(_) | Plan::ExecuteTask(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Alter])
+                    .await?;
+            }
+            Plan::DropTask(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -588,6 +683,9 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::Begin | Plan::Commit | Plan::Rollback => {}
+            // The prepared statement is checked when it's executed.
+            Plan::Prepare(_) | Plan::ExecutePrepared(_) | Plan::Deallocate(_) => {}
         }
 
         Ok(())
@@ -14,8 +14,12 @@
 
 mod grant;
 mod table;
+mod task;
 mod util;
 
 pub use grant::validate_grant_object_exists;
 pub use table::check_referenced_computed_columns;
+pub use task::check_task_license;
+pub use task::get_task;
+pub use task::unknown_task;
 pub use util::check_deduplicate_label;
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_license::license::Feature;
+use common_license::license_manager::get_license_manager;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_users::UserApiProvider;
+
+/// Tasks are run by the background service, which is an enterprise feature.
+pub fn check_task_license(ctx: Arc<dyn TableContext>) -> Result<()> {
+    get_license_manager().manager.check_enterprise_enabled(
+        &ctx.get_settings(),
+        ctx.get_tenant(),
+        Feature::BackgroundService,
+    )
+}
+
+/// Returns the background job of a task, or `None` if there is no task with the name.
+///
+/// Tasks share the namespace of background jobs, jobs of other task types are not tasks.
+pub async fn get_task(ident: &BackgroundJobIdent) -> Result<Option<BackgroundJobInfo>> {
+    let meta_api = UserApiProvider::instance().get_meta_store_client();
+    let reply = meta_api
+        .get_background_job(GetBackgroundJobReq {
+            name: ident.clone(),
+        })
+        .await
+        .map_err(ErrorCode::from);
+    match reply {
+        Ok(reply) if reply.info.task_type == BackgroundTaskType::SQL => Ok(Some(reply.info)),
+        Ok(_) => Ok(None),
+        Err(e) if e.code() == ErrorCode::UNKNOWN_BACKGROUND_JOB => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn unknown_task(ident: &BackgroundJobIdent) -> ErrorCode {
+    ErrorCode::UnknownBackgroundJob(format!("unknown task {}", ident.name))
+}
@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::DeallocatePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct DeallocateInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DeallocatePlan,
+}
+
+impl DeallocateInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DeallocatePlan) -> Result<Self> {
+        Ok(DeallocateInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DeallocateInterpreter {
+    fn name(&self) -> &str {
+        "DeallocateInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        match self
+            .ctx
+            .get_current_session()
+            .remove_prepared_statement(&self.plan.name)
+        {
+            Some(_) => Ok(PipelineBuildResult::create()),
+            None => Err(ErrorCode::UnknownPreparedStatement(format!(
+                "Unknown prepared statement {}",
+                self.plan.name
+            ))),
+        }
+    }
+}
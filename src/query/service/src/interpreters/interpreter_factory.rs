@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -44,7 +46,10 @@ use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::ExecutePreparedPlan;
 use crate::sql::plans::Plan;
+use crate::sql::Planner;
 
 /// InterpreterFactory is the entry of Interpreter.
 pub struct InterpreterFactory;
@@ -54,15 +59,55 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        // `EXECUTE` is planned from the prepared statement of the session, the parameters are
+        // substituted before the optimization, and the interpreter of the planned statement
+        // is returned so that the schema of its result set is known.
+        let prepared_plan;
+        let plan = match plan {
+            Plan::ExecutePrepared(execute) => {
+                prepared_plan = Self::plan_prepared(&ctx, execute).await?;
+                &prepared_plan
+            }
+            _ => plan,
+        };
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+
+        // Only `COMMIT` and `ROLLBACK` are accepted after a statement of the transaction failed.
+        if ctx.txn_mgr().lock().state() == TxnState::Fail
+            && !matches!(plan, Plan::Commit | Plan::Rollback)
+        {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+
         Self::get_inner(ctx, plan)
     }
 
+    #[async_backtrace::framed]
+    async fn plan_prepared(ctx: &Arc<QueryContext>, execute: &ExecutePreparedPlan) -> Result<Plan> {
+        let prepared = ctx
+            .get_current_session()
+            .get_prepared_statement(&execute.name)
+            .ok_or_else(|| {
+                ErrorCode::UnknownPreparedStatement(format!(
+                    "Unknown prepared statement {}",
+                    execute.name
+                ))
+            })?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner
+            .plan_prepared(&prepared, execute.params.clone())
+            .await?;
+        Ok(plan)
+    }
+
     /// This is used for handlers to get the schema of the plan.
     /// Some plan may miss the schema and return empty plan such as `CallPlan`
     /// So we need to map the plan into to `Interpreter` and get the right schema.
@@ -234,6 +279,42 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
+
+            // Tasks
+            Plan::CreateTask(create_task) => Ok(Arc::new(CreateTaskInterpreter::try_create(
+                ctx,
+                *create_task.clone(),
+            )?)),
+            Plan::AlterTask(alter_task) => Ok(Arc::new(AlterTaskInterpreter::try_create(
+                ctx,
+                *alter_task.clone(),
+            )?)),
+            Plan::DropTask(drop_task) => Ok(Arc::new(DropTaskInterpreter::try_create(
+                ctx,
+                *drop_task.clone(),
+            )?)),
+            Plan::ExecuteTask(execute_task) => Ok(Arc::new(ExecuteTaskInterpreter::try_create(
+                ctx,
+                *execute_task.clone(),
+            )?)),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
                 ctx,
@@ -248,6 +329,12 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumns(create_virtual_columns) => Ok(Arc::new(
                 CreateVirtualColumnsInterpreter::try_create(ctx, *create_virtual_columns.clone())?,
@@ -292,6 +379,11 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            Plan::MergeInto(merge_into) => Ok(Arc::new(MergeIntoInterpreter::try_create(
+                ctx,
+                *merge_into.clone(),
+            )?)),
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -387,6 +479,21 @@ impl InterpreterFactory {
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
 
+            // prepared statements
+            Plan::Prepare(p) => Ok(Arc::new(PrepareInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::ExecutePrepared(_) => Err(ErrorCode::Internal(
+                "EXECUTE must be planned from the prepared statement before interpreting",
+            )),
+            Plan::Deallocate(p) => Ok(Arc::new(DeallocateInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            // transaction plans
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Rollback => Ok(Arc::new(RollbackInterpreter::try_create(ctx)?)),
+
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
                 CreateShareEndpointInterpreter::try_create(ctx, *p.clone())?,
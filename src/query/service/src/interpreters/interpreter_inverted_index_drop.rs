@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropInvertedIndexPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_TBL_INVERTED_INDEX_PREFIX;
+use common_storages_share::save_share_table_info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropInvertedIndexPlan,
+}
+
+impl DropInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropInvertedIndexPlan) -> Result<Self> {
+        Ok(DropInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        let table_info = table.get_table_info();
+
+        let mut new_table_meta = table_info.meta.clone();
+        let index = match new_table_meta.indexes.remove(&self.plan.index_name) {
+            Some(index) => index,
+            None => {
+                return if self.plan.if_exists {
+                    Ok(PipelineBuildResult::create())
+                } else {
+                    Err(ErrorCode::UnknownIndex(format!(
+                        "index {} does not exist on table {}.{}",
+                        self.plan.index_name, self.plan.database, self.plan.table
+                    )))
+                };
+            }
+        };
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        // All the files of the index are under the directory of its version, which is never
+        // reused by an index created later.
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            let index_dir = format!(
+                "{}/{}/{}/",
+                fuse_table.meta_location_generator().prefix(),
+                FUSE_TBL_INVERTED_INDEX_PREFIX,
+                index.version
+            );
+            fuse_table.get_operator().remove_all(&index_dir).await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateMaterializedViewPlan;
+use storages_common_table_meta::table::MaterializedViewRef;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+
+use crate::interpreters::InsertInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::insert::Insert;
+use crate::sql::plans::insert::InsertInputSource;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+
+    /// Register the view in the options of its source table, so that the queries on the
+    /// source table can find the view without listing all the tables.
+    #[async_backtrace::framed]
+    async fn register_view(&self, catalog: &dyn Catalog, view_id: u64) -> Result<()> {
+        let (source_database, source_table) = match &self.plan.source_table {
+            Some(source_table) => source_table,
+            None => return Ok(()),
+        };
+        let view = MaterializedViewRef {
+            table_id: view_id,
+            database: self.plan.database.clone(),
+            name: self.plan.view_name.clone(),
+        };
+
+        let mut retries = 0;
+        loop {
+            let (ident, meta) = catalog
+                .get_table_meta_by_id(source_table.ident.table_id)
+                .await?;
+            let views = MaterializedViewRef::register(&meta.options, view.clone())?;
+            let req = UpsertTableOptionReq {
+                table_id: ident.table_id,
+                seq: MatchSeq::Exact(ident.seq),
+                options: HashMap::from([(OPT_KEY_MATERIALIZED_VIEWS.to_string(), Some(views))]),
+            };
+            match catalog
+                .upsert_table_option(&self.plan.tenant, source_database, req)
+                .await
+            {
+                Err(e)
+                    if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                        && retries < MAX_REGISTER_RETRIES =>
+                {
+                    // The source table is updated concurrently, retry with its latest meta.
+                    retries += 1;
+                }
+                res => return res.map(|_| ()),
+            }
+        }
+    }
+}
+
+const MAX_REGISTER_RETRIES: usize = 10;
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+
+        // The view is stored as a fuse table, and filled like `CREATE TABLE ... AS SELECT ...`.
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.view_name.clone(),
+            },
+            table_meta: TableMeta {
+                engine: "FUSE".to_string(),
+                schema: self.plan.schema.clone(),
+                options: self.plan.options.clone(),
+                ..Default::default()
+            },
+        };
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table {
+            return Ok(PipelineBuildResult::create());
+        }
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.view_name)
+            .await?;
+        self.register_view(catalog.as_ref(), table.get_id()).await?;
+
+        let insert_plan = Insert {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.view_name.clone(),
+            table_id: table.get_id(),
+            schema: self.plan.schema.clone(),
+            overwrite: false,
+            source: InsertInputSource::SelectPlan(self.plan.query_plan.clone()),
+        };
+
+        InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
+            .execute2()
+            .await
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::plan::PruningStatistics;
+use common_catalog::table::Table;
+use common_catalog::txn::TxnManager;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchemaRef;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::executor::DistributedInsertSelect;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::executor::PhysicalPlanReplacer;
+use common_sql::plans::Plan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::CommitInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::schedulers::ReplaceReadSource;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+
+    /// Try to read only the blocks appended to the source table since the last refresh.
+    ///
+    /// Returns [None] if the view has to be fully refreshed, e.g. the view query is not
+    /// append-only, or some blocks are removed from the source table.
+    #[async_backtrace::framed]
+    async fn incremental_plan(&self, query_plan: &PhysicalPlan) -> Result<Option<PhysicalPlan>> {
+        let source_info = match &self.plan.source_table {
+            Some(source_info) if self.plan.incremental => source_info,
+            _ => return Ok(None),
+        };
+        let options = self.plan.table_info.options();
+        if options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+            != Some(&source_info.ident.table_id.to_string())
+        {
+            // The source table is replaced since the last refresh.
+            return Ok(None);
+        }
+
+        let source_table = FuseTable::do_create(source_info.clone())?;
+        let last_location = options
+            .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION)
+            .cloned();
+        let block_metas = source_table
+            .changed_block_metas(self.ctx.clone(), last_location)
+            .await?;
+        if block_metas
+            .iter()
+            .any(|(index, _)| index.as_ref().map_or(false, |index| index.is_deleted))
+        {
+            return Ok(None);
+        }
+
+        let mut sources = vec![];
+        let mut collect_read_source = |plan: &PhysicalPlan| {
+            if let PhysicalPlan::TableScan(scan) = plan {
+                sources.push(*scan.source.clone())
+            }
+        };
+        PhysicalPlan::traverse(
+            query_plan,
+            &mut |_| true,
+            &mut collect_read_source,
+            &mut |_| {},
+        );
+        if sources.len() != 1 {
+            return Err(ErrorCode::Internal(
+                "Invalid source with multiple table scan when do refresh materialized view",
+            ));
+        }
+
+        let mut source = sources.remove(0);
+        let (statistics, parts) = source_table.read_partitions_with_metas(
+            source_table.schema(),
+            source.push_downs.clone(),
+            &block_metas,
+            None,
+            block_metas.len(),
+            PruningStatistics::default(),
+        )?;
+        source.parts = parts;
+        source.statistics = statistics;
+
+        let mut replace_read_source = ReplaceReadSource { source };
+        Ok(Some(replace_read_source.replace(query_plan)?))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let private_txn = !self.ctx.txn_mgr().lock().is_active();
+        if private_txn {
+            // The transaction of the session is left untouched.
+            let txn_mgr = TxnManager::init();
+            txn_mgr.lock().begin();
+            self.ctx.set_txn_mgr(txn_mgr);
+        }
+
+        let mv_info = self.plan.table_info.clone();
+        let source_id = self
+            .plan
+            .source_table
+            .as_ref()
+            .map(|source_info| source_info.ident.table_id.to_string());
+        let source_location = self
+            .plan
+            .source_table
+            .as_ref()
+            .and_then(|source_info| source_info.options().get(OPT_KEY_SNAPSHOT_LOCATION))
+            .cloned();
+
+        // The view is already refreshed to the current snapshot of the source table.
+        if source_id.is_some()
+            && mv_info
+                .options()
+                .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+                == source_id.as_ref()
+            && mv_info
+                .options()
+                .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION)
+                == source_location.as_ref()
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let (query_plan, select_schema, select_column_bindings) =
+            match self.plan.query_plan.as_ref() {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => {
+                    let mut builder =
+                        PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+                    (
+                        builder.build(s_expr).await?,
+                        bind_context.output_schema(),
+                        bind_context.columns.clone(),
+                    )
+                }
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "Refresh materialized view encounter Non-Query Plan",
+                    ));
+                }
+            };
+
+        let (mut query_plan, incremental) = match self.incremental_plan(&query_plan).await? {
+            Some(plan) => (plan, true),
+            None => (query_plan, false),
+        };
+
+        let mv_table = self
+            .ctx
+            .get_table(
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.view_name,
+            )
+            .await?;
+        let insert_schema: DataSchemaRef = Arc::new(mv_table.schema().into());
+        if select_schema.fields().len() != insert_schema.fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Fields in materialized view query is not equal with the view, query fields: {}, view fields: {}",
+                select_schema.fields().len(),
+                insert_schema.fields().len(),
+            )));
+        }
+        let cast_needed = select_schema != insert_schema;
+
+        let insert_select_plan = match query_plan {
+            PhysicalPlan::Exchange(ref mut exchange) => {
+                // insert can be dispatched to different nodes
+                let input = exchange.input.clone();
+                exchange.input = Box::new(PhysicalPlan::DistributedInsertSelect(Box::new(
+                    DistributedInsertSelect {
+                        plan_id: exchange.plan_id,
+                        input,
+                        catalog: self.plan.catalog.clone(),
+                        table_info: mv_table.get_table_info().clone(),
+                        select_schema,
+                        select_column_bindings,
+                        insert_schema,
+                        cast_needed,
+                    },
+                )));
+                query_plan
+            }
+            other_plan => {
+                // insert should wait until all nodes finished
+                PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+                    plan_id: other_plan.get_id(),
+                    input: Box::new(other_plan),
+                    catalog: self.plan.catalog.clone(),
+                    table_info: mv_table.get_table_info().clone(),
+                    select_schema,
+                    select_column_bindings,
+                    insert_schema,
+                    cast_needed,
+                }))
+            }
+        };
+
+        let mut build_res =
+            build_query_pipeline_without_render_result_set(&self.ctx, &insert_select_plan, false)
+                .await?;
+        // A full refresh replaces the data of the view.
+        mv_table.commit_insertion(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            None,
+            !incremental,
+        )?;
+
+        // The new data and the refreshed position of the view are committed together:
+        // the private transaction is committed when the pipeline finishes, the one of
+        // the session is committed by `COMMIT`.
+        let txn_mgr = self.ctx.txn_mgr();
+        let ctx = self.ctx.clone();
+        let catalog = self.plan.catalog.clone();
+        build_res
+            .main_pipeline
+            .set_on_finished(move |may_error| match may_error {
+                None => {
+                    {
+                        let mut txn_mgr = txn_mgr.lock();
+                        let mut new_table_meta = txn_mgr
+                            .apply_table_update(&mv_info)
+                            .unwrap_or_else(|| mv_info.clone())
+                            .meta;
+                        match source_id {
+                            Some(source_id) => {
+                                new_table_meta.options.insert(
+                                    OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID.to_string(),
+                                    source_id,
+                                );
+                            }
+                            None => {
+                                new_table_meta
+                                    .options
+                                    .remove(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+                            }
+                        }
+                        match source_location {
+                            Some(location) => {
+                                new_table_meta.options.insert(
+                                    OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION.to_string(),
+                                    location,
+                                );
+                            }
+                            None => {
+                                new_table_meta
+                                    .options
+                                    .remove(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION);
+                            }
+                        }
+                        txn_mgr.update_table_meta(&catalog, UpdateTableMetaReq {
+                            table_id: mv_info.ident.table_id,
+                            seq: MatchSeq::Exact(mv_info.ident.seq),
+                            new_table_meta,
+                            copied_files: None,
+                            deduplicated_label: None,
+                        });
+                    }
+                    if !private_txn {
+                        return Ok(());
+                    }
+                    GlobalIORuntime::instance().block_on(async move {
+                        CommitInterpreter::try_create(ctx)?
+                            .execute2()
+                            .await
+                            .map(|_| ())
+                    })
+                }
+                Some(error_code) => Err(error_code.clone()),
+            });
+
+        Ok(build_res)
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_sql::optimizer::CascadesOptimizer;
+use common_sql::optimizer::HeuristicOptimizer;
+use common_sql::optimizer::SExpr;
+use common_sql::optimizer::DEFAULT_REWRITE_RULES;
+use common_sql::plans::BoundColumnRef;
+use common_sql::plans::EvalScalar;
+use common_sql::plans::MergeInto;
+use common_sql::plans::RelOperator;
+use common_sql::plans::ScalarItem;
+use common_sql::BindContext;
+use common_sql::ColumnBinding;
+use common_sql::ScalarExpr;
+use common_sql::Visibility;
+use common_storages_fuse::FuseTable;
+use futures_util::TryStreamExt;
+use table_lock::TableLockHandlerWrapper;
+
+use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::transforms::MatchedLayout;
+use crate::pipelines::processors::transforms::MergeIntoMatchedRowIds;
+use crate::pipelines::processors::transforms::TransformMergeIntoSplit;
+use crate::pipelines::processors::transforms::UnmatchedLayout;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::stream::PullingExecutorStream;
+
+/// interprets MergeInto
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeInto,
+}
+
+impl MergeIntoInterpreter {
+    /// Create the MergeIntoInterpreter from MergeInto plan
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeInto) -> Result<Self> {
+        Ok(MergeIntoInterpreter { ctx, plan })
+    }
+
+    // Build the pipeline of the MERGE INTO statement, the source join is streamed through
+    // the WHEN clauses into the append pipeline of the table, which outputs the mutation
+    // logs of the appended segments only. The returned pipeline deletes the matched rows
+    // and commits the mutation.
+    #[async_backtrace::framed]
+    async fn build_merge_into(&self, fuse_table: &FuseTable) -> Result<PipelineBuildResult> {
+        let row_id_binding = self.plan.row_id_binding();
+        let mut items = vec![ScalarItem {
+            scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: row_id_binding.clone(),
+            }),
+            index: row_id_binding.index,
+        }];
+        let mut result_columns = vec![row_id_binding];
+
+        let mut add_item = |scalar: &ScalarExpr| -> Result<usize> {
+            let data_type = scalar.data_type()?;
+            let name = format!("merge_into_{}", result_columns.len());
+            let index = self
+                .plan
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), data_type.clone());
+            items.push(ScalarItem {
+                scalar: scalar.clone(),
+                index,
+            });
+            result_columns.push(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: name,
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+            Ok(result_columns.len() - 1)
+        };
+
+        let mut matched_layouts = Vec::with_capacity(self.plan.matched_evaluators.len());
+        for evaluator in self.plan.matched_evaluators.iter() {
+            let condition = evaluator
+                .condition
+                .as_ref()
+                .map(&mut add_item)
+                .transpose()?;
+            let update = evaluator
+                .update
+                .as_ref()
+                .map(|row| row.iter().map(&mut add_item).collect::<Result<Vec<_>>>())
+                .transpose()?;
+            matched_layouts.push(MatchedLayout { condition, update });
+        }
+
+        let mut unmatched_layouts = Vec::with_capacity(self.plan.unmatched_evaluators.len());
+        for evaluator in self.plan.unmatched_evaluators.iter() {
+            let condition = evaluator
+                .condition
+                .as_ref()
+                .map(&mut add_item)
+                .transpose()?;
+            let values = evaluator
+                .values
+                .iter()
+                .map(&mut add_item)
+                .collect::<Result<Vec<_>>>()?;
+            unmatched_layouts.push(UnmatchedLayout { condition, values });
+        }
+
+        let expr = SExpr::create_unary(
+            Arc::new(RelOperator::EvalScalar(EvalScalar { items })),
+            Arc::new(*self.plan.input.clone()),
+        );
+
+        // Optimize expression
+        // BindContext is only used by pre_optimize and post_optimize, so we can use a mock one.
+        let mock_bind_context = Box::new(BindContext::new());
+        let heuristic_optimizer = HeuristicOptimizer::new(
+            self.ctx.get_function_context()?,
+            &mock_bind_context,
+            self.plan.metadata.clone(),
+        );
+        let mut expr = heuristic_optimizer.optimize_expression(&expr, &DEFAULT_REWRITE_RULES)?;
+        let mut cascades =
+            CascadesOptimizer::create(self.ctx.clone(), self.plan.metadata.clone(), false)?;
+        expr = cascades.optimize(expr)?;
+
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            BindContext::new(),
+            expr,
+            self.plan.metadata.clone(),
+            None,
+            false,
+        )?;
+        let physical_plan = select_interpreter.build_physical_plan().await?;
+        let mut pipeline =
+            build_query_pipeline(&self.ctx, &result_columns, &physical_plan, false, false).await?;
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let schema = fuse_table.schema();
+        let matched_layouts = Arc::new(matched_layouts);
+        let unmatched_layouts = Arc::new(unmatched_layouts);
+        let matched_row_ids = Arc::new(MergeIntoMatchedRowIds::default());
+        pipeline.main_pipeline.add_transform(|input, output| {
+            TransformMergeIntoSplit::try_create(
+                input,
+                output,
+                func_ctx.clone(),
+                schema.clone(),
+                matched_layouts.clone(),
+                unmatched_layouts.clone(),
+                matched_row_ids.clone(),
+            )
+        })?;
+        fuse_table.do_append_data(
+            self.ctx.clone(),
+            &mut pipeline.main_pipeline,
+            AppendMode::Normal,
+        )?;
+
+        // Execute pipeline, only the mutation logs of the appended segments are collected.
+        let settings = self.ctx.get_settings();
+        let query_id = self.ctx.get_id();
+        let settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(pipeline, settings)?;
+        self.ctx.set_executor(pulling_executor.get_inner())?;
+        let append_logs = PullingExecutorStream::create(pulling_executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+
+        let deleted_row_ids = matched_row_ids.take();
+        let mut build_res = PipelineBuildResult::create();
+        if deleted_row_ids.is_empty() && append_logs.is_empty() {
+            return Ok(build_res);
+        }
+
+        fuse_table
+            .build_merge_into_pipeline(
+                self.ctx.clone(),
+                deleted_row_ids,
+                append_logs,
+                &mut build_res.main_pipeline,
+            )
+            .await?;
+        Ok(build_res)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    /// Get the name of current interpreter
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", name = "merge_into_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if check_deduplicate_label(self.ctx.clone()).await? {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The table is cached by the query context, so the `_row_id`s of the source
+        // are generated from the same snapshot that is mutated.
+        let tbl = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table =
+            tbl.as_any()
+                .downcast_ref::<FuseTable>()
+                .ok_or(ErrorCode::Unimplemented(format!(
+                    "table {}, engine type {}, does not support MERGE INTO",
+                    tbl.name(),
+                    tbl.get_table_info().engine(),
+                )))?;
+
+        // Add table lock heartbeat.
+        let handler = TableLockHandlerWrapper::instance(self.ctx.clone());
+        let mut heartbeat = handler
+            .try_lock(self.ctx.clone(), tbl.get_table_info().clone())
+            .await?;
+
+        let mut build_res = match self.build_merge_into(fuse_table).await {
+            Ok(build_res) => build_res,
+            Err(cause) => {
+                heartbeat.shutdown().await?;
+                return Err(cause);
+            }
+        };
+
+        if build_res.main_pipeline.is_empty() {
+            heartbeat.shutdown().await?;
+            return Ok(build_res);
+        }
+
+        build_res.main_pipeline.set_on_finished(move |may_error| {
+            // shutdown table lock heartbeat.
+            GlobalIORuntime::instance().block_on(async move { heartbeat.shutdown().await })?;
+            match may_error {
+                None => Ok(()),
+                Some(error_code) => Err(error_code.clone()),
+            }
+        });
+        Ok(build_res)
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Statement;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::PreparePlan;
+use common_sql::Planner;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct PrepareInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: PreparePlan,
+}
+
+impl PrepareInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: PreparePlan) -> Result<Self> {
+        Ok(PrepareInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for PrepareInterpreter {
+    fn name(&self) -> &str {
+        "PrepareInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let mut planner = Planner::new(self.ctx.clone());
+        let prepared = planner.prepare_sql(&self.plan.sql).await?;
+        if matches!(
+            prepared.statement,
+            Statement::Prepare(_) | Statement::Execute(_) | Statement::Deallocate(_)
+        ) {
+            return Err(ErrorCode::SyntaxException(
+                "prepared statements can not be nested",
+            ));
+        }
+
+        // Preparing with an existing name replaces the old statement.
+        self.ctx
+            .get_current_session()
+            .set_prepared_statement(self.plan.name.clone(), Arc::new(prepared));
+        Ok(PipelineBuildResult::create())
+    }
+}
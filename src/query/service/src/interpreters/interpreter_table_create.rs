@@ -22,6 +22,8 @@ use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_expression::BLOCK_NAME_COL_NAME;
+use common_expression::CHANGE_ACTION_COL_NAME;
+use common_expression::CHANGE_ROW_ID_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_expression::SNAPSHOT_NAME_COL_NAME;
@@ -36,6 +38,7 @@ use common_sql::field_default_value;
 use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
+use common_sql::NgramIndexColumns;
 use common_storage::DataOperator;
 use common_storages_fuse::io::MetaReaders;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
@@ -54,6 +57,7 @@ use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -259,7 +263,9 @@ impl CreateTableInterpreter {
         is_valid_block_per_segment(&table_meta.options)?;
 
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&table_meta.options, schema)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -355,6 +361,7 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
@@ -379,6 +386,8 @@ pub static INTERNAL_COLUMN_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(SNAPSHOT_NAME_COL_NAME);
     r.insert(SEGMENT_NAME_COL_NAME);
     r.insert(BLOCK_NAME_COL_NAME);
+    r.insert(CHANGE_ACTION_COL_NAME);
+    r.insert(CHANGE_ROW_ID_COL_NAME);
 
     r.insert(PREDICATE_COLUMN_NAME);
 
@@ -417,3 +426,13 @@ pub fn is_valid_bloom_index_columns(
     }
     Ok(())
 }
+
+pub fn is_valid_ngram_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        NgramIndexColumns::verify_definition(value, schema)?;
+    }
+    Ok(())
+}
@@ -21,9 +21,11 @@ use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::DropTableColumnPlan;
 use common_sql::BloomIndexColumns;
+use common_sql::NgramIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -100,6 +102,18 @@ impl Interpreter for DropTableColumnInterpreter {
                 }
             }
         }
+        if let Some(value) = opts.get_mut(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+            let mut ngram_index_cols = value.parse::<NgramIndexColumns>()?;
+            if let Some(pos) = ngram_index_cols
+                .0
+                .iter()
+                .position(|(x, _)| *x == self.plan.column)
+            {
+                // remove from the ngram index columns.
+                ngram_index_cols.0.remove(pos);
+                *value = ngram_index_cols.to_string();
+            }
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
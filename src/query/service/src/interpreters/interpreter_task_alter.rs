@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::AlterTaskAction;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobState;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_sql::plans::AlterTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::common::check_task_license;
+use crate::interpreters::common::get_task;
+use crate::interpreters::common::unknown_task;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct AlterTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTaskPlan,
+}
+
+impl AlterTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTaskPlan) -> Result<Self> {
+        Ok(AlterTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTaskInterpreter {
+    fn name(&self) -> &str {
+        "AlterTaskInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        check_task_license(self.ctx.clone())?;
+
+        let ident = BackgroundJobIdent {
+            tenant: self.plan.tenant.clone(),
+            name: self.plan.task_name.clone(),
+        };
+        let info = match get_task(&ident).await? {
+            Some(info) => info,
+            None if self.plan.if_exists => return Ok(PipelineBuildResult::create()),
+            None => return Err(unknown_task(&ident)),
+        };
+
+        let mut status = match (info.job_status, info.job_params) {
+            (Some(status), _) => status,
+            (None, Some(params)) => BackgroundJobStatus::new(&params),
+            (None, None) => BackgroundJobStatus::default(),
+        };
+        status.job_state = match self.plan.action {
+            AlterTaskAction::Suspend => BackgroundJobState::SUSPENDED,
+            AlterTaskAction::Resume => BackgroundJobState::RUNNING,
+        };
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: ident,
+                status,
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::CreateBackgroundJobReq;
+use common_sql::plans::CreateTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::common::check_task_license;
+use crate::interpreters::common::get_task;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTaskPlan,
+}
+
+impl CreateTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTaskPlan) -> Result<Self> {
+        Ok(CreateTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTaskInterpreter {
+    fn name(&self) -> &str {
+        "CreateTaskInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        check_task_license(self.ctx.clone())?;
+        BackgroundJobParams::check_cron_expression(&self.plan.schedule)
+            .map_err(ErrorCode::InvalidCronExpression)?;
+
+        let ident = BackgroundJobIdent {
+            tenant: self.plan.tenant.clone(),
+            name: self.plan.task_name.clone(),
+        };
+        // Only an existing task satisfies IF NOT EXISTS, a background job of
+        // another type with the same name is reported as already existing.
+        if self.plan.if_not_exists && get_task(&ident).await?.is_some() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The task runs as its creator under the role it is created with.
+        let user = self.ctx.get_current_user()?;
+        let owner_role = self.ctx.get_current_role().map(|role| role.name);
+        let params = BackgroundJobParams::new_cron_job(self.plan.schedule.clone(), None);
+        let info = BackgroundJobInfo::new_sql_task_job(
+            params,
+            self.plan.sql.clone(),
+            owner_role,
+            user.identity(),
+        );
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        meta_api
+            .create_background_job(CreateBackgroundJobReq {
+                if_not_exists: false,
+                job_name: ident,
+                job_info: info,
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
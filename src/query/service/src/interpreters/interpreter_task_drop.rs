@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::DeleteBackgroundJobReq;
+use common_sql::plans::DropTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::common::check_task_license;
+use crate::interpreters::common::get_task;
+use crate::interpreters::common::unknown_task;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct DropTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTaskPlan,
+}
+
+impl DropTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTaskPlan) -> Result<Self> {
+        Ok(DropTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTaskInterpreter {
+    fn name(&self) -> &str {
+        "DropTaskInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        check_task_license(self.ctx.clone())?;
+
+        let ident = BackgroundJobIdent {
+            tenant: self.plan.tenant.clone(),
+            name: self.plan.task_name.clone(),
+        };
+        if get_task(&ident).await?.is_none() {
+            return if self.plan.if_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(unknown_task(&ident))
+            };
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        meta_api
+            .drop_background_job(DeleteBackgroundJobReq { name: ident })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
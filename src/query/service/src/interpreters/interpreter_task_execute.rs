@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::uuid::Uuid;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::UpdateBackgroundJobParamsReq;
+use common_sql::plans::ExecuteTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::common::check_task_license;
+use crate::interpreters::common::get_task;
+use crate::interpreters::common::unknown_task;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct ExecuteTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExecuteTaskPlan,
+}
+
+impl ExecuteTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExecuteTaskPlan) -> Result<Self> {
+        Ok(ExecuteTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExecuteTaskInterpreter {
+    fn name(&self) -> &str {
+        "ExecuteTaskInterpreter"
+    }
+
+    /// Registers a manual trigger on the task, the job scheduler of the
+    /// background service runs the task once on its next tick.
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        check_task_license(self.ctx.clone())?;
+
+        let ident = BackgroundJobIdent {
+            tenant: self.plan.tenant.clone(),
+            name: self.plan.task_name.clone(),
+        };
+        let info = match get_task(&ident).await? {
+            Some(info) => info,
+            None => return Err(unknown_task(&ident)),
+        };
+
+        let mut params = info.job_params.unwrap_or_default();
+        let trigger = self.ctx.get_current_user()?.identity();
+        params.manual_trigger_params = Some(ManualTriggerParams::new(
+            Uuid::new_v4().to_string(),
+            trigger,
+        ));
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: ident,
+                params,
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TruncateTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use tracing::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The transaction ends whether the commit succeeds or not.
+        let (state, table_updates, truncated_tables) = {
+            let mut txn_mgr = self.ctx.txn_mgr().lock();
+            let state = txn_mgr.state();
+            let table_updates = txn_mgr.table_updates();
+            let truncated_tables = txn_mgr.truncated_tables();
+            txn_mgr.clear();
+            (state, table_updates, truncated_tables)
+        };
+
+        match state {
+            // `COMMIT` outside a transaction is ignored.
+            TxnState::AutoCommit => Ok(PipelineBuildResult::create()),
+            TxnState::Fail => Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, it has been rolled back",
+            )),
+            TxnState::Active => {
+                let catalog_name = match table_updates.first() {
+                    None => return Ok(PipelineBuildResult::create()),
+                    Some(update) => update.catalog.clone(),
+                };
+                if table_updates.iter().any(|u| u.catalog != catalog_name) {
+                    return Err(ErrorCode::Unimplemented(
+                        "updating tables of different catalogs in one transaction is not supported",
+                    ));
+                }
+
+                let catalog = self.ctx.get_catalog(&catalog_name)?;
+                let req = UpdateMultiTableMetaReq {
+                    update_table_metas: table_updates.into_iter().map(|u| u.req).collect(),
+                };
+                catalog.update_multi_table_meta(req).await?;
+
+                // best effort to remove the copied files of the truncated tables, the same
+                // as `TRUNCATE` outside a transaction.
+                for truncated in truncated_tables {
+                    let req = TruncateTableReq {
+                        table_id: truncated.table_info.ident.table_id,
+                        batch_size: None,
+                    };
+                    if let Err(cause) = catalog.truncate_table(&truncated.table_info, req).await {
+                        warn!(
+                            "failed to remove the copied files of table {}: {:?}",
+                            truncated.table_info.desc, cause
+                        );
+                    }
+                }
+                Ok(PipelineBuildResult::create())
+            }
+        }
+    }
+}
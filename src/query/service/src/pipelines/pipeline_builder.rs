@@ -46,6 +46,7 @@ use common_pipeline_transforms::processors::profile_wrapper::ProcessorProfileWra
 use common_pipeline_transforms::processors::profile_wrapper::ProfileStub;
 use common_pipeline_transforms::processors::profile_wrapper::TransformProfileWrapper;
 use common_pipeline_transforms::processors::transforms::build_full_sort_pipeline;
+use common_pipeline_transforms::processors::transforms::SortSpillParams;
 use common_pipeline_transforms::processors::transforms::Transformer;
 use common_profile::SharedProcessorProfiles;
 use common_sql::evaluator::BlockOperator;
@@ -99,6 +100,7 @@ use crate::pipelines::processors::transforms::build_partition_bucket;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
+use crate::pipelines::processors::transforms::HashJoinSpillState;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RangeJoinState;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
@@ -110,6 +112,7 @@ use crate::pipelines::processors::transforms::TransformPartialGroupBy;
 use crate::pipelines::processors::transforms::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::TransformRangeJoinRight;
 use crate::pipelines::processors::transforms::TransformWindow;
+use crate::pipelines::processors::transforms::WindowSpillParams;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::SinkRuntimeFilterSource;
@@ -392,8 +395,9 @@ impl PipelineBuilder {
 
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         let state = self.build_join_state(join)?;
-        self.expand_build_side_pipeline(&join.build, join, state.clone())?;
-        self.build_join_probe(join, state)
+        let spill_state = self.build_join_spill_state(join)?;
+        self.expand_build_side_pipeline(&join.build, join, state.clone(), spill_state.clone())?;
+        self.build_join_probe(join, state, spill_state)
     }
 
     fn build_join_state(&mut self, join: &HashJoin) -> Result<Arc<JoinHashTable>> {
@@ -406,11 +410,34 @@ impl PipelineBuilder {
         )
     }
 
+    fn build_join_spill_state(&self, join: &HashJoin) -> Result<Option<Arc<HashJoinSpillState>>> {
+        let threshold_per_proc = self
+            .ctx
+            .get_settings()
+            .get_join_spilling_bytes_threshold_per_proc()?;
+
+        // The runtime filter is built from the whole build side, which must stay in memory.
+        if threshold_per_proc == 0 || join.contain_runtime_filter {
+            return Ok(None);
+        }
+
+        let operator = DataOperator::instance().operator();
+        let location_prefix = format!("_hash_join_spill/{}", self.ctx.get_tenant());
+        HashJoinSpillState::try_create(
+            self.ctx.clone(),
+            join,
+            operator,
+            location_prefix,
+            threshold_per_proc,
+        )
+    }
+
     fn expand_build_side_pipeline(
         &mut self,
         build: &PhysicalPlan,
         hash_join_plan: &HashJoin,
         join_state: Arc<JoinHashTable>,
+        spill_state: Option<Arc<HashJoinSpillState>>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
         let build_side_builder = PipelineBuilder::create(
@@ -425,6 +452,7 @@ impl PipelineBuilder {
             let transform = TransformHashJoinBuild::create(
                 input,
                 TransformHashJoinBuild::attach(join_state.clone())?,
+                spill_state.clone(),
             );
 
             if self.enable_profiling {
@@ -491,7 +519,12 @@ impl PipelineBuilder {
 
         // Fill internal columns if needed.
         if let Some(internal_columns) = &scan.internal_column {
-            if table.support_row_id_column() {
+            let change_columns = table.change_columns();
+            if table.support_row_id_column()
+                || internal_columns
+                    .values()
+                    .all(|column| change_columns.contains(column))
+            {
                 self.main_pipeline.add_transform(|input, output| {
                     Ok(ProcessorPtr::create(Box::new(
                         FillInternalColumnProcessor::create(
@@ -1066,6 +1099,20 @@ impl PipelineBuilder {
         // `TransformWindow` is a pipeline breaker.
         self.main_pipeline.try_resize(1)?;
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
+
+        let spilling_bytes_threshold_per_proc = self
+            .ctx
+            .get_settings()
+            .get_window_spilling_bytes_threshold_per_proc()?;
+        let spill_params = match spilling_bytes_threshold_per_proc.is_zero() {
+            true => None,
+            false => Some(WindowSpillParams {
+                operator: DataOperator::instance().operator(),
+                location_prefix: format!("_window_spill/{}", self.ctx.get_tenant()),
+                spilling_bytes_threshold_per_proc,
+            }),
+        };
+
         // Window
         self.main_pipeline.add_transform(|input, output| {
             // The transform can only be created here, because it cannot be cloned.
@@ -1080,6 +1127,7 @@ impl PipelineBuilder {
                     partition_by.clone(),
                     order_by.clone(),
                     (start_bound, end_bound),
+                    spill_params.clone(),
                 )?) as Box<dyn Processor>
             } else {
                 if order_by.len() == 1 {
@@ -1101,6 +1149,7 @@ impl PipelineBuilder {
                                     partition_by.clone(),
                                     order_by.clone(),
                                     (start_bound, end_bound),
+                                    spill_params.clone(),
                                 )?,
                             )
                                 as Box<dyn Processor>));
@@ -1120,6 +1169,7 @@ impl PipelineBuilder {
                     partition_by.clone(),
                     order_by.clone(),
                     (start_bound, end_bound),
+                    spill_params.clone(),
                 )?) as Box<dyn Processor>
             };
             Ok(ProcessorPtr::create(transform))
@@ -1202,6 +1252,19 @@ impl PipelineBuilder {
             None
         };
 
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_sort_spilling_bytes_threshold_per_proc()?;
+        let spill_params = match spilling_bytes_threshold.is_zero() {
+            true => None,
+            false => Some(SortSpillParams {
+                operator: DataOperator::instance().operator(),
+                location_prefix: format!("_sort_spill/{}", self.ctx.get_tenant()),
+                spilling_bytes_threshold,
+            }),
+        };
+
         build_full_sort_pipeline(
             &mut self.main_pipeline,
             input_schema,
@@ -1211,6 +1274,7 @@ impl PipelineBuilder {
             block_size,
             prof_info,
             after_exchange,
+            spill_params,
         )
     }
 
@@ -1245,7 +1309,12 @@ impl PipelineBuilder {
         )
     }
 
-    fn build_join_probe(&mut self, join: &HashJoin, state: Arc<JoinHashTable>) -> Result<()> {
+    fn build_join_probe(
+        &mut self,
+        join: &HashJoin,
+        state: Arc<JoinHashTable>,
+        spill_state: Option<Arc<HashJoinSpillState>>,
+    ) -> Result<()> {
         self.build_pipeline(&join.probe)?;
 
         let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
@@ -1259,6 +1328,7 @@ impl PipelineBuilder {
                 func_ctx.clone(),
                 &join.join_type,
                 !join.non_equi_conditions.is_empty(),
+                spill_state.clone(),
             )?;
 
             if self.enable_profiling {
@@ -1453,6 +1523,7 @@ impl PipelineBuilder {
                 ProcessorPtr::create(TransformHashJoinBuild::create(
                     input.clone(),
                     TransformHashJoinBuild::attach(self.join_state.as_ref().unwrap().clone())?,
+                    None,
                 )),
                 vec![input],
                 vec![],
@@ -0,0 +1,562 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::type_check::check_function;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::executor::HashJoin;
+use futures_util::future::BoxFuture;
+use opendal::Operator;
+use parking_lot::Mutex;
+use tracing::info;
+use tracing::warn;
+
+use crate::api::get_hash_values;
+use crate::pipelines::processors::transforms::hash_join::HashJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_hash_join_spill_partition_count;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_hash_join_spill_read_bytes;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_hash_join_spill_read_count;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_hash_join_spill_read_milliseconds;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_hash_join_spill_write_bytes;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_hash_join_spill_write_count;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_hash_join_spill_write_milliseconds;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The number of partitions both sides of a spillable hash join are scattered into.
+pub const SPILL_PARTITIONS: usize = 16;
+
+/// Rows of spilled partitions are buffered by each processor and written out once they exceed this size.
+const SPILL_BUFFER_BYTES: usize = 8 * 1024 * 1024;
+
+/// Seed the partition hash so that it is independent of the hash used to shuffle the join
+/// between cluster nodes, otherwise each node would only see a few of the partitions.
+const SPILL_HASH_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// A file written by a processor, holding several blocks of one partition.
+#[derive(Clone)]
+pub struct SpilledFile {
+    location: String,
+    // The number of rows and the serialized size of each column, for every block in the file.
+    blocks_layout: Vec<(usize, Vec<usize>)>,
+}
+
+struct BuildPartition {
+    spilled: bool,
+    memory_size: usize,
+    blocks: Vec<DataBlock>,
+}
+
+/// Rows of spilled partitions held by a single processor before being written to storage.
+pub struct SpillBuffer {
+    partitions: Vec<Vec<DataBlock>>,
+    memory_size: usize,
+}
+
+impl SpillBuffer {
+    pub fn create() -> SpillBuffer {
+        SpillBuffer {
+            partitions: vec![vec![]; SPILL_PARTITIONS],
+            memory_size: 0,
+        }
+    }
+
+    fn push(&mut self, partition: usize, block: DataBlock) {
+        self.memory_size += block.memory_size();
+        self.partitions[partition].push(block);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memory_size == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.memory_size >= SPILL_BUFFER_BYTES
+    }
+
+    pub fn take(&mut self) -> Vec<(usize, Vec<DataBlock>)> {
+        self.memory_size = 0;
+        self.partitions
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, blocks)| !blocks.is_empty())
+            .map(|(partition, blocks)| (partition, std::mem::take(blocks)))
+            .collect()
+    }
+}
+
+/// Shared state of a grace hash join.
+///
+/// Both sides of the join are scattered into [`SPILL_PARTITIONS`] partitions by the hash of
+/// the join keys. Build partitions stay in memory until the memory used by the build side
+/// exceeds the threshold of all the build processors, then the largest ones are spilled to
+/// storage. Partitions still in memory at the end of the build phase make up the regular join
+/// hash table, probe rows that fall into spilled partitions are spilled as well. Once all probe
+/// processors are done, each spilled partition is restored and joined on its own with a private
+/// join hash table.
+///
+/// The spilled files are written under a location private to the join, which is removed
+/// when the state is dropped.
+pub struct HashJoinSpillState {
+    ctx: Arc<QueryContext>,
+    join: HashJoin,
+    func_ctx: FunctionContext,
+    operator: Operator,
+    location_prefix: String,
+    threshold_per_proc: usize,
+    // The memory the build side can use, `threshold_per_proc` for each build processor.
+    threshold: AtomicUsize,
+    build_hash_keys: Vec<Expr>,
+    probe_hash_keys: Vec<Expr>,
+
+    spilled: AtomicBool,
+    memory_size: AtomicUsize,
+    partitions: Vec<Mutex<BuildPartition>>,
+    build_files: Vec<Mutex<Vec<SpilledFile>>>,
+    probe_files: Vec<Mutex<Vec<SpilledFile>>>,
+
+    build_count: Mutex<usize>,
+    probe_count: Mutex<usize>,
+    probe_done: Mutex<bool>,
+    probe_done_notify: Arc<Notify>,
+    restore_partitions: Mutex<VecDeque<usize>>,
+}
+
+impl HashJoinSpillState {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        join: &HashJoin,
+        operator: Operator,
+        location_prefix: String,
+        threshold_per_proc: usize,
+    ) -> Result<Option<Arc<HashJoinSpillState>>> {
+        // Mark joins keep a global null state for the whole build side and cross joins
+        // have no key to partition by, both are always joined in memory.
+        if join.build_keys.is_empty()
+            || matches!(
+                join.join_type,
+                JoinType::Cross | JoinType::LeftMark | JoinType::RightMark
+            )
+        {
+            return Ok(None);
+        }
+
+        let hash_keys = |keys: &[RemoteExpr]| {
+            keys.iter()
+                .map(|key| {
+                    check_function(
+                        None,
+                        "siphash",
+                        &[],
+                        &[key.as_expr(&BUILTIN_FUNCTIONS)],
+                        &BUILTIN_FUNCTIONS,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(Some(Arc::new(HashJoinSpillState {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            join: join.clone(),
+            operator,
+            location_prefix: format!("{}/{}", location_prefix, GlobalUniqName::unique()),
+            threshold_per_proc,
+            threshold: AtomicUsize::new(0),
+            build_hash_keys: hash_keys(&join.build_keys)?,
+            probe_hash_keys: hash_keys(&join.probe_keys)?,
+            spilled: AtomicBool::new(false),
+            memory_size: AtomicUsize::new(0),
+            partitions: (0..SPILL_PARTITIONS)
+                .map(|_| {
+                    Mutex::new(BuildPartition {
+                        spilled: false,
+                        memory_size: 0,
+                        blocks: vec![],
+                    })
+                })
+                .collect(),
+            build_files: (0..SPILL_PARTITIONS).map(|_| Mutex::new(vec![])).collect(),
+            probe_files: (0..SPILL_PARTITIONS).map(|_| Mutex::new(vec![])).collect(),
+            build_count: Mutex::new(0),
+            probe_count: Mutex::new(0),
+            probe_done: Mutex::new(false),
+            probe_done_notify: Arc::new(Notify::new()),
+            restore_partitions: Mutex::new(VecDeque::new()),
+        })))
+    }
+
+    pub fn has_spilled(&self) -> bool {
+        self.spilled.load(Ordering::Acquire)
+    }
+
+    fn partition(&self, hash_keys: &[Expr], block: &DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        let mut hashes = vec![DefaultHasher::default(); num_rows];
+        for hasher in hashes.iter_mut() {
+            hasher.write_u64(SPILL_HASH_SEED);
+        }
+        for key in hash_keys.iter() {
+            let values = evaluator.run(key)?;
+            for (hasher, value) in hashes.iter_mut().zip(get_hash_values(&values, num_rows)?) {
+                hasher.write_u64(value);
+            }
+        }
+
+        let indices = hashes
+            .into_iter()
+            .map(|hasher| hasher.finish() % SPILL_PARTITIONS as u64)
+            .collect::<Vec<_>>();
+        DataBlock::scatter(block, &indices, SPILL_PARTITIONS)
+    }
+
+    pub fn build_attach(&self) {
+        let mut count = self.build_count.lock();
+        *count += 1;
+        self.threshold
+            .fetch_add(self.threshold_per_proc, Ordering::SeqCst);
+    }
+
+    /// Scatter a build block, rows of partitions that are already spilled go to `buffer`.
+    pub fn add_build_block(&self, block: DataBlock, buffer: &mut SpillBuffer) -> Result<()> {
+        let block = block.convert_to_full();
+        for (idx, block) in self
+            .partition(&self.build_hash_keys, &block)?
+            .into_iter()
+            .enumerate()
+        {
+            if block.is_empty() {
+                continue;
+            }
+
+            let mut partition = self.partitions[idx].lock();
+            if partition.spilled {
+                buffer.push(idx, block);
+                continue;
+            }
+
+            let memory_size = block.memory_size();
+            partition.memory_size += memory_size;
+            partition.blocks.push(block);
+            self.memory_size.fetch_add(memory_size, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    /// Take the largest in-memory build partitions out until the memory used by the
+    /// build side is below the threshold. The caller is responsible for spilling them.
+    pub fn take_spilling_partitions(&self) -> Vec<(usize, Vec<DataBlock>)> {
+        let mut spilling_partitions = vec![];
+        while self.memory_size.load(Ordering::SeqCst) > self.threshold.load(Ordering::SeqCst) {
+            let mut largest: Option<(usize, usize)> = None;
+            for (idx, partition) in self.partitions.iter().enumerate() {
+                let partition = partition.lock();
+                if !partition.spilled
+                    && partition.memory_size != 0
+                    && largest.map_or(true, |(_, size)| partition.memory_size > size)
+                {
+                    largest = Some((idx, partition.memory_size));
+                }
+            }
+
+            if let Some((idx, _)) = largest {
+                let mut partition = self.partitions[idx].lock();
+                if partition.spilled {
+                    continue;
+                }
+
+                partition.spilled = true;
+                self.spilled.store(true, Ordering::Release);
+                self.memory_size
+                    .fetch_sub(partition.memory_size, Ordering::SeqCst);
+                partition.memory_size = 0;
+                spilling_partitions.push((idx, std::mem::take(&mut partition.blocks)));
+                metrics_inc_hash_join_spill_partition_count();
+            } else {
+                break;
+            }
+        }
+        spilling_partitions
+    }
+
+    /// Called by each build processor once its input is exhausted and its buffer is spilled.
+    /// The last one moves the build partitions left in memory into the join hash table.
+    pub fn build_done(&self, join_state: &Arc<dyn HashJoinState>) -> Result<()> {
+        let mut count = self.build_count.lock();
+        *count -= 1;
+        if *count == 0 {
+            let mut restore_partitions = self.restore_partitions.lock();
+            for (idx, partition) in self.partitions.iter().enumerate() {
+                let mut partition = partition.lock();
+                if partition.spilled {
+                    restore_partitions.push_back(idx);
+                    continue;
+                }
+
+                for block in std::mem::take(&mut partition.blocks) {
+                    join_state.build(block)?;
+                }
+                partition.memory_size = 0;
+            }
+            self.memory_size.store(0, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    pub fn probe_attach(&self) {
+        let mut count = self.probe_count.lock();
+        *count += 1;
+    }
+
+    /// Scatter a probe block, rows of spilled partitions go to `buffer` and the rest
+    /// are returned to be probed against the in-memory hash table.
+    pub fn add_probe_block(
+        &self,
+        block: &DataBlock,
+        buffer: &mut SpillBuffer,
+    ) -> Result<Vec<DataBlock>> {
+        let mut in_memory_blocks = vec![];
+        for (idx, block) in self
+            .partition(&self.probe_hash_keys, block)?
+            .into_iter()
+            .enumerate()
+        {
+            if block.is_empty() {
+                continue;
+            }
+
+            match self.partitions[idx].lock().spilled {
+                true => buffer.push(idx, block),
+                false => in_memory_blocks.push(block),
+            }
+        }
+        Ok(in_memory_blocks)
+    }
+
+    /// Called by each probe processor once its input is exhausted and its buffer is spilled.
+    pub fn probe_done(&self) {
+        let mut count = self.probe_count.lock();
+        *count -= 1;
+        if *count == 0 {
+            let mut probe_done = self.probe_done.lock();
+            *probe_done = true;
+            self.probe_done_notify.notify_waiters();
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn wait_probe_finish(&self) -> Result<()> {
+        let notified = {
+            let probe_done_guard = self.probe_done.lock();
+
+            match *probe_done_guard {
+                true => None,
+                false => Some(self.probe_done_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    pub fn spill_build(
+        &self,
+        partitions: Vec<(usize, Vec<DataBlock>)>,
+    ) -> BoxFuture<'static, Result<()>> {
+        self.spill(&self.build_files, partitions)
+    }
+
+    pub fn spill_probe(
+        &self,
+        partitions: Vec<(usize, Vec<DataBlock>)>,
+    ) -> BoxFuture<'static, Result<()>> {
+        self.spill(&self.probe_files, partitions)
+    }
+
+    fn spill(
+        &self,
+        files: &[Mutex<Vec<SpilledFile>>],
+        partitions: Vec<(usize, Vec<DataBlock>)>,
+    ) -> BoxFuture<'static, Result<()>> {
+        let mut write_data = Vec::with_capacity(partitions.len());
+        for (idx, blocks) in partitions.into_iter() {
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+            let mut columns_data = vec![];
+            let mut blocks_layout = Vec::with_capacity(blocks.len());
+            for block in blocks.into_iter() {
+                let num_rows = block.num_rows();
+                let mut columns_layout = Vec::with_capacity(block.num_columns());
+                for entry in block.convert_to_full().columns() {
+                    let column_data = serialize_column(entry.value.as_column().unwrap());
+                    columns_layout.push(column_data.len());
+                    columns_data.push(column_data);
+                }
+                blocks_layout.push((num_rows, columns_layout));
+            }
+
+            // The file is only read after all the processors of this side are done,
+            // and they wait for their writes before that.
+            files[idx].lock().push(SpilledFile {
+                location: location.clone(),
+                blocks_layout,
+            });
+            write_data.push((location, columns_data));
+        }
+
+        let operator = self.operator.clone();
+        Box::pin(async move {
+            for (location, columns_data) in write_data.into_iter() {
+                let instant = Instant::now();
+
+                let mut write_bytes = 0;
+                let mut writer = operator.writer(&location).await?;
+                for data in columns_data.into_iter() {
+                    write_bytes += data.len();
+                    writer.write(data).await?;
+                }
+                writer.close().await?;
+
+                // perf
+                {
+                    metrics_inc_hash_join_spill_write_count();
+                    metrics_inc_hash_join_spill_write_bytes(write_bytes as u64);
+                    metrics_inc_hash_join_spill_write_milliseconds(
+                        instant.elapsed().as_millis() as u64
+                    );
+                }
+
+                info!(
+                    "Write hash join spill {} successfully, elapsed: {:?}",
+                    location,
+                    instant.elapsed()
+                );
+            }
+            Ok(())
+        })
+    }
+
+    /// Pop the next spilled partition to be joined.
+    pub fn next_restore_partition(&self) -> Option<usize> {
+        self.restore_partitions.lock().pop_front()
+    }
+
+    pub fn build_files(&self, partition: usize) -> Vec<SpilledFile> {
+        std::mem::take(&mut *self.build_files[partition].lock())
+    }
+
+    pub fn probe_files(&self, partition: usize) -> Vec<SpilledFile> {
+        std::mem::take(&mut *self.probe_files[partition].lock())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_spilled_file(&self, file: &SpilledFile) -> Result<Vec<DataBlock>> {
+        let instant = Instant::now();
+        let data = self.operator.read(&file.location).await?;
+
+        // perf
+        {
+            metrics_inc_hash_join_spill_read_count();
+            metrics_inc_hash_join_spill_read_bytes(data.len() as u64);
+            metrics_inc_hash_join_spill_read_milliseconds(instant.elapsed().as_millis() as u64);
+        }
+
+        info!(
+            "Read hash join spill {} successfully, elapsed: {:?}",
+            file.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut blocks = Vec::with_capacity(file.blocks_layout.len());
+        for (num_rows, columns_layout) in file.blocks_layout.iter() {
+            let mut columns = Vec::with_capacity(columns_layout.len());
+            for column_layout in columns_layout.iter() {
+                let column =
+                    deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Failed to deserialize hash join spill {}",
+                            file.location
+                        ))
+                    })?;
+                columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+                begin += column_layout;
+            }
+            blocks.push(DataBlock::new(columns, *num_rows));
+        }
+
+        Ok(blocks)
+    }
+
+    /// Create a private join hash table to join one restored partition.
+    pub fn create_join_state(&self) -> Result<Arc<JoinHashTable>> {
+        JoinHashTable::create_join_state(
+            self.ctx.clone(),
+            &self.join.build_keys,
+            self.join.build.output_schema()?,
+            self.join.probe.output_schema()?,
+            HashJoinDesc::create(&self.join)?,
+        )
+    }
+}
+
+impl Drop for HashJoinSpillState {
+    fn drop(&mut self) {
+        if !self.has_spilled() {
+            return;
+        }
+
+        let operator = self.operator.clone();
+        let location = format!("{}/", self.location_prefix);
+        GlobalIORuntime::instance().spawn(async move {
+            match operator.remove_all(&location).await {
+                Ok(_) => info!("Remove hash join spill {} successfully", location),
+                Err(cause) => warn!("Failed to remove hash join spill {}: {:?}", location, cause),
+            }
+        });
+    }
+}
@@ -84,3 +84,55 @@ pub fn metrics_inc_aggregate_spill_data_deserialize_milliseconds(c: u64) {
         c as f64
     );
 }
+
+pub fn metrics_inc_hash_join_spill_partition_count() {
+    increment_gauge!(key!("hash_join_spill_partition_count"), 1_f64);
+}
+
+pub fn metrics_inc_hash_join_spill_write_count() {
+    increment_gauge!(key!("hash_join_spill_write_count"), 1_f64);
+}
+
+pub fn metrics_inc_hash_join_spill_write_bytes(c: u64) {
+    increment_gauge!(key!("hash_join_spill_write_bytes"), c as f64);
+}
+
+pub fn metrics_inc_hash_join_spill_write_milliseconds(c: u64) {
+    increment_gauge!(key!("hash_join_spill_write_milliseconds"), c as f64);
+}
+
+pub fn metrics_inc_hash_join_spill_read_count() {
+    increment_gauge!(key!("hash_join_spill_read_count"), 1_f64);
+}
+
+pub fn metrics_inc_hash_join_spill_read_bytes(c: u64) {
+    increment_gauge!(key!("hash_join_spill_read_bytes"), c as f64);
+}
+
+pub fn metrics_inc_hash_join_spill_read_milliseconds(c: u64) {
+    increment_gauge!(key!("hash_join_spill_read_milliseconds"), c as f64);
+}
+
+pub fn metrics_inc_window_spill_write_count() {
+    increment_gauge!(key!("window_spill_write_count"), 1_f64);
+}
+
+pub fn metrics_inc_window_spill_write_bytes(c: u64) {
+    increment_gauge!(key!("window_spill_write_bytes"), c as f64);
+}
+
+pub fn metrics_inc_window_spill_write_milliseconds(c: u64) {
+    increment_gauge!(key!("window_spill_write_milliseconds"), c as f64);
+}
+
+pub fn metrics_inc_window_spill_read_count() {
+    increment_gauge!(key!("window_spill_read_count"), 1_f64);
+}
+
+pub fn metrics_inc_window_spill_read_bytes(c: u64) {
+    increment_gauge!(key!("window_spill_read_bytes"), c as f64);
+}
+
+pub fn metrics_inc_window_spill_read_milliseconds(c: u64) {
+    increment_gauge!(key!("window_spill_read_milliseconds"), c as f64);
+}
@@ -28,6 +28,7 @@ mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_ie_join;
 mod transform_merge_block;
+mod transform_merge_into_split;
 mod transform_resort_addon;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
@@ -57,6 +58,7 @@ use common_pipeline_transforms::processors::transforms::transform_compact;
 use common_pipeline_transforms::processors::transforms::transform_sort_partial;
 pub use hash_join::FixedKeyHashJoinHashTable;
 pub use hash_join::HashJoinDesc;
+pub use hash_join::HashJoinSpillState;
 pub use hash_join::HashJoinState;
 pub use hash_join::JoinHashTable;
 pub use range_join::RangeJoinState;
@@ -76,6 +78,10 @@ pub use transform_ie_join::TransformRangeJoinLeft;
 pub use transform_ie_join::TransformRangeJoinRight;
 pub use transform_limit::TransformLimit;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_merge_into_split::MatchedLayout;
+pub use transform_merge_into_split::MergeIntoMatchedRowIds;
+pub use transform_merge_into_split::TransformMergeIntoSplit;
+pub use transform_merge_into_split::UnmatchedLayout;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
@@ -84,3 +90,4 @@ pub use transform_sort_partial::TransformSortPartial;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
+pub use window::WindowSpillParams;
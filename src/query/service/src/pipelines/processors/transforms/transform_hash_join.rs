@@ -21,8 +21,12 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_sql::plans::JoinType;
+use futures_util::future::BoxFuture;
 
+use super::hash_join::HashJoinSpillState;
 use super::hash_join::ProbeState;
+use super::hash_join::SpillBuffer;
+use super::hash_join::SpilledFile;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
@@ -34,9 +38,27 @@ enum HashJoinStep {
     Finalize,
     Probe,
     FinalScan,
+    // Join the partitions spilled to storage one by one.
+    Restore,
     Finished,
 }
 
+/// A spilled partition being joined by a probe processor.
+struct RestoreTask {
+    build_data: Vec<DataBlock>,
+    join_state: Option<Arc<dyn HashJoinState>>,
+    probe_files: VecDeque<SpilledFile>,
+    probe_data: VecDeque<DataBlock>,
+    probe_finished: bool,
+    finished: bool,
+}
+
+impl RestoreTask {
+    fn need_read(&self) -> bool {
+        self.join_state.is_some() && self.probe_data.is_empty() && !self.probe_files.is_empty()
+    }
+}
+
 pub struct TransformHashJoinProbe {
     input_data: VecDeque<DataBlock>,
     output_data_blocks: VecDeque<DataBlock>,
@@ -48,6 +70,15 @@ pub struct TransformHashJoinProbe {
     probe_state: ProbeState,
     max_block_size: usize,
     outer_scan_finished: bool,
+
+    spill_state: Option<Arc<HashJoinSpillState>>,
+    spill_buffer: SpillBuffer,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+    spill_probe_finished: bool,
+    // Whether the probe rows of the in-memory partitions need to be probed,
+    // false if the in-memory hash table is empty and can't produce any row.
+    probe_in_memory: bool,
+    restore_task: Option<RestoreTask>,
 }
 
 impl TransformHashJoinProbe {
@@ -59,7 +90,12 @@ impl TransformHashJoinProbe {
         func_ctx: FunctionContext,
         join_type: &JoinType,
         with_conjunct: bool,
+        spill_state: Option<Arc<HashJoinSpillState>>,
     ) -> Result<Box<dyn Processor>> {
+        if let Some(spill_state) = &spill_state {
+            spill_state.probe_attach();
+        }
+
         Ok(Box::new(TransformHashJoinProbe {
             input_data: VecDeque::new(),
             output_data_blocks: VecDeque::new(),
@@ -70,6 +106,12 @@ impl TransformHashJoinProbe {
             probe_state: ProbeState::create(max_block_size, join_type, with_conjunct, func_ctx),
             max_block_size,
             outer_scan_finished: false,
+            spill_state,
+            spill_buffer: SpillBuffer::create(),
+            spilling_future: None,
+            spill_probe_finished: false,
+            probe_in_memory: true,
+            restore_task: None,
         }))
     }
 
@@ -79,12 +121,89 @@ impl TransformHashJoinProbe {
     }
 
     fn probe(&mut self, block: &DataBlock) -> Result<()> {
+        if !self.probe_in_memory {
+            return Ok(());
+        }
+
         self.probe_state.clear();
         self.output_data_blocks
             .extend(self.join_state.probe(block, &mut self.probe_state)?);
         Ok(())
     }
 
+    fn has_spilled(&self) -> bool {
+        matches!(&self.spill_state, Some(spill_state) if spill_state.has_spilled())
+    }
+
+    // Notify the spill state that all the probe rows of this processor are spilled.
+    fn finish_spill_probe(&mut self) {
+        if let Some(spill_state) = &self.spill_state {
+            if !self.spill_probe_finished {
+                self.spill_probe_finished = true;
+                spill_state.probe_done();
+            }
+        }
+    }
+
+    fn finish_probe(&mut self) -> Result<Event> {
+        if self.has_spilled() {
+            self.step = HashJoinStep::Restore;
+            return Ok(Event::Async);
+        }
+
+        self.output_port.finish();
+        Ok(Event::Finished)
+    }
+
+    fn restore(&mut self) -> Result<()> {
+        let spill_state = self.spill_state.as_ref().unwrap();
+        let task = self.restore_task.as_mut().unwrap();
+        match &task.join_state {
+            None => {
+                let join_state: Arc<dyn HashJoinState> = spill_state.create_join_state()?;
+                join_state.build_attach()?;
+                for data_block in std::mem::take(&mut task.build_data) {
+                    join_state.build(data_block)?;
+                }
+                join_state.build_done()?;
+                while let Some(finalize_task) = join_state.finalize_task() {
+                    join_state.finalize(finalize_task)?;
+                }
+                join_state.finalize_done()?;
+                join_state.probe_attach()?;
+
+                // Same as the fast path of the in-memory hash table.
+                if join_state.fast_return()?
+                    && !matches!(
+                        join_state.join_type(),
+                        JoinType::Left | JoinType::Full | JoinType::LeftSingle | JoinType::LeftAnti
+                    )
+                {
+                    task.probe_files.clear();
+                    task.finished = true;
+                }
+                task.join_state = Some(join_state);
+            }
+            Some(join_state) => {
+                if let Some(data_block) = task.probe_data.pop_front() {
+                    self.probe_state.clear();
+                    self.output_data_blocks
+                        .extend(join_state.probe(&data_block, &mut self.probe_state)?);
+                } else if !task.probe_finished {
+                    join_state.probe_done()?;
+                    task.probe_finished = true;
+                    task.finished = !join_state.need_outer_scan();
+                } else if let Some(final_scan_task) = join_state.final_scan_task() {
+                    self.output_data_blocks
+                        .extend(join_state.final_scan(final_scan_task, &mut self.probe_state)?);
+                } else {
+                    task.finished = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn final_scan(&mut self, task: usize) -> Result<()> {
         self.output_data_blocks
             .extend(self.join_state.final_scan(task, &mut self.probe_state)?);
@@ -103,6 +222,10 @@ impl Processor for TransformHashJoinProbe {
     }
 
     fn event(&mut self) -> Result<Event> {
+        if self.spilling_future.is_some() {
+            return Ok(Event::Async);
+        }
+
         match self.step {
             HashJoinStep::Build => Ok(Event::Async),
             HashJoinStep::Finalize => unreachable!(),
@@ -117,6 +240,7 @@ impl Processor for TransformHashJoinProbe {
                     if self.join_state.need_outer_scan() || self.join_state.need_mark_scan() {
                         self.join_state.probe_done()?;
                     }
+                    self.finish_spill_probe();
 
                     return Ok(Event::Finished);
                 }
@@ -148,13 +272,21 @@ impl Processor for TransformHashJoinProbe {
                 }
 
                 if self.input_port.is_finished() {
+                    if let Some(spill_state) = &self.spill_state {
+                        if !self.spill_buffer.is_empty() {
+                            let partitions = self.spill_buffer.take();
+                            self.spilling_future = Some(spill_state.spill_probe(partitions));
+                            return Ok(Event::Async);
+                        }
+                    }
+                    self.finish_spill_probe();
+
                     return if self.join_state.need_outer_scan() || self.join_state.need_mark_scan()
                     {
                         self.join_state.probe_done()?;
                         Ok(Event::Async)
                     } else {
-                        self.output_port.finish();
-                        Ok(Event::Finished)
+                        self.finish_probe()
                     };
                 }
 
@@ -178,10 +310,27 @@ impl Processor for TransformHashJoinProbe {
 
                 match self.outer_scan_finished {
                     false => Ok(Event::Sync),
-                    true => {
-                        self.output_port.finish();
-                        Ok(Event::Finished)
-                    }
+                    true => self.finish_probe(),
+                }
+            }
+            HashJoinStep::Restore => {
+                if self.output_port.is_finished() {
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.output_data_blocks.is_empty() {
+                    let data = self.output_data_blocks.pop_front().unwrap();
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                match &self.restore_task {
+                    Some(task) if !task.need_read() && !task.finished => Ok(Event::Sync),
+                    _ => Ok(Event::Async),
                 }
             }
         }
@@ -198,7 +347,21 @@ impl Processor for TransformHashJoinProbe {
             HashJoinStep::Probe => {
                 if let Some(data) = self.input_data.pop_front() {
                     let data = data.convert_to_full();
-                    self.probe(&data)?;
+                    match self.spill_state.clone() {
+                        Some(spill_state) if spill_state.has_spilled() => {
+                            let data_blocks =
+                                spill_state.add_probe_block(&data, &mut self.spill_buffer)?;
+                            for data_block in data_blocks.iter() {
+                                self.probe(data_block)?;
+                            }
+
+                            if self.spill_buffer.is_full() {
+                                let partitions = self.spill_buffer.take();
+                                self.spilling_future = Some(spill_state.spill_probe(partitions));
+                            }
+                        }
+                        _ => self.probe(&data)?,
+                    }
                 }
                 Ok(())
             }
@@ -210,11 +373,16 @@ impl Processor for TransformHashJoinProbe {
                 }
                 Ok(())
             }
+            HashJoinStep::Restore => self.restore(),
         }
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_future) = self.spilling_future.take() {
+            return spilling_future.await;
+        }
+
         match self.step {
             HashJoinStep::Build => {
                 self.join_state.wait_finalize_finish().await?;
@@ -227,7 +395,14 @@ impl Processor for TransformHashJoinProbe {
                         | JoinType::RightAnti
                         | JoinType::RightSemi
                         | JoinType::LeftSemi => {
-                            self.step = HashJoinStep::Finished;
+                            // The probe rows of spilled partitions still need to be spilled.
+                            match self.has_spilled() {
+                                true => {
+                                    self.probe_in_memory = false;
+                                    self.step = HashJoinStep::Probe;
+                                }
+                                false => self.step = HashJoinStep::Finished,
+                            }
                         }
                         JoinType::Left
                         | JoinType::Full
@@ -249,10 +424,44 @@ impl Processor for TransformHashJoinProbe {
             HashJoinStep::Finalize => unreachable!(),
             HashJoinStep::Probe => {
                 self.join_state.wait_probe_finish().await?;
-                if self.join_state.fast_return()? {
-                    self.step = HashJoinStep::Finished;
-                } else {
+                if !self.join_state.fast_return()? {
                     self.step = HashJoinStep::FinalScan;
+                } else if self.has_spilled() {
+                    self.step = HashJoinStep::Restore;
+                } else {
+                    self.step = HashJoinStep::Finished;
+                }
+            }
+            HashJoinStep::Restore => {
+                let spill_state = self.spill_state.clone().unwrap();
+                if let Some(task) = &mut self.restore_task {
+                    if task.need_read() {
+                        let file = task.probe_files.pop_front().unwrap();
+                        task.probe_data
+                            .extend(spill_state.read_spilled_file(&file).await?);
+                        return Ok(());
+                    }
+                }
+
+                // Wait for all the probe rows to be spilled before joining the spilled partitions.
+                spill_state.wait_probe_finish().await?;
+                self.restore_task = None;
+                match spill_state.next_restore_partition() {
+                    Some(partition) => {
+                        let mut build_data = vec![];
+                        for file in spill_state.build_files(partition).iter() {
+                            build_data.extend(spill_state.read_spilled_file(file).await?);
+                        }
+                        self.restore_task = Some(RestoreTask {
+                            build_data,
+                            join_state: None,
+                            probe_files: spill_state.probe_files(partition).into(),
+                            probe_data: VecDeque::new(),
+                            probe_finished: false,
+                            finished: false,
+                        });
+                    }
+                    None => self.step = HashJoinStep::Finished,
                 }
             }
             HashJoinStep::FinalScan | HashJoinStep::Finished => unreachable!(),
@@ -268,19 +477,31 @@ pub struct TransformHashJoinBuild {
     step: HashJoinStep,
     join_state: Arc<dyn HashJoinState>,
     finalize_finished: bool,
+
+    spill_state: Option<Arc<HashJoinSpillState>>,
+    spill_buffer: SpillBuffer,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
 }
 
 impl TransformHashJoinBuild {
     pub fn create(
         input_port: Arc<InputPort>,
         join_state: Arc<dyn HashJoinState>,
+        spill_state: Option<Arc<HashJoinSpillState>>,
     ) -> Box<dyn Processor> {
+        if let Some(spill_state) = &spill_state {
+            spill_state.build_attach();
+        }
+
         Box::new(TransformHashJoinBuild {
             input_port,
             input_data: None,
             step: HashJoinStep::Build,
             join_state,
             finalize_finished: false,
+            spill_state,
+            spill_buffer: SpillBuffer::create(),
+            spilling_future: None,
         })
     }
 
@@ -301,6 +522,10 @@ impl Processor for TransformHashJoinBuild {
     }
 
     fn event(&mut self) -> Result<Event> {
+        if self.spilling_future.is_some() {
+            return Ok(Event::Async);
+        }
+
         match self.step {
             HashJoinStep::Build => {
                 if self.input_data.is_some() {
@@ -308,6 +533,14 @@ impl Processor for TransformHashJoinBuild {
                 }
 
                 if self.input_port.is_finished() {
+                    if let Some(spill_state) = &self.spill_state {
+                        if !self.spill_buffer.is_empty() {
+                            let partitions = self.spill_buffer.take();
+                            self.spilling_future = Some(spill_state.spill_build(partitions));
+                            return Ok(Event::Async);
+                        }
+                        spill_state.build_done(&self.join_state)?;
+                    }
                     self.join_state.build_done()?;
                     return Ok(Event::Async);
                 }
@@ -327,8 +560,9 @@ impl Processor for TransformHashJoinBuild {
                 false => Ok(Event::Sync),
                 true => Ok(Event::Finished),
             },
-            HashJoinStep::Probe => unreachable!(),
-            HashJoinStep::FinalScan => unreachable!(),
+            HashJoinStep::Probe | HashJoinStep::FinalScan | HashJoinStep::Restore => {
+                unreachable!()
+            }
             HashJoinStep::Finished => Ok(Event::Finished),
         }
     }
@@ -341,7 +575,20 @@ impl Processor for TransformHashJoinBuild {
         match self.step {
             HashJoinStep::Build => {
                 if let Some(data_block) = self.input_data.take() {
-                    self.join_state.build(data_block)?;
+                    match &self.spill_state {
+                        Some(spill_state) => {
+                            spill_state.add_build_block(data_block, &mut self.spill_buffer)?;
+
+                            let mut partitions = spill_state.take_spilling_partitions();
+                            if self.spill_buffer.is_full() {
+                                partitions.extend(self.spill_buffer.take());
+                            }
+                            if !partitions.is_empty() {
+                                self.spilling_future = Some(spill_state.spill_build(partitions));
+                            }
+                        }
+                        None => self.join_state.build(data_block)?,
+                    }
                 }
                 Ok(())
             }
@@ -353,14 +600,19 @@ impl Processor for TransformHashJoinBuild {
                     self.join_state.finalize_done()
                 }
             }
-            HashJoinStep::Probe | HashJoinStep::FinalScan | HashJoinStep::Finished => {
-                unreachable!()
-            }
+            HashJoinStep::Probe
+            | HashJoinStep::FinalScan
+            | HashJoinStep::Restore
+            | HashJoinStep::Finished => unreachable!(),
         }
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_future) = self.spilling_future.take() {
+            return spilling_future.await;
+        }
+
         if let HashJoinStep::Build = &self.step {
             self.join_state.wait_build_finish().await?;
             if self.join_state.fast_return()? {
@@ -0,0 +1,241 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+
+/// Offsets of a WHEN MATCHED clause in the blocks produced by the MERGE INTO source,
+/// the offset 0 is always the `_row_id` of the target table.
+pub struct MatchedLayout {
+    pub condition: Option<usize>,
+    // `None` means delete the matched rows.
+    pub update: Option<Vec<usize>>,
+}
+
+/// Offsets of a WHEN NOT MATCHED clause in the blocks produced by the MERGE INTO source.
+pub struct UnmatchedLayout {
+    pub condition: Option<usize>,
+    pub values: Vec<usize>,
+}
+
+/// The `_row_id`s of the target rows matched by MERGE INTO, shared by the split transforms.
+#[derive(Default)]
+pub struct MergeIntoMatchedRowIds {
+    row_ids: Mutex<HashSet<u64>>,
+}
+
+impl MergeIntoMatchedRowIds {
+    fn insert(&self, row_ids: impl Iterator<Item = u64>) -> Result<()> {
+        let mut matched = self.row_ids.lock();
+        for row_id in row_ids {
+            if !matched.insert(row_id) {
+                return Err(ErrorCode::BadArguments(
+                    "One target row is matched by multiple source rows in MERGE INTO",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn take(&self) -> Vec<u64> {
+        std::mem::take(&mut *self.row_ids.lock())
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Applies the WHEN clauses of MERGE INTO to the blocks of the source, the first clause whose
+/// condition is satisfied takes effect.
+///
+/// The matched `_row_id`s are collected into [`MergeIntoMatchedRowIds`], and the rows appended
+/// by the clauses are output with the schema of the target table.
+pub struct TransformMergeIntoSplit {
+    func_ctx: FunctionContext,
+    schema: TableSchemaRef,
+    matched_layouts: Arc<Vec<MatchedLayout>>,
+    unmatched_layouts: Arc<Vec<UnmatchedLayout>>,
+    matched_row_ids: Arc<MergeIntoMatchedRowIds>,
+}
+
+impl TransformMergeIntoSplit {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        schema: TableSchemaRef,
+        matched_layouts: Arc<Vec<MatchedLayout>>,
+        unmatched_layouts: Arc<Vec<UnmatchedLayout>>,
+        matched_row_ids: Arc<MergeIntoMatchedRowIds>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformMergeIntoSplit {
+                func_ctx,
+                schema,
+                matched_layouts,
+                unmatched_layouts,
+                matched_row_ids,
+            },
+        )))
+    }
+
+    // Take the selected rows of the columns at `offsets`, and cast them to the table schema.
+    fn project(
+        &self,
+        block: &DataBlock,
+        offsets: &[usize],
+        selected: &Bitmap,
+    ) -> Result<DataBlock> {
+        let entries = offsets
+            .iter()
+            .map(|offset| block.get_by_offset(*offset).clone())
+            .collect();
+        let block = DataBlock::new(entries, block.num_rows()).filter_with_bitmap(selected)?;
+
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut columns = Vec::with_capacity(self.schema.num_fields());
+        for (index, field) in self.schema.fields().iter().enumerate() {
+            let entry = block.get_by_offset(index);
+            let dest_type = DataType::from(field.data_type());
+            if entry.data_type == dest_type {
+                columns.push(entry.clone());
+                continue;
+            }
+            let expr = Expr::Cast {
+                span: None,
+                is_try: false,
+                expr: Box::new(Expr::ColumnRef {
+                    span: None,
+                    id: index,
+                    data_type: entry.data_type.clone(),
+                    display_name: field.name().clone(),
+                }),
+                dest_type: dest_type.clone(),
+            };
+            let value = evaluator.run(&expr)?;
+            columns.push(BlockEntry::new(dest_type, value));
+        }
+        Ok(DataBlock::new(columns, block.num_rows()))
+    }
+}
+
+// The rows whose predicate is TRUE, NULL is taken as FALSE.
+fn predicate_bitmap(block: &DataBlock, offset: Option<usize>) -> Bitmap {
+    let num_rows = block.num_rows();
+    let offset = match offset {
+        None => return MutableBitmap::from_len_set(num_rows).into(),
+        Some(offset) => offset,
+    };
+    let entry = block.get_by_offset(offset);
+    match entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows)
+    {
+        Column::Boolean(bitmap) => bitmap,
+        Column::Nullable(column) => match &column.column {
+            Column::Boolean(bitmap) => bitmap & &column.validity,
+            _ => MutableBitmap::from_len_zeroed(num_rows).into(),
+        },
+        _ => MutableBitmap::from_len_zeroed(num_rows).into(),
+    }
+}
+
+impl AccumulatingTransform for TransformMergeIntoSplit {
+    const NAME: &'static str = "MergeIntoSplit";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = data.num_rows();
+        let row_id_entry = data.get_by_offset(0);
+        let (row_ids, matched) = match row_id_entry
+            .value
+            .convert_to_full_column(&row_id_entry.data_type, num_rows)
+        {
+            Column::Nullable(column) => (
+                UInt64Type::try_downcast_column(&column.column)
+                    .ok_or_else(|| ErrorCode::Internal("unexpected, `_row_id` should be UInt64"))?,
+                column.validity.clone(),
+            ),
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "unexpected, `_row_id` of MERGE INTO should be nullable",
+                ));
+            }
+        };
+
+        let mut outputs = Vec::new();
+
+        // WHEN MATCHED clauses, the rows not taken by a clause are left to the next one.
+        let mut remaining = matched.clone();
+        for layout in self.matched_layouts.iter() {
+            if remaining.unset_bits() == num_rows {
+                break;
+            }
+            let selected = &remaining & &predicate_bitmap(&data, layout.condition);
+            remaining = &remaining & &!&selected;
+
+            self.matched_row_ids.insert(
+                row_ids
+                    .iter()
+                    .zip(selected.iter())
+                    .filter(|(_, selected)| *selected)
+                    .map(|(row_id, _)| *row_id),
+            )?;
+            if let Some(update) = &layout.update {
+                if selected.unset_bits() != num_rows {
+                    outputs.push(self.project(&data, update, &selected)?);
+                }
+            }
+        }
+
+        // WHEN NOT MATCHED clauses.
+        let mut remaining = !&matched;
+        for layout in self.unmatched_layouts.iter() {
+            if remaining.unset_bits() == num_rows {
+                break;
+            }
+            let selected = &remaining & &predicate_bitmap(&data, layout.condition);
+            remaining = &remaining & &!&selected;
+
+            if selected.unset_bits() != num_rows {
+                outputs.push(self.project(&data, &layout.values, &selected)?);
+            }
+        }
+
+        Ok(outputs)
+    }
+}
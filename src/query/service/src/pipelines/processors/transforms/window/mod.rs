@@ -15,7 +15,9 @@
 mod frame_bound;
 mod transform_window;
 mod window_function;
+mod window_spill;
 
 pub use frame_bound::FrameBound;
 pub use transform_window::TransformWindow;
 pub use window_function::WindowFunctionInfo;
+pub use window_spill::WindowSpillParams;
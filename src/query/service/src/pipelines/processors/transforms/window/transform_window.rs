@@ -39,11 +39,15 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
 use common_sql::executor::LagLeadDefault;
 use common_sql::plans::WindowFuncFrameUnits;
+use futures_util::future::BoxFuture;
+use once_cell::unsync::OnceCell;
 
 use super::frame_bound::FrameBound;
 use super::window_function::WindowFuncAggImpl;
 use super::window_function::WindowFunctionImpl;
+use super::window_spill::SpilledBlock;
 use super::WindowFunctionInfo;
+use super::WindowSpillParams;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
 struct RowPtr {
@@ -62,6 +66,57 @@ impl RowPtr {
 struct WindowBlock {
     block: DataBlock,
     builder: ColumnBuilder,
+    /// Set if the columns of `block` are spilled, `block` only keeps the partition and
+    /// order by columns then.
+    spilled: Option<SpilledBlock>,
+    /// The spilled columns which are read back.
+    restored: OnceCell<DataBlock>,
+}
+
+impl WindowBlock {
+    fn new(block: DataBlock, builder: ColumnBuilder) -> Self {
+        Self {
+            block,
+            builder,
+            spilled: None,
+            restored: OnceCell::new(),
+        }
+    }
+
+    #[inline(always)]
+    fn is_resident(&self) -> bool {
+        self.spilled.is_none() || self.restored.get().is_some()
+    }
+
+    /// The columns other than the key columns are only valid if the block is resident.
+    #[inline(always)]
+    fn data(&self) -> &DataBlock {
+        self.restored.get().unwrap_or(&self.block)
+    }
+
+    fn memory_size(&self) -> usize {
+        self.block.memory_size() + self.restored.get().map_or(0, |block| block.memory_size())
+    }
+
+    /// Take the data to spill, the key columns are kept to advance the cursors without
+    /// reading the block back, the other columns are replaced by placeholders.
+    fn take_spilling_data(&mut self, key_columns: &[usize]) -> DataBlock {
+        let columns = self
+            .block
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| match key_columns.contains(&index) {
+                true => entry.clone(),
+                false => BlockEntry::new(
+                    entry.data_type.clone(),
+                    Value::Scalar(Scalar::default_value(&entry.data_type)),
+                ),
+            })
+            .collect();
+        let key_block = DataBlock::new(columns, self.block.num_rows());
+        std::mem::replace(&mut self.block, key_block)
+    }
 }
 
 /// The input [`DataBlock`] of [`TransformWindow`] should be sorted by partition and order by columns.
@@ -137,6 +192,22 @@ pub struct TransformWindow<T: Number> {
 
     // If `is_empty_frame`, the window function result of non-NULL rows will be NULL.
     is_empty_frame: bool,
+
+    // Blocks not referenced by any cursor are spilled once the buffered blocks exceed the threshold.
+    spill_params: Option<WindowSpillParams>,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+    has_spilled: bool,
+    // The files written by the spilling, they are removed once the transform is finished.
+    spilled_locations: Vec<String>,
+    // The evaluation stopped at a block boundary to give a chance to spill, or it stopped to
+    // wait for `restoring_block`, it should be resumed.
+    need_resume: bool,
+    // The spilled block which must be read back before the evaluation goes on.
+    restoring_block: Option<usize>,
+    // The row the aggregation of the current frame stopped at.
+    aggregating_row: Option<RowPtr>,
+    // If the aggregation of the current frame is finished.
+    frame_computed: bool,
 }
 
 impl<T: Number> TransformWindow<T> {
@@ -147,14 +218,21 @@ impl<T: Number> TransformWindow<T> {
 
     #[inline(always)]
     fn block_rows(&self, index: &RowPtr) -> usize {
-        self.block_at(index).num_rows()
+        // The number of rows is kept even if the block is spilled.
+        self.blocks[index.block - self.first_block].block.num_rows()
     }
 
     #[inline(always)]
     fn block_at(&self, index: &RowPtr) -> &DataBlock {
-        &self.blocks[index.block - self.first_block].block
+        self.blocks[index.block - self.first_block].data()
     }
 
+    #[inline(always)]
+    fn is_resident(&self, index: &RowPtr) -> bool {
+        self.blocks[index.block - self.first_block].is_resident()
+    }
+
+    /// Only the key columns can be accessed by this method if the block is not resident.
     #[inline(always)]
     fn column_at(&self, index: &RowPtr, column_index: usize) -> &Column {
         self.block_at(index)
@@ -412,11 +490,18 @@ impl<T: Number> TransformWindow<T> {
 
     fn check_outputs(&mut self) {
         while self.next_output_block - self.first_block < self.blocks.len() {
-            let block = &mut self.blocks[self.next_output_block - self.first_block];
+            let index = self.next_output_block - self.first_block;
 
-            if block.block.num_rows() == block.builder.len() {
+            if self.blocks[index].block.num_rows() == self.blocks[index].builder.len() {
+                if !self.blocks[index].is_resident() {
+                    // Output the block once its spilled columns are read back.
+                    self.restoring_block = Some(self.next_output_block);
+                    self.need_resume = true;
+                    break;
+                }
                 // Can output
-                let mut output = block.block.clone();
+                let mut output = self.blocks[index].data().clone();
+                let block = &mut self.blocks[index];
                 let data_type = block.builder.data_type();
                 // The memory of the builder can be released.
                 let builder = std::mem::replace(
@@ -446,7 +531,8 @@ impl<T: Number> TransformWindow<T> {
         }
     }
 
-    fn apply_aggregate(&self, agg: &WindowFuncAggImpl) -> Result<()> {
+    /// Returns the row to go on from if the aggregation reaches a block which is not resident.
+    fn apply_aggregate(&self, agg: &WindowFuncAggImpl) -> Result<Option<RowPtr>> {
         debug_assert!(self.frame_started);
         debug_assert!(self.frame_ended);
         debug_assert!(self.frame_start <= self.frame_end);
@@ -462,9 +548,15 @@ impl<T: Number> TransformWindow<T> {
             (self.frame_start, self.frame_end, true)
         };
 
-        if reset {
-            agg.reset();
-        }
+        let rows_start = match self.aggregating_row {
+            Some(row) => row,
+            None => {
+                if reset {
+                    agg.reset();
+                }
+                rows_start
+            }
+        };
 
         let end_block = if rows_end.row == 0 {
             rows_end.block
@@ -473,12 +565,16 @@ impl<T: Number> TransformWindow<T> {
         };
 
         for block in rows_start.block..end_block {
-            let data = &self.blocks[block - self.first_block].block;
+            let window_block = &self.blocks[block - self.first_block];
             let start_row = if block == rows_start.block {
                 rows_start.row
             } else {
                 0
             };
+            if !window_block.is_resident() {
+                return Ok(Some(RowPtr::new(block, start_row)));
+            }
+            let data = window_block.data();
             let end_row = if block == rows_end.block {
                 rows_end.row
             } else {
@@ -490,7 +586,44 @@ impl<T: Number> TransformWindow<T> {
             }
         }
 
-        Ok(())
+        Ok(None)
+    }
+
+    /// The n-th row of the frame, `frame_end` if there is no such row.
+    fn nth_row_of_frame(&self, mut n: u64) -> RowPtr {
+        let mut cur = self.frame_start;
+        // n is counting from 1
+        while n > 1 && cur < self.frame_end {
+            cur = self.advance_row(cur);
+            n -= 1;
+        }
+        cur
+    }
+
+    /// The row whose arguments are read by `merge_result_of_current_row`.
+    fn merge_arg_row(&self) -> Option<RowPtr> {
+        match &self.func {
+            WindowFunctionImpl::LagLead(ll) => {
+                if self.frame_start != self.frame_end {
+                    Some(self.frame_start)
+                } else if matches!(ll.default, LagLeadDefault::Index(_)) {
+                    Some(self.current_row)
+                } else {
+                    None
+                }
+            }
+            WindowFunctionImpl::NthValue(func) => {
+                if self.frame_start == self.frame_end {
+                    None
+                } else if let Some(n) = func.n {
+                    let cur = self.nth_row_of_frame(n);
+                    (cur != self.frame_end).then_some(cur)
+                } else {
+                    Some(self.goback_row(self.frame_end))
+                }
+            }
+            _ => None,
+        }
     }
 
     #[inline]
@@ -532,19 +665,14 @@ impl<T: Number> TransformWindow<T> {
                     let default_value = match ll.default.clone() {
                         LagLeadDefault::Null => Scalar::Null,
                         LagLeadDefault::Index(col) => {
-                            let block =
-                                &self.blocks[self.current_row.block - self.first_block].block;
+                            let block = self.block_at(&self.current_row);
                             let value = &block.get_by_offset(col).value;
                             value.index(self.current_row.row).unwrap().to_owned()
                         }
                     };
                     default_value
                 } else {
-                    let block = &self
-                        .blocks
-                        .get(self.frame_start.block - self.first_block)
-                        .unwrap()
-                        .block;
+                    let block = self.block_at(&self.frame_start);
                     let value = &block.get_by_offset(ll.arg).value;
                     value.index(self.frame_start.row).unwrap().to_owned()
                 };
@@ -555,15 +683,10 @@ impl<T: Number> TransformWindow<T> {
             WindowFunctionImpl::NthValue(func) => {
                 let value = if self.frame_start == self.frame_end {
                     Scalar::Null
-                } else if let Some(mut n) = func.n {
-                    let mut cur = self.frame_start;
-                    // n is counting from 1
-                    while n > 1 && cur < self.frame_end {
-                        cur = self.advance_row(cur);
-                        n -= 1;
-                    }
+                } else if let Some(n) = func.n {
+                    let cur = self.nth_row_of_frame(n);
                     if cur != self.frame_end {
-                        let block = &self.blocks.get(cur.block - self.first_block).unwrap().block;
+                        let block = self.block_at(&cur);
                         let col = block.get_by_offset(func.arg).value.as_column().unwrap();
                         col.index(cur.row).unwrap().to_owned()
                     } else {
@@ -574,7 +697,7 @@ impl<T: Number> TransformWindow<T> {
                     // last_value
                     let cur = self.goback_row(self.frame_end);
                     debug_assert!(self.frame_start <= cur);
-                    let block = &self.blocks.get(cur.block - self.first_block).unwrap().block;
+                    let block = self.block_at(&cur);
                     let col = block.get_by_offset(func.arg).value.as_column().unwrap();
                     col.index(cur.row).unwrap().to_owned()
                 };
@@ -638,6 +761,120 @@ impl<T: Number> TransformWindow<T> {
         }
         false
     }
+
+    /// The blocks referenced by the cursors, they are accessed by the next evaluation.
+    fn hot_blocks(&self) -> Vec<usize> {
+        vec![
+            self.current_row.block,
+            self.current_row.block + 1,
+            self.frame_start.block,
+            self.frame_end.block,
+            self.prev_frame_start.block,
+            self.prev_frame_end.block,
+            self.peer_group_start.block,
+            self.peer_group_end.block,
+            self.partition_end.block,
+            self.blocks_end().block.saturating_sub(1),
+        ]
+    }
+
+    /// The spilled block which the evaluation waits for or reaches next, it is read back
+    /// in `async_process`.
+    fn read_ahead_block(&self) -> Option<usize> {
+        if !self.has_spilled {
+            return None;
+        }
+
+        let blocks_end = self.blocks_end().block;
+        self.restoring_block
+            .into_iter()
+            .chain([self.current_row.block, self.current_row.block + 1])
+            .find(|index| {
+                *index >= self.first_block
+                    && *index < blocks_end
+                    && !self.blocks[*index - self.first_block].is_resident()
+            })
+    }
+
+    /// Spill the blocks not referenced by any cursor if the buffered blocks exceed the threshold.
+    fn try_spill(&mut self) {
+        let spilling_bytes_threshold = match &self.spill_params {
+            None => return,
+            Some(params) => params.spilling_bytes_threshold_per_proc,
+        };
+
+        let mut memory_size = self
+            .blocks
+            .iter()
+            .map(|block| block.memory_size())
+            .sum::<usize>();
+        if memory_size <= spilling_bytes_threshold {
+            return;
+        }
+
+        // Release the blocks read back at first, they are still kept in storage.
+        let hot_blocks = self.hot_blocks();
+        for (index, block) in self.blocks.iter_mut().enumerate() {
+            if block.spilled.is_some() && !hot_blocks.contains(&(index + self.first_block)) {
+                if let Some(restored) = block.restored.take() {
+                    memory_size -= restored.memory_size();
+                }
+            }
+        }
+        if memory_size <= spilling_bytes_threshold {
+            return;
+        }
+
+        let key_columns = self
+            .partition_indices
+            .iter()
+            .copied()
+            .chain(self.order_by.iter().map(|desc| desc.offset))
+            .collect::<Vec<_>>();
+        let mut spilling_indices = vec![];
+        let mut spilling_blocks = vec![];
+        for (index, block) in self.blocks.iter_mut().enumerate() {
+            if block.spilled.is_none() && !hot_blocks.contains(&(index + self.first_block)) {
+                spilling_blocks.push(block.take_spilling_data(&key_columns));
+                spilling_indices.push(index);
+
+                if block.builder.len() == 0 {
+                    // The builder grows again once the rows of the block are evaluated.
+                    let data_type = block.builder.data_type();
+                    block.builder = ColumnBuilder::with_capacity(&data_type, 0);
+                }
+            }
+        }
+        if spilling_blocks.is_empty() {
+            return;
+        }
+
+        let params = self.spill_params.as_ref().unwrap();
+        let (spilled_blocks, spilling_future) = params.spill_blocks(spilling_blocks);
+        self.spilled_locations
+            .push(spilled_blocks[0].location().to_string());
+        for (index, spilled) in spilling_indices.into_iter().zip(spilled_blocks) {
+            self.blocks[index].spilled = Some(spilled);
+        }
+        self.has_spilled = true;
+        self.spilling_future = Some(spilling_future);
+    }
+
+    fn remove_spilled_files(&mut self) {
+        if self.spilled_locations.is_empty() {
+            return;
+        }
+        let locations = std::mem::take(&mut self.spilled_locations);
+        self.spill_params.as_ref().unwrap().remove_files(locations);
+    }
+
+    #[inline]
+    fn add_block_event(&self) -> Event {
+        match self.read_ahead_block() {
+            Some(_) => Event::Async,
+            None => Event::Sync,
+        }
+    }
 }
 
 // For ROWS frame
@@ -650,6 +887,7 @@ impl TransformWindow<u64> {
         partition_indices: Vec<usize>,
         order_by: Vec<SortColumnDescription>,
         bounds: (FrameBound<u64>, FrameBound<u64>),
+        spill_params: Option<WindowSpillParams>,
     ) -> Result<Self> {
         let func = WindowFunctionImpl::try_create(func)?;
         let (start_bound, end_bound) = bounds;
@@ -698,6 +936,14 @@ impl TransformWindow<u64> {
             current_dense_rank: 1,
             input_is_finished: false,
             is_empty_frame,
+            spill_params,
+            spilling_future: None,
+            has_spilled: false,
+            spilled_locations: vec![],
+            need_resume: false,
+            restoring_block: None,
+            aggregating_row: None,
+            frame_computed: false,
         })
     }
 }
@@ -714,6 +960,7 @@ where T: Number + ResultTypeOfUnary
         partition_indices: Vec<usize>,
         order_by: Vec<SortColumnDescription>,
         bounds: (FrameBound<T>, FrameBound<T>),
+        spill_params: Option<WindowSpillParams>,
     ) -> Result<Self> {
         let func = WindowFunctionImpl::try_create(func)?;
         let (start_bound, end_bound) = bounds;
@@ -768,6 +1015,14 @@ where T: Number + ResultTypeOfUnary
             current_dense_rank: 1,
             input_is_finished: false,
             is_empty_frame,
+            spill_params,
+            spilling_future: None,
+            has_spilled: false,
+            spilled_locations: vec![],
+            need_resume: false,
+            restoring_block: None,
+            aggregating_row: None,
+            frame_computed: false,
         })
     }
 
@@ -889,10 +1144,26 @@ where T: Number + ResultTypeOfUnary
         }
     }
 
-    fn compute_on_frame(&mut self) -> Result<()> {
-        match &self.func {
-            WindowFunctionImpl::Aggregate(agg) => self.apply_aggregate(agg),
-            _ => Ok(()),
+    /// Returns false if the aggregation waits for a spilled block to be read back, it goes on
+    /// from `aggregating_row` when the evaluation is resumed.
+    fn compute_on_frame(&mut self) -> Result<bool> {
+        if self.frame_computed {
+            return Ok(true);
+        }
+        let aggregating_row = match &self.func {
+            WindowFunctionImpl::Aggregate(agg) => self.apply_aggregate(agg)?,
+            _ => None,
+        };
+        self.aggregating_row = aggregating_row;
+        match aggregating_row {
+            Some(row) => {
+                self.restoring_block = Some(row.block);
+                Ok(false)
+            }
+            None => {
+                self.frame_computed = true;
+                Ok(true)
+            }
         }
     }
 
@@ -907,12 +1178,16 @@ where T: Number + ResultTypeOfUnary
     fn add_block(&mut self, data: Option<DataBlock>) -> Result<()> {
         if let Some(data) = data {
             let num_rows = data.num_rows();
-            self.blocks.push_back(WindowBlock {
-                block: data.convert_to_full(),
-                builder: ColumnBuilder::with_capacity(&self.func.return_type()?, num_rows),
-            });
+            self.blocks.push_back(WindowBlock::new(
+                data.convert_to_full(),
+                ColumnBuilder::with_capacity(&self.func.return_type()?, num_rows),
+            ));
         }
 
+        self.need_resume = false;
+        self.restoring_block = None;
+        let start_block = self.current_row.block;
+
         // Each loop will do:
         // 1. Try to advance the partition.
         // 2. Try to advance the frame (if the frame is not started or ended, break the loop and end the process).
@@ -987,7 +1262,10 @@ where T: Number + ResultTypeOfUnary
                         self.frame_end = self.frame_start;
                     }
 
-                    self.advance_frame_end();
+                    // The frame end is already advanced if the evaluation is resumed.
+                    if !self.frame_ended {
+                        self.advance_frame_end();
+                    }
                     if !self.frame_ended {
                         debug_assert!(!self.input_is_finished);
                         debug_assert!(!self.partition_ended);
@@ -995,9 +1273,19 @@ where T: Number + ResultTypeOfUnary
                     }
 
                     // 3.1
-                    self.compute_on_frame()?;
+                    if !self.compute_on_frame()? {
+                        self.need_resume = true;
+                        return Ok(());
+                    }
                 }
 
+                if let Some(row) = self.merge_arg_row() {
+                    if !self.is_resident(&row) {
+                        self.restoring_block = Some(row.block);
+                        self.need_resume = true;
+                        return Ok(());
+                    }
+                }
                 self.merge_result_of_current_row()?;
 
                 // 3.2
@@ -1007,6 +1295,14 @@ where T: Number + ResultTypeOfUnary
                 self.prev_frame_end = self.frame_end;
                 self.frame_started = false;
                 self.frame_ended = false;
+                self.frame_computed = false;
+
+                // Stop at the block boundary, so that the blocks already evaluated can be
+                // output and the blocks read back can be released before going on.
+                if self.has_spilled && self.current_row.block != start_block {
+                    self.need_resume = true;
+                    return Ok(());
+                }
             }
 
             if self.input_is_finished {
@@ -1173,6 +1469,7 @@ where T: Number + ResultTypeOfUnary
     fn event(&mut self) -> Result<Event> {
         if self.output.is_finished() {
             self.input.finish();
+            self.remove_spilled_files();
             return Ok(Event::Finished);
         }
 
@@ -1180,16 +1477,25 @@ where T: Number + ResultTypeOfUnary
             return Ok(Event::NeedConsume);
         }
 
+        if self.spilling_future.is_some() {
+            return Ok(Event::Async);
+        }
+
         let input_is_finished = self.input.is_finished();
         match self.state {
             ProcessorState::Consume => {
+                if self.need_resume {
+                    self.state = ProcessorState::AddBlock(None);
+                    return Ok(self.add_block_event());
+                }
+
                 self.input.set_need_data();
                 let has_data = self.input.has_data();
                 match (input_is_finished, has_data) {
                     (_, true) => {
                         let data = self.input.pull_data().transpose()?;
                         self.state = ProcessorState::AddBlock(data);
-                        Ok(Event::Sync)
+                        Ok(self.add_block_event())
                     }
                     (false, false) => Ok(Event::NeedData),
                     (true, _) => {
@@ -1198,9 +1504,10 @@ where T: Number + ResultTypeOfUnary
                         if self.next_output_block - self.first_block < self.blocks.len() {
                             // There are still some blocks are not output.
                             self.state = ProcessorState::AddBlock(None);
-                            Ok(Event::Sync)
+                            Ok(self.add_block_event())
                         } else {
                             self.output.finish();
+                            self.remove_spilled_files();
                             Ok(Event::Finished)
                         }
                     }
@@ -1214,7 +1521,7 @@ where T: Number + ResultTypeOfUnary
                 }
                 Ok(Event::NeedConsume)
             }
-            _ => unreachable!(),
+            ProcessorState::AddBlock(_) => Ok(Event::Sync),
         }
     }
 
@@ -1224,6 +1531,7 @@ where T: Number + ResultTypeOfUnary
         {
             self.add_block(data)?;
             self.check_outputs();
+            self.try_spill();
             self.state = if self.outputs.is_empty() {
                 ProcessorState::Consume
             } else {
@@ -1234,6 +1542,30 @@ where T: Number + ResultTypeOfUnary
         }
         Ok(())
     }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_future) = self.spilling_future.take() {
+            return spilling_future.await;
+        }
+
+        if let Some(index) = self.read_ahead_block() {
+            let spilled = self.blocks[index - self.first_block]
+                .spilled
+                .clone()
+                .unwrap();
+            let params = self.spill_params.clone().unwrap();
+            let restored = params.read_block(&spilled).await?;
+            let _ = self.blocks[index - self.first_block].restored.set(restored);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Number> Drop for TransformWindow<T> {
+    fn drop(&mut self) {
+        self.remove_spilled_files();
+    }
 }
 
 #[cfg(test)]
@@ -1278,6 +1610,7 @@ mod tests {
             vec![0],
             vec![],
             bounds,
+            None,
         )
     }
 
@@ -1289,10 +1622,10 @@ mod tests {
         let data_type = column.data_type();
         let num_rows = column.len();
         let mut transform = get_transform_window(unit, bounds, data_type.clone())?;
-        transform.blocks.push_back(WindowBlock {
-            block: DataBlock::new_from_columns(vec![column]),
-            builder: ColumnBuilder::with_capacity(&data_type, num_rows),
-        });
+        transform.blocks.push_back(WindowBlock::new(
+            DataBlock::new_from_columns(vec![column]),
+            ColumnBuilder::with_capacity(&data_type, num_rows),
+        ));
         Ok(transform)
     }
 
@@ -1896,6 +2229,7 @@ mod tests {
             vec![0],
             vec![],
             bounds,
+            None,
         )?;
 
         Ok((Box::new(transform), input, output))
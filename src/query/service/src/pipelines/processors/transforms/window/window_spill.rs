@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Value;
+use futures_util::future::BoxFuture;
+use opendal::Operator;
+use tracing::info;
+use tracing::warn;
+
+use crate::pipelines::processors::transforms::metrics::metrics_inc_window_spill_read_bytes;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_window_spill_read_count;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_window_spill_read_milliseconds;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_window_spill_write_bytes;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_window_spill_write_count;
+use crate::pipelines::processors::transforms::metrics::metrics_inc_window_spill_write_milliseconds;
+
+/// Parameters to spill the blocks buffered by [`super::TransformWindow`].
+///
+/// The transform keeps the locations of the files it wrote and removes them once it is
+/// finished or dropped.
+#[derive(Clone)]
+pub struct WindowSpillParams {
+    pub operator: Operator,
+    pub location_prefix: String,
+    /// Blocks are spilled once the blocks buffered by one processor exceed this size.
+    pub spilling_bytes_threshold_per_proc: usize,
+}
+
+/// The columns of a block which are written to storage.
+///
+/// The blocks spilled at the same time share one file, each of them is a range of the file.
+#[derive(Clone)]
+pub struct SpilledBlock {
+    location: String,
+    offset: u64,
+    num_rows: usize,
+    columns_layout: Vec<usize>,
+}
+
+impl SpilledBlock {
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+}
+
+impl WindowSpillParams {
+    /// Serialize the blocks, all of them are written into one file by the returned future.
+    pub fn spill_blocks(
+        &self,
+        blocks: Vec<DataBlock>,
+    ) -> (Vec<SpilledBlock>, BoxFuture<'static, Result<()>>) {
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+
+        let mut offset = 0;
+        let mut spilled_blocks = Vec::with_capacity(blocks.len());
+        let mut write_data = Vec::with_capacity(blocks.len());
+        for block in blocks.into_iter() {
+            let mut columns_layout = Vec::with_capacity(block.num_columns());
+            let block_offset = offset;
+            for entry in block.convert_to_full().columns() {
+                let column_data = serialize_column(entry.value.as_column().unwrap());
+                columns_layout.push(column_data.len());
+                offset += column_data.len() as u64;
+                write_data.push(column_data);
+            }
+
+            spilled_blocks.push(SpilledBlock {
+                location: location.clone(),
+                offset: block_offset,
+                num_rows: block.num_rows(),
+                columns_layout,
+            });
+        }
+
+        let operator = self.operator.clone();
+        let future = Box::pin(async move {
+            let instant = Instant::now();
+
+            let mut writer = operator.writer(&location).await?;
+            for data in write_data.into_iter() {
+                writer.write(data).await?;
+            }
+            writer.close().await?;
+
+            // perf
+            {
+                metrics_inc_window_spill_write_count();
+                metrics_inc_window_spill_write_bytes(offset);
+                metrics_inc_window_spill_write_milliseconds(instant.elapsed().as_millis() as u64);
+            }
+
+            info!(
+                "Write window spill {} successfully, elapsed: {:?}",
+                location,
+                instant.elapsed()
+            );
+            Ok(())
+        });
+
+        (spilled_blocks, future)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_block(&self, spilled: &SpilledBlock) -> Result<DataBlock> {
+        let instant = Instant::now();
+        let len = spilled.columns_layout.iter().sum::<usize>() as u64;
+        let data = self
+            .operator
+            .range_read(&spilled.location, spilled.offset..spilled.offset + len)
+            .await?;
+
+        // perf
+        {
+            metrics_inc_window_spill_read_count();
+            metrics_inc_window_spill_read_bytes(data.len() as u64);
+            metrics_inc_window_spill_read_milliseconds(instant.elapsed().as_millis() as u64);
+        }
+
+        info!(
+            "Read window spill {} successfully, elapsed: {:?}",
+            spilled.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(spilled.columns_layout.len());
+        for column_layout in spilled.columns_layout.iter() {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Failed to deserialize window spill {}",
+                        spilled.location
+                    ))
+                })?;
+            columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+            begin += column_layout;
+        }
+        Ok(DataBlock::new(columns, spilled.num_rows))
+    }
+
+    /// Remove the spilled files in the background.
+    pub fn remove_files(&self, locations: Vec<String>) {
+        let operator = self.operator.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                match operator.delete(&location).await {
+                    Ok(_) => info!("Remove window spill {} successfully", location),
+                    Err(cause) => warn!("Failed to remove window spill {}: {:?}", location, cause),
+                }
+            }
+        });
+    }
+}
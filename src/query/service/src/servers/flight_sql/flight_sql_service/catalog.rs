@@ -14,24 +14,53 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
+use arrow_schema::SchemaRef;
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
+use common_expression::DataSchema;
+use common_functions::scalars::like;
+use common_storages_view::view_table::VIEW_ENGINE;
 use futures_util::stream;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+/// Table types reported to Flight SQL clients, the same as `information_schema.tables`.
+const TABLE_TYPE_BASE_TABLE: &str = "BASE TABLE";
+const TABLE_TYPE_VIEW: &str = "VIEW";
+
 pub(super) struct CatalogInfoProvider {}
 
+/// Filters of `CommandGetTables`.
+pub(super) struct TablesFilter {
+    pub catalog: Option<String>,
+    pub db_schema_filter_pattern: Option<String>,
+    pub table_name_filter_pattern: Option<String>,
+    pub table_types: Vec<String>,
+    pub include_schema: bool,
+}
+
+struct TableRow {
+    catalog_name: String,
+    db_schema_name: String,
+    table_name: String,
+    table_type: String,
+    table_schema: Option<Vec<u8>>,
+}
+
 impl CatalogInfoProvider {
     fn batch_to_get_stream(batch: RecordBatch) -> Result<DoGetStream, Status> {
         let schema = (*batch.schema()).clone();
@@ -44,14 +73,20 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    /// Match `name` with a SQL LIKE pattern, a missing pattern matches everything.
+    fn match_pattern(name: &str, pattern: &Option<String>) -> bool {
+        match pattern {
+            Some(pattern) => like(name.as_bytes(), pattern.as_bytes()),
+            None => true,
+        }
+    }
+
+    fn get_catalogs_internal(
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)> {
-        let tenant = ctx.get_tenant();
+    ) -> common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
+        let mut catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name
+        {
             vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&catalog_name)?,
@@ -63,65 +98,233 @@ impl CatalogInfoProvider {
                 .map(|r| (r.key().to_string(), r.value().clone()))
                 .collect()
         };
+        catalogs.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(catalogs)
+    }
 
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
         let mut catalog_names = vec![];
         let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(tenant.as_str(), database_name).await?]
-            } else {
-                catalog.list_databases(tenant.as_str()).await?
-            };
+        for (catalog_name, catalog) in Self::get_catalogs_internal(catalog_name)? {
+            let mut dbs = catalog
+                .list_databases(tenant.as_str())
+                .await?
+                .into_iter()
+                .map(|db| db.name().to_string())
+                .filter(|name| Self::match_pattern(name, &db_schema_filter_pattern))
+                .collect::<Vec<_>>();
+            dbs.sort();
+            for db_name in dbs {
+                catalog_names.push(catalog_name.clone());
+                database_names.push(db_name);
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        filter: &TablesFilter,
+    ) -> common_exception::Result<Vec<TableRow>> {
+        let tenant = ctx.get_tenant();
+        let mut rows = vec![];
+        for (catalog_name, catalog) in Self::get_catalogs_internal(filter.catalog.clone())? {
+            let mut dbs = catalog.list_databases(tenant.as_str()).await?;
+            dbs.retain(|db| Self::match_pattern(db.name(), &filter.db_schema_filter_pattern));
+            dbs.sort_by(|a, b| a.name().cmp(b.name()));
             for db in dbs {
-                let db_name = db.name().to_string().into_boxed_str();
-                let db_name: &str = Box::leak(db_name);
-                let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
+                let db_name = db.name();
+                let mut tables = match catalog.list_tables(tenant.as_str(), db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
-                        tracing::warn!("list tables failed on db {}: {}", db.name(), err);
+                        tracing::warn!("list tables failed on db {}: {}", db_name, err);
                         continue;
                     }
                     Err(err) => return Err(err),
                 };
+                tables.sort_by(|a, b| a.name().cmp(b.name()));
                 for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    if !Self::match_pattern(table.name(), &filter.table_name_filter_pattern) {
+                        continue;
+                    }
+                    let table_type = if table.engine() == VIEW_ENGINE {
+                        TABLE_TYPE_VIEW
+                    } else {
+                        TABLE_TYPE_BASE_TABLE
+                    };
+                    if !filter.table_types.is_empty()
+                        && !filter
+                            .table_types
+                            .iter()
+                            .any(|t| t.eq_ignore_ascii_case(table_type))
+                    {
+                        continue;
+                    }
+                    let table_schema = if filter.include_schema {
+                        let schema = DataSchema::from(table.schema());
+                        Some(Self::schema_to_ipc(&(&schema).into())?)
+                    } else {
+                        None
+                    };
+                    rows.push(TableRow {
+                        catalog_name: catalog_name.clone(),
+                        db_schema_name: db_name.to_string(),
+                        table_name: table.name().to_string(),
+                        table_type: table_type.to_string(),
+                        table_schema,
+                    });
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok(rows)
     }
 
-    pub(crate) async fn get_tables(
+    fn schema_to_ipc(schema: &Schema) -> common_exception::Result<Vec<u8>> {
+        let message: IpcMessage = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| ErrorCode::Internal(format!("Unable to serialize schema: {e:?}")))?;
+        let IpcMessage(schema_bytes) = message;
+        Ok(schema_bytes.to_vec())
+    }
+
+    pub(crate) fn get_catalogs() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_name = Self::get_catalogs_internal(None)
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(catalog_name)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
-            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("catalog_name", DataType::Utf8, true),
             Field::new("db_schema_name", DataType::Utf8, false),
-            Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
+        let (catalog_name, db_schema_name) =
+            Self::get_schemas_internal(ctx, catalog_name, db_schema_filter_pattern)
                 .await
                 .map_err(|e| Status::internal(format!("{e:?}")))?;
         let batch = RecordBatch::try_new(schema, vec![
             Self::string_array(catalog_name),
             Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
         ])
         .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
         Self::batch_to_get_stream(batch)
     }
 
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        filter: TablesFilter,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        if filter.include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        let schema = Arc::new(Schema::new(fields));
+        let rows = Self::get_tables_internal(ctx.clone(), &filter)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+
+        let mut catalog_name = StringBuilder::new();
+        let mut db_schema_name = StringBuilder::new();
+        let mut table_name = StringBuilder::new();
+        let mut table_type = StringBuilder::new();
+        let mut table_schema = BinaryBuilder::new();
+        for row in rows {
+            catalog_name.append_value(row.catalog_name);
+            db_schema_name.append_value(row.db_schema_name);
+            table_name.append_value(row.table_name);
+            table_type.append_value(row.table_type);
+            if let Some(bytes) = row.table_schema {
+                table_schema.append_value(bytes);
+            }
+        }
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(catalog_name.finish()),
+            Arc::new(db_schema_name.finish()),
+            Arc::new(table_name.finish()),
+            Arc::new(table_type.finish()),
+        ];
+        if filter.include_schema {
+            columns.push(Arc::new(table_schema.finish()));
+        }
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let table_types = vec![
+            TABLE_TYPE_BASE_TABLE.to_string(),
+            TABLE_TYPE_VIEW.to_string(),
+        ];
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(table_types)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Databend has no primary key constraint, the result is always empty.
+    pub(crate) fn get_primary_keys() -> Result<DoGetStream, Status> {
+        let schema: SchemaRef = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
+    /// Databend has no foreign key constraint, the result is always empty.
+    ///
+    /// Shared by `CommandGetExportedKeys`, `CommandGetImportedKeys` and `CommandGetCrossReference`.
+    pub(crate) fn get_foreign_keys() -> Result<DoGetStream, Status> {
+        let schema: SchemaRef = Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
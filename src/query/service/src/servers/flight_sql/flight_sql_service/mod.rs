@@ -19,20 +19,24 @@ mod query;
 mod service;
 mod session;
 mod sql_info;
+mod xdbc_type_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
+use catalog::TablesFilter;
 use common_sql::plans::Plan;
 use common_sql::PlanExtras;
 use dashmap::DashMap;
 use futures::Stream;
 use parking_lot::Mutex;
+pub use service::CommandStatementIngest;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
+use xdbc_type_info::XdbcTypeInfoProvider;
 
 use crate::servers::http::v1::ExpiringMap;
 use crate::sessions::Session;
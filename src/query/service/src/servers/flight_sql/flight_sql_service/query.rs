@@ -12,34 +12,56 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use arrow_array::ArrayRef;
+use arrow_flight::utils::flight_data_to_arrow_batch;
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
 use arrow_ipc::writer::IpcWriteOptions;
+use arrow_ipc::MessageHeader;
 use arrow_schema::Schema as ArrowSchema;
 use common_base::base::tokio;
+use common_base::base::tokio::sync::mpsc::Receiver;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_pipeline_sources::SyncReceiverSource;
+use common_sql::plans::Insert;
+use common_sql::plans::InsertInputSource;
 use common_sql::plans::Plan;
 use common_sql::PlanExtras;
 use common_sql::Planner;
 use common_storages_fuse::TableContext;
 use futures::Stream;
 use futures::StreamExt;
+use parking_lot::Mutex;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use tonic::Streaming;
 
 use super::status;
+use super::CommandStatementIngest;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
+use crate::interpreters::Accessor;
+use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
+use crate::pipelines::builders::build_append2table_with_commit_pipeline;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::QueryContext;
 use crate::sessions::Session;
 
 /// A app_metakey which indicates the data is a progress type
@@ -106,6 +128,109 @@ impl FlightSqlServiceImpl {
         Ok(affected_rows as i64)
     }
 
+    #[async_backtrace::framed]
+    pub(super) async fn execute_ingest(
+        &self,
+        session: Arc<Session>,
+        command: &CommandStatementIngest,
+        mut flight_data: Streaming<FlightData>,
+    ) -> Result<i64> {
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        let catalog = match &command.catalog {
+            Some(catalog) => catalog.clone(),
+            None => context.get_current_catalog(),
+        };
+        let database = match &command.schema {
+            Some(database) => database.clone(),
+            None => context.get_current_database(),
+        };
+        let table = context
+            .get_table(&catalog, &database, &command.table)
+            .await?;
+
+        // Ingestion requires the same privileges as an INSERT into the table.
+        let plan = Plan::Insert(Box::new(Insert {
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: command.table.clone(),
+            table_id: table.get_id(),
+            schema: table.schema(),
+            overwrite: false,
+            source: InsertInputSource::StreamingWithFormat("Arrow".to_string(), 0, None),
+        }));
+        Accessor::create(context.clone()).check(&plan).await?;
+
+        context.attach_query_str(
+            "Insert".to_string(),
+            format!("INGEST INTO {catalog}.{database}.{}", command.table),
+        );
+
+        // the source schema is taken from the first record batch.
+        let table_schema: DataSchema = table.schema().remove_computed_fields().into();
+        let mut decoder = FlightDataDecoder::new(ArrowSchema::from(&table_schema));
+        let (first_block, source_schema) = match decoder.next_block(&mut flight_data).await? {
+            Some(decoded) => decoded,
+            None => return Ok(0),
+        };
+
+        // columns are matched by name, the missing ones are filled with the default values.
+        let insert_fields = source_schema
+            .fields()
+            .iter()
+            .map(|f| table_schema.field_with_name(f.name()).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        let source_schema: DataSchemaRef = Arc::new(source_schema);
+        let insert_schema: DataSchemaRef = Arc::new(DataSchema::new(insert_fields));
+
+        // The blocks are decoded while the pipeline is running, at most a few of them are
+        // buffered in the channel.
+        let (sender, receiver) = tokio::sync::mpsc::channel(2);
+        let expected_schema = source_schema.clone();
+        tokio::spawn(async move {
+            let mut block = first_block;
+            loop {
+                // the receiver is dropped if the pipeline is finished or aborted.
+                if !block.is_empty() && sender.send(Ok(block)).await.is_err() {
+                    break;
+                }
+                block = match decoder.next_block(&mut flight_data).await {
+                    Ok(Some((block, schema))) if schema == *expected_schema => block,
+                    Ok(Some(_)) => {
+                        let cause = ErrorCode::BadArguments(
+                            "All record batches of DoPut must have the same schema",
+                        );
+                        let _ = sender.send(Err(cause)).await;
+                        break;
+                    }
+                    Ok(None) => break,
+                    Err(cause) => {
+                        let _ = sender.send(Err(cause)).await;
+                        break;
+                    }
+                };
+            }
+        });
+
+        let interpreter = IngestInterpreter {
+            ctx: context.clone(),
+            table,
+            source_schema,
+            insert_schema,
+            receiver: Mutex::new(Some(receiver)),
+        };
+        let mut blocks = interpreter.execute(context.clone()).await?;
+        while let Some(block) = blocks.next().await {
+            block?;
+        }
+
+        let affected_rows = context.get_write_progress_value().rows;
+        Ok(affected_rows as i64)
+    }
+
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
@@ -226,3 +351,122 @@ struct ProgressValue {
     pub read_rows: usize,
     pub read_bytes: usize,
 }
+
+/// Decodes the flight data of DoPut into blocks.
+///
+/// arrow-flight consumes the first message of DoPut to read the descriptor, the schema sent
+/// along with the descriptor is lost, so clients should send the descriptor on its own.
+struct FlightDataDecoder {
+    schema: Arc<ArrowSchema>,
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+}
+
+impl FlightDataDecoder {
+    /// `default_schema` is used if the stream has no schema message.
+    fn new(default_schema: ArrowSchema) -> Self {
+        FlightDataDecoder {
+            schema: Arc::new(default_schema),
+            dictionaries_by_id: HashMap::new(),
+        }
+    }
+
+    /// Read the stream until the next record batch, returns the block and its schema.
+    #[async_backtrace::framed]
+    async fn next_block(
+        &mut self,
+        stream: &mut Streaming<FlightData>,
+    ) -> Result<Option<(DataBlock, DataSchema)>> {
+        while let Some(data) = stream.message().await? {
+            // the descriptor may be sent with an empty message.
+            if data.data_header.is_empty() {
+                continue;
+            }
+            let message = arrow_ipc::root_as_message(&data.data_header[..])
+                .map_err(|e| ErrorCode::BadBytes(format!("Invalid flight data: {e:?}")))?;
+            match message.header_type() {
+                MessageHeader::Schema => {
+                    self.schema = Arc::new(
+                        ArrowSchema::try_from(&data)
+                            .map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?,
+                    );
+                }
+                MessageHeader::RecordBatch => {
+                    let batch = flight_data_to_arrow_batch(
+                        &data,
+                        self.schema.clone(),
+                        &self.dictionaries_by_id,
+                    )
+                    .map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?;
+                    let decoded = DataBlock::from_record_batch(&batch)
+                        .map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?;
+                    return Ok(Some(decoded));
+                }
+                other => {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "Unsupported flight data message {other:?} in DoPut"
+                    )));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Appends the blocks decoded from DoPut to the table.
+///
+/// Running as an interpreter, the ingestion is recorded in the query log like other statements.
+struct IngestInterpreter {
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    source_schema: DataSchemaRef,
+    insert_schema: DataSchemaRef,
+    receiver: Mutex<Option<Receiver<Result<DataBlock>>>>,
+}
+
+#[async_trait::async_trait]
+impl Interpreter for IngestInterpreter {
+    fn name(&self) -> &str {
+        "IngestInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let receiver = self
+            .receiver
+            .lock()
+            .take()
+            .ok_or_else(|| ErrorCode::Internal("The DoPut stream is already consumed"))?;
+
+        let mut build_res = PipelineBuildResult::create();
+        let output = OutputPort::create();
+        let mut source_builder = SourcePipeBuilder::create();
+        source_builder.add_source(
+            output.clone(),
+            SyncReceiverSource::create(self.ctx.clone(), receiver, output)?,
+        );
+        build_res.main_pipeline.add_pipe(source_builder.finalize());
+
+        if self.source_schema != self.insert_schema {
+            let func_ctx = self.ctx.get_function_context()?;
+            build_res.main_pipeline.add_transform(|input, output| {
+                TransformCastSchema::try_create(
+                    input,
+                    output,
+                    self.source_schema.clone(),
+                    self.insert_schema.clone(),
+                    func_ctx.clone(),
+                )
+            })?;
+        }
+        build_append2table_with_commit_pipeline(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            self.table.clone(),
+            self.insert_schema.clone(),
+            None,
+            false,
+            AppendMode::Normal,
+        )?;
+        Ok(build_res)
+    }
+}
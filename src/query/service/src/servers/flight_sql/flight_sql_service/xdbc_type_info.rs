@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::builder::Int32Builder;
+use arrow_array::builder::ListBuilder;
+use arrow_array::builder::StringBuilder;
+use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_flight::sql::Nullable;
+use arrow_flight::sql::Searchable;
+use arrow_flight::sql::XdbcDataType;
+use arrow_flight::utils::batches_to_flight_data;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use futures_util::stream;
+use tonic::Status;
+
+use crate::servers::flight_sql::flight_sql_service::DoGetStream;
+
+// `XdbcDatetimeSubcode` values, the generated enum drops the aliased names.
+const XDBC_SUBCODE_DATE: i32 = 1;
+const XDBC_SUBCODE_TIMESTAMP: i32 = 3;
+
+struct XdbcTypeInfo {
+    type_name: &'static str,
+    data_type: XdbcDataType,
+    column_size: Option<i32>,
+    literal_prefix: Option<&'static str>,
+    literal_suffix: Option<&'static str>,
+    create_params: Option<&'static [&'static str]>,
+    case_sensitive: bool,
+    searchable: Searchable,
+    unsigned_attribute: Option<bool>,
+    fixed_prec_scale: bool,
+    minimum_scale: Option<i32>,
+    maximum_scale: Option<i32>,
+    sql_data_type: XdbcDataType,
+    datetime_subcode: Option<i32>,
+    num_prec_radix: Option<i32>,
+}
+
+impl XdbcTypeInfo {
+    const fn numeric(type_name: &'static str, data_type: XdbcDataType, column_size: i32) -> Self {
+        XdbcTypeInfo {
+            type_name,
+            data_type,
+            column_size: Some(column_size),
+            literal_prefix: None,
+            literal_suffix: None,
+            create_params: None,
+            case_sensitive: false,
+            searchable: Searchable::Basic,
+            unsigned_attribute: Some(false),
+            fixed_prec_scale: false,
+            minimum_scale: None,
+            maximum_scale: None,
+            sql_data_type: data_type,
+            datetime_subcode: None,
+            num_prec_radix: Some(10),
+        }
+    }
+
+    const fn quoted(type_name: &'static str, data_type: XdbcDataType) -> Self {
+        XdbcTypeInfo {
+            type_name,
+            data_type,
+            column_size: None,
+            literal_prefix: Some("'"),
+            literal_suffix: Some("'"),
+            create_params: None,
+            case_sensitive: true,
+            searchable: Searchable::Full,
+            unsigned_attribute: None,
+            fixed_prec_scale: false,
+            minimum_scale: None,
+            maximum_scale: None,
+            sql_data_type: data_type,
+            datetime_subcode: None,
+            num_prec_radix: None,
+        }
+    }
+
+    const fn datetime(type_name: &'static str, data_type: XdbcDataType, subcode: i32) -> Self {
+        XdbcTypeInfo {
+            case_sensitive: false,
+            searchable: Searchable::Basic,
+            sql_data_type: XdbcDataType::XdbcDatetime,
+            datetime_subcode: Some(subcode),
+            ..Self::quoted(type_name, data_type)
+        }
+    }
+}
+
+/// The Databend types exposed to XDBC clients, ordered by `data_type`.
+const XDBC_TYPE_INFOS: &[XdbcTypeInfo] = &[
+    XdbcTypeInfo::numeric("BOOLEAN", XdbcDataType::XdbcBit, 1),
+    XdbcTypeInfo::numeric("TINYINT", XdbcDataType::XdbcTinyint, 3),
+    XdbcTypeInfo::numeric("BIGINT", XdbcDataType::XdbcBigint, 19),
+    XdbcTypeInfo {
+        literal_prefix: None,
+        literal_suffix: None,
+        ..XdbcTypeInfo::quoted("BINARY", XdbcDataType::XdbcVarbinary)
+    },
+    XdbcTypeInfo::quoted("VARIANT", XdbcDataType::XdbcLongvarchar),
+    XdbcTypeInfo {
+        create_params: Some(&["precision", "scale"]),
+        fixed_prec_scale: true,
+        minimum_scale: Some(0),
+        maximum_scale: Some(76),
+        ..XdbcTypeInfo::numeric("DECIMAL", XdbcDataType::XdbcDecimal, 76)
+    },
+    XdbcTypeInfo::numeric("INT", XdbcDataType::XdbcInteger, 10),
+    XdbcTypeInfo::numeric("SMALLINT", XdbcDataType::XdbcSmallint, 5),
+    XdbcTypeInfo::numeric("FLOAT", XdbcDataType::XdbcReal, 7),
+    XdbcTypeInfo::numeric("DOUBLE", XdbcDataType::XdbcDouble, 15),
+    XdbcTypeInfo::quoted("VARCHAR", XdbcDataType::XdbcVarchar),
+    XdbcTypeInfo::datetime("DATE", XdbcDataType::XdbcDate, XDBC_SUBCODE_DATE),
+    XdbcTypeInfo::datetime(
+        "TIMESTAMP",
+        XdbcDataType::XdbcTimestamp,
+        XDBC_SUBCODE_TIMESTAMP,
+    ),
+];
+
+pub(super) struct XdbcTypeInfoProvider {}
+
+impl XdbcTypeInfoProvider {
+    fn schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            Field::new("type_name", DataType::Utf8, false),
+            Field::new("data_type", DataType::Int32, false),
+            Field::new("column_size", DataType::Int32, true),
+            Field::new("literal_prefix", DataType::Utf8, true),
+            Field::new("literal_suffix", DataType::Utf8, true),
+            Field::new(
+                "create_params",
+                DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+            Field::new("nullable", DataType::Int32, false),
+            Field::new("case_sensitive", DataType::Boolean, false),
+            Field::new("searchable", DataType::Int32, false),
+            Field::new("unsigned_attribute", DataType::Boolean, true),
+            Field::new("fixed_prec_scale", DataType::Boolean, false),
+            Field::new("auto_increment", DataType::Boolean, true),
+            Field::new("local_type_name", DataType::Utf8, true),
+            Field::new("minimum_scale", DataType::Int32, true),
+            Field::new("maximum_scale", DataType::Int32, true),
+            Field::new("sql_data_type", DataType::Int32, false),
+            Field::new("datetime_subcode", DataType::Int32, true),
+            Field::new("num_prec_radix", DataType::Int32, true),
+            Field::new("interval_precision", DataType::Int32, true),
+        ]))
+    }
+
+    fn batch(data_type: Option<i32>) -> Result<RecordBatch, Status> {
+        let mut type_name = StringBuilder::new();
+        let mut xdbc_data_type = Int32Builder::new();
+        let mut column_size = Int32Builder::new();
+        let mut literal_prefix = StringBuilder::new();
+        let mut literal_suffix = StringBuilder::new();
+        let mut create_params = ListBuilder::new(StringBuilder::new());
+        let mut nullable = Int32Builder::new();
+        let mut case_sensitive = BooleanBuilder::new();
+        let mut searchable = Int32Builder::new();
+        let mut unsigned_attribute = BooleanBuilder::new();
+        let mut fixed_prec_scale = BooleanBuilder::new();
+        let mut auto_increment = BooleanBuilder::new();
+        let mut local_type_name = StringBuilder::new();
+        let mut minimum_scale = Int32Builder::new();
+        let mut maximum_scale = Int32Builder::new();
+        let mut sql_data_type = Int32Builder::new();
+        let mut datetime_subcode = Int32Builder::new();
+        let mut num_prec_radix = Int32Builder::new();
+        let mut interval_precision = Int32Builder::new();
+
+        for info in XDBC_TYPE_INFOS
+            .iter()
+            .filter(|info| data_type.map_or(true, |ty| info.data_type as i32 == ty))
+        {
+            type_name.append_value(info.type_name);
+            xdbc_data_type.append_value(info.data_type as i32);
+            column_size.append_option(info.column_size);
+            literal_prefix.append_option(info.literal_prefix);
+            literal_suffix.append_option(info.literal_suffix);
+            match info.create_params {
+                Some(params) => {
+                    for param in params {
+                        create_params.values().append_value(param);
+                    }
+                    create_params.append(true);
+                }
+                None => create_params.append(false),
+            }
+            nullable.append_value(Nullable::NullabilityNullable as i32);
+            case_sensitive.append_value(info.case_sensitive);
+            searchable.append_value(info.searchable as i32);
+            unsigned_attribute.append_option(info.unsigned_attribute);
+            fixed_prec_scale.append_value(info.fixed_prec_scale);
+            auto_increment.append_value(false);
+            local_type_name.append_value(info.type_name);
+            minimum_scale.append_option(info.minimum_scale);
+            maximum_scale.append_option(info.maximum_scale);
+            sql_data_type.append_value(info.sql_data_type as i32);
+            datetime_subcode.append_option(info.datetime_subcode);
+            num_prec_radix.append_option(info.num_prec_radix);
+            interval_precision.append_null();
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(type_name.finish()),
+            Arc::new(xdbc_data_type.finish()),
+            Arc::new(column_size.finish()),
+            Arc::new(literal_prefix.finish()),
+            Arc::new(literal_suffix.finish()),
+            Arc::new(create_params.finish()),
+            Arc::new(nullable.finish()),
+            Arc::new(case_sensitive.finish()),
+            Arc::new(searchable.finish()),
+            Arc::new(unsigned_attribute.finish()),
+            Arc::new(fixed_prec_scale.finish()),
+            Arc::new(auto_increment.finish()),
+            Arc::new(local_type_name.finish()),
+            Arc::new(minimum_scale.finish()),
+            Arc::new(maximum_scale.finish()),
+            Arc::new(sql_data_type.finish()),
+            Arc::new(datetime_subcode.finish()),
+            Arc::new(num_prec_radix.finish()),
+            Arc::new(interval_precision.finish()),
+        ];
+        RecordBatch::try_new(Self::schema(), columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))
+    }
+
+    pub fn type_info(data_type: Option<i32>) -> Result<DoGetStream, Status> {
+        let batch = Self::batch(data_type)?;
+        let schema = (*batch.schema()).clone();
+        let flight_data = batches_to_flight_data(schema, vec![batch])
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(Ok);
+        let stream = stream::iter(flight_data);
+        Ok(Box::pin(stream))
+    }
+}
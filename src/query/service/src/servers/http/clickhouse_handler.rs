@@ -108,7 +108,12 @@ async fn execute(
     params: StatementHandlerParams,
     handle: Option<JoinHandle<()>>,
 ) -> Result<WithContentType<Body>> {
-    let format_typ = format.typ.clone();
+    let content_type = if format.suffixes.stream {
+        // FORMAT ArrowStream
+        "application/vnd.apache.arrow.stream".to_string()
+    } else {
+        format.typ.get_content_type()
+    };
     let schema = interpreter.schema();
 
     // the reason of spawning new task to execute the interpreter:
@@ -207,7 +212,7 @@ async fn execute(
                 handle.await.expect("must")
             }
 
-            Ok(Body::from_bytes_stream(stream).with_content_type(format_typ.get_content_type()))
+            Ok(Body::from_bytes_stream(stream).with_content_type(content_type))
         }
     })?
     .await
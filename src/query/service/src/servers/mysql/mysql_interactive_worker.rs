@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
@@ -24,22 +25,31 @@ use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
+use common_expression::types::NumberScalar;
 use common_expression::DataBlock;
+use common_expression::DataField;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::UserIdentity;
+use common_sql::plans::Plan;
+use common_sql::PlanExtras;
 use common_sql::Planner;
+use common_sql::PreparedStatement;
 use common_users::CertifiedInfo;
 use common_users::UserApiProvider;
 use futures_util::StreamExt;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
+use opensrv_mysql::Value;
+use opensrv_mysql::ValueInner;
 use rand::RngCore;
 use tracing::error;
 use tracing::info;
@@ -48,6 +58,8 @@ use tracing::Instrument;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::writers::convert_schema;
+use crate::servers::mysql::writers::make_column_from_field;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -61,6 +73,9 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase<W: AsyncWrite + Send + Unpin> {
     session: Arc<Session>,
+    /// Statements prepared by `COM_STMT_PREPARE` in this session, keyed by statement id.
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
     generic_hold: PhantomData<W>,
 }
 
@@ -269,34 +284,85 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
     }
 
     #[async_backtrace::framed]
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+    async fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        match self.prepare_statement(query).await {
+            Ok((id, params, columns)) => writer.reply(id, &params, &columns).await?,
+            Err(error) => {
+                let error = error.display_with_sql(query);
+                error!("OnPrepare Error: {:?}", error);
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                    .await?
+            }
+        }
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    async fn prepare_statement(&mut self, query: &str) -> Result<(u32, Vec<Column>, Vec<Column>)> {
+        info!("Prepare statement: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let prepared = planner.prepare_sql(query).await?;
+
+        let params = prepared
+            .param_types
+            .iter()
+            .map(|data_type| make_column_from_field(&DataField::new("?", data_type.clone())))
+            .collect::<Result<Vec<_>>>()?;
+        let columns = convert_schema(&prepared.schema)?;
+
+        self.next_statement_id = self.next_statement_id.wrapping_add(1);
+        let id = self.next_statement_id;
+        self.prepared_statements.insert(id, prepared);
+        Ok((id, params, columns))
+    }
+
     #[async_backtrace::framed]
     async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+        let query_result = match self.prepared_statements.get(&id).cloned() {
+            Some(prepared) => self
+                .execute_prepared(&prepared, params)
+                .await
+                .map_err(|err| err.display_with_sql(&prepared.sql)),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement id: {}",
+                id
+            ))),
+        };
+
+        let format = self.session.get_format_settings();
+        writer.write(query_result, &format).await
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn execute_prepared(
+        &mut self,
+        prepared: &PreparedStatement,
+        params: ParamParser<'_>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        info!("Execute prepared statement: {}", prepared.sql);
+        let params = params
+            .into_iter()
+            .map(|param| param_to_scalar(param.value))
+            .collect::<Vec<_>>();
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_prepared(prepared, params).await?;
+        Self::execute_plan(context, plan, extras, &prepared.sql).await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_close(&mut self, stmt_id: u32) {
+        self.prepared_statements.remove(&stmt_id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -341,33 +407,45 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::execute_plan(context, plan, extras, query).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = interpreter.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+    #[async_backtrace::framed]
+    async fn execute_plan(
+        context: Arc<QueryContext>,
+        plan: Plan,
+        extras: PlanExtras,
+        query: &str,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = interpreter.schema();
+                // `EXECUTE` has a result set only if the prepared statement has one.
+                let has_result_set = match &plan {
+                    Plan::ExecutePrepared(_) => schema.num_fields() > 0,
+                    _ => plan.has_result_set(),
+                };
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -446,6 +524,8 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
         InteractiveWorker::<W> {
             base: InteractiveWorkerBase::<W> {
                 session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 0,
                 generic_hold: PhantomData,
             },
             salt: scramble,
@@ -455,6 +535,58 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
     }
 }
 
+/// Convert a parameter of `COM_STMT_EXECUTE` to the value to bind to the placeholder.
+/// Strings are passed through as bytes, which may not be valid UTF-8.
+fn param_to_scalar(value: Value) -> Scalar {
+    match value.into_inner() {
+        ValueInner::NULL => Scalar::Null,
+        ValueInner::Bytes(bytes) => Scalar::String(bytes.to_vec()),
+        ValueInner::Int(v) => Scalar::Number(NumberScalar::Int64(v)),
+        ValueInner::UInt(v) => Scalar::Number(NumberScalar::UInt64(v)),
+        ValueInner::Double(v) => Scalar::Number(NumberScalar::Float64(v.into())),
+        ValueInner::Date(bytes) | ValueInner::Datetime(bytes) => {
+            Scalar::String(decode_datetime(bytes).into_bytes())
+        }
+        ValueInner::Time(bytes) => Scalar::String(decode_time(bytes).into_bytes()),
+    }
+}
+
+/// Decode a `DATE` or `DATETIME` parameter in the binary protocol, which is
+/// `year(2) month(1) day(1) [hour(1) minute(1) second(1) [micros(4)]]`.
+fn decode_datetime(bytes: &[u8]) -> String {
+    let mut buf = [0u8; 11];
+    let len = bytes.len().min(buf.len());
+    buf[..len].copy_from_slice(&bytes[..len]);
+
+    let year = u16::from_le_bytes([buf[0], buf[1]]);
+    let date = format!("{:04}-{:02}-{:02}", year, buf[2], buf[3]);
+    if len <= 4 {
+        return date;
+    }
+    let micros = u32::from_le_bytes([buf[7], buf[8], buf[9], buf[10]]);
+    format!(
+        "{} {:02}:{:02}:{:02}.{:06}",
+        date, buf[4], buf[5], buf[6], micros
+    )
+}
+
+/// Decode a `TIME` parameter in the binary protocol, which is
+/// `is_negative(1) days(4) hour(1) minute(1) second(1) [micros(4)]`.
+fn decode_time(bytes: &[u8]) -> String {
+    let mut buf = [0u8; 12];
+    let len = bytes.len().min(buf.len());
+    buf[..len].copy_from_slice(&bytes[..len]);
+
+    let sign = if buf[0] == 1 { "-" } else { "" };
+    let days = u32::from_le_bytes([buf[1], buf[2], buf[3], buf[4]]);
+    let hours = days as u64 * 24 + buf[5] as u64;
+    let micros = u32::from_le_bytes([buf[8], buf[9], buf[10], buf[11]]);
+    format!(
+        "{}{:02}:{:02}:{:02}.{:06}",
+        sign, hours, buf[6], buf[7], micros
+    )
+}
+
 struct ContextProgressReporter {
     context: Arc<QueryContext>,
     instant: Instant,
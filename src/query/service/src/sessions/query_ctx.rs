@@ -36,6 +36,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -50,6 +51,7 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_pipeline_core::InputError;
 use common_profile::QueryProfileManager;
 use common_settings::ChangeValue;
@@ -57,6 +59,7 @@ use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StorageMetrics;
+use common_storages_fuse::StreamTable;
 use common_storages_fuse::TableContext;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultScan;
@@ -233,6 +236,11 @@ impl QueryContext {
         self.shared.set_executor(weak_ptr)
     }
 
+    /// Run the query in the transaction `txn_mgr` instead of the one of the session.
+    pub fn set_txn_mgr(&self, txn_mgr: TxnManagerRef) {
+        self.shared.set_txn_mgr(txn_mgr)
+    }
+
     pub fn attach_stage(&self, attachment: StageAttachment) {
         self.shared.attach_stage(attachment);
     }
@@ -258,6 +266,7 @@ impl TableContext for QueryContext {
             }
             DataSourceInfo::ParquetSource(table_info) => ParquetTable::from_info(table_info),
             DataSourceInfo::ResultScanSource(table_info) => ResultScan::from_info(table_info),
+            DataSourceInfo::StreamSource(table_info) => StreamTable::from_info(table_info),
         }
     }
 
@@ -570,6 +579,28 @@ impl TableContext for QueryContext {
         Ok(self.shared.data_operator.clone())
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.txn_mgr()
+    }
+
+    fn add_stream_update(&self, req: UpdateTableMetaReq) {
+        let mut stream_updates = self.shared.stream_updates.write();
+        stream_updates.insert(req.table_id, req);
+    }
+
+    fn get_stream_updates(&self) -> Vec<UpdateTableMetaReq> {
+        self.shared
+            .stream_updates
+            .read()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn clear_stream_updates(&self) {
+        self.shared.stream_updates.write().clear();
+    }
+
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
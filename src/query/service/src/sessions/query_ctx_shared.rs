@@ -23,11 +23,13 @@ use std::time::SystemTime;
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_pipeline_core::InputError;
 use common_profile::QueryProfileManager;
 use common_settings::ChangeValue;
@@ -81,6 +83,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) on_error_mode: Arc<RwLock<Option<OnErrorMode>>>,
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
     pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
+    /// The new offsets of the streams consumed by the query, keyed by the stream table id.
+    pub(in crate::sessions) stream_updates: Arc<RwLock<HashMap<u64, UpdateTableMetaReq>>>,
+    /// The transaction of the query, which overrides the one of the session if set.
+    pub(in crate::sessions) txn_mgr: Arc<RwLock<Option<TxnManagerRef>>>,
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     pub(in crate::sessions) can_scan_from_agg_index: Arc<AtomicBool>,
     // Status info.
@@ -117,6 +123,8 @@ impl QueryContextShared {
             on_error_map: Arc::new(RwLock::new(None)),
             on_error_mode: Arc::new(RwLock::new(None)),
             partitions_shas: Arc::new(RwLock::new(vec![])),
+            stream_updates: Arc::new(RwLock::new(HashMap::new())),
+            txn_mgr: Arc::new(RwLock::new(None)),
             cacheable: Arc::new(AtomicBool::new(true)),
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
@@ -124,6 +132,17 @@ impl QueryContextShared {
         }))
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        match &*self.txn_mgr.read() {
+            Some(txn_mgr) => txn_mgr.clone(),
+            None => self.session.txn_mgr(),
+        }
+    }
+
+    pub fn set_txn_mgr(&self, txn_mgr: TxnManagerRef) {
+        *self.txn_mgr.write() = Some(txn_mgr);
+    }
+
     pub fn set_error(&self, err: ErrorCode) {
         let mut guard = self.error.lock();
         *guard = Some(err);
@@ -268,7 +287,16 @@ impl QueryContextShared {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+
+        // The statements inside a transaction see the uncommitted changes of the previous ones.
+        let txn_table_info = self
+            .txn_mgr()
+            .lock()
+            .apply_table_update(cache_table.get_table_info());
+        if let Some(table_info) = txn_table_info {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
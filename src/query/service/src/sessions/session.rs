@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,6 +27,7 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_sql::PreparedStatement;
 use common_users::RoleCacheManager;
 use common_users::BUILTIN_ROLE_PUBLIC;
 use parking_lot::RwLock;
@@ -344,6 +346,10 @@ impl Session {
         self.session_ctx.get_changed_settings()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
     pub fn apply_changed_settings(&self, changes: HashMap<String, ChangeValue>) -> Result<()> {
         self.session_ctx.apply_changed_settings(changes)
     }
@@ -365,6 +371,21 @@ impl Session {
         self.session_ctx
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
+
+    pub fn get_prepared_statement(self: &Arc<Self>, name: &str) -> Option<Arc<PreparedStatement>> {
+        self.session_ctx.get_prepared_statement(name)
+    }
+
+    pub fn set_prepared_statement(self: &Arc<Self>, name: String, stmt: Arc<PreparedStatement>) {
+        self.session_ctx.set_prepared_statement(name, stmt)
+    }
+
+    pub fn remove_prepared_statement(
+        self: &Arc<Self>,
+        name: &str,
+    ) -> Option<Arc<PreparedStatement>> {
+        self.session_ctx.remove_prepared_statement(name)
+    }
 }
 
 impl Drop for Session {
@@ -20,12 +20,15 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_sql::PreparedStatement;
 use parking_lot::RwLock;
 
 use super::SessionType;
@@ -57,6 +60,12 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The state of the transaction started by `BEGIN`, the table meta updates made inside the
+    // transaction are buffered here until `COMMIT`.
+    txn_mgr: TxnManagerRef,
+    // The statements prepared by `PREPARE name FROM '...'`, they live until `DEALLOCATE` or the
+    // end of the session.
+    prepared_statements: RwLock<HashMap<String, Arc<PreparedStatement>>>,
     typ: SessionType,
 }
 
@@ -75,6 +84,8 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            txn_mgr: TxnManager::init(),
+            prepared_statements: Default::default(),
             typ,
         }))
     }
@@ -104,6 +115,10 @@ impl SessionContext {
         Ok(())
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     // Get current catalog name.
     pub fn get_current_catalog(&self) -> String {
         let lock = self.current_catalog.read();
@@ -280,4 +295,19 @@ impl SessionContext {
         let lock = self.query_ids_results.read();
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
+
+    pub fn get_prepared_statement(&self, name: &str) -> Option<Arc<PreparedStatement>> {
+        let lock = self.prepared_statements.read();
+        lock.get(name).cloned()
+    }
+
+    pub fn set_prepared_statement(&self, name: String, statement: Arc<PreparedStatement>) {
+        let mut lock = self.prepared_statements.write();
+        lock.insert(name, statement);
+    }
+
+    pub fn remove_prepared_statement(&self, name: &str) -> Option<Arc<PreparedStatement>> {
+        let mut lock = self.prepared_statements.write();
+        lock.remove(name)
+    }
 }
@@ -19,6 +19,8 @@ use common_catalog::table_args::TableArgs;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::MetaId;
+use common_storages_iceberg::IcebergManifestsTable;
+use common_storages_iceberg::IcebergSnapshotsTable;
 use itertools::Itertools;
 use parking_lot::RwLock;
 
@@ -125,6 +127,15 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(ClusteringInformationTable::create)),
         );
 
+        creators.insert(
+            "iceberg_snapshots".to_string(),
+            (next_id(), Arc::new(IcebergSnapshotsTable::create)),
+        );
+        creators.insert(
+            "iceberg_manifests".to_string(),
+            (next_id(), Arc::new(IcebergManifestsTable::create)),
+        );
+
         creators.insert(
             "sync_crash_me".to_string(),
             (next_id(), Arc::new(SyncCrashMeTable::create)),
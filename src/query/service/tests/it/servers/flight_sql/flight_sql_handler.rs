@@ -15,24 +15,48 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 use std::fs;
+use std::future::Future;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::BinaryArray;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::sql::XdbcDataType;
+use arrow_flight::utils::batches_to_flight_data;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
+use arrow_flight::IpcMessage;
+use arrow_flight::PutResult;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
 use common_base::base::tokio;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::PasswordHashMethod;
+use databend_query::servers::flight_sql::flight_sql_service::CommandStatementIngest;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
 use futures::TryStreamExt;
 use goldenfile::Mint;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -56,6 +80,16 @@ async fn client_with_uds(path: String) -> FlightSqlServiceClient<Channel> {
     FlightSqlServiceClient::new(channel)
 }
 
+async fn fetch_batches(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<Vec<RecordBatch>, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    flight_data_to_batches(&flight_data)
+}
+
 async fn run_query(
     client: &mut FlightSqlServiceClient<Channel>,
     sql: &str,
@@ -66,10 +100,7 @@ async fn run_query(
         affected_rows.to_string()
     } else {
         let flight_info = stmt.execute().await?;
-        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
-        let flight_data = client.do_get(ticket).await?;
-        let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
-        let batches = flight_data_to_batches(&flight_data)?;
+        let batches = fetch_batches(client, flight_info).await?;
         pretty_format_batches(batches.as_slice())?.to_string()
     };
     Ok(res)
@@ -88,10 +119,12 @@ fn prepare_config() -> InnerConfig {
         .build()
 }
 
-#[tokio::test]
-async fn test_query() -> Result<()> {
-    let _guard = TestGlobalServices::setup(prepare_config()).await?;
-
+// We would just listen on TCP, but it seems impossible to know when tonic is ready to serve
+async fn run_with_server<F, Fut>(request: F)
+where
+    F: FnOnce(FlightSqlServiceClient<Channel>) -> Fut,
+    Fut: Future<Output = ()>,
+{
     let file = NamedTempFile::new().unwrap();
     let path = file.into_temp_path().to_str().unwrap().to_string();
     let _ = fs::remove_file(path.clone());
@@ -99,7 +132,6 @@ async fn test_query() -> Result<()> {
     let uds = UnixListener::bind(path.clone()).unwrap();
     let stream = UnixListenerStream::new(uds);
 
-    // We would just listen on TCP, but it seems impossible to know when tonic is ready to serve
     let service = FlightSqlServiceImpl::create();
     let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
     let serve_future = Server::builder()
@@ -107,11 +139,31 @@ async fn test_query() -> Result<()> {
         .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
 
     let request_future = async {
-        let mut mint = Mint::new("tests/it/servers/flight_sql/testdata");
-        let mut file = mint.new_goldenfile("query.txt").unwrap();
         let mut client = client_with_uds(path).await;
         let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
         debug!("Auth succeeded with token: {:?}", token);
+        request(client).await
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+    debug!("Server shutdown!");
+}
+
+#[tokio::test]
+async fn test_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    run_with_server(|mut client| async move {
+        let mut mint = Mint::new("tests/it/servers/flight_sql/testdata");
+        let mut file = mint.new_goldenfile("query.txt").unwrap();
         let cases = [
             "select 1, 'abc', 1.1, 1.1::float32, 1::nullable(int)",
             "select [1, 2]",
@@ -133,18 +185,242 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
-    };
-    tokio::pin!(serve_future);
+    })
+    .await;
 
-    tokio::select! {
-        _ = &mut serve_future => panic!("server returned first"),
-        _ = request_future => {
-            debug!("Client finished!");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_catalog_info() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    run_with_server(|mut client| async move {
+        let mut mint = Mint::new("tests/it/servers/flight_sql/testdata");
+        let mut file = mint.new_goldenfile("catalog_info.txt").unwrap();
+        for sql in [
+            "create table test_meta1(a int, b string)",
+            "create table test_meta2(c int)",
+            "create view test_meta_v as select 1 as d",
+        ] {
+            run_query(&mut client, sql).await.unwrap();
         }
-    }
-    shutdown_tx.send(()).unwrap();
-    serve_future.await.unwrap();
-    debug!("Server shutdown!");
+
+        let cases: Vec<(&str, FlightInfo)> = vec![
+            ("get_catalogs", client.get_catalogs().await.unwrap()),
+            (
+                "get_db_schemas(db_schema_filter_pattern = 'def%')",
+                client
+                    .get_db_schemas(CommandGetDbSchemas {
+                        catalog: Some("default".to_string()),
+                        db_schema_filter_pattern: Some("def%".to_string()),
+                    })
+                    .await
+                    .unwrap(),
+            ),
+            (
+                "get_tables(table_name_filter_pattern = 'test\\_meta%')",
+                client
+                    .get_tables(CommandGetTables {
+                        catalog: None,
+                        db_schema_filter_pattern: Some("default".to_string()),
+                        table_name_filter_pattern: Some("test\\_meta%".to_string()),
+                        table_types: vec![],
+                        include_schema: false,
+                    })
+                    .await
+                    .unwrap(),
+            ),
+            (
+                "get_tables(table_types = ['VIEW'])",
+                client
+                    .get_tables(CommandGetTables {
+                        catalog: None,
+                        db_schema_filter_pattern: Some("default".to_string()),
+                        table_name_filter_pattern: Some("test_meta%".to_string()),
+                        table_types: vec!["VIEW".to_string()],
+                        include_schema: false,
+                    })
+                    .await
+                    .unwrap(),
+            ),
+            ("get_table_types", client.get_table_types().await.unwrap()),
+            (
+                "get_primary_keys(table = 'test_meta1')",
+                client
+                    .get_primary_keys(CommandGetPrimaryKeys {
+                        catalog: None,
+                        db_schema: Some("default".to_string()),
+                        table: "test_meta1".to_string(),
+                    })
+                    .await
+                    .unwrap(),
+            ),
+            (
+                "get_xdbc_type_info(data_type = XDBC_VARCHAR)",
+                client
+                    .get_xdbc_type_info(CommandGetXdbcTypeInfo {
+                        data_type: Some(XdbcDataType::XdbcVarchar as i32),
+                    })
+                    .await
+                    .unwrap(),
+            ),
+        ];
+        for (case, flight_info) in cases {
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+            let res = pretty_format_batches(batches.as_slice()).unwrap();
+            writeln!(file, "{}", res).unwrap();
+        }
+
+        // `include_schema` attaches the IPC encoded table schema.
+        let flight_info = client
+            .get_tables(CommandGetTables {
+                catalog: None,
+                db_schema_filter_pattern: Some("default".to_string()),
+                table_name_filter_pattern: Some("test_meta1".to_string()),
+                table_types: vec![],
+                include_schema: true,
+            })
+            .await
+            .unwrap();
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        let table_schema = batches[0]
+            .column_by_name("table_schema")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .unwrap()
+            .value(0)
+            .to_vec();
+        let schema = Schema::try_from(IpcMessage(table_schema.into())).unwrap();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["a", "b"]);
+    })
+    .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingest() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    run_with_server(|mut client| async move {
+        run_query(
+            &mut client,
+            "create table test_ingest(a int, b string, c int default 7)",
+        )
+        .await
+        .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec!["x", "y"])),
+        ])
+        .unwrap();
+
+        let command = CommandStatementIngest {
+            table: "test_ingest".to_string(),
+            schema: None,
+            catalog: None,
+        };
+        // The descriptor is sent on its own, so the schema message is kept.
+        let mut flight_data = vec![FlightData {
+            flight_descriptor: Some(FlightDescriptor::new_cmd(command.as_any().encode_to_vec())),
+            ..Default::default()
+        }];
+        flight_data.extend(batches_to_flight_data(schema.as_ref().clone(), vec![batch]).unwrap());
+
+        let results: Vec<PutResult> = client
+            .do_put(futures::stream::iter(flight_data))
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let result: DoPutUpdateResult = Any::decode(&*results[0].app_metadata)
+            .unwrap()
+            .unpack()
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.record_count, 2);
+
+        let res = run_query(
+            &mut client,
+            "select count(*) as n, sum(a) as sum_a, sum(c) as sum_c from test_ingest",
+        )
+        .await
+        .unwrap();
+        let expected = vec![
+            "+---+-------+-------+",
+            "| n | sum_a | sum_c |",
+            "+---+-------+-------+",
+            "| 2 | 3     | 14    |",
+            "+---+-------+-------+",
+        ];
+        assert_eq!(res, expected.join("\n"));
+    })
+    .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingest_without_privilege() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    run_with_server(|mut client| async move {
+        run_query(&mut client, "create table test_ingest_denied(a int)")
+            .await
+            .unwrap();
+        run_query(
+            &mut client,
+            "create user test_ingest_user identified by 'test_ingest_password'",
+        )
+        .await
+        .unwrap();
+        client
+            .handshake("test_ingest_user", "test_ingest_password")
+            .await
+            .unwrap();
+
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(
+            Int64Array::from(vec![1, 2]),
+        )])
+        .unwrap();
+        let command = CommandStatementIngest {
+            table: "test_ingest_denied".to_string(),
+            schema: None,
+            catalog: None,
+        };
+        let mut flight_data = vec![FlightData {
+            flight_descriptor: Some(FlightDescriptor::new_cmd(command.as_any().encode_to_vec())),
+            ..Default::default()
+        }];
+        flight_data.extend(batches_to_flight_data(schema, vec![batch]).unwrap());
+
+        let err = match client.do_put(futures::stream::iter(flight_data)).await {
+            Ok(stream) => {
+                let res = stream.try_collect::<Vec<PutResult>>().await;
+                res.unwrap_err().to_string()
+            }
+            Err(e) => e.to_string(),
+        };
+        assert!(err.contains("Permission denied"), "{err}");
+    })
+    .await;
 
     Ok(())
 }
@@ -13,7 +13,12 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::io::Cursor;
 
+use common_arrow::arrow::array::Int32Array;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
 use common_base::base::tokio;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
@@ -185,6 +190,34 @@ async fn test_output_format_compress() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_output_format_arrow_stream() -> PoemResult<()> {
+    let config = ConfigBuilder::create().build();
+    let _guard = TestGlobalServices::setup(config.clone()).await.unwrap();
+    let server = Server::new().await;
+    let sql = "select number::int32 as a from numbers(3) order by a format ArrowStream";
+    let (status, body) = server
+        .get_response_bytes(QueryBuilder::new("").body(sql.to_string()).build())
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let mut reader = Cursor::new(body);
+    let metadata = read_stream_metadata(&mut reader).unwrap();
+    assert_eq!(metadata.schema.fields[0].name, "a");
+    let mut values = vec![];
+    for state in StreamReader::new(reader, metadata, None) {
+        if let StreamState::Some(chunk) = state.unwrap() {
+            let array = chunk.arrays()[0]
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            values.extend(array.values_iter().copied());
+        }
+    }
+    assert_eq!(values, vec![0, 1, 2]);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_insert_format_values() -> PoemResult<()> {
     let config = ConfigBuilder::create().build();
@@ -29,6 +29,7 @@ use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
 use mysql_async::Row;
+use mysql_async::Value;
 use tokio::sync::Barrier;
 
 #[tokio::test(flavor = "current_thread")]
@@ -156,6 +157,79 @@ async fn test_rejected_session_with_parallel() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let result: std::result::Result<_, mysql_async::Error> = async {
+        connection
+            .query_drop("CREATE TABLE test_prepare(a INT, b VARCHAR, c DATE)")
+            .await?;
+
+        let insert = connection
+            .prep("INSERT INTO test_prepare VALUES (?, ?, ?)")
+            .await?;
+        assert_eq!(insert.num_params(), 3);
+        connection
+            .exec_drop(&insert, (1, "x", "2023-01-01"))
+            .await?;
+        connection
+            .exec_drop(&insert, (-2, "y", "2023-01-02"))
+            .await?;
+        connection.close(insert).await?;
+
+        let select = connection
+            .prep("SELECT a, b, c FROM test_prepare WHERE a > ? ORDER BY a")
+            .await?;
+        assert_eq!(select.num_params(), 1);
+        assert_eq!(select.columns().len(), 3);
+        let rows: Vec<(i32, String, Value)> = connection.exec(&select, (-5,)).await?;
+        let rows_gt_zero: Vec<(i32, String, Value)> = connection.exec(&select, (0,)).await?;
+        // The bound plan of the statement is reused until the table changes.
+        connection
+            .query_drop("INSERT INTO test_prepare VALUES (3, 'z', '2023-01-03')")
+            .await?;
+        let rows_after_insert: Vec<(i32, String, Value)> = connection.exec(&select, (0,)).await?;
+        connection.close(select).await?;
+
+        // Bytes that are not valid UTF-8 are passed through.
+        let hex: Vec<String> = connection
+            .exec("SELECT hex(?)", (Value::Bytes(vec![0xff, 0xfe]),))
+            .await?;
+        Ok((rows, rows_gt_zero, rows_after_insert, hex))
+    }
+    .await;
+
+    let (rows, rows_gt_zero, rows_after_insert, hex) = result.unwrap();
+    assert_eq!(rows, vec![
+        (-2, "y".to_string(), Value::Date(2023, 1, 2, 0, 0, 0, 0)),
+        (1, "x".to_string(), Value::Date(2023, 1, 1, 0, 0, 0, 0)),
+    ]);
+    assert_eq!(rows_gt_zero, vec![(
+        1,
+        "x".to_string(),
+        Value::Date(2023, 1, 1, 0, 0, 0, 0)
+    )]);
+    assert_eq!(rows_after_insert, vec![
+        (1, "x".to_string(), Value::Date(2023, 1, 1, 0, 0, 0, 0)),
+        (3, "z".to_string(), Value::Date(2023, 1, 3, 0, 0, 0, 0)),
+    ]);
+    assert_eq!(hex, vec!["fffe".to_string()]);
+
+    // Placeholders are only allowed in prepared statements.
+    let result = connection.query_iter("SELECT ?").await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 async fn create_connection(port: u16) -> Result<mysql_async::Conn> {
     let uri = &format!("mysql://root@127.0.0.1:{}", port);
     let opts = mysql_async::Opts::from_url(uri).unwrap();
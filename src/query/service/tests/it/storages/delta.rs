@@ -0,0 +1,354 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::builder::Int32Builder;
+use arrow_array::builder::Int64Builder;
+use arrow_array::builder::ListBuilder;
+use arrow_array::builder::MapBuilder;
+use arrow_array::builder::StringBuilder;
+use arrow_array::ArrayRef;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_array::StructArray;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::block_debug::pretty_format_blocks;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use futures::TryStreamExt;
+use parquet::arrow::ArrowWriter;
+use serde_json::json;
+use serde_json::Value;
+
+/// Write a data file of columns `id` and `name`, returns the add action of it.
+fn write_data_file(root: &Path, path: &str, p: &str, ids: Vec<i64>, names: Vec<&str>) -> Value {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    let num_records = ids.len();
+    let batch = RecordBatch::try_new(schema.clone(), vec![
+        Arc::new(Int64Array::from(ids)),
+        Arc::new(StringArray::from(names)),
+    ])
+    .unwrap();
+
+    let file_path = root.join(path);
+    std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+    let mut writer =
+        ArrowWriter::try_new(std::fs::File::create(&file_path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    json!({"add": {
+        "path": path,
+        "partitionValues": {"p": p},
+        "size": std::fs::metadata(&file_path).unwrap().len(),
+        "modificationTime": 0,
+        "dataChange": true,
+        "stats": json!({"numRecords": num_records}).to_string(),
+    }})
+}
+
+fn write_commit(root: &Path, version: u64, actions: &[Value]) {
+    let log_dir = root.join("_delta_log");
+    std::fs::create_dir_all(&log_dir).unwrap();
+    let lines = actions.iter().map(Value::to_string).collect::<Vec<_>>();
+    std::fs::write(
+        log_dir.join(format!("{version:020}.json")),
+        lines.join("\n"),
+    )
+    .unwrap();
+}
+
+/// Write the actions into a checkpoint file named `name`, each action is a row with only
+/// its own column set, like the checkpoints written by spark.
+fn write_checkpoint(root: &Path, name: &str, actions: &[Value]) {
+    let mut add_path = StringBuilder::new();
+    let mut add_partition_values =
+        MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+    let mut add_size = Int64Builder::new();
+    let mut add_stats = StringBuilder::new();
+    let mut remove_path = StringBuilder::new();
+    let mut schema_string = StringBuilder::new();
+    let mut partition_columns = ListBuilder::new(StringBuilder::new());
+    let mut min_reader_version = Int32Builder::new();
+    for action in actions {
+        let add = &action["add"];
+        add_path.append_option(add["path"].as_str());
+        match add["partitionValues"].as_object() {
+            Some(values) => {
+                for (k, v) in values {
+                    add_partition_values.keys().append_value(k);
+                    add_partition_values.values().append_option(v.as_str());
+                }
+                add_partition_values.append(true).unwrap();
+            }
+            None => add_partition_values.append(false).unwrap(),
+        }
+        add_size.append_option(add["size"].as_i64());
+        add_stats.append_option(add["stats"].as_str());
+
+        remove_path.append_option(action["remove"]["path"].as_str());
+
+        let meta_data = &action["metaData"];
+        schema_string.append_option(meta_data["schemaString"].as_str());
+        match meta_data["partitionColumns"].as_array() {
+            Some(columns) => {
+                for column in columns {
+                    partition_columns.values().append_option(column.as_str());
+                }
+                partition_columns.append(true);
+            }
+            None => partition_columns.append(false),
+        }
+
+        let protocol = &action["protocol"];
+        min_reader_version.append_option(protocol["minReaderVersion"].as_i64().map(|v| v as i32));
+    }
+
+    let add = StructArray::try_from(vec![
+        ("path", Arc::new(add_path.finish()) as ArrayRef),
+        ("partitionValues", Arc::new(add_partition_values.finish())),
+        ("size", Arc::new(add_size.finish())),
+        ("stats", Arc::new(add_stats.finish())),
+    ])
+    .unwrap();
+    let remove =
+        StructArray::try_from(vec![("path", Arc::new(remove_path.finish()) as ArrayRef)]).unwrap();
+    let meta_data = StructArray::try_from(vec![
+        ("schemaString", Arc::new(schema_string.finish()) as ArrayRef),
+        ("partitionColumns", Arc::new(partition_columns.finish())),
+    ])
+    .unwrap();
+    let protocol = StructArray::try_from(vec![(
+        "minReaderVersion",
+        Arc::new(min_reader_version.finish()) as ArrayRef,
+    )])
+    .unwrap();
+    let batch = RecordBatch::try_from_iter(vec![
+        ("add", Arc::new(add) as ArrayRef),
+        ("remove", Arc::new(remove)),
+        ("metaData", Arc::new(meta_data)),
+        ("protocol", Arc::new(protocol)),
+    ])
+    .unwrap();
+
+    let file = std::fs::File::create(root.join("_delta_log").join(name)).unwrap();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+}
+
+fn remove_commit(root: &Path, version: u64) {
+    std::fs::remove_file(root.join("_delta_log").join(format!("{version:020}.json"))).unwrap();
+}
+
+async fn expect_ids(ctx: Arc<QueryContext>, case: &str, ids: &[i64]) -> Result<()> {
+    let res = execute_query(ctx, "SELECT id FROM default.delta_ckpt ORDER BY id").await;
+    let rows = ids
+        .iter()
+        .map(|id| format!("| {id:<8} |"))
+        .collect::<Vec<_>>();
+    let mut expected = vec!["+----------+", "| Column 0 |", "+----------+"];
+    expected.extend(rows.iter().map(String::as_str));
+    expected.push("+----------+");
+    expects_ok(case, res, expected).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delta_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let table_dir = tempfile::tempdir()?;
+    let root = table_dir.path();
+    let schema = json!({"type": "struct", "fields": [
+        {"name": "id", "type": "long", "nullable": true, "metadata": {}},
+        {"name": "name", "type": "string", "nullable": true, "metadata": {}},
+        {"name": "p", "type": "integer", "nullable": true, "metadata": {}},
+    ]});
+    write_commit(root, 0, &[
+        json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": 2}}),
+        json!({"metaData": {
+            "id": "delta-test",
+            "format": {"provider": "parquet", "options": {}},
+            "schemaString": schema.to_string(),
+            "partitionColumns": ["p"],
+            "configuration": {},
+            "createdTime": 0,
+        }}),
+        write_data_file(root, "p=1/part-0.parquet", "1", vec![1, 2], vec!["a", "b"]),
+        write_data_file(root, "p=2/part-0.parquet", "2", vec![3], vec!["c"]),
+        write_data_file(root, "p=3/part-0.parquet", "3", vec![4], vec!["d"]),
+    ]);
+    // rewrite the file of partition 3
+    write_commit(root, 1, &[
+        json!({"remove": {"path": "p=3/part-0.parquet", "dataChange": true}}),
+        write_data_file(root, "p=3/part-1.parquet", "3", vec![5], vec!["e"]),
+    ]);
+
+    let create_table = format!(
+        "CREATE TABLE default.delta_t ENGINE = DELTA LOCATION = 'fs://{}/'",
+        root.display()
+    );
+    execute_command(ctx.clone(), &create_table).await?;
+
+    let res = execute_query(
+        ctx.clone(),
+        "SELECT id, name, p FROM default.delta_t ORDER BY id",
+    )
+    .await;
+    expects_ok("read delta table", res, vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 1        | 'a'      | 1        |",
+        "| 2        | 'b'      | 1        |",
+        "| 3        | 'c'      | 2        |",
+        "| 5        | 'e'      | 3        |",
+        "+----------+----------+----------+",
+    ])
+    .await?;
+
+    for (filter, scanned, rows) in [("p = 1", 1, 2), ("p > 1", 2, 2), ("p < 0", 0, 0)] {
+        let qry = format!("EXPLAIN SELECT id FROM default.delta_t WHERE {filter}");
+        let blocks = execute_query(ctx.clone(), &qry)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let explain = pretty_format_blocks(&blocks)?;
+        assert!(
+            explain.contains("partitions total: 3")
+                && explain.contains(&format!("partitions scanned: {scanned}")),
+            "{filter}: {explain}"
+        );
+
+        let qry = format!("SELECT count(*) FROM default.delta_t WHERE {filter}");
+        let res = execute_query(ctx.clone(), &qry).await;
+        let count = format!("| {rows}        |");
+        expects_ok(filter, res, vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            &count,
+            "+----------+",
+        ])
+        .await?;
+    }
+
+    // the schema is read from the delta log
+    let create_table = format!(
+        "CREATE TABLE default.delta_t2 (a INT) ENGINE = DELTA LOCATION = 'fs://{}/'",
+        root.display()
+    );
+    assert!(execute_command(ctx.clone(), &create_table).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delta_table_checkpoint() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let table_dir = tempfile::tempdir()?;
+    let root = table_dir.path();
+    let schema = json!({"type": "struct", "fields": [
+        {"name": "id", "type": "long", "nullable": true, "metadata": {}},
+        {"name": "name", "type": "string", "nullable": true, "metadata": {}},
+        {"name": "p", "type": "integer", "nullable": true, "metadata": {}},
+    ]});
+    let protocol = json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": 2}});
+    let meta_data = json!({"metaData": {
+        "id": "delta-test",
+        "format": {"provider": "parquet", "options": {}},
+        "schemaString": schema.to_string(),
+        "partitionColumns": ["p"],
+        "configuration": {},
+        "createdTime": 0,
+    }});
+    let add_a = write_data_file(root, "p=1/part-0.parquet", "1", vec![1, 2], vec!["a", "b"]);
+    let add_b = write_data_file(root, "p=2/part-0.parquet", "2", vec![3], vec!["c"]);
+    let remove_b = json!({"remove": {"path": "p=2/part-0.parquet", "dataChange": true}});
+    let add_c = write_data_file(root, "p=2/part-1.parquet", "2", vec![4], vec!["d"]);
+    let add_d = write_data_file(root, "p=3/part-0.parquet", "3", vec![5], vec!["e"]);
+    let remove_a = json!({"remove": {"path": "p=1/part-0.parquet", "dataChange": true}});
+    let add_e = write_data_file(root, "p=1/part-1.parquet", "1", vec![6], vec!["f"]);
+
+    write_commit(root, 0, &[
+        protocol.clone(),
+        meta_data.clone(),
+        add_a.clone(),
+        add_b,
+    ]);
+    write_commit(root, 1, &[remove_b.clone(), add_c.clone()]);
+    write_checkpoint(root, &format!("{:020}.checkpoint.parquet", 1), &[
+        protocol.clone(),
+        meta_data.clone(),
+        add_a.clone(),
+        add_c.clone(),
+        remove_b,
+    ]);
+    write_commit(root, 2, &[add_d.clone()]);
+
+    let create_table = format!(
+        "CREATE TABLE default.delta_ckpt ENGINE = DELTA LOCATION = 'fs://{}/'",
+        root.display()
+    );
+    execute_command(ctx.clone(), &create_table).await?;
+    expect_ids(ctx.clone(), "read with checkpoint", &[1, 2, 4, 5]).await?;
+
+    // the commits before the checkpoint are not needed.
+    remove_commit(root, 0);
+    expect_ids(ctx.clone(), "read without the old commits", &[1, 2, 4, 5]).await?;
+
+    // a multi-part checkpoint, the replay fails if it is skipped since the commits before
+    // it are removed.
+    write_checkpoint(
+        root,
+        &format!("{:020}.checkpoint.{:010}.{:010}.parquet", 2, 1, 2),
+        &[protocol.clone(), meta_data, add_a],
+    );
+    write_checkpoint(
+        root,
+        &format!("{:020}.checkpoint.{:010}.{:010}.parquet", 2, 2, 2),
+        &[add_c, add_d],
+    );
+    write_commit(root, 3, &[remove_a]);
+    remove_commit(root, 1);
+    remove_commit(root, 2);
+    expect_ids(ctx.clone(), "read with multi-part checkpoint", &[4, 5]).await?;
+
+    // an incomplete checkpoint is ignored, the replay starts from the previous one.
+    write_checkpoint(
+        root,
+        &format!("{:020}.checkpoint.{:010}.{:010}.parquet", 4, 1, 2),
+        &[protocol],
+    );
+    write_commit(root, 4, &[add_e]);
+    expect_ids(ctx.clone(), "read with incomplete checkpoint", &[4, 5, 6]).await?;
+
+    Ok(())
+}
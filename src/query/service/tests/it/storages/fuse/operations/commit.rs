@@ -30,6 +30,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -542,6 +543,22 @@ impl TableContext for CtxDelegation {
         self.ctx.get_data_operator()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
+    fn add_stream_update(&self, req: UpdateTableMetaReq) {
+        self.ctx.add_stream_update(req)
+    }
+
+    fn get_stream_updates(&self) -> Vec<UpdateTableMetaReq> {
+        self.ctx.get_stream_updates()
+    }
+
+    fn clear_stream_updates(&self) {
+        self.ctx.clear_stream_updates()
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatParams> {
         todo!()
     }
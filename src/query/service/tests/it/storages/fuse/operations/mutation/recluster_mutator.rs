@@ -26,6 +26,7 @@ use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_sql::BloomIndexColumns;
+use common_sql::NgramIndexColumns;
 use common_storages_fuse::operations::BlockMetaIndex;
 use common_storages_fuse::pruning::create_segment_location_vector;
 use common_storages_fuse::pruning::FusePruner;
@@ -134,6 +135,8 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
         schema,
         &None,
         BloomIndexColumns::All,
+        NgramIndexColumns::default(),
+        &BTreeMap::new(),
     )?
     .read_pruning(segment_locations)
     .await?;
@@ -12,12 +12,16 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 use common_base::base::tokio;
+use common_config::GlobalConfig;
 use common_exception::Result;
+use common_meta_app::storage::StorageParams;
+use common_storages_fuse::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use databend_query::test_kits::table_test_fixture::append_sample_data;
 use databend_query::test_kits::table_test_fixture::check_data_dir;
 use databend_query::test_kits::table_test_fixture::execute_command;
 use databend_query::test_kits::table_test_fixture::history_should_have_item;
 use databend_query::test_kits::table_test_fixture::TestFixture;
+use walkdir::WalkDir;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_fuse_truncate_purge_stmt() -> Result<()> {
@@ -73,3 +77,62 @@ async fn test_fuse_truncate_purge_stmt() -> Result<()> {
     .await?;
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_purge_inverted_index_files() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("create table {}.t_inverted(id int, msg string)", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let qry = format!("create inverted index idx on {}.t_inverted(msg)", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    for i in 0..2 {
+        let qry = format!(
+            "insert into {}.t_inverted values({}, 'disk error on node-{}')",
+            db, i, i
+        );
+        execute_command(ctx.clone(), qry.as_str()).await?;
+    }
+    assert_eq!(inverted_index_file_count(), 2);
+
+    // the index files of the purged blocks are removed.
+    let qry = format!("truncate table {}.t_inverted purge", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    assert_eq!(inverted_index_file_count(), 0);
+
+    // all the files of a dropped index are removed.
+    let qry = format!("insert into {}.t_inverted values(3, 'all good')", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    assert_eq!(inverted_index_file_count(), 1);
+    let qry = format!("drop inverted index idx on {}.t_inverted", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    assert_eq!(inverted_index_file_count(), 0);
+
+    // the existing blocks are indexed when the index is created.
+    let qry = format!("create inverted index idx on {}.t_inverted(msg)", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    assert_eq!(inverted_index_file_count(), 1);
+
+    Ok(())
+}
+
+fn inverted_index_file_count() -> usize {
+    let root = match &GlobalConfig::instance().storage.params {
+        StorageParams::Fs(v) => v.root.clone(),
+        _ => panic!("storage type is not fs"),
+    };
+    WalkDir::new(root)
+        .into_iter()
+        .map(|entry| entry.unwrap())
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && entry
+                    .path()
+                    .components()
+                    .any(|c| c.as_os_str() == FUSE_TBL_INVERTED_INDEX_PREFIX)
+        })
+        .count()
+}
@@ -0,0 +1,466 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::block_debug::pretty_format_blocks;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_table_insert() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let warehouse = tempfile::tempdir()?;
+    std::fs::create_dir(warehouse.path().join("db"))?;
+    let create_catalog = format!(
+        "CREATE CATALOG ice TYPE=ICEBERG CONNECTION=(URL='fs://{}/')",
+        warehouse.path().display()
+    );
+    execute_command(ctx.clone(), &create_catalog).await?;
+
+    execute_command(
+        ctx.clone(),
+        "CREATE TABLE ice.db.t(a INT NOT NULL, b STRING NULL, c DOUBLE NULL)",
+    )
+    .await?;
+    let table_dir = warehouse.path().join("db").join("t");
+    assert!(table_dir.join("metadata/v1.metadata.json").exists());
+
+    // empty table
+    let res = execute_query(ctx.clone(), "SELECT count(*) FROM ice.db.t").await;
+    expects_ok("empty iceberg table", res, vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 0        |",
+        "+----------+",
+    ])
+    .await?;
+
+    execute_command(
+        ctx.clone(),
+        "INSERT INTO ice.db.t VALUES (1, 'a', 1.5), (2, NULL, NULL)",
+    )
+    .await?;
+    execute_command(ctx.clone(), "INSERT INTO ice.db.t VALUES (3, 'c', 3.5)").await?;
+    assert!(table_dir.join("metadata/v3.metadata.json").exists());
+    let hint = std::fs::read_to_string(table_dir.join("metadata/version-hint.text"))?;
+    assert_eq!(hint.trim(), "3");
+
+    let res = execute_query(ctx.clone(), "SELECT a, b, c FROM ice.db.t").await;
+    expects_ok("insert into iceberg table", res, vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 1        | 'a'      | 1.5      |",
+        "| 2        | NULL     | NULL     |",
+        "| 3        | 'c'      | 3.5      |",
+        "+----------+----------+----------+",
+    ])
+    .await?;
+
+    // overwrite replaces the live data files
+    execute_command(
+        ctx.clone(),
+        "INSERT OVERWRITE ice.db.t VALUES (4, 'd', 4.5)",
+    )
+    .await?;
+    let res = execute_query(ctx.clone(), "SELECT a, b, c FROM ice.db.t").await;
+    expects_ok("insert overwrite iceberg table", res, vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 4        | 'd'      | 4.5      |",
+        "+----------+----------+----------+",
+    ])
+    .await?;
+
+    // create table as select
+    execute_command(
+        ctx.clone(),
+        "CREATE TABLE ice.db.t2 AS SELECT number, number * 2 AS doubled FROM numbers(5)",
+    )
+    .await?;
+    let res = execute_query(
+        ctx.clone(),
+        "SELECT sum(number), sum(doubled) FROM ice.db.t2",
+    )
+    .await;
+    expects_ok("create iceberg table as select", res, vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 10       | 20       |",
+        "+----------+----------+",
+    ])
+    .await?;
+
+    // creating an existing table fails unless IF NOT EXISTS is given
+    assert!(
+        execute_command(ctx.clone(), "CREATE TABLE ice.db.t(a INT)")
+            .await
+            .is_err()
+    );
+    execute_command(ctx.clone(), "CREATE TABLE IF NOT EXISTS ice.db.t(a INT)").await?;
+
+    Ok(())
+}
+
+// Commits are only serialized within a query node, see `commit_metadata`.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_concurrent_commits() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let warehouse = tempfile::tempdir()?;
+    std::fs::create_dir(warehouse.path().join("db"))?;
+    let create_catalog = format!(
+        "CREATE CATALOG ice_c TYPE=ICEBERG CONNECTION=(URL='fs://{}/')",
+        warehouse.path().display()
+    );
+    execute_command(ctx.clone(), &create_catalog).await?;
+    execute_command(ctx.clone(), "CREATE TABLE ice_c.db.t(a INT NOT NULL)").await?;
+
+    let mut handles = Vec::with_capacity(8);
+    for i in 0..8 {
+        let ctx = ctx.get_current_session().create_query_context().await?;
+        handles.push(tokio::spawn(async move {
+            execute_command(ctx, &format!("INSERT INTO ice_c.db.t VALUES ({i})")).await
+        }));
+    }
+    let mut committed = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(_) => committed += 1,
+            Err(e) => assert_eq!(e.code(), ErrorCode::TABLE_VERSION_MISMATCHED, "{e}"),
+        }
+    }
+    assert!(committed > 0);
+
+    // every successful commit publishes its own version, none of them is lost
+    let hint = std::fs::read_to_string(warehouse.path().join("db/t/metadata/version-hint.text"))?;
+    assert_eq!(hint.trim(), (committed + 1).to_string());
+    let res = execute_query(ctx.clone(), "SELECT count(*) FROM ice_c.db.t").await;
+    let count = format!("| {committed}        |");
+    expects_ok("iceberg table after concurrent commits", res, vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        &count,
+        "+----------+",
+    ])
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_time_travel() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let warehouse = tempfile::tempdir()?;
+    std::fs::create_dir(warehouse.path().join("db"))?;
+    let create_catalog = format!(
+        "CREATE CATALOG ice_tt TYPE=ICEBERG CONNECTION=(URL='fs://{}/')",
+        warehouse.path().display()
+    );
+    execute_command(ctx.clone(), &create_catalog).await?;
+    execute_command(ctx.clone(), "CREATE TABLE ice_tt.db.t(a INT NOT NULL)").await?;
+    execute_command(ctx.clone(), "INSERT INTO ice_tt.db.t VALUES (1)").await?;
+    // a point of time strictly between the two snapshots.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let between_inserts = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S%.6f");
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    execute_command(ctx.clone(), "INSERT INTO ice_tt.db.t VALUES (2)").await?;
+
+    let metadata = std::fs::read(warehouse.path().join("db/t/metadata/v2.metadata.json"))?;
+    let metadata: serde_json::Value = serde_json::from_slice(&metadata)?;
+    let first_snapshot = metadata["current-snapshot-id"].as_i64().unwrap();
+
+    let qry = format!("SELECT a FROM ice_tt.db.t AT (SNAPSHOT => '{first_snapshot}')");
+    let res = execute_query(ctx.clone(), &qry).await;
+    expects_ok("iceberg table at snapshot", res, vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ])
+    .await?;
+
+    let qry = format!(
+        "SELECT a FROM ice_tt.db.t AT (TIMESTAMP => '{between_inserts}'::TIMESTAMP) ORDER BY a"
+    );
+    let res = execute_query(ctx.clone(), &qry).await;
+    expects_ok("iceberg table at timestamp between inserts", res, vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ])
+    .await?;
+
+    let res = execute_query(
+        ctx.clone(),
+        "SELECT a FROM ice_tt.db.t AT (TIMESTAMP => now()) ORDER BY a",
+    )
+    .await;
+    expects_ok("iceberg table at timestamp", res, vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "| 2        |",
+        "+----------+",
+    ])
+    .await?;
+
+    let qry = "SELECT count(*), sum(added_records), sum(is_current::INT) \
+               FROM iceberg_snapshots('ice_tt', 'db', 't')";
+    let res = execute_query(ctx.clone(), qry).await;
+    expects_ok("iceberg_snapshots", res, vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 2        | 2        | 1        |",
+        "+----------+----------+----------+",
+    ])
+    .await?;
+
+    let res = execute_query(
+        ctx.clone(),
+        "SELECT count(*) FROM iceberg_manifests('ice_tt', 'db', 't')",
+    )
+    .await;
+    expects_ok("iceberg_manifests of current snapshot", res, vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 2        |",
+        "+----------+",
+    ])
+    .await?;
+
+    let qry =
+        format!("SELECT count(*) FROM iceberg_manifests('ice_tt', 'db', 't', '{first_snapshot}')");
+    let res = execute_query(ctx.clone(), &qry).await;
+    expects_ok("iceberg_manifests of first snapshot", res, vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ])
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_pruning() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let warehouse = tempfile::tempdir()?;
+    std::fs::create_dir(warehouse.path().join("db"))?;
+    let create_catalog = format!(
+        "CREATE CATALOG ice_p TYPE=ICEBERG CONNECTION=(URL='fs://{}/')",
+        warehouse.path().display()
+    );
+    execute_command(ctx.clone(), &create_catalog).await?;
+    execute_command(
+        ctx.clone(),
+        "CREATE TABLE ice_p.db.t(a INT NOT NULL, b STRING NULL)",
+    )
+    .await?;
+    // one data file per insertion
+    execute_command(
+        ctx.clone(),
+        "INSERT INTO ice_p.db.t VALUES (1, 'a'), (2, 'b')",
+    )
+    .await?;
+    execute_command(
+        ctx.clone(),
+        "INSERT INTO ice_p.db.t VALUES (5, 'e'), (6, NULL)",
+    )
+    .await?;
+    execute_command(
+        ctx.clone(),
+        "INSERT INTO ice_p.db.t VALUES (8, NULL), (9, NULL)",
+    )
+    .await?;
+
+    for (filter, scanned, rows) in [
+        ("a = 2", 1, 1),
+        ("a > 5", 2, 3),
+        ("a < 0", 0, 0),
+        ("b IS NOT NULL", 2, 3),
+        ("b = 'e'", 1, 1),
+    ] {
+        let qry = format!("EXPLAIN SELECT a FROM ice_p.db.t WHERE {filter}");
+        let blocks = execute_query(ctx.clone(), &qry)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let explain = pretty_format_blocks(&blocks)?;
+        assert!(
+            explain.contains("partitions total: 3")
+                && explain.contains(&format!("partitions scanned: {scanned}")),
+            "{filter}: {explain}"
+        );
+
+        let qry = format!("SELECT count(*) FROM ice_p.db.t WHERE {filter}");
+        let res = execute_query(ctx.clone(), &qry).await;
+        let count = format!("| {rows}        |");
+        expects_ok(filter, res, vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            &count,
+            "+----------+",
+        ])
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_partition_pruning() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // See testdata/iceberg_partitioned/readme.md, the data files have no
+    // column metrics, only their partition values can prune them.
+    let warehouse = tempfile::tempdir()?;
+    copy_dir(
+        Path::new("tests/it/storages/testdata/iceberg_partitioned/db"),
+        &warehouse.path().join("db"),
+    )?;
+    let create_catalog = format!(
+        "CREATE CATALOG ice_pp TYPE=ICEBERG CONNECTION=(URL='fs://{}/')",
+        warehouse.path().display()
+    );
+    execute_command(ctx.clone(), &create_catalog).await?;
+
+    for (filter, scanned, rows) in [
+        // bucket[4] and truncate[10] of id
+        ("id = 3", 3, 1),
+        ("id >= 30", 3, 3),
+        // day and hour of ts
+        ("ts >= '2023-01-02 00:00:00'", 4, 4),
+        ("ts < '2023-02-01 09:00:00'", 5, 5),
+        // year and month of d
+        ("d < '2022-01-01'", 2, 2),
+        ("d = '2023-08-15'", 2, 1),
+    ] {
+        let qry = format!("EXPLAIN SELECT id FROM ice_pp.db.t WHERE {filter}");
+        let blocks = execute_query(ctx.clone(), &qry)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let explain = pretty_format_blocks(&blocks)?;
+        assert!(
+            explain.contains("partitions total: 6")
+                && explain.contains(&format!("partitions scanned: {scanned}")),
+            "{filter}: {explain}"
+        );
+
+        let qry = format!("SELECT count(*) FROM ice_pp.db.t WHERE {filter}");
+        let res = execute_query(ctx.clone(), &qry).await;
+        let count = format!("| {rows}        |");
+        expects_ok(filter, res, vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            &count,
+            "+----------+",
+        ])
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_deletes() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // See testdata/iceberg_deletes/readme.md for the history of the table, it
+    // holds a spark position delete file and a flink equality delete file.
+    let warehouse = tempfile::tempdir()?;
+    copy_dir(
+        Path::new("tests/it/storages/testdata/iceberg_deletes/db"),
+        &warehouse.path().join("db"),
+    )?;
+    let create_catalog = format!(
+        "CREATE CATALOG ice_d TYPE=ICEBERG CONNECTION=(URL='fs://{}/')",
+        warehouse.path().display()
+    );
+    execute_command(ctx.clone(), &create_catalog).await?;
+
+    let res = execute_query(ctx.clone(), "SELECT id, data FROM ice_d.db.t ORDER BY id").await;
+    expects_ok("iceberg table with deletes", res, vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 'a'      |",
+        "| 3        | 'c'      |",
+        "| 4        | 'x'      |",
+        "+----------+----------+",
+    ])
+    .await?;
+
+    // the deletes don't apply to the snapshots before them
+    for (snapshot, rows) in [(1002, 3), (1001, 4)] {
+        let qry = format!("SELECT count(*) FROM ice_d.db.t AT (SNAPSHOT => '{snapshot}')");
+        let res = execute_query(ctx.clone(), &qry).await;
+        let count = format!("| {rows}        |");
+        expects_ok(&qry, res, vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            &count,
+            "+----------+",
+        ])
+        .await?;
+    }
+
+    Ok(())
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
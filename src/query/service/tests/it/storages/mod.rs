@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod delta;
 mod fuse;
+mod iceberg;
 mod null;
+mod orc;
 mod statistics;
 mod system;
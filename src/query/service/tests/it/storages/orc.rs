@@ -0,0 +1,158 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::Scalar;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OrcFileFormatParams;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_sources::input_formats::read_orc_schema_async;
+use common_pipeline_sources::input_formats::OrcSplitMeta;
+use common_pipeline_sources::input_formats::SplitInfo;
+use common_sql::parse_to_remote_string_expr;
+use common_storage::init_stage_operator;
+use common_storage::StageFilesInfo;
+use common_storages_stage::StageTable;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+
+/// `tests/data/sample.orc` holds two stripes, id 1 to 3 and id 4 to 6.
+async fn sample_orc_table() -> Result<Arc<dyn Table>> {
+    let root = format!("{}/../../../tests/data/", env!("CARGO_MANIFEST_DIR"));
+    let mut stage_info =
+        StageInfo::new_external_stage(StorageParams::Fs(StorageFsConfig { root }), "/");
+    stage_info.file_format_params = FileFormatParams::Orc(OrcFileFormatParams::default());
+    let operator = init_stage_operator(&stage_info)?;
+    let schema = read_orc_schema_async(&operator, "sample.orc").await?;
+    StageTable::try_create(StageTableInfo {
+        schema: Arc::new(schema),
+        files_info: StageFilesInfo {
+            path: "".to_string(),
+            files: Some(vec!["sample.orc".to_string()]),
+            pattern: None,
+        },
+        stage_info,
+        files_to_copy: None,
+        is_select: true,
+    })
+}
+
+fn stripes_of(parts: &Partitions) -> Vec<usize> {
+    parts
+        .partitions
+        .iter()
+        .map(|part| {
+            let split = part.as_any().downcast_ref::<SplitInfo>().unwrap();
+            let meta = split.format_info.as_ref().unwrap();
+            meta.as_any().downcast_ref::<OrcSplitMeta>().unwrap().stripe
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_orc_stripe_pruning() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let table = sample_orc_table().await?;
+
+    let (_, parts) = table.read_partitions(ctx.clone(), None, false).await?;
+    assert_eq!(stripes_of(&parts), vec![0, 1]);
+
+    let cases = vec![
+        ("id > 4", vec![1]),
+        ("id <= 3", vec![0]),
+        ("id > 6", vec![]),
+        ("big < 20", vec![0]),
+        ("score > 4", vec![1]),
+        ("\"Name\" = 'e'", vec![1]),
+        ("id > 2 and big < 50", vec![0, 1]),
+    ];
+    for (filter, expected) in cases {
+        let push_downs = PushDownInfo {
+            filter: Some(parse_to_remote_string_expr(
+                ctx.clone(),
+                table.clone(),
+                filter,
+            )?),
+            ..Default::default()
+        };
+        let (_, parts) = table
+            .read_partitions(ctx.clone(), Some(push_downs), false)
+            .await?;
+        assert_eq!(stripes_of(&parts), expected, "filter: {filter}");
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_orc_column_statistics() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let table = sample_orc_table().await?;
+
+    let (_, parts) = table.read_partitions(ctx.clone(), None, false).await?;
+    let split = parts.partitions[1]
+        .as_any()
+        .downcast_ref::<SplitInfo>()
+        .unwrap();
+    let meta = split
+        .format_info
+        .as_ref()
+        .unwrap()
+        .as_any()
+        .downcast_ref::<OrcSplitMeta>()
+        .unwrap();
+
+    let stats = meta.column_statistics("id", false).unwrap();
+    assert_eq!(stats.min, Scalar::Number(NumberScalar::Int32(4)));
+    assert_eq!(stats.max, Scalar::Number(NumberScalar::Int32(6)));
+    assert_eq!(stats.null_count, 0);
+
+    let stats = meta.column_statistics("big", false).unwrap();
+    assert_eq!(stats.min, Scalar::Number(NumberScalar::Int64(40)));
+    assert_eq!(stats.max, Scalar::Number(NumberScalar::Int64(60)));
+
+    // The column is matched by name the same way as the rows are read.
+    let stats = meta.column_statistics("name", false).unwrap();
+    assert_eq!(stats.min, Scalar::String(b"d".to_vec()));
+    assert_eq!(stats.max, Scalar::String(b"f".to_vec()));
+    assert!(meta.column_statistics("name", true).is_none());
+    assert!(meta.column_statistics("Name", true).is_some());
+    assert!(meta.column_statistics("not_exist", false).is_none());
+
+    // The first stripe has a null score.
+    let split = parts.partitions[0]
+        .as_any()
+        .downcast_ref::<SplitInfo>()
+        .unwrap();
+    let meta = split
+        .format_info
+        .as_ref()
+        .unwrap()
+        .as_any()
+        .downcast_ref::<OrcSplitMeta>()
+        .unwrap();
+    let stats = meta.column_statistics("score", false).unwrap();
+    assert_eq!(stats.null_count, 1);
+    Ok(())
+}
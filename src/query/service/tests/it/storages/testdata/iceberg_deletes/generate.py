@@ -0,0 +1,581 @@
+#!/usr/bin/env python3
+# Copyright 2023 Datafuse Labs.
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Generate the `db/t` iceberg table with delete files, see readme.md.
+
+Only the python standard library is used, parquet and avro files are encoded
+by hand with the layouts written by iceberg-java (Spark and Flink).
+"""
+
+import io
+import json
+import os
+import shutil
+import struct
+import zlib
+
+LOCATION = "s3://testbucket/iceberg_deletes/db/t"
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "db", "t")
+
+POS_FILE_PATH_ID = 2147483546
+POS_POS_ID = 2147483545
+
+TABLE_SCHEMA = {
+    "type": "struct",
+    "schema-id": 0,
+    "identifier-field-ids": [1],
+    "fields": [
+        {"id": 1, "name": "id", "required": True, "type": "int"},
+        {"id": 2, "name": "data", "required": False, "type": "string"},
+    ],
+}
+
+
+# ---------------------------------------------------------------- thrift compact
+
+
+def varint(n):
+    out = bytearray()
+    while True:
+        b = n & 0x7F
+        n >>= 7
+        if n:
+            out.append(b | 0x80)
+        else:
+            out.append(b)
+            return bytes(out)
+
+
+def zigzag(n):
+    return (n << 1) ^ (n >> 63)
+
+
+class Struct:
+    """A thrift struct, fields are `(id, type, value)` in ascending id order."""
+
+    def __init__(self, *fields):
+        self.fields = [f for f in fields if f[2] is not None]
+
+
+T_BOOL, T_I32, T_I64, T_BINARY, T_LIST, T_STRUCT = 1, 5, 6, 8, 9, 12
+
+
+def encode_value(ty, value, out):
+    if ty in (T_I32, T_I64):
+        out += varint(zigzag(value))
+    elif ty == T_BINARY:
+        data = value.encode() if isinstance(value, str) else value
+        out += varint(len(data)) + data
+    elif ty == T_STRUCT:
+        encode_struct(value, out)
+    elif ty == T_LIST:
+        elem_ty, items = value
+        if len(items) < 15:
+            out.append((len(items) << 4) | elem_ty)
+        else:
+            out.append(0xF0 | elem_ty)
+            out += varint(len(items))
+        for item in items:
+            encode_value(elem_ty, item, out)
+
+
+def encode_struct(struct_, out):
+    last = 0
+    for field_id, ty, value in struct_.fields:
+        # booleans are encoded in the field header, 1 for true and 2 for false
+        header_ty = (1 if value else 2) if ty == T_BOOL else ty
+        delta = field_id - last
+        if 0 < delta <= 15:
+            out.append((delta << 4) | header_ty)
+        else:
+            out.append(header_ty)
+            out += varint(zigzag(field_id))
+        if ty != T_BOOL:
+            encode_value(ty, value, out)
+        last = field_id
+    out.append(0)
+
+
+def thrift(struct_):
+    out = bytearray()
+    encode_struct(struct_, out)
+    return bytes(out)
+
+
+# ---------------------------------------------------------------- parquet
+
+INT32, INT64, BYTE_ARRAY = 1, 2, 6
+REQUIRED, OPTIONAL = 0, 1
+PLAIN, RLE = 0, 3
+
+# logical type -> (converted type, LogicalType union)
+LOGICAL_TYPES = {
+    "string": (0, Struct((1, T_STRUCT, Struct()))),
+    "date": (6, Struct((6, T_STRUCT, Struct()))),
+    # timestamp without time zone, in micros
+    "timestamp": (10, Struct((8, T_STRUCT, Struct(
+        (1, T_BOOL, False),
+        (2, T_STRUCT, Struct((2, T_STRUCT, Struct()))),
+    )))),
+}
+
+
+def plain(ptype, values):
+    if ptype == INT32:
+        return b"".join(struct.pack("<i", v) for v in values)
+    if ptype == INT64:
+        return b"".join(struct.pack("<q", v) for v in values)
+    return b"".join(struct.pack("<I", len(v.encode())) + v.encode() for v in values)
+
+
+def def_levels(values):
+    # RLE/bit-packed hybrid with bit width 1, one RLE run per level.
+    runs = bytearray()
+    i = 0
+    while i < len(values):
+        level = 0 if values[i] is None else 1
+        j = i
+        while j < len(values) and (0 if values[j] is None else 1) == level:
+            j += 1
+        runs += varint((j - i) << 1) + bytes([level])
+        i = j
+    return struct.pack("<I", len(runs)) + bytes(runs)
+
+
+def write_parquet(path, columns, num_rows, iceberg_schema):
+    """`columns` are `(name, field_id, physical_type, repetition, values[, logical_type])`,
+    byte arrays are strings unless told otherwise."""
+    out = io.BytesIO()
+    out.write(b"PAR1")
+    chunks = []
+    for name, field_id, ptype, repetition, values, *_ in columns:
+        page = b""
+        if repetition == OPTIONAL:
+            page += def_levels(values)
+        page += plain(ptype, [v for v in values if v is not None])
+        header = thrift(
+            Struct(
+                (1, T_I32, 0),
+                (2, T_I32, len(page)),
+                (3, T_I32, len(page)),
+                (5, T_STRUCT, Struct((1, T_I32, num_rows), (2, T_I32, PLAIN),
+                                     (3, T_I32, RLE), (4, T_I32, RLE))),
+            )
+        )
+        offset = out.tell()
+        out.write(header + page)
+        size = len(header) + len(page)
+        meta = Struct(
+            (1, T_I32, ptype),
+            (2, T_LIST, (T_I32, [PLAIN, RLE])),
+            (3, T_LIST, (T_BINARY, [name])),
+            (4, T_I32, 0),
+            (5, T_I64, num_rows),
+            (6, T_I64, size),
+            (7, T_I64, size),
+            (9, T_I64, offset),
+        )
+        chunks.append((offset, size, meta))
+
+    schema = [Struct((4, T_BINARY, "table"), (5, T_I32, len(columns)))]
+    for name, field_id, ptype, repetition, _, *logical in columns:
+        logical = logical[0] if logical else ("string" if ptype == BYTE_ARRAY else None)
+        converted, logical = LOGICAL_TYPES.get(logical, (None, None))
+        schema.append(
+            Struct(
+                (1, T_I32, ptype),
+                (3, T_I32, repetition),
+                (4, T_BINARY, name),
+                (6, T_I32, converted),
+                (9, T_I32, field_id),
+                (10, T_STRUCT, logical),
+            )
+        )
+    row_group = Struct(
+        (1, T_LIST, (T_STRUCT, [Struct((2, T_I64, o), (3, T_STRUCT, m)) for o, _, m in chunks])),
+        (2, T_I64, sum(s for _, s, _ in chunks)),
+        (3, T_I64, num_rows),
+    )
+    footer = thrift(
+        Struct(
+            (1, T_I32, 1),
+            (2, T_LIST, (T_STRUCT, schema)),
+            (3, T_I64, num_rows),
+            (4, T_LIST, (T_STRUCT, [row_group])),
+            (5, T_LIST, (T_STRUCT, [Struct((1, T_BINARY, "iceberg.schema"),
+                                           (2, T_BINARY, json.dumps(iceberg_schema)))])),
+            (6, T_BINARY, "parquet-mr version 1.13.1 (build db4183109d5b734ec5930d870cdae161e408ddba)"),
+        )
+    )
+    out.write(footer + struct.pack("<I", len(footer)) + b"PAR1")
+    data = out.getvalue()
+    with open(os.path.join(ROOT, path), "wb") as f:
+        f.write(data)
+    return len(data)
+
+
+# ---------------------------------------------------------------- avro
+
+
+def avro_long(n):
+    return varint(zigzag(n))
+
+
+def avro_bytes(b):
+    b = b.encode() if isinstance(b, str) else b
+    return avro_long(len(b)) + b
+
+
+def avro_encode(schema, value, named):
+    if isinstance(schema, str):
+        if schema in named:
+            return avro_encode(named[schema], value, named)
+        if schema == "null":
+            return b""
+        if schema == "boolean":
+            return bytes([1 if value else 0])
+        if schema in ("int", "long"):
+            return avro_long(value)
+        if schema in ("string", "bytes"):
+            return avro_bytes(value)
+        raise ValueError(schema)
+    if isinstance(schema, list):
+        # unions are only ["null", T]
+        if value is None:
+            return avro_long(0)
+        return avro_long(1) + avro_encode(schema[1], value, named)
+    if schema["type"] == "record":
+        named[schema["name"]] = schema
+        return b"".join(avro_encode(f["type"], value.get(f["name"]), named) for f in schema["fields"])
+    if schema["type"] == "array":
+        if not value:
+            return avro_long(0)
+        items = b"".join(avro_encode(schema["items"], v, named) for v in value)
+        return avro_long(len(value)) + items + avro_long(0)
+    return avro_encode(schema["type"], value, named)
+
+
+def write_avro(path, schema, records, metadata):
+    sync = bytes(range(16))
+    meta = dict(metadata)
+    meta["avro.schema"] = json.dumps(schema)
+    meta["avro.codec"] = "deflate"
+    out = bytearray(b"Obj\x01")
+    out += avro_long(len(meta))
+    for k, v in meta.items():
+        out += avro_bytes(k) + avro_bytes(v)
+    out += avro_long(0) + sync
+    body = b"".join(avro_encode(schema, r, {}) for r in records)
+    compressor = zlib.compressobj(wbits=-15)
+    body = compressor.compress(body) + compressor.flush()
+    out += avro_long(len(records)) + avro_long(len(body)) + body + sync
+    with open(os.path.join(ROOT, path), "wb") as f:
+        f.write(out)
+    return len(out)
+
+
+def map_field(name, field_id, key_id, value_type):
+    """Iceberg encodes maps with int keys as arrays of key-value records."""
+    return {"name": name, "default": None, "field-id": field_id, "type": [
+        "null", {"type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": f"k{key_id}_v{key_id + 1}", "fields": [
+                {"name": "key", "type": "int", "field-id": key_id},
+                {"name": "value", "type": value_type, "field-id": key_id + 1}]}}]}
+
+
+MANIFEST_ENTRY_SCHEMA = {
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int", "field-id": 0},
+        {"name": "snapshot_id", "type": ["null", "long"], "default": None, "field-id": 1},
+        {"name": "sequence_number", "type": ["null", "long"], "default": None, "field-id": 3},
+        {"name": "file_sequence_number", "type": ["null", "long"], "default": None,
+         "field-id": 4},
+        {"name": "data_file", "field-id": 2, "type": {
+            "type": "record",
+            "name": "r2",
+            "fields": [
+                {"name": "content", "type": "int", "field-id": 134,
+                 "doc": "Contents of the file: 0=data, 1=position deletes, 2=equality deletes"},
+                {"name": "file_path", "type": "string", "field-id": 100},
+                {"name": "file_format", "type": "string", "field-id": 101},
+                {"name": "partition", "field-id": 102,
+                 "type": {"type": "record", "name": "r102", "fields": []}},
+                {"name": "record_count", "type": "long", "field-id": 103},
+                {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                map_field("column_sizes", 108, 117, "long"),
+                map_field("value_counts", 109, 119, "long"),
+                map_field("null_value_counts", 110, 121, "long"),
+                map_field("nan_value_counts", 137, 138, "long"),
+                map_field("lower_bounds", 125, 126, "bytes"),
+                map_field("upper_bounds", 128, 129, "bytes"),
+                {"name": "key_metadata", "type": ["null", "bytes"], "default": None,
+                 "field-id": 131},
+                {"name": "split_offsets", "default": None, "field-id": 132,
+                 "type": ["null", {"type": "array", "items": "long", "element-id": 133}]},
+                {"name": "equality_ids", "default": None, "field-id": 135,
+                 "type": ["null", {"type": "array", "items": "int", "element-id": 136}]},
+                {"name": "sort_order_id", "type": ["null", "int"], "default": None,
+                 "field-id": 140},
+            ],
+        }},
+    ],
+}
+
+MANIFEST_FILE_SCHEMA = {
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string", "field-id": 500},
+        {"name": "manifest_length", "type": "long", "field-id": 501},
+        {"name": "partition_spec_id", "type": "int", "field-id": 502},
+        {"name": "content", "type": "int", "field-id": 517},
+        {"name": "sequence_number", "type": "long", "field-id": 515},
+        {"name": "min_sequence_number", "type": "long", "field-id": 516},
+        {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+        {"name": "added_files_count", "type": "int", "field-id": 504},
+        {"name": "existing_files_count", "type": "int", "field-id": 505},
+        {"name": "deleted_files_count", "type": "int", "field-id": 506},
+        {"name": "added_rows_count", "type": "long", "field-id": 512},
+        {"name": "existing_rows_count", "type": "long", "field-id": 513},
+        {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+        {"name": "partitions", "default": None, "field-id": 507, "type": ["null", {
+            "type": "array", "element-id": 508, "items": {
+                "type": "record", "name": "r508", "fields": [
+                    {"name": "contains_null", "type": "boolean", "field-id": 509},
+                    {"name": "contains_nan", "type": ["null", "boolean"], "default": None,
+                     "field-id": 518},
+                    {"name": "lower_bound", "type": ["null", "bytes"], "default": None,
+                     "field-id": 510},
+                    {"name": "upper_bound", "type": ["null", "bytes"], "default": None,
+                     "field-id": 511}]}}]},
+        {"name": "key_metadata", "type": ["null", "bytes"], "default": None, "field-id": 519},
+    ],
+}
+
+
+def kv(pairs):
+    return [{"key": k, "value": v} for k, v in pairs]
+
+
+def write_manifest(path, content, snapshot_id, entries):
+    write_avro(path, MANIFEST_ENTRY_SCHEMA, entries, {
+        "schema": json.dumps(TABLE_SCHEMA),
+        "schema-id": "0",
+        "partition-spec": "[]",
+        "partition-spec-id": "0",
+        "format-version": "2",
+        "content": content,
+    })
+    return {
+        "manifest_path": f"{LOCATION}/{path}",
+        "manifest_length": os.path.getsize(os.path.join(ROOT, path)),
+        "partition_spec_id": 0,
+        "content": 0 if content == "data" else 1,
+        "added_snapshot_id": snapshot_id,
+        "added_files_count": len(entries),
+        "existing_files_count": 0,
+        "deleted_files_count": 0,
+        "added_rows_count": sum(e["data_file"]["record_count"] for e in entries),
+        "existing_rows_count": 0,
+        "deleted_rows_count": 0,
+        "partitions": [],
+    }
+
+
+def write_manifest_list(path, snapshot_id, parent_id, sequence_number, manifests):
+    write_avro(path, MANIFEST_FILE_SCHEMA, manifests, {
+        "snapshot-id": str(snapshot_id),
+        "parent-snapshot-id": "null" if parent_id is None else str(parent_id),
+        "sequence-number": str(sequence_number),
+        "format-version": "2",
+    })
+
+
+def entry(snapshot_id, content, path, size, record_count, stats, equality_ids=None):
+    column_sizes, value_counts, null_counts, lower, upper = stats
+    return {
+        "status": 1,
+        "snapshot_id": snapshot_id,
+        # the added files inherit the sequence number of the manifest.
+        "sequence_number": None,
+        "file_sequence_number": None,
+        "data_file": {
+            "content": content,
+            "file_path": f"{LOCATION}/{path}",
+            "file_format": "PARQUET",
+            "partition": {},
+            "record_count": record_count,
+            "file_size_in_bytes": size,
+            "column_sizes": kv(column_sizes),
+            "value_counts": kv(value_counts),
+            "null_value_counts": kv(null_counts),
+            "nan_value_counts": [],
+            "lower_bounds": kv(lower),
+            "upper_bounds": kv(upper),
+            "key_metadata": None,
+            "split_offsets": [4],
+            "equality_ids": equality_ids,
+            "sort_order_id": 0 if content != 1 else None,
+        },
+    }
+
+
+def i32(v):
+    return struct.pack("<i", v)
+
+
+def i64(v):
+    return struct.pack("<q", v)
+
+
+def data_file(path, snapshot_id, rows):
+    ids = [r[0] for r in rows]
+    data = [r[1] for r in rows]
+    size = write_parquet(path, [
+        ("id", 1, INT32, REQUIRED, ids),
+        ("data", 2, BYTE_ARRAY, OPTIONAL, data),
+    ], len(rows), TABLE_SCHEMA)
+    present = [d for d in data if d is not None]
+    stats = (
+        [(1, 4 * len(ids)), (2, sum(len(d) for d in present))],
+        [(1, len(ids)), (2, len(data))],
+        [(1, 0), (2, len(data) - len(present))],
+        [(1, i32(min(ids))), (2, min(present).encode())],
+        [(1, i32(max(ids))), (2, max(present).encode())],
+    )
+    return entry(snapshot_id, 0, path, size, len(rows), stats)
+
+
+def main():
+    shutil.rmtree(ROOT, ignore_errors=True)
+    os.makedirs(os.path.join(ROOT, "data"))
+    os.makedirs(os.path.join(ROOT, "metadata"))
+
+    s1, s2, s3 = 1001, 1002, 1003
+    ts = [1690000000000, 1690000001000, 1690000002000, 1690000003000]
+
+    # 1. spark: INSERT INTO t VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')
+    file_a = "data/00000-0-5f3a4f3c-6f0b-4a47-9a6e-0b3c2b1e7a10-00001.parquet"
+    entry_a = data_file(file_a, s1, [(1, "a"), (2, "b"), (3, "c"), (4, "d")])
+    manifest_a = write_manifest(
+        "metadata/5f3a4f3c-6f0b-4a47-9a6e-0b3c2b1e7a10-m0.avro", "data", s1, [entry_a])
+    manifest_a.update(sequence_number=1, min_sequence_number=1)
+    list_1 = "metadata/snap-1001-1-5f3a4f3c-6f0b-4a47-9a6e-0b3c2b1e7a10.avro"
+    write_manifest_list(list_1, s1, None, 1, [manifest_a])
+
+    # 2. spark, merge-on-read: DELETE FROM t WHERE id = 2
+    file_p = "data/00000-2-7c1d9e2a-3b4f-4c5d-8e6f-1a2b3c4d5e6f-00001-deletes.parquet"
+    path_a = f"{LOCATION}/{file_a}"
+    size = write_parquet(file_p, [
+        ("file_path", POS_FILE_PATH_ID, BYTE_ARRAY, REQUIRED, [path_a]),
+        ("pos", POS_POS_ID, INT64, REQUIRED, [1]),
+    ], 1, {"type": "struct", "schema-id": 0, "fields": [
+        {"id": POS_FILE_PATH_ID, "name": "file_path", "required": True, "type": "string"},
+        {"id": POS_POS_ID, "name": "pos", "required": True, "type": "long"},
+    ]})
+    entry_p = entry(s2, 1, file_p, size, 1, (
+        [(POS_FILE_PATH_ID, len(path_a)), (POS_POS_ID, 8)],
+        [(POS_FILE_PATH_ID, 1), (POS_POS_ID, 1)],
+        [(POS_FILE_PATH_ID, 0), (POS_POS_ID, 0)],
+        [(POS_FILE_PATH_ID, path_a.encode()), (POS_POS_ID, i64(1))],
+        [(POS_FILE_PATH_ID, path_a.encode()), (POS_POS_ID, i64(1))],
+    ))
+    manifest_p = write_manifest(
+        "metadata/7c1d9e2a-3b4f-4c5d-8e6f-1a2b3c4d5e6f-m0.avro", "deletes", s2, [entry_p])
+    manifest_p.update(sequence_number=2, min_sequence_number=2)
+    list_2 = "metadata/snap-1002-1-7c1d9e2a-3b4f-4c5d-8e6f-1a2b3c4d5e6f.avro"
+    write_manifest_list(list_2, s2, s1, 2, [manifest_p, manifest_a])
+
+    # 3. flink, upsert on the identifier field `id`: (4, 'x')
+    file_b = "data/00000-0-9e8d7c6b-5a4f-4e3d-2c1b-0a9f8e7d6c5b-00001.parquet"
+    entry_b = data_file(file_b, s3, [(4, "x")])
+    file_e = "data/00000-0-9e8d7c6b-5a4f-4e3d-2c1b-0a9f8e7d6c5b-00002.parquet"
+    size = write_parquet(file_e, [("id", 1, INT32, REQUIRED, [4])], 1, {
+        "type": "struct", "schema-id": 0, "identifier-field-ids": [1],
+        "fields": [TABLE_SCHEMA["fields"][0]]})
+    entry_e = entry(s3, 2, file_e, size, 1, (
+        [(1, 4)], [(1, 1)], [(1, 0)], [(1, i32(4))], [(1, i32(4))]), equality_ids=[1])
+    manifest_b = write_manifest(
+        "metadata/9e8d7c6b-5a4f-4e3d-2c1b-0a9f8e7d6c5b-m0.avro", "data", s3, [entry_b])
+    manifest_b.update(sequence_number=3, min_sequence_number=3)
+    manifest_e = write_manifest(
+        "metadata/9e8d7c6b-5a4f-4e3d-2c1b-0a9f8e7d6c5b-m1.avro", "deletes", s3, [entry_e])
+    manifest_e.update(sequence_number=3, min_sequence_number=3)
+    list_3 = "metadata/snap-1003-1-9e8d7c6b-5a4f-4e3d-2c1b-0a9f8e7d6c5b.avro"
+    write_manifest_list(list_3, s3, s2, 3, [manifest_b, manifest_e, manifest_p, manifest_a])
+
+    snapshots = [
+        {"sequence-number": 1, "snapshot-id": s1, "timestamp-ms": ts[1], "summary": {
+            "operation": "append", "added-data-files": "1", "added-records": "4",
+            "total-records": "4", "total-data-files": "1", "total-delete-files": "0",
+            "total-position-deletes": "0", "total-equality-deletes": "0"},
+         "manifest-list": f"{LOCATION}/{list_1}", "schema-id": 0},
+        {"sequence-number": 2, "snapshot-id": s2, "parent-snapshot-id": s1,
+         "timestamp-ms": ts[2], "summary": {
+            "operation": "delete", "added-delete-files": "1", "added-position-deletes": "1",
+            "total-records": "4", "total-data-files": "1", "total-delete-files": "1",
+            "total-position-deletes": "1", "total-equality-deletes": "0"},
+         "manifest-list": f"{LOCATION}/{list_2}", "schema-id": 0},
+        {"sequence-number": 3, "snapshot-id": s3, "parent-snapshot-id": s2,
+         "timestamp-ms": ts[3], "summary": {
+            "operation": "overwrite", "flink.job-id": "8f0e4c1b2a3d4e5f6a7b8c9d0e1f2a3b",
+            "added-data-files": "1", "added-records": "1", "added-delete-files": "1",
+            "added-equality-deletes": "1", "total-records": "5", "total-data-files": "2",
+            "total-delete-files": "2", "total-position-deletes": "1",
+            "total-equality-deletes": "1"},
+         "manifest-list": f"{LOCATION}/{list_3}", "schema-id": 0},
+    ]
+    metadata_files = []
+    for version in range(4):
+        name = f"metadata/{version:05}-2d6e0f1a-{version:04}-4b5c-9d8e-7f6a5b4c3d2e.metadata.json"
+        current = snapshots[:version]
+        metadata = {
+            "format-version": 2,
+            "table-uuid": "2d6e0f1a-8c7b-4b5c-9d8e-7f6a5b4c3d2e",
+            "location": LOCATION,
+            "last-sequence-number": version,
+            "last-updated-ms": ts[version],
+            "last-column-id": 2,
+            "current-schema-id": 0,
+            "schemas": [TABLE_SCHEMA],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {
+                "write.delete.mode": "merge-on-read",
+                "write.upsert.enabled": "true",
+            },
+            "current-snapshot-id": current[-1]["snapshot-id"] if current else -1,
+            "refs": {"main": {"snapshot-id": current[-1]["snapshot-id"], "type": "branch"}}
+            if current else {},
+            "snapshots": current,
+            "statistics": [],
+            "snapshot-log": [
+                {"timestamp-ms": s["timestamp-ms"], "snapshot-id": s["snapshot-id"]}
+                for s in current],
+            "metadata-log": metadata_files[:],
+        }
+        with open(os.path.join(ROOT, name), "w") as f:
+            json.dump(metadata, f, indent=2)
+            f.write("\n")
+        metadata_files.append({"timestamp-ms": ts[version], "metadata-file": f"{LOCATION}/{name}"})
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,288 @@
+#!/usr/bin/env python3
+# Copyright 2023 Datafuse Labs.
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Generate the partitioned `db/t` iceberg table, see readme.md.
+
+The parquet and avro encoders of `../iceberg_deletes/generate.py` are reused.
+"""
+
+import copy
+import datetime
+import json
+import os
+import shutil
+import struct
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "iceberg_deletes"))
+import generate as base  # noqa: E402
+
+LOCATION = "s3://testbucket/iceberg_partitioned/db/t"
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "db", "t")
+base.ROOT = ROOT
+
+TABLE_SCHEMA = {
+    "type": "struct",
+    "schema-id": 0,
+    "fields": [
+        {"id": 1, "name": "id", "required": True, "type": "int"},
+        {"id": 2, "name": "ts", "required": False, "type": "timestamp"},
+        {"id": 3, "name": "d", "required": False, "type": "date"},
+    ],
+}
+
+# spec 1 replaces spec 0 after the first snapshot
+SPECS = [
+    {"spec-id": 0, "fields": [
+        {"name": "id_bucket", "transform": "bucket[4]", "source-id": 1, "field-id": 1000},
+        {"name": "id_trunc", "transform": "truncate[10]", "source-id": 1, "field-id": 1001},
+        {"name": "ts_day", "transform": "day", "source-id": 2, "field-id": 1002},
+        {"name": "d_year", "transform": "year", "source-id": 3, "field-id": 1003},
+    ]},
+    {"spec-id": 1, "fields": [
+        {"name": "ts_hour", "transform": "hour", "source-id": 2, "field-id": 1004},
+        {"name": "d_month", "transform": "month", "source-id": 3, "field-id": 1005},
+    ]},
+]
+
+EPOCH = datetime.datetime(1970, 1, 1)
+
+
+def murmur3_32(data):
+    def rotl(x, r):
+        return ((x << r) | (x >> (32 - r))) & 0xFFFFFFFF
+
+    def mix(k):
+        return (rotl((k * 0xCC9E2D51) & 0xFFFFFFFF, 15) * 0x1B873593) & 0xFFFFFFFF
+
+    h = 0
+    body = len(data) // 4 * 4
+    for i in range(0, body, 4):
+        h ^= mix(struct.unpack("<I", data[i:i + 4])[0])
+        h = (rotl(h, 13) * 5 + 0xE6546B64) & 0xFFFFFFFF
+    if data[body:]:
+        h ^= mix(sum(b << (8 * i) for i, b in enumerate(data[body:])))
+    h ^= len(data)
+    h ^= h >> 16
+    h = (h * 0x85EBCA6B) & 0xFFFFFFFF
+    h ^= h >> 13
+    h = (h * 0xC2B2AE35) & 0xFFFFFFFF
+    h ^= h >> 16
+    return h
+
+
+def micros(ts):
+    return (ts - EPOCH) // datetime.timedelta(microseconds=1)
+
+
+def days(d):
+    return (d - EPOCH.date()).days
+
+
+def partition(spec_id, row):
+    id_, ts, d = row
+    if spec_id == 0:
+        return {
+            "id_bucket": (murmur3_32(struct.pack("<q", id_)) & 0x7FFFFFFF) % 4,
+            "id_trunc": id_ - id_ % 10,
+            "ts_day": days(ts.date()),
+            "d_year": d.year - 1970,
+        }
+    return {
+        "ts_hour": micros(ts) // 3_600_000_000,
+        "d_month": (d.year - 1970) * 12 + d.month - 1,
+    }
+
+
+def partition_dir(spec_id, row):
+    """The hive style directory of the partition, like the one spark writes."""
+    values = partition(spec_id, row)
+    _, ts, d = row
+    human = {
+        "ts_day": ts.strftime("%Y-%m-%d"),
+        "d_year": str(d.year),
+        "ts_hour": ts.strftime("%Y-%m-%d-%H"),
+        "d_month": d.strftime("%Y-%m"),
+    }
+    return "/".join(f"{k}={human.get(k, v)}" for k, v in values.items())
+
+
+def partition_record(spec):
+    types = {"bucket[4]": "int", "truncate[10]": "int", "year": "int", "month": "int",
+             "hour": "int", "day": {"type": "int", "logicalType": "date"}}
+    return {"type": "record", "name": "r102", "fields": [
+        {"name": f["name"], "type": ["null", types[f["transform"]]], "default": None,
+         "field-id": f["field-id"]} for f in spec["fields"]]}
+
+
+def data_file(path, spec_id, snapshot_id, row):
+    """One row per file, without column metrics, so that only the partition
+    values can prune the file."""
+    id_, ts, d = row
+    os.makedirs(os.path.dirname(os.path.join(ROOT, path)), exist_ok=True)
+    size = base.write_parquet(path, [
+        ("id", 1, base.INT32, base.REQUIRED, [id_]),
+        ("ts", 2, base.INT64, base.OPTIONAL, [micros(ts)], "timestamp"),
+        ("d", 3, base.INT32, base.OPTIONAL, [days(d)], "date"),
+    ], 1, TABLE_SCHEMA)
+    return {
+        "status": 1,
+        "snapshot_id": snapshot_id,
+        "sequence_number": None,
+        "file_sequence_number": None,
+        "data_file": {
+            "content": 0,
+            "file_path": f"{LOCATION}/{path}",
+            "file_format": "PARQUET",
+            "partition": partition(spec_id, row),
+            "record_count": 1,
+            "file_size_in_bytes": size,
+            "column_sizes": [],
+            "value_counts": [],
+            "null_value_counts": [],
+            "nan_value_counts": [],
+            "lower_bounds": [],
+            "upper_bounds": [],
+            "key_metadata": None,
+            "split_offsets": [4],
+            "equality_ids": None,
+            "sort_order_id": 0,
+        },
+    }
+
+
+def write_manifest(path, spec, snapshot_id, sequence_number, entries):
+    schema = copy.deepcopy(base.MANIFEST_ENTRY_SCHEMA)
+    data_file_fields = schema["fields"][4]["type"]["fields"]
+    next(f for f in data_file_fields if f["name"] == "partition")["type"] = partition_record(spec)
+    base.write_avro(path, schema, entries, {
+        "schema": json.dumps(TABLE_SCHEMA),
+        "schema-id": "0",
+        "partition-spec": json.dumps(spec["fields"]),
+        "partition-spec-id": str(spec["spec-id"]),
+        "format-version": "2",
+        "content": "data",
+    })
+    return {
+        "manifest_path": f"{LOCATION}/{path}",
+        "manifest_length": os.path.getsize(os.path.join(ROOT, path)),
+        "partition_spec_id": spec["spec-id"],
+        "content": 0,
+        "sequence_number": sequence_number,
+        "min_sequence_number": sequence_number,
+        "added_snapshot_id": snapshot_id,
+        "added_files_count": len(entries),
+        "existing_files_count": 0,
+        "deleted_files_count": 0,
+        "added_rows_count": len(entries),
+        "existing_rows_count": 0,
+        "deleted_rows_count": 0,
+        "partitions": [],
+    }
+
+
+def main():
+    shutil.rmtree(ROOT, ignore_errors=True)
+    os.makedirs(os.path.join(ROOT, "data"))
+    os.makedirs(os.path.join(ROOT, "metadata"))
+
+    s1, s2 = 2001, 2002
+    ts = [1690000000000, 1690000001000, 1690000002000]
+    dt = datetime.datetime
+
+    # 1. spec 0: bucket[4](id), truncate[10](id), day(ts), year(d)
+    rows_1 = [
+        (1, dt(2023, 1, 1, 10), datetime.date(2021, 6, 1)),
+        (3, dt(2023, 1, 1, 11), datetime.date(2021, 7, 1)),
+        (15, dt(2023, 1, 2, 10), datetime.date(2022, 6, 1)),
+        (34, dt(2023, 1, 3, 10), datetime.date(2023, 6, 1)),
+    ]
+    uuid_1 = "3a1e5c7d-0b2f-4d6e-8a9c-1b3d5f7e9a0c"
+    entries_1 = [
+        data_file(f"data/{partition_dir(0, row)}/00000-{i}-{uuid_1}-00001.parquet",
+                  0, s1, row)
+        for i, row in enumerate(rows_1)
+    ]
+    manifest_1 = write_manifest(f"metadata/{uuid_1}-m0.avro", SPECS[0], s1, 1, entries_1)
+    list_1 = f"metadata/snap-{s1}-1-{uuid_1}.avro"
+    base.write_manifest_list(list_1, s1, None, 1, [manifest_1])
+
+    # 2. spec 1: hour(ts), month(d)
+    rows_2 = [
+        (50, dt(2023, 2, 1, 8, 30), datetime.date(2023, 7, 15)),
+        (60, dt(2023, 2, 1, 9, 30), datetime.date(2023, 8, 15)),
+    ]
+    uuid_2 = "6f8a0b2c-4d1e-4f3a-9b5c-7d9e1f3a5b7c"
+    entries_2 = [
+        data_file(f"data/{partition_dir(1, row)}/00000-{i}-{uuid_2}-00001.parquet",
+                  1, s2, row)
+        for i, row in enumerate(rows_2)
+    ]
+    manifest_2 = write_manifest(f"metadata/{uuid_2}-m0.avro", SPECS[1], s2, 2, entries_2)
+    list_2 = f"metadata/snap-{s2}-1-{uuid_2}.avro"
+    base.write_manifest_list(list_2, s2, s1, 2, [manifest_2, manifest_1])
+
+    snapshots = [
+        {"sequence-number": 1, "snapshot-id": s1, "timestamp-ms": ts[1], "summary": {
+            "operation": "append", "added-data-files": "4", "added-records": "4",
+            "total-records": "4", "total-data-files": "4", "total-delete-files": "0",
+            "total-position-deletes": "0", "total-equality-deletes": "0"},
+         "manifest-list": f"{LOCATION}/{list_1}", "schema-id": 0},
+        {"sequence-number": 2, "snapshot-id": s2, "parent-snapshot-id": s1,
+         "timestamp-ms": ts[2], "summary": {
+            "operation": "append", "added-data-files": "2", "added-records": "2",
+            "total-records": "6", "total-data-files": "6", "total-delete-files": "0",
+            "total-position-deletes": "0", "total-equality-deletes": "0"},
+         "manifest-list": f"{LOCATION}/{list_2}", "schema-id": 0},
+    ]
+    metadata_files = []
+    for version in range(3):
+        name = f"metadata/{version:05}-9c7e5a3b-{version:04}-4e2d-8f6a-0b1c2d3e4f5a.metadata.json"
+        current = snapshots[:version]
+        # the spec is evolved right before the second snapshot
+        specs = SPECS[:2 if version == 2 else 1]
+        metadata = {
+            "format-version": 2,
+            "table-uuid": "9c7e5a3b-1d2f-4e2d-8f6a-0b1c2d3e4f5a",
+            "location": LOCATION,
+            "last-sequence-number": version,
+            "last-updated-ms": ts[version],
+            "last-column-id": 3,
+            "current-schema-id": 0,
+            "schemas": [TABLE_SCHEMA],
+            "default-spec-id": specs[-1]["spec-id"],
+            "partition-specs": specs,
+            "last-partition-id": specs[-1]["fields"][-1]["field-id"],
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {"write.metadata.metrics.default": "none"},
+            "current-snapshot-id": current[-1]["snapshot-id"] if current else -1,
+            "refs": {"main": {"snapshot-id": current[-1]["snapshot-id"], "type": "branch"}}
+            if current else {},
+            "snapshots": current,
+            "statistics": [],
+            "snapshot-log": [
+                {"timestamp-ms": s["timestamp-ms"], "snapshot-id": s["snapshot-id"]}
+                for s in current],
+            "metadata-log": metadata_files[:],
+        }
+        with open(os.path.join(ROOT, name), "w") as f:
+            json.dump(metadata, f, indent=2)
+            f.write("\n")
+        metadata_files.append({"timestamp-ms": ts[version], "metadata-file": f"{LOCATION}/{name}"})
+
+
+if __name__ == "__main__":
+    main()
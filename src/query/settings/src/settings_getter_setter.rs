@@ -310,6 +310,30 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_sort_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("sort_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn set_sort_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        self.try_set_u64("sort_spilling_bytes_threshold_per_proc", value as u64)
+    }
+
+    pub fn get_join_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn set_join_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        self.try_set_u64("join_spilling_bytes_threshold_per_proc", value as u64)
+    }
+
+    pub fn get_window_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn set_window_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        self.try_set_u64("window_spilling_bytes_threshold_per_proc", value as u64)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -386,4 +410,12 @@ impl Settings {
     pub fn set_enable_aggregating_index_scan(&self, val: bool) -> Result<()> {
         self.try_set_u64("enable_aggregating_index_scan", u64::from(val))
     }
+
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn set_enable_materialized_view_rewrite(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_materialized_view_rewrite", u64::from(val))
+    }
 }
@@ -25,6 +25,7 @@ use common_catalog::plan::InternalColumn;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::StreamTableInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
@@ -155,7 +156,12 @@ impl ToReadDataSourcePlan for dyn Table {
             output_schema = Arc::new(schema);
         }
 
-        let data_mask_policy = if let DataSourceInfo::TableSource(table_info) = &source_info {
+        let data_mask_policy = if let DataSourceInfo::TableSource(table_info)
+        | DataSourceInfo::StreamSource(StreamTableInfo {
+            source_info: table_info,
+            ..
+        }) = &source_info
+        {
             let table_meta = &table_info.meta;
             let tenant = ctx.get_tenant();
 
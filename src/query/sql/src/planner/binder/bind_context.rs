@@ -156,6 +156,14 @@ pub struct BindContext {
     /// It's used to avoid infinite loop.
     pub planning_agg_index: bool,
 
+    /// If true, the query is planning for materialized view.
+    /// It's used to avoid rewriting a view query with itself.
+    pub planning_materialized_view: bool,
+
+    /// If true, the query of a materialized view is planning to rewrite the queries
+    /// on its source table. It's used to avoid resolving indexes and views recursively.
+    pub planning_materialized_view_rewrite: bool,
+
     pub window_definitions: DashMap<String, WindowSpec>,
 }
 
@@ -179,6 +187,8 @@ impl BindContext {
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: false,
+            planning_materialized_view_rewrite: false,
             window_definitions: DashMap::new(),
         }
     }
@@ -196,6 +206,9 @@ impl BindContext {
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: false,
+            // The subqueries of the view query are guarded as well.
+            planning_materialized_view_rewrite: parent.planning_materialized_view_rewrite,
             window_definitions: DashMap::new(),
         }
     }
@@ -32,6 +32,7 @@ use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::ConstantFolder;
 use common_expression::Expr;
+use common_expression::Scalar;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserDefinedFunction;
@@ -45,12 +46,15 @@ use crate::plans::CallPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateUDFPlan;
+use crate::plans::DeallocatePlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
+use crate::plans::ExecutePreparedPlan;
 use crate::plans::Plan;
+use crate::plans::PreparePlan;
 use crate::plans::RewriteKind;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
@@ -143,6 +147,39 @@ impl<'a> Binder {
         self.ctx.get_settings().set_batch_settings(&hint_settings)
     }
 
+    /// Evaluate the parameters of `EXECUTE`, which must be constant.
+    #[async_backtrace::framed]
+    async fn bind_execute_params(
+        &mut self,
+        bind_context: &mut BindContext,
+        params: &[common_ast::ast::Expr],
+    ) -> Result<Vec<Scalar>> {
+        let mut type_checker = TypeChecker::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+        );
+        let func_ctx = self.ctx.get_function_context()?;
+        let mut values = Vec::with_capacity(params.len());
+        for param in params {
+            let (scalar, _) = *type_checker.resolve(param).await?;
+            let expr = scalar.as_expr()?;
+            match ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS).0 {
+                Expr::Constant { scalar, .. } => values.push(scalar),
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "parameter of EXECUTE must be constant value",
+                    )
+                    .set_span(param.span()));
+                }
+            }
+        }
+        Ok(values)
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub(crate) async fn bind_statement(
@@ -268,10 +305,27 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Materialized views
+            Statement::CreateMaterializedView(stmt) => self.bind_create_materialized_view(stmt).await?,
+            Statement::RefreshMaterializedView(stmt) => self.bind_refresh_materialized_view(stmt).await?,
+
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
+
+            // Tasks
+            Statement::CreateTask(stmt) => self.bind_create_task(stmt).await?,
+            Statement::AlterTask(stmt) => self.bind_alter_task(stmt).await?,
+            Statement::DropTask(stmt) => self.bind_drop_task(stmt).await?,
+            Statement::ShowTasks { limit } => self.bind_show_tasks(bind_context, limit).await?,
+            Statement::ExecuteTask(stmt) => self.bind_execute_task(stmt).await?,
+
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumns(stmt) => self.bind_create_virtual_columns(stmt).await?,
@@ -357,6 +411,17 @@ impl<'a> Binder {
                 }
                 self.bind_update(bind_context, stmt).await?
             }
+            Statement::MergeInto(stmt) => {
+                if let Some(hints) = &stmt.hints {
+                    if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
+                        warn!(
+                            "In MERGE INTO resolve optimize hints {:?} failed, err: {:?}",
+                            hints, e
+                        );
+                    }
+                }
+                self.bind_merge_into(bind_context, stmt).await?
+            }
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -449,6 +514,18 @@ impl<'a> Binder {
                 args: stmt.args.clone(),
             })),
 
+            Statement::Prepare(stmt) => Plan::Prepare(Box::new(PreparePlan {
+                name: normalize_identifier(&stmt.name, &self.name_resolution_ctx).name,
+                sql: stmt.sql.clone(),
+            })),
+            Statement::Execute(stmt) => Plan::ExecutePrepared(Box::new(ExecutePreparedPlan {
+                name: normalize_identifier(&stmt.name, &self.name_resolution_ctx).name,
+                params: self.bind_execute_params(bind_context, &stmt.params).await?,
+            })),
+            Statement::Deallocate(stmt) => Plan::Deallocate(Box::new(DeallocatePlan {
+                name: normalize_identifier(&stmt.name, &self.name_resolution_ctx).name,
+            })),
+
             Statement::Presign(stmt) => self.bind_presign(bind_context, stmt).await?,
 
             Statement::SetVariable {
@@ -477,6 +554,10 @@ impl<'a> Binder {
                     .await?
             }
 
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Rollback => Plan::Rollback,
+
             // share statements
             Statement::CreateShareEndpoint(stmt) => {
                 self.bind_create_share_endpoint(stmt).await?
@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
@@ -29,6 +31,7 @@ use common_ast::walk_statement_mut;
 use common_ast::Dialect;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_meta_app::schema::GetIndexReq;
 use common_meta_app::schema::IndexNameIdent;
 
@@ -37,7 +40,9 @@ use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
 use crate::AggregatingIndexRewriter;
@@ -197,6 +202,90 @@ impl Binder {
         Ok(Plan::RefreshIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(
+                "Inverted Index only support FUSE engine",
+            ));
+        }
+        let schema = table_info.schema();
+
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = self.normalize_object_identifier(column);
+            let field = schema.field_with_name(&column)?;
+            let data_type = DataType::from(field.data_type());
+            if data_type.remove_nullable() != DataType::String {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted Index only support String column, but column {} is {}",
+                    column, data_type
+                )));
+            }
+            if column_ids.contains(&field.column_id()) {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted Index column {} is duplicated",
+                    column
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+            column_ids,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+
     fn check_index_support(query: &Query) -> Result<()> {
         let err = Err(ErrorCode::UnsupportedIndex(format!(
             "Currently create aggregating index just support simple query, like: {}",
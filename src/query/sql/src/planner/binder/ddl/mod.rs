@@ -21,6 +21,8 @@ mod index;
 mod role;
 mod share;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod view;
 mod virtual_column;
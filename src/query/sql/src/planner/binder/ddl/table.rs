@@ -67,6 +67,7 @@ use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_delta::DeltaTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use itertools::Itertools;
@@ -451,8 +452,21 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // The schema of a delta table is taken from the delta log.
+        if engine == Engine::Delta && (source.is_some() || as_query.is_some()) {
+            return Err(ErrorCode::BadArguments(
+                "Cannot specify columns or AS SELECT for DELTA table",
+            ));
+        }
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
+            (None, None) if engine == Engine::Delta => {
+                let sp = storage_params.as_ref().ok_or_else(|| {
+                    ErrorCode::BadArguments("LOCATION is required for DELTA table")
+                })?;
+                (Arc::new(DeltaTable::infer_schema(sp).await?), vec![])
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -513,61 +527,13 @@ impl Binder {
         }
 
         if engine == Engine::Fuse {
-            // Currently, [Table] can not accesses its database id yet, thus
-            // here we keep the db id AS an entry of `table_meta.options`.
-            //
-            // To make the unit/stateless test cases (`show create ..`) easier,
-            // here we care about the FUSE engine only.
-            //
-            // Later, when database id is kept, let say in `TableInfo`, we can
-            // safely eliminate this "FUSE" constant and the table meta option entry.
-            let catalog = self.ctx.get_catalog(&catalog)?;
-            let db = catalog
-                .get_database(&self.ctx.get_tenant(), &database)
-                .await?;
-            let db_id = db.get_db_info().ident.db_id;
-            options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
-
-            let config = GlobalConfig::instance();
-            let is_blocking_fs = matches!(
-                storage_params.as_ref().unwrap_or(&config.storage.params),
-                StorageParams::Fs(_)
-            );
-
-            // we should persist the storage format and compression type instead of using the default value in fuse table
-            if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
-                let default_storage_format = match config.query.default_storage_format.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "native"
-                        } else {
-                            "parquet"
-                        }
-                    }
-                    _ => config.query.default_storage_format.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_STORAGE_FORMAT.to_owned(),
-                    default_storage_format.to_owned(),
-                );
-            }
-
-            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
-                let default_compression = match config.query.default_compression.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "lz4"
-                        } else {
-                            "zstd"
-                        }
-                    }
-                    _ => config.query.default_compression.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_TABLE_COMPRESSION.to_owned(),
-                    default_compression.to_owned(),
-                );
-            }
+            self.fill_fuse_table_options(
+                &catalog,
+                &database,
+                storage_params.as_ref(),
+                &mut options,
+            )
+            .await?;
         }
 
         let cluster_key = {
@@ -1315,8 +1281,78 @@ impl Binder {
         }
     }
 
+    /// Fill the options every fuse table keeps, such as the database id,
+    /// the storage format and the compression.
+    pub(in crate::planner::binder) async fn fill_fuse_table_options(
+        &self,
+        catalog: &str,
+        database: &str,
+        storage_params: Option<&StorageParams>,
+        options: &mut BTreeMap<String, String>,
+    ) -> Result<()> {
+        // Currently, [Table] can not accesses its database id yet, thus
+        // here we keep the db id AS an entry of `table_meta.options`.
+        //
+        // To make the unit/stateless test cases (`show create ..`) easier,
+        // here we care about the FUSE engine only.
+        //
+        // Later, when database id is kept, let say in `TableInfo`, we can
+        // safely eliminate this "FUSE" constant and the table meta option entry.
+        let catalog = self.ctx.get_catalog(catalog)?;
+        let db = catalog
+            .get_database(&self.ctx.get_tenant(), database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+        options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+
+        let config = GlobalConfig::instance();
+        let is_blocking_fs = matches!(
+            storage_params.unwrap_or(&config.storage.params),
+            StorageParams::Fs(_)
+        );
+
+        // we should persist the storage format and compression type instead of using the default value in fuse table
+        if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
+            let default_storage_format = match config.query.default_storage_format.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "native"
+                    } else {
+                        "parquet"
+                    }
+                }
+                _ => config.query.default_storage_format.as_str(),
+            };
+            options.insert(
+                OPT_KEY_STORAGE_FORMAT.to_owned(),
+                default_storage_format.to_owned(),
+            );
+        }
+
+        if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
+            let default_compression = match config.query.default_compression.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "lz4"
+                    } else {
+                        "zstd"
+                    }
+                }
+                _ => config.query.default_compression.as_str(),
+            };
+            options.insert(
+                OPT_KEY_TABLE_COMPRESSION.to_owned(),
+                default_compression.to_owned(),
+            );
+        }
+
+        Ok(())
+    }
+
     /// Validate the schema of the table to be created.
-    fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
+    pub(in crate::planner::binder) fn validate_create_table_schema(
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
         // Check if there are duplicated column names
         let mut name_set = HashSet::new();
         for field in schema.fields() {
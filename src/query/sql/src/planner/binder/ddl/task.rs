@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::AlterTaskStmt;
+use common_ast::ast::CreateTaskStmt;
+use common_ast::ast::DropTaskStmt;
+use common_ast::ast::ExecuteTaskStmt;
+use common_ast::ast::ShowLimit;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterTaskPlan;
+use crate::plans::CreateTaskPlan;
+use crate::plans::DropTaskPlan;
+use crate::plans::ExecuteTaskPlan;
+use crate::plans::Plan;
+use crate::plans::RewriteKind;
+use crate::BindContext;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_task(
+        &mut self,
+        stmt: &CreateTaskStmt,
+    ) -> Result<Plan> {
+        let CreateTaskStmt {
+            if_not_exists,
+            name,
+            schedule,
+            sql,
+        } = stmt;
+
+        let plan = CreateTaskPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            task_name: self.normalize_object_identifier(name),
+            schedule: schedule.clone(),
+            // The statement is stored as text and planned again on each run.
+            sql: sql.to_string(),
+        };
+        Ok(Plan::CreateTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_task(
+        &mut self,
+        stmt: &AlterTaskStmt,
+    ) -> Result<Plan> {
+        let AlterTaskStmt {
+            if_exists,
+            name,
+            action,
+        } = stmt;
+
+        let plan = AlterTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            task_name: self.normalize_object_identifier(name),
+            action: action.clone(),
+        };
+        Ok(Plan::AlterTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_task(
+        &mut self,
+        stmt: &DropTaskStmt,
+    ) -> Result<Plan> {
+        let DropTaskStmt { if_exists, name } = stmt;
+
+        let plan = DropTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            task_name: self.normalize_object_identifier(name),
+        };
+        Ok(Plan::DropTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_execute_task(
+        &mut self,
+        stmt: &ExecuteTaskStmt,
+    ) -> Result<Plan> {
+        let plan = ExecuteTaskPlan {
+            tenant: self.ctx.get_tenant(),
+            task_name: self.normalize_object_identifier(&stmt.name),
+        };
+        Ok(Plan::ExecuteTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_tasks(
+        &mut self,
+        bind_context: &mut BindContext,
+        limit: &Option<ShowLimit>,
+    ) -> Result<Plan> {
+        // rewrite show tasks to select * from system.tasks ...
+        let query = format!(
+            "SELECT name, schedule, state, definition, owner, next_scheduled_time, last_run_time, created_on FROM system.tasks {} ORDER BY name",
+            match limit {
+                None => "".to_string(),
+                Some(ShowLimit::Like { pattern }) => format!("WHERE name LIKE '{}'", pattern),
+                Some(ShowLimit::Where { selection }) => format!("WHERE {}", selection),
+            }
+        );
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowTasks)
+            .await
+    }
+}
@@ -12,17 +12,45 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
 use common_ast::ast::AlterViewStmt;
+use common_ast::ast::CreateMaterializedViewStmt;
 use common_ast::ast::CreateViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableInfo;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::binder::Binder;
+use crate::optimizer::optimize;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::plans::RelOperator;
+use crate::BindContext;
+use crate::TableDatabaseQualifier;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -117,4 +145,176 @@ impl Binder {
         };
         Ok(Plan::DropView(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        // The view is refreshed and read in other sessions,
+        // so the tables in the query must not depend on the current database.
+        let mut query = query.clone();
+        let mut qualifier = TableDatabaseQualifier::new(self.ctx.get_current_database());
+        qualifier.visit_query(&mut query);
+
+        let mut bind_context = BindContext::new();
+        bind_context.planning_materialized_view = true;
+        let stmt = Statement::Query(query.clone());
+        let select_plan = self.bind_statement(&mut bind_context, &stmt).await?;
+        let fields = match &select_plan {
+            Plan::Query { bind_context, .. } => bind_context
+                .columns
+                .iter()
+                .map(|column_binding| {
+                    Ok(TableField::new(
+                        &column_binding.column_name,
+                        infer_schema_type(&column_binding.data_type)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => unreachable!(),
+        };
+        let schema = TableSchemaRefExt::create(fields);
+        Self::validate_create_table_schema(&schema)?;
+        let mut options = BTreeMap::new();
+        self.fill_fuse_table_options(&catalog, &database, None, &mut options)
+            .await?;
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            query.to_string(),
+        );
+        // The view is filled with the snapshot of the source table the query reads.
+        let source_table = self.materialized_view_source_table();
+        if let Some((_, source_table)) = &source_table {
+            options.insert(
+                OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID.to_string(),
+                source_table.ident.table_id.to_string(),
+            );
+            if let Some(location) = source_table.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+                options.insert(
+                    OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION.to_string(),
+                    location.clone(),
+                );
+            }
+        }
+
+        // Don't enable distributed optimization for `CREATE MATERIALIZED VIEW` for now,
+        // same as `CREATE TABLE ... AS SELECT ...`.
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig::default()));
+        let query_plan = optimize(self.ctx.clone(), opt_ctx, select_plan)?;
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+            schema,
+            options,
+            query_plan: Box::new(query_plan),
+            source_table,
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let table = self.ctx.get_table(&catalog, &database, &view_name).await?;
+        let query = match table.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+            Some(query) => query.clone(),
+            None => {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} is not a materialized view",
+                    &database, &view_name
+                )));
+            }
+        };
+
+        let tokens = tokenize_sql(&query)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        if !matches!(stmt, Statement::Query(_)) {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid materialized view object: {}.{}",
+                &database, &view_name
+            )));
+        }
+
+        let mut bind_context = BindContext::new();
+        bind_context.planning_materialized_view = true;
+        let select_plan = self.bind_statement(&mut bind_context, &stmt).await?;
+        let incremental = match &select_plan {
+            Plan::Query { s_expr, .. } => {
+                !s_expr.contain_subquery() && Self::is_append_only_plan(s_expr)
+            }
+            _ => false,
+        };
+        let source_table = self
+            .materialized_view_source_table()
+            .map(|(_, table_info)| table_info);
+
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        let query_plan = optimize(self.ctx.clone(), opt_ctx, select_plan)?;
+
+        let plan = RefreshMaterializedViewPlan {
+            catalog,
+            database,
+            view_name,
+            table_info: table.get_table_info().clone(),
+            query_plan: Box::new(query_plan),
+            source_table,
+            incremental,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
+
+    /// The database and the info of the table a materialized view is maintained from,
+    /// only the view reading from exactly one fuse table has it.
+    fn materialized_view_source_table(&self) -> Option<(String, TableInfo)> {
+        let metadata = self.metadata.read();
+        match metadata.tables() {
+            [table_entry] if table_entry.table().engine() == "FUSE" => Some((
+                table_entry.database().to_string(),
+                table_entry.table().get_table_info().clone(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Check if the result of the plan on appended rows can be appended to the
+    /// previous result, i.e. the plan only consists of projections and filters.
+    fn is_append_only_plan(s_expr: &SExpr) -> bool {
+        match s_expr.plan() {
+            RelOperator::EvalScalar(_) | RelOperator::Filter(_) => match s_expr.child(0) {
+                Ok(child) => Self::is_append_only_plan(child),
+                Err(_) => false,
+            },
+            RelOperator::Scan(_) => true,
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,336 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Scalar;
+use common_expression::TableField;
+use common_expression::ROW_ID_COL_NAME;
+
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::binder::INTERNAL_COLUMN_FACTORY;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::FunctionCall;
+use crate::plans::MatchedEvaluator;
+use crate::plans::MergeInto;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::plans::UnmatchedEvaluator;
+use crate::BindContext;
+use crate::IndexType;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table,
+            target_alias,
+            source,
+            join_expr,
+            ..
+        } = stmt;
+
+        let catalog_name = catalog
+            .as_ref()
+            .map_or_else(|| self.ctx.get_current_catalog(), |i| i.name.clone());
+        let database_name = database
+            .as_ref()
+            .map_or_else(|| self.ctx.get_current_database(), |i| i.name.clone());
+        let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+
+        let target_table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let schema = target_table.schema();
+        if schema.fields().iter().any(|f| f.computed_expr().is_some()) {
+            return Err(ErrorCode::Unimplemented(
+                "MERGE INTO is not supported for tables with computed columns",
+            ));
+        }
+
+        // Every source row is kept, the target columns are NULL if no target row matches.
+        let target = TableReference::Table {
+            span: None,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table.clone(),
+            alias: target_alias.clone(),
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        };
+        let join = TableReference::Join {
+            span: None,
+            join: Join {
+                op: JoinOperator::LeftOuter,
+                condition: JoinCondition::On(Box::new(join_expr.clone())),
+                left: Box::new(source.clone()),
+                right: Box::new(target),
+            },
+        };
+        let (input, mut context) = self.bind_table_reference(bind_context, &join).await?;
+
+        let target_index =
+            self.find_merge_target_index(&catalog_name, &database_name, &table_name)?;
+        let (input, row_id_index) = self.add_merge_row_id(input, target_index);
+
+        let (matched_clauses, unmatched_clauses) = stmt.split_clauses();
+        let target_columns = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                context
+                    .columns
+                    .iter()
+                    .find(|c| c.table_index == Some(target_index) && &c.column_name == field.name())
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Cannot find column `{}` of table `{}` in MERGE INTO",
+                            field.name(),
+                            table_name
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut scalar_binder = ScalarBinder::new(
+            &mut context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        let mut matched_evaluators = Vec::with_capacity(matched_clauses.len());
+        for clause in matched_clauses.iter() {
+            let condition = match &clause.selection {
+                Some(selection) => {
+                    let (scalar, data_type) = scalar_binder.bind(selection).await?;
+                    Some(wrap_non_null_boolean(scalar, &data_type))
+                }
+                None => None,
+            };
+
+            let update = match &clause.operation {
+                MatchOperation::Delete => None,
+                MatchOperation::Update { update_list } => {
+                    let mut update_columns = HashMap::with_capacity(update_list.len());
+                    for update_expr in update_list {
+                        let col_name =
+                            normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+                        let index = schema.index_of(&col_name)?;
+                        if update_columns.contains_key(&index) {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Multiple assignments in the single statement to column `{}`",
+                                col_name
+                            )));
+                        }
+                        let (scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
+                        if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
+                            return Err(ErrorCode::Internal(
+                                "update_list in merge into statement does not support subquery temporarily",
+                            ));
+                        }
+                        update_columns.insert(index, scalar);
+                    }
+
+                    // Columns which are not assigned keep the value of the target row.
+                    let row = target_columns
+                        .iter()
+                        .enumerate()
+                        .map(|(index, column)| {
+                            update_columns.remove(&index).unwrap_or_else(|| {
+                                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                                    span: None,
+                                    column: column.clone(),
+                                })
+                            })
+                        })
+                        .collect();
+                    Some(row)
+                }
+            };
+
+            matched_evaluators.push(MatchedEvaluator { condition, update });
+        }
+
+        let mut unmatched_evaluators = Vec::with_capacity(unmatched_clauses.len());
+        for clause in unmatched_clauses.iter() {
+            let condition = match &clause.selection {
+                Some(selection) => {
+                    let (scalar, data_type) = scalar_binder.bind(selection).await?;
+                    Some(wrap_non_null_boolean(scalar, &data_type))
+                }
+                None => None,
+            };
+
+            let insert = &clause.insert_operation;
+            let fields: Vec<&TableField> = match &insert.columns {
+                Some(columns) if !columns.is_empty() => columns
+                    .iter()
+                    .map(|ident| {
+                        let col_name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                        schema.field_with_name(&col_name)
+                    })
+                    .collect::<Result<_>>()?,
+                _ => schema.fields().iter().collect(),
+            };
+            if fields.len() != insert.values.len() {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "Table columns count is not match, expect {}, input: {}",
+                    fields.len(),
+                    insert.values.len()
+                )));
+            }
+
+            let mut values = HashMap::with_capacity(fields.len());
+            for (field, expr) in fields.iter().zip(insert.values.iter()) {
+                if values.contains_key(field.name()) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Multiple assignments in the single statement to column `{}`",
+                        field.name()
+                    )));
+                }
+                let (scalar, _) = scalar_binder.bind(expr).await?;
+                values.insert(field.name().clone(), scalar);
+            }
+
+            let mut row = Vec::with_capacity(schema.num_fields());
+            for field in schema.fields() {
+                let scalar = match values.remove(field.name()) {
+                    Some(scalar) => scalar,
+                    None => match field.default_expr() {
+                        Some(default_expr) => {
+                            let tokens = tokenize_sql(default_expr)?;
+                            let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
+                            scalar_binder.bind(&ast).await?.0
+                        }
+                        None => {
+                            let data_type = DataType::from(field.data_type());
+                            // If field data type is nullable, then we'll fill it with null.
+                            let value = if data_type.is_nullable() {
+                                Scalar::Null
+                            } else {
+                                Scalar::default_value(&data_type)
+                            };
+                            ScalarExpr::ConstantExpr(ConstantExpr { span: None, value })
+                        }
+                    },
+                };
+                row.push(scalar);
+            }
+
+            unmatched_evaluators.push(UnmatchedEvaluator {
+                condition,
+                values: row,
+            });
+        }
+
+        let plan = MergeInto {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            input: Box::new(input),
+            bind_context: Box::new(context.clone()),
+            metadata: self.metadata.clone(),
+            row_id_index,
+            matched_evaluators,
+            unmatched_evaluators,
+        };
+        Ok(Plan::MergeInto(Box::new(plan)))
+    }
+
+    // The target table is bound after the source, so it is the last matched one.
+    fn find_merge_target_index(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<IndexType> {
+        self.metadata
+            .read()
+            .tables()
+            .iter()
+            .rev()
+            .find(|t| {
+                !t.is_source_of_view()
+                    && t.catalog() == catalog
+                    && t.database() == database
+                    && t.name() == table
+            })
+            .map(|t| t.index())
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Cannot find target table `{}`.`{}` of MERGE INTO",
+                    database, table
+                ))
+            })
+    }
+
+    // Add `_row_id` of the target table into the join, it's NULL for the unmatched rows.
+    fn add_merge_row_id(&self, s_expr: SExpr, target_index: IndexType) -> (SExpr, IndexType) {
+        let internal_column = INTERNAL_COLUMN_FACTORY
+            .get_internal_column(ROW_ID_COL_NAME)
+            .unwrap();
+        let row_id_index = self
+            .metadata
+            .write()
+            .add_internal_column(target_index, internal_column);
+        let s_expr = SExpr::add_internal_column_index(&s_expr, target_index, row_id_index);
+        (s_expr, row_id_index)
+    }
+}
+
+/// Wrap the condition into `is_true(try_cast(<expr> as boolean))` if it's not a boolean.
+fn wrap_non_null_boolean(scalar: ScalarExpr, data_type: &DataType) -> ScalarExpr {
+    if data_type == &DataType::Boolean {
+        return scalar;
+    }
+    ScalarExpr::FunctionCall(FunctionCall {
+        span: scalar.span(),
+        func_name: "is_true".to_string(),
+        params: vec![],
+        arguments: vec![ScalarExpr::CastExpr(CastExpr {
+            span: scalar.span(),
+            is_try: true,
+            argument: Box::new(scalar),
+            target_type: Box::new(DataType::Boolean),
+        })],
+    })
+}
@@ -29,6 +29,7 @@ mod join;
 mod kill;
 mod limit;
 mod location;
+mod merge_into;
 mod presign;
 mod project;
 mod project_set;
@@ -222,6 +222,8 @@ pub fn wrap_cast_scalar(
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
+            | DataType::Binary
             | DataType::Bitmap
             | DataType::Variant => wrap_cast(scalar, target_type),
             DataType::String => {
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_ast::ast::Expr as AExpr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
 use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
@@ -75,8 +77,11 @@ impl BindContext {
                 }
             }
 
-            let (scalar, data_type) = scalar_binder.bind(expr).await?;
             let target_type = schema.field(i).data_type();
+            let hex_expr = hex_binary_literal(expr, target_type);
+            let (scalar, data_type) = scalar_binder
+                .bind(hex_expr.as_ref().unwrap_or(expr))
+                .await?;
             let scalar = wrap_cast_scalar(&scalar, &data_type, target_type)?;
             let expr = scalar
                 .as_expr()?
@@ -110,6 +115,28 @@ impl BindContext {
     }
 }
 
+/// String literals of BINARY columns are hex encoded, the same as the values of the input formats.
+fn hex_binary_literal(expr: &AExpr, data_type: &DataType) -> Option<AExpr> {
+    match expr {
+        AExpr::Literal {
+            span,
+            lit: Literal::String(_),
+        } if data_type.remove_nullable() == DataType::Binary => Some(AExpr::FunctionCall {
+            span: *span,
+            distinct: false,
+            name: Identifier {
+                name: "from_hex".to_string(),
+                quote: None,
+                span: *span,
+            },
+            args: vec![expr.clone()],
+            params: vec![],
+            window: None,
+        }),
+        _ => None,
+    }
+}
+
 async fn fill_default_value(
     binder: &mut ScalarBinder<'_>,
     map_exprs: &mut Vec<Expr>,
@@ -55,11 +55,15 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_license::license::Feature::AggregateIndex;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
+use common_pipeline_sources::input_formats::read_orc_schema_async;
+use common_storage::init_stage_operator;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -71,6 +75,11 @@ use common_storages_stage::StageTable;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
+use storages_common_table_meta::table::MaterializedViewRef;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::binder::copy::parse_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -90,6 +99,7 @@ use crate::BindContext;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
 use crate::IndexType;
+use crate::MaterializedViewEntry;
 use crate::TableInternalColumn;
 use crate::VirtualColumn;
 
@@ -232,6 +242,7 @@ impl Binder {
                         .get_settings()
                         .get_enable_aggregating_index_scan()?
                     && !bind_context.planning_agg_index
+                    && !bind_context.planning_materialized_view_rewrite
                     && table_meta.support_index()
                     && table_meta.engine() != "VIEW"
                 {
@@ -270,6 +281,26 @@ impl Binder {
                     }
                 }
 
+                let mut materialized_views = vec![];
+                if self
+                    .ctx
+                    .get_settings()
+                    .get_enable_materialized_view_rewrite()?
+                    && !bind_context.planning_agg_index
+                    && !bind_context.planning_materialized_view
+                    && !bind_context.planning_materialized_view_rewrite
+                    && table_meta.engine() == "FUSE"
+                {
+                    materialized_views = self
+                        .resolve_materialized_views(
+                            bind_context,
+                            tenant.as_str(),
+                            catalog.as_str(),
+                            table_meta.as_ref(),
+                        )
+                        .await?;
+                }
+
                 match table_meta.engine() {
                     "VIEW" => {
                         Self::check_view_dep(bind_context, &database, &table_name)?;
@@ -326,14 +357,21 @@ impl Binder {
                             table_meta,
                             table_alias_name,
                             bind_context.view_info.is_some(),
-                            bind_context.planning_agg_index,
+                            bind_context.planning_agg_index
+                                || bind_context.planning_materialized_view_rewrite,
                         );
 
                         if !agg_indexes.is_empty() {
                             // Should use bound table id.
                             self.metadata
                                 .write()
-                                .add_agg_indexes(full_table_name, agg_indexes);
+                                .add_agg_indexes(full_table_name.clone(), agg_indexes);
+                        }
+
+                        if !materialized_views.is_empty() {
+                            self.metadata
+                                .write()
+                                .add_materialized_views(full_table_name, materialized_views);
                         }
 
                         let (s_expr, mut bind_context) = self
@@ -547,7 +585,7 @@ impl Binder {
                 ParquetTable::create(stage_info.clone(), files_info, read_options, files_to_copy)
                     .await?
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(..) | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -561,6 +599,22 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
+            FileFormatParams::Orc(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) if !files.is_empty() => files[0].clone(),
+                    _ => files_info.first_file(&operator).await?,
+                };
+                let schema = read_orc_schema_async(&operator, &first_file.path).await?;
+                let info = StageTableInfo {
+                    schema: Arc::new(schema),
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: false,
+                };
+                StageTable::try_create(info)?
+            }
             FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
                 let max_column_position = self.metadata.read().get_max_column_position();
                 if max_column_position == 0 {
@@ -586,7 +640,7 @@ impl Binder {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(
-                    "query stage files only support parquet/NDJson/CSV/TSV format for now",
+                    "query stage files only support parquet/NDJson/Avro/ORC/CSV/TSV format for now",
                 ));
             }
         };
@@ -710,6 +764,8 @@ impl Binder {
             srfs: Default::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: false,
+            planning_materialized_view_rewrite: false,
             window_definitions: DashMap::new(),
         };
         let (s_expr, mut new_bind_context) = self
@@ -804,6 +860,27 @@ impl Binder {
             }
         }
 
+        // The change columns are bound to the streams only, `SELECT *` doesn't expand them.
+        let mut change_column_indexes = vec![];
+        for internal_column in table.table().change_columns() {
+            let column_index = self
+                .metadata
+                .write()
+                .add_internal_column(table_index, internal_column.clone());
+            bind_context.add_column_binding(ColumnBinding {
+                database_name: Some(database_name.to_string()),
+                table_name: Some(table.name().to_string()),
+                table_index: Some(table_index),
+                column_name: internal_column.column_name().clone(),
+                column_position: None,
+                index: column_index,
+                data_type: Box::new(internal_column.data_type()),
+                visibility: Visibility::InVisible,
+                virtual_computed_expr: None,
+            });
+            change_column_indexes.push(column_index);
+        }
+
         let stat = table.table().table_statistics()?;
 
         Ok((
@@ -827,6 +904,7 @@ impl Binder {
                                 column_index
                             }
                         })
+                        .chain(change_column_indexes)
                         .collect(),
                     statistics: Statistics {
                         statistics: stat,
@@ -917,6 +995,100 @@ impl Binder {
 
         Ok(index_metas)
     }
+
+    /// Bind the materialized views which are fresh with respect to `table`,
+    /// so that the optimizer can try to answer queries from them.
+    ///
+    /// The views are registered in the options of `table` when they are created.
+    #[async_backtrace::framed]
+    async fn resolve_materialized_views(
+        &mut self,
+        bind_context: &BindContext,
+        tenant: &str,
+        catalog_name: &str,
+        table: &dyn Table,
+    ) -> Result<Vec<MaterializedViewEntry>> {
+        let catalog = self.catalogs.get_catalog(catalog_name)?;
+        let table_id = table.get_id().to_string();
+        let snapshot_location = table.options().get(OPT_KEY_SNAPSHOT_LOCATION);
+
+        let mut views = vec![];
+        for view_ref in MaterializedViewRef::list(table.options()) {
+            // The view may be dropped or renamed since it's registered.
+            let view = match catalog
+                .get_table(tenant, &view_ref.database, &view_ref.name)
+                .await
+            {
+                Ok(view) if view.get_id() == view_ref.table_id => view,
+                _ => continue,
+            };
+            let options = view.options();
+            let query = match options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+                Some(query) => query.clone(),
+                None => continue,
+            };
+            // Only the views refreshed to the current snapshot of the table can be used.
+            if options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID) != Some(&table_id)
+                || options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT_LOCATION)
+                    != snapshot_location
+            {
+                continue;
+            }
+            // The query is only answered from the views the current user can read.
+            if !self.can_select(catalog_name, &view_ref.database, &view_ref.name)? {
+                continue;
+            }
+
+            let tokens = tokenize_sql(&query)?;
+            let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+            if let Statement::Query(query) = &stmt {
+                let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+                new_bind_context.planning_materialized_view_rewrite = true;
+                let (s_expr, new_bind_context) =
+                    self.bind_query(&mut new_bind_context, query).await?;
+                let output_columns = new_bind_context
+                    .columns
+                    .iter()
+                    .map(|column| column.index)
+                    .collect();
+
+                let name = format!("{catalog_name}.{}.{}", view_ref.database, view_ref.name);
+                let table_index = self.metadata.write().add_table(
+                    catalog_name.to_string(),
+                    view_ref.database,
+                    view,
+                    None,
+                    false,
+                    true,
+                );
+                views.push(MaterializedViewEntry {
+                    name,
+                    table_index,
+                    plan: s_expr,
+                    output_columns,
+                });
+            }
+        }
+
+        Ok(views)
+    }
+
+    fn can_select(&self, catalog: &str, database: &str, table: &str) -> Result<bool> {
+        let object =
+            GrantObject::Table(catalog.to_string(), database.to_string(), table.to_string());
+        let privileges = vec![UserPrivilegeType::Select];
+        if self
+            .ctx
+            .get_current_user()?
+            .grants
+            .verify_privilege(&object, privileges.clone())
+        {
+            return Ok(true);
+        }
+        Ok(self.ctx.get_current_role().map_or(false, |role| {
+            role.grants.verify_privilege(&object, privileges)
+        }))
+    }
 }
 
 // copy from common-storages-fuse to avoid cyclic dependency.
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::str::FromStr;
 
 use common_ast::parser::parse_comma_separated_idents;
@@ -144,3 +146,128 @@ impl BloomIndexColumns {
         Ok(fields_map)
     }
 }
+
+/// The default gram size of the ngram index columns.
+const DEFAULT_NGRAM_SIZE: usize = 3;
+/// Larger grams rarely help pruning, but make the filters much bigger.
+const MAX_NGRAM_SIZE: usize = 10;
+
+/// The string columns of ngram bloom index with their gram sizes, defined like `'title:3, body'`.
+#[derive(Clone, Default)]
+pub struct NgramIndexColumns(pub Vec<(String, usize)>);
+
+impl FromStr for NgramIndexColumns {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sql_dialect = Dialect::MySQL;
+        let settings = Settings::create("".to_string());
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+
+        let mut cols = vec![];
+        for definition in s.split(',') {
+            let definition = definition.trim();
+            if definition.is_empty() {
+                continue;
+            }
+            let (name, gram_size) = match definition.rsplit_once(':') {
+                Some((name, size)) => {
+                    let gram_size = size.trim().parse::<usize>().map_err(|_| {
+                        ErrorCode::TableOptionInvalid(format!(
+                            "Invalid gram size '{}' for ngram index column '{}'",
+                            size.trim(),
+                            name.trim()
+                        ))
+                    })?;
+                    (name, gram_size)
+                }
+                None => (definition, DEFAULT_NGRAM_SIZE),
+            };
+            if gram_size == 0 || gram_size > MAX_NGRAM_SIZE {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "The gram size of ngram index column '{}' must be between 1 and {}",
+                    name.trim(),
+                    MAX_NGRAM_SIZE
+                )));
+            }
+
+            let tokens = tokenize_sql(name)?;
+            let idents = parse_comma_separated_idents(&tokens, sql_dialect)?;
+            if idents.len() != 1 {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Invalid ngram index column '{}'",
+                    name.trim()
+                )));
+            }
+            let name = normalize_identifier(&idents[0], &name_resolution_ctx).name;
+            cols.push((name, gram_size));
+        }
+        Ok(NgramIndexColumns(cols))
+    }
+}
+
+impl Display for NgramIndexColumns {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let cols = self
+            .0
+            .iter()
+            .map(|(name, gram_size)| format!("{}:{}", name, gram_size))
+            .collect::<Vec<_>>();
+        write!(f, "{}", cols.join(","))
+    }
+}
+
+impl NgramIndexColumns {
+    /// Verify the definition based on schema.
+    pub fn verify_definition(definition: &str, schema: TableSchemaRef) -> Result<()> {
+        let cols = definition.parse::<NgramIndexColumns>()?;
+        for (i, (name, _)) in cols.0.iter().enumerate() {
+            let field = schema.field_with_name(name)?;
+
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "The value specified for computed column '{}' is not allowed for ngram index",
+                    name
+                )));
+            }
+
+            let data_type = field.data_type();
+            if data_type.remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Unsupported data type '{}' for ngram index",
+                    data_type
+                )));
+            }
+
+            if cols.0[..i].iter().any(|(col, _)| col == name) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Duplicated ngram index column '{}'",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the field index, table field and gram size of the ngram index columns based on schema.
+    pub fn ngram_index_fields(
+        &self,
+        schema: TableSchemaRef,
+    ) -> Result<Vec<(FieldIndex, TableField, usize)>> {
+        let source_schema = schema.remove_virtual_computed_fields();
+        let mut fields = Vec::with_capacity(self.0.len());
+        for (col, gram_size) in self.0.iter() {
+            let field_index = source_schema.index_of(col)?;
+            let field = source_schema.fields[field_index].clone();
+            let data_type = field.data_type();
+            if data_type.remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Unsupported data type for ngram index: {:?}",
+                    data_type
+                )));
+            }
+            fields.push((field_index, field, *gram_size));
+        }
+        Ok(fields)
+    }
+}
@@ -49,6 +49,9 @@ impl Plan {
             Plan::Copy(plan) => Ok(format!("{:?}", plan)),
 
             Plan::Call(plan) => Ok(format!("{:?}", plan)),
+            Plan::Prepare(plan) => Ok(format!("{:?}", plan)),
+            Plan::ExecutePrepared(plan) => Ok(format!("{:?}", plan)),
+            Plan::Deallocate(plan) => Ok(format!("{:?}", plan)),
 
             // catalog
             Plan::ShowCreateCatalog(show_create_catalog) => {
@@ -101,10 +104,26 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(format!("{:?}", create_view)),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(format!("{:?}", refresh_view)),
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
+
+            // Tasks
+            Plan::CreateTask(create_task) => Ok(format!("{:?}", create_task)),
+            Plan::AlterTask(alter_task) => Ok(format!("{:?}", alter_task)),
+            Plan::DropTask(drop_task) => Ok(format!("{:?}", drop_task)),
+            Plan::ExecuteTask(execute_task) => Ok(format!("{:?}", execute_task)),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
             Plan::RefreshIndex(index) => Ok(format!("{index:?}")),
+            Plan::CreateInvertedIndex(index) => Ok(format!("{index:?}")),
+            Plan::DropInvertedIndex(index) => Ok(format!("{index:?}")),
 
             // Virtual Columns
             Plan::CreateVirtualColumns(create_virtual_columns) => {
@@ -125,6 +144,7 @@ impl Plan {
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => format_delete(delete),
             Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
@@ -158,6 +178,9 @@ impl Plan {
             Plan::SetRole(p) => Ok(format!("{:?}", p)),
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Rollback => Ok("Rollback".to_string()),
 
             Plan::CreateShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::ShowShareEndpoint(p) => Ok(format!("{:?}", p)),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -49,6 +50,20 @@ pub type ColumnSet = HashSet<IndexType>;
 /// Callers can clone this ref safely and cheaply.
 pub type MetadataRef = Arc<RwLock<Metadata>>;
 
+/// A fresh materialized view over a table, which can be used to answer
+/// queries on that table.
+#[derive(Clone, Debug)]
+pub struct MaterializedViewEntry {
+    /// Full name of the materialized view table.
+    pub name: String,
+    /// Index of the materialized view table in the metadata.
+    pub table_index: IndexType,
+    /// The bound plan of the view query.
+    pub plan: SExpr,
+    /// Output columns of the view query, in the order of the view table columns.
+    pub output_columns: Vec<IndexType>,
+}
+
 /// Metadata stores information about columns and tables used in a query.
 /// Tables and columns are identified with its unique index.
 /// Notice that index value of a column can be same with that of a table.
@@ -59,7 +74,14 @@ pub struct Metadata {
     //// Columns that are lazy materialized.
     lazy_columns: HashSet<usize>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    materialized_views: HashMap<String, Vec<MaterializedViewEntry>>,
     max_column_position: usize, // for CSV
+    /// Inferred types of the `?` placeholders of a prepared statement, keyed by
+    /// their offsets in the SQL text. `None` if placeholders are not allowed.
+    placeholder_types: Option<BTreeMap<usize, DataType>>,
+    /// Whether the placeholders are bound as parameters of the types in `placeholder_types`,
+    /// otherwise they are bound as `NULL` to infer their types.
+    typed_placeholders: bool,
 }
 
 impl Metadata {
@@ -231,6 +253,44 @@ impl Metadata {
         column_index
     }
 
+    pub fn allow_placeholders(&mut self) {
+        self.placeholder_types.get_or_insert_with(BTreeMap::new);
+    }
+
+    pub fn placeholders_allowed(&self) -> bool {
+        self.placeholder_types.is_some()
+    }
+
+    /// Record the type of the placeholder at `offset`, the first inferred type wins.
+    pub fn set_placeholder_type(&mut self, offset: usize, data_type: DataType) {
+        if let Some(types) = self.placeholder_types.as_mut() {
+            types.entry(offset).or_insert(data_type);
+        }
+    }
+
+    pub fn placeholder_type(&self, offset: usize) -> Option<&DataType> {
+        self.placeholder_types
+            .as_ref()
+            .and_then(|types| types.get(&offset))
+    }
+
+    /// Bind the placeholders as parameters of the inferred `types`, whose values are
+    /// substituted in the bound plan on each execution.
+    pub fn bind_typed_placeholders(&mut self, types: BTreeMap<usize, DataType>) {
+        self.placeholder_types = Some(types);
+        self.typed_placeholders = true;
+    }
+
+    /// The type of the parameter bound to the placeholder at `offset`, if the
+    /// placeholders are bound as typed parameters.
+    pub fn placeholder_param_type(&self, offset: usize) -> Option<&DataType> {
+        if self.typed_placeholders {
+            self.placeholder_type(offset)
+        } else {
+            None
+        }
+    }
+
     pub fn add_agg_indexes(&mut self, table: String, agg_indexes: Vec<(u64, String, SExpr)>) {
         self.agg_indexes
             .entry(table)
@@ -242,6 +302,17 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_materialized_views(&mut self, table: String, views: Vec<MaterializedViewEntry>) {
+        self.materialized_views
+            .entry(table)
+            .and_modify(|entries| entries.extend_from_slice(&views))
+            .or_insert(views);
+    }
+
+    pub fn get_materialized_views(&self, table: &str) -> Option<&[MaterializedViewEntry]> {
+        self.materialized_views.get(table).map(|v| v.as_slice())
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
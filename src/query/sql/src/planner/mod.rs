@@ -35,10 +35,13 @@ pub use binder::ScalarVisitor;
 pub use binder::SelectBuilder;
 pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
+pub use bloom_index::NgramIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
 pub use metadata::*;
+pub use planner::BoundPlan;
 pub use planner::PlanExtras;
 pub use planner::Planner;
+pub use planner::PreparedStatement;
 pub use plans::ScalarExpr;
 pub use semantic::*;
@@ -0,0 +1,469 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Rewrite a query on a table to read one of the fresh materialized views of the table.
+///
+/// Only the views whose query is in the form of `SELECT <exprs> FROM t [WHERE <preds>]`
+/// can be used, and every predicate of the view must appear in the query.
+pub struct RuleTryApplyMaterializedView {
+    id: RuleID,
+    metadata: MetadataRef,
+
+    patterns: Vec<SExpr>,
+}
+
+impl RuleTryApplyMaterializedView {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyMaterializedView,
+            metadata,
+            patterns: vec![
+                // Expression
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                ),
+                // Expression
+                //     |
+                //   Filter
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Filter,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_leaf(Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Scan,
+                            }
+                            .into(),
+                        ))),
+                    )),
+                ),
+            ],
+        }
+    }
+}
+
+impl Rule for RuleTryApplyMaterializedView {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let metadata = self.metadata.read();
+        let query_info = match PlanInformation::collect(s_expr) {
+            Some(info) => info,
+            None => return Ok(()),
+        };
+
+        let table = metadata.table(query_info.scan.table_index);
+        let table_name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+        let views = match metadata.get_materialized_views(&table_name) {
+            Some(views) if !views.is_empty() => views,
+            _ => return Ok(()),
+        };
+
+        // Search all the views, use the first matched one to rewrite the query.
+        for view in views.iter() {
+            if let Some(mut result) = try_rewrite(&metadata, &query_info, view)? {
+                result.set_applied_rule(&self.id);
+                state.add_result(result);
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The information of a plan in the form of `EvalScalar* -> Filter* -> Scan`.
+struct PlanInformation<'a> {
+    scan: &'a Scan,
+    /// Items of the top `EvalScalar`.
+    items: &'a [ScalarItem],
+    /// Scalars of the derived columns, used to format the columns referring to them.
+    derived_columns: HashMap<IndexType, &'a ScalarExpr>,
+    predicates: Vec<&'a ScalarExpr>,
+}
+
+impl<'a> PlanInformation<'a> {
+    fn collect(s_expr: &'a SExpr) -> Option<Self> {
+        let mut info = PlanInformation {
+            scan: Self::find_scan(s_expr)?,
+            items: &[],
+            derived_columns: HashMap::new(),
+            predicates: vec![],
+        };
+
+        let mut s_expr = s_expr;
+        let mut is_top = true;
+        loop {
+            match s_expr.plan() {
+                RelOperator::EvalScalar(eval) => {
+                    if is_top {
+                        info.items = &eval.items;
+                    }
+                    for item in eval.items.iter() {
+                        match &item.scalar {
+                            ScalarExpr::BoundColumnRef(col) if col.column.index == item.index => {}
+                            scalar => {
+                                info.derived_columns.insert(item.index, scalar);
+                            }
+                        }
+                    }
+                }
+                RelOperator::Filter(filter) => {
+                    info.predicates.extend(filter.predicates.iter());
+                }
+                RelOperator::Scan(scan) => {
+                    if let Some(prewhere) = &scan.prewhere {
+                        info.predicates.extend(prewhere.predicates.iter());
+                    }
+                    return Some(info);
+                }
+                _ => return None,
+            }
+            is_top = false;
+            s_expr = s_expr.child(0).ok()?;
+        }
+    }
+
+    fn find_scan(s_expr: &'a SExpr) -> Option<&'a Scan> {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => Some(scan),
+            _ if s_expr.arity() == 1 => Self::find_scan(s_expr.child(0).ok()?),
+            _ => None,
+        }
+    }
+
+    /// Format the scalar with the names of base table columns,
+    /// so that the scalars of different bindings of the same table can be compared.
+    /// Returns [None] if the scalar can not be compared.
+    fn format_scalar(&self, metadata: &Metadata, scalar: &ScalarExpr) -> Option<String> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(col) => self.format_column(metadata, col.column.index),
+            ScalarExpr::ConstantExpr(val) => Some(format!("{}", val.value)),
+            ScalarExpr::FunctionCall(func) => Some(format!(
+                "{}({})",
+                &func.func_name,
+                func.arguments
+                    .iter()
+                    .map(|arg| self.format_scalar(metadata, arg))
+                    .collect::<Option<Vec<_>>>()?
+                    .join(", ")
+            )),
+            ScalarExpr::CastExpr(cast) => Some(format!(
+                "{}({} AS {})",
+                if cast.is_try { "TRY_CAST" } else { "CAST" },
+                self.format_scalar(metadata, &cast.argument)?,
+                cast.target_type
+            )),
+            _ => None,
+        }
+    }
+
+    fn format_column(&self, metadata: &Metadata, index: IndexType) -> Option<String> {
+        if let Some(scalar) = self.derived_columns.get(&index) {
+            return self.format_scalar(metadata, scalar);
+        }
+        match metadata.column(index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => {
+                Some(format!("#{column_name}"))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The formatted output scalars of a view, mapped to the columns of the view table.
+type ViewSelection = HashMap<String, ColumnBinding>;
+
+fn try_rewrite(
+    metadata: &Metadata,
+    query_info: &PlanInformation<'_>,
+    view: &MaterializedViewEntry,
+) -> Result<Option<SExpr>> {
+    let view_info = match PlanInformation::collect(&view.plan) {
+        Some(info) => info,
+        None => return Ok(None),
+    };
+
+    // 1. Map the output of the view query to the columns of the view table.
+    let view_columns = metadata
+        .columns_by_table_index(view.table_index)
+        .into_iter()
+        .filter_map(|col| match col {
+            ColumnEntry::BaseTableColumn(col) if col.path_indices.is_none() => Some(col),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if view_columns.len() != view.output_columns.len() {
+        return Ok(None);
+    }
+    let mut selection = ViewSelection::with_capacity(view_columns.len());
+    for (output, col) in view.output_columns.iter().zip(view_columns.iter()) {
+        if let Some(key) = view_info.format_column(metadata, *output) {
+            selection.entry(key).or_insert_with(|| ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: col.column_position,
+                table_index: Some(col.table_index),
+                column_name: col.column_name.clone(),
+                index: col.column_index,
+                data_type: Box::new(DataType::from(&col.data_type)),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+        }
+    }
+
+    // 2. Every predicate of the view must appear in the query,
+    // the rest predicates of the query will be evaluated on the view.
+    let mut query_predicates = Vec::with_capacity(query_info.predicates.len());
+    for pred in query_info.predicates.iter() {
+        match query_info.format_scalar(metadata, pred) {
+            Some(key) => query_predicates.push((key, *pred)),
+            None => return Ok(None),
+        }
+    }
+    let mut view_predicates = HashSet::with_capacity(view_info.predicates.len());
+    for pred in view_info.predicates.iter() {
+        match view_info.format_scalar(metadata, pred) {
+            Some(key) if query_predicates.iter().any(|(k, _)| k == &key) => {
+                view_predicates.insert(key);
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    let mut used_columns = ColumnSet::new();
+    let mut new_predicates = vec![];
+    for (key, pred) in query_predicates.iter() {
+        if view_predicates.contains(key) {
+            continue;
+        }
+        match rewrite_scalar(metadata, query_info, &selection, pred)? {
+            Some(new_pred) => {
+                used_columns.extend(pred.used_columns());
+                new_predicates.push(new_pred);
+            }
+            None => return Ok(None),
+        }
+    }
+
+    // 3. Rewrite the query items with the view columns.
+    let mut new_items = Vec::with_capacity(query_info.items.len());
+    for item in query_info.items.iter() {
+        match rewrite_scalar(metadata, query_info, &selection, &item.scalar)? {
+            Some(scalar) => {
+                used_columns.extend(item.scalar.used_columns());
+                new_items.push(ScalarItem {
+                    index: item.index,
+                    scalar,
+                });
+            }
+            None => return Ok(None),
+        }
+    }
+
+    // 4. The columns of the table may be required by the parent operators,
+    // keep them if they are available in the view.
+    for index in query_info.scan.columns.iter() {
+        if new_items.iter().any(|item| item.index == *index) {
+            continue;
+        }
+        let column = query_info
+            .format_column(metadata, *index)
+            .and_then(|key| selection.get(&key));
+        match column {
+            Some(column) => {
+                let data_type = metadata.column(*index).data_type();
+                new_items.push(ScalarItem {
+                    index: *index,
+                    scalar: view_column(column, &data_type),
+                });
+            }
+            None if used_columns.contains(index) => {}
+            None => return Ok(None),
+        }
+    }
+
+    let mut view_used_columns = ColumnSet::new();
+    for item in new_items.iter() {
+        view_used_columns.extend(item.scalar.used_columns());
+    }
+    for pred in new_predicates.iter() {
+        view_used_columns.extend(pred.used_columns());
+    }
+
+    let mut result = SExpr::create_leaf(Arc::new(
+        Scan {
+            table_index: view.table_index,
+            columns: view_used_columns,
+            ..Default::default()
+        }
+        .into(),
+    ));
+    if !new_predicates.is_empty() {
+        result = SExpr::create_unary(
+            Arc::new(
+                Filter {
+                    predicates: new_predicates,
+                    is_having: false,
+                }
+                .into(),
+            ),
+            Arc::new(result),
+        );
+    }
+    new_items.sort_by_key(|item| item.index);
+    result = SExpr::create_unary(
+        Arc::new(EvalScalar { items: new_items }.into()),
+        Arc::new(result),
+    );
+
+    Ok(Some(result))
+}
+
+/// Rewrite the scalar of the query to be evaluated on the view columns.
+/// Returns [None] if the scalar can not be computed from the view.
+fn rewrite_scalar(
+    metadata: &Metadata,
+    query_info: &PlanInformation<'_>,
+    selection: &ViewSelection,
+    scalar: &ScalarExpr,
+) -> Result<Option<ScalarExpr>> {
+    if let Some(column) = query_info
+        .format_scalar(metadata, scalar)
+        .and_then(|key| selection.get(&key))
+    {
+        return Ok(Some(view_column(column, &scalar.data_type()?)));
+    }
+
+    Ok(match scalar {
+        ScalarExpr::ConstantExpr(_) => Some(scalar.clone()),
+        ScalarExpr::CastExpr(cast) => {
+            match rewrite_scalar(metadata, query_info, selection, &cast.argument)? {
+                Some(argument) => Some(
+                    CastExpr {
+                        span: cast.span,
+                        is_try: cast.is_try,
+                        argument: Box::new(argument),
+                        target_type: cast.target_type.clone(),
+                    }
+                    .into(),
+                ),
+                None => None,
+            }
+        }
+        ScalarExpr::FunctionCall(func) => {
+            let mut arguments = Vec::with_capacity(func.arguments.len());
+            for arg in func.arguments.iter() {
+                match rewrite_scalar(metadata, query_info, selection, arg)? {
+                    Some(arg) => arguments.push(arg),
+                    None => return Ok(None),
+                }
+            }
+            Some(
+                FunctionCall {
+                    span: func.span,
+                    func_name: func.func_name.clone(),
+                    params: func.params.clone(),
+                    arguments,
+                }
+                .into(),
+            )
+        }
+        _ => None,
+    })
+}
+
+/// Reference to the view column, casted to the data type the query expects.
+fn view_column(column: &ColumnBinding, data_type: &DataType) -> ScalarExpr {
+    let column_ref: ScalarExpr = BoundColumnRef {
+        span: None,
+        column: column.clone(),
+    }
+    .into();
+    if column.data_type.as_ref() == data_type {
+        column_ref
+    } else {
+        CastExpr {
+            span: None,
+            is_try: false,
+            argument: Box::new(column_ref),
+            target_type: Box::new(data_type.clone()),
+        }
+        .into()
+    }
+}
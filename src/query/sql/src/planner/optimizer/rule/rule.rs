@@ -65,6 +65,7 @@ pub enum RuleID {
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    TryApplyMaterializedView,
     CommuteJoin,
 
     // Exploration rules
@@ -105,6 +106,7 @@ impl Display for RuleID {
             RuleID::LeftExchangeJoin => write!(f, "LeftExchangeJoin"),
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::TryApplyMaterializedView => write!(f, "TryApplyMaterializedView"),
         }
     }
 }
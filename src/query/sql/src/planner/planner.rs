@@ -12,24 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
 use common_ast::ast::Statement;
+use common_ast::ast::UnaryOperator;
 use common_ast::parser::parse_sql;
 use common_ast::parser::token::Token;
 use common_ast::parser::token::TokenKind;
 use common_ast::parser::token::Tokenizer;
+use common_ast::parser::tokenize_sql;
 use common_ast::walk_statement_mut;
 use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use parking_lot::RwLock;
 
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use super::semantic::PlaceholderBinder;
+use super::semantic::PlaceholderRewriter;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
@@ -55,6 +67,33 @@ pub struct PlanExtras {
     pub statement: Statement,
 }
 
+/// A statement parsed and bound by [`Planner::prepare_sql`], whose `?` placeholders
+/// are bound to parameters by [`Planner::plan_prepared`] on each execution.
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    pub sql: String,
+    pub statement: Statement,
+    /// Offsets of the placeholders in the SQL text, in the order of parameters.
+    pub placeholders: Vec<usize>,
+    /// Inferred parameter types, `String` for the ones that can't be inferred.
+    pub param_types: Vec<DataType>,
+    /// Schema of the result set, empty if the statement doesn't return one.
+    pub schema: DataSchemaRef,
+    /// The plan bound with the placeholders as typed parameters, shared by the clones of
+    /// the statement. `None` if the parameters are bound on each execution.
+    pub bound_plan: Option<Arc<RwLock<BoundPlan>>>,
+}
+
+/// A query bound with the placeholders as typed parameters, see [`Planner::plan_prepared`].
+#[derive(Debug)]
+pub struct BoundPlan {
+    plan: Plan,
+    /// The current database when the plan is bound.
+    database: String,
+    /// Whether the result of the query can be cached, see [`TableContext::get_cacheable`].
+    cacheable: bool,
+}
+
 impl Planner {
     pub fn new(ctx: Arc<dyn TableContext>) -> Self {
         Planner { ctx }
@@ -96,21 +135,10 @@ impl Planner {
 
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
                 let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-                let plan = binder.bind(&stmt).await?;
+                let plan = self.bind_stmt(&stmt, metadata.clone()).await?;
 
                 // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
-                    enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
-                }));
-
-                let optimized_plan = optimize(self.ctx.clone(), opt_ctx, plan)?;
+                let optimized_plan = self.optimize_plan(plan)?;
                 Ok((optimized_plan, PlanExtras {
                     metadata,
                     format,
@@ -159,6 +187,294 @@ impl Planner {
         }
     }
 
+    /// Parse and bind a statement with `?` placeholders, inferring the parameter types
+    /// from where the placeholders are used.
+    #[async_backtrace::framed]
+    pub async fn prepare_sql(&mut self, sql: &str) -> Result<PreparedStatement> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(sql)?;
+        let (mut stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        self.replace_stmt(&mut stmt, sql_dialect);
+
+        let mut rewriter = PlaceholderRewriter::new(sql, HashMap::new());
+        walk_statement_mut(&mut rewriter, &mut stmt);
+        let placeholders = rewriter.placeholders();
+
+        let mut metadata = Metadata::default();
+        metadata.allow_placeholders();
+        let metadata = Arc::new(RwLock::new(metadata));
+        let plan = self.bind_stmt(&stmt, metadata.clone()).await?;
+        let mut schema = if plan.has_result_set() {
+            plan.schema()
+        } else {
+            Arc::new(DataSchema::empty())
+        };
+
+        let param_types = {
+            let metadata = metadata.read();
+            placeholders
+                .iter()
+                .map(|offset| {
+                    metadata
+                        .placeholder_type(*offset)
+                        .cloned()
+                        .unwrap_or(DataType::String)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Queries are bound again with the placeholders as typed parameters, so the bound
+        // plan is reused by the executions. Placeholders the plan can't keep as parameters,
+        // e.g. in `LIMIT`, are substituted into the statement and bound on each execution.
+        let mut bound_plan = None;
+        if matches!(plan, Plan::Query { .. }) {
+            if let Ok(Some(plan)) = self
+                .bind_typed_plan(&stmt, &placeholders, &param_types)
+                .await
+            {
+                schema = plan.plan.schema();
+                bound_plan = Some(Arc::new(RwLock::new(plan)));
+            }
+        }
+
+        Ok(PreparedStatement {
+            sql: sql.to_string(),
+            statement: stmt,
+            placeholders,
+            param_types,
+            schema,
+            bound_plan,
+        })
+    }
+
+    /// Bind the parameters to a prepared statement and plan it.
+    ///
+    /// The parameters are substituted into the bound plan of the statement if it has one,
+    /// which is bound again if the tables it reads have changed since. Otherwise they are
+    /// substituted into the statement before binding.
+    #[async_backtrace::framed]
+    pub async fn plan_prepared(
+        &mut self,
+        prepared: &PreparedStatement,
+        params: Vec<Scalar>,
+    ) -> Result<(Plan, PlanExtras)> {
+        if params.len() != prepared.placeholders.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                prepared.placeholders.len(),
+                params.len()
+            )));
+        }
+
+        let bound_plan = match &prepared.bound_plan {
+            Some(bound_plan) => bound_plan,
+            None => return self.plan_prepared_stmt(prepared, params).await,
+        };
+        if !self.is_bound_plan_valid(bound_plan).await? {
+            match self
+                .bind_typed_plan(
+                    &prepared.statement,
+                    &prepared.placeholders,
+                    &prepared.param_types,
+                )
+                .await?
+            {
+                Some(plan) => *bound_plan.write() = plan,
+                None => return self.plan_prepared_stmt(prepared, params).await,
+            }
+        }
+
+        let (plan, cacheable) = {
+            let bound_plan = bound_plan.read();
+            (bound_plan.plan.clone(), bound_plan.cacheable)
+        };
+        match plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                rewrite_kind,
+                formatted_ast,
+                ignore_result,
+            } => {
+                let values = prepared
+                    .placeholders
+                    .iter()
+                    .cloned()
+                    .zip(params.iter().cloned())
+                    .collect::<HashMap<_, _>>();
+                let s_expr = PlaceholderBinder::new(&values).bind_s_expr(&s_expr);
+                // The optimizer may add columns to the metadata, which is shared by the
+                // executions otherwise.
+                let metadata = Arc::new(RwLock::new(metadata.read().clone()));
+                if !cacheable {
+                    self.ctx.set_cacheable(false);
+                }
+
+                let plan = Plan::Query {
+                    s_expr: Box::new(s_expr),
+                    metadata: metadata.clone(),
+                    bind_context,
+                    rewrite_kind,
+                    // The parameters are part of the key of the query result cache.
+                    formatted_ast: formatted_ast.map(|ast| format!("{ast} -- {params:?}")),
+                    ignore_result,
+                };
+                let optimized_plan = self.optimize_plan(plan)?;
+                Ok((optimized_plan, PlanExtras {
+                    metadata,
+                    format: None,
+                    statement: prepared.statement.clone(),
+                }))
+            }
+            _ => Err(ErrorCode::Internal(
+                "Bound plan of prepared statement must be a query",
+            )),
+        }
+    }
+
+    /// Substitute the parameters into the prepared statement and bind it, so they take
+    /// part in the binding like literals.
+    #[async_backtrace::framed]
+    async fn plan_prepared_stmt(
+        &self,
+        prepared: &PreparedStatement,
+        params: Vec<Scalar>,
+    ) -> Result<(Plan, PlanExtras)> {
+        let values = prepared
+            .placeholders
+            .iter()
+            .cloned()
+            .zip(params.iter().map(param_to_literal))
+            .map(|(offset, param)| Ok((offset, param?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+        let mut stmt = prepared.statement.clone();
+        walk_statement_mut(
+            &mut PlaceholderRewriter::new(&prepared.sql, values),
+            &mut stmt,
+        );
+
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let plan = self.bind_stmt(&stmt, metadata.clone()).await?;
+        let optimized_plan = self.optimize_plan(plan)?;
+        Ok((optimized_plan, PlanExtras {
+            metadata,
+            format: None,
+            statement: stmt,
+        }))
+    }
+
+    /// Bind a query with the placeholders as parameters of `param_types`. Returns `None`
+    /// if the bound plan doesn't keep all the placeholders as parameters, or reads a view
+    /// whose definition may change without the tables it reads changing.
+    #[async_backtrace::framed]
+    async fn bind_typed_plan(
+        &self,
+        stmt: &Statement,
+        placeholders: &[usize],
+        param_types: &[DataType],
+    ) -> Result<Option<BoundPlan>> {
+        let mut metadata = Metadata::default();
+        metadata.allow_placeholders();
+        metadata.bind_typed_placeholders(
+            placeholders
+                .iter()
+                .cloned()
+                .zip(param_types.iter().cloned())
+                .collect(),
+        );
+        let plan = self
+            .bind_stmt(stmt, Arc::new(RwLock::new(metadata)))
+            .await?;
+
+        let reusable = match &plan {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => {
+                let values = placeholders
+                    .iter()
+                    .map(|offset| (*offset, Scalar::Null))
+                    .collect::<HashMap<_, _>>();
+                let mut binder = PlaceholderBinder::new(&values);
+                binder.bind_s_expr(s_expr);
+                binder.placeholders().len() == placeholders.len()
+                    && !metadata
+                        .read()
+                        .tables()
+                        .iter()
+                        .any(|table| table.is_source_of_view())
+            }
+            _ => false,
+        };
+        if !reusable {
+            return Ok(None);
+        }
+
+        Ok(Some(BoundPlan {
+            plan,
+            database: self.ctx.get_current_database(),
+            cacheable: self.ctx.get_cacheable(),
+        }))
+    }
+
+    /// Whether the bound plan still reads the same versions of the tables, its table
+    /// statistics are used by the optimizer, e.g. to fold `COUNT(*)`.
+    #[async_backtrace::framed]
+    async fn is_bound_plan_valid(&self, bound_plan: &RwLock<BoundPlan>) -> Result<bool> {
+        let (database, tables) = {
+            let bound_plan = bound_plan.read();
+            let tables = match &bound_plan.plan {
+                Plan::Query { metadata, .. } => metadata
+                    .read()
+                    .tables()
+                    .iter()
+                    .map(|table| {
+                        (
+                            table.catalog().to_string(),
+                            table.database().to_string(),
+                            table.name().to_string(),
+                            table.table().get_table_info().ident,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+                _ => vec![],
+            };
+            (bound_plan.database.clone(), tables)
+        };
+
+        // Unqualified table names are resolved in the current database.
+        if database != self.ctx.get_current_database() {
+            return Ok(false);
+        }
+        for (catalog, database, name, ident) in tables {
+            match self.ctx.get_table(&catalog, &database, &name).await {
+                Ok(table) if table.get_table_info().ident == ident => {}
+                _ => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+
+    #[async_backtrace::framed]
+    async fn bind_stmt(&self, stmt: &Statement, metadata: MetadataRef) -> Result<Plan> {
+        let settings = self.ctx.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata,
+        );
+        binder.bind(stmt).await
+    }
+
+    fn optimize_plan(&self, plan: Plan) -> Result<Plan> {
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        optimize(self.ctx.clone(), opt_ctx, plan)
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -182,3 +498,69 @@ impl Planner {
         self.add_max_rows_limit(stmt);
     }
 }
+
+/// Convert a parameter of a prepared statement to a literal to substitute the placeholder.
+fn param_to_literal(param: &Scalar) -> Result<Expr> {
+    let lit = match param {
+        Scalar::Null => Literal::Null,
+        Scalar::Boolean(v) => Literal::Boolean(*v),
+        Scalar::Number(number) => match number {
+            NumberScalar::UInt8(v) => Literal::UInt64(*v as u64),
+            NumberScalar::UInt16(v) => Literal::UInt64(*v as u64),
+            NumberScalar::UInt32(v) => Literal::UInt64(*v as u64),
+            NumberScalar::UInt64(v) => Literal::UInt64(*v),
+            NumberScalar::Int8(v) => return Ok(int_literal(*v as i64)),
+            NumberScalar::Int16(v) => return Ok(int_literal(*v as i64)),
+            NumberScalar::Int32(v) => return Ok(int_literal(*v as i64)),
+            NumberScalar::Int64(v) => return Ok(int_literal(*v)),
+            NumberScalar::Float32(v) => Literal::Float64(f64::from(v.0)),
+            NumberScalar::Float64(v) => Literal::Float64(v.0),
+        },
+        Scalar::String(bytes) => match std::str::from_utf8(bytes) {
+            Ok(s) => Literal::String(s.to_string()),
+            Err(_) => return Ok(from_hex(bytes)),
+        },
+        Scalar::Binary(bytes) => return Ok(from_hex(bytes)),
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unsupported parameter of prepared statement: {param}"
+            )));
+        }
+    };
+    Ok(Expr::Literal { span: None, lit })
+}
+
+fn int_literal(value: i64) -> Expr {
+    let lit = Expr::Literal {
+        span: None,
+        lit: Literal::UInt64(value.unsigned_abs()),
+    };
+    if value < 0 {
+        Expr::UnaryOp {
+            span: None,
+            op: UnaryOperator::Minus,
+            expr: Box::new(lit),
+        }
+    } else {
+        lit
+    }
+}
+
+/// `from_hex('<hex>')`, to substitute bytes that are not valid UTF-8 losslessly.
+fn from_hex(bytes: &[u8]) -> Expr {
+    Expr::FunctionCall {
+        span: None,
+        distinct: false,
+        name: Identifier {
+            name: "from_hex".to_string(),
+            quote: None,
+            span: None,
+        },
+        args: vec![Expr::Literal {
+            span: None,
+            lit: Literal::String(hex::encode(bytes)),
+        }],
+        params: vec![],
+        window: None,
+    }
+}
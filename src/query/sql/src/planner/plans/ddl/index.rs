@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_ast::ast::TableIndexType;
+use common_expression::ColumnId;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::TableInfo;
 use common_meta_types::MetaId;
@@ -47,3 +48,22 @@ pub struct RefreshIndexPlan {
     pub metadata: MetadataRef,
     pub user_defined_block_name: bool,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateInvertedIndexPlan {
+    pub if_not_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub column_ids: Vec<ColumnId>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropInvertedIndexPlan {
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
@@ -18,7 +18,9 @@ mod database;
 mod file_format;
 mod index;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod udf;
 mod view;
 mod virtual_column;
@@ -29,7 +31,9 @@ pub use database::*;
 pub use file_format::*;
 pub use index::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use udf::*;
 pub use view::*;
 pub use virtual_column::*;
@@ -12,6 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use common_expression::TableSchemaRef;
+use common_meta_app::schema::TableInfo;
+
+use crate::plans::Plan;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
@@ -41,3 +48,33 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: TableSchemaRef,
+    /// Options of the fuse table backing the view, including the view query
+    /// and the snapshot of the source table the query reads.
+    pub options: BTreeMap<String, String>,
+    pub query_plan: Box<Plan>,
+    /// The database and the info of the table the view is refreshed from,
+    /// the view is registered in the options of the table.
+    pub source_table: Option<(String, TableInfo)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshMaterializedViewPlan {
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub table_info: TableInfo,
+    pub query_plan: Box<Plan>,
+    pub source_table: Option<TableInfo>,
+    /// Whether the view can be refreshed by only processing
+    /// the blocks appended to the source table.
+    pub incremental: bool,
+}
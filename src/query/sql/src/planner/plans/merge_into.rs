@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::ROW_ID_COL_NAME;
+
+use crate::optimizer::SExpr;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::ColumnBinding;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::Visibility;
+
+#[derive(Clone, Debug)]
+pub struct MatchedEvaluator {
+    pub condition: Option<ScalarExpr>,
+    // `None` means delete the matched row, otherwise the full row of the target table.
+    pub update: Option<Vec<ScalarExpr>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnmatchedEvaluator {
+    pub condition: Option<ScalarExpr>,
+    // The full row of the target table, one expression per field.
+    pub values: Vec<ScalarExpr>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MergeInto {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    // source LEFT OUTER JOIN target
+    pub input: Box<SExpr>,
+    pub bind_context: Box<BindContext>,
+    pub metadata: MetadataRef,
+    // `_row_id`'s index of the target table, NULL if the source row is not matched
+    pub row_id_index: IndexType,
+    pub matched_evaluators: Vec<MatchedEvaluator>,
+    pub unmatched_evaluators: Vec<UnmatchedEvaluator>,
+}
+
+impl MergeInto {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+
+    pub fn row_id_binding(&self) -> ColumnBinding {
+        ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_position: None,
+            table_index: None,
+            column_name: ROW_ID_COL_NAME.to_string(),
+            index: self.row_id_index,
+            data_type: Box::new(DataType::Nullable(Box::new(DataType::Number(
+                NumberDataType::UInt64,
+            )))),
+            visibility: Visibility::Visible,
+            virtual_computed_expr: None,
+        }
+    }
+}
@@ -26,10 +26,12 @@ use common_expression::DataSchemaRefExt;
 use super::data_mask::CreateDatamaskPolicyPlan;
 use super::CopyIntoTableMode;
 use super::CreateIndexPlan;
+use super::CreateInvertedIndexPlan;
 use super::CreateShareEndpointPlan;
 use super::DescDatamaskPolicyPlan;
 use super::DropDatamaskPolicyPlan;
 use super::DropIndexPlan;
+use super::DropInvertedIndexPlan;
 use super::DropShareEndpointPlan;
 use super::ModifyTableColumnPlan;
 use super::RenameTableColumnPlan;
@@ -52,6 +54,7 @@ use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
@@ -61,13 +64,17 @@ use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnsPlan;
+use crate::plans::DeallocatePlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
@@ -75,20 +82,27 @@ use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnsPlan;
+use crate::plans::ExecutePreparedPlan;
+use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GenerateVirtualColumnsPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeInto;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::PreparePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTablePlan;
@@ -147,6 +161,11 @@ pub enum Plan {
     // Call
     Call(Box<CallPlan>),
 
+    // Prepared statements
+    Prepare(Box<PreparePlan>),
+    ExecutePrepared(Box<ExecutePreparedPlan>),
+    Deallocate(Box<DeallocatePlan>),
+
     // Catalogs
     ShowCreateCatalog(Box<ShowCreateCatalogPlan>),
     CreateCatalog(Box<CreateCatalogPlan>),
@@ -188,16 +207,33 @@ pub enum Plan {
     Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
+    MergeInto(Box<MergeInto>),
 
     // Views
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
+
+    // Tasks
+    CreateTask(Box<CreateTaskPlan>),
+    AlterTask(Box<AlterTaskPlan>),
+    DropTask(Box<DropTaskPlan>),
+    ExecuteTask(Box<ExecuteTaskPlan>),
+
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumns(Box<CreateVirtualColumnsPlan>),
@@ -244,6 +280,11 @@ pub enum Plan {
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
 
+    // Transaction
+    Begin,
+    Commit,
+    Rollback,
+
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
     ShowShareEndpoint(Box<ShowShareEndpointPlan>),
@@ -280,6 +321,7 @@ pub enum RewriteKind {
 
     ShowFunctions,
     ShowTableFunctions,
+    ShowTasks,
 
     ShowUsers,
     ShowStages,
@@ -332,9 +374,19 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
+            Plan::CreateTask(_) => write!(f, "CreateTask"),
+            Plan::AlterTask(_) => write!(f, "AlterTask"),
+            Plan::DropTask(_) => write!(f, "DropTask"),
+            Plan::ExecuteTask(_) => write!(f, "ExecuteTask"),
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
             Plan::RefreshIndex(_) => write!(f, "RefreshIndex"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
             Plan::CreateVirtualColumns(_) => write!(f, "CreateVirtualColumns"),
             Plan::AlterVirtualColumns(_) => write!(f, "AlterVirtualColumns"),
             Plan::DropVirtualColumns(_) => write!(f, "DropVirtualColumns"),
@@ -363,12 +415,19 @@ impl Display for Plan {
             Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
             Plan::Call(_) => write!(f, "Call"),
+            Plan::Prepare(_) => write!(f, "Prepare"),
+            Plan::ExecutePrepared(_) => write!(f, "ExecutePrepared"),
+            Plan::Deallocate(_) => write!(f, "Deallocate"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
             Plan::UnSetVariable(_) => write!(f, "UnSetVariable"),
             Plan::SetRole(_) => write!(f, "SetRole"),
             Plan::Kill(_) => write!(f, "Kill"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Rollback => write!(f, "Rollback"),
             Plan::CreateShareEndpoint(_) => write!(f, "CreateShareEndpoint"),
             Plan::ShowShareEndpoint(_) => write!(f, "ShowShareEndpoint"),
             Plan::DropShareEndpoint(_) => write!(f, "DropShareEndpoint"),
@@ -432,6 +491,8 @@ impl Plan {
             Plan::Replace(plan) => plan.schema(),
 
             Plan::Call(_) => Arc::new(DataSchema::empty()),
+            // Depends on the prepared statement, see `InterpreterFactory::get`.
+            Plan::ExecutePrepared(_) => Arc::new(DataSchema::empty()),
             Plan::Presign(plan) => plan.schema(),
             Plan::ShowShareEndpoint(plan) => plan.schema(),
             Plan::DescShare(plan) => plan.schema(),
@@ -456,6 +517,7 @@ impl Plan {
                 | Plan::ExplainSyntax { .. }
                 | Plan::ExplainAnalyze { .. }
                 | Plan::Call(_)
+                | Plan::ExecutePrepared(_)
                 | Plan::ShowCreateDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowFileFormats(_)
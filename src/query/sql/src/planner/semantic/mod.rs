@@ -18,6 +18,8 @@ mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod placeholder_rewriter;
+mod table_database_qualifier;
 mod type_check;
 mod window_check;
 
@@ -30,6 +32,9 @@ pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub use placeholder_rewriter::PlaceholderBinder;
+pub use placeholder_rewriter::PlaceholderRewriter;
+pub use table_database_qualifier::TableDatabaseQualifier;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
@@ -0,0 +1,304 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::InsertSource;
+use common_ast::ast::InsertStmt;
+use common_ast::ast::ReplaceStmt;
+use common_ast::ast::TableReference;
+use common_ast::ast::UpdateStmt;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
+use common_expression::Scalar;
+
+use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::plans::WindowFuncType;
+use crate::ScalarExpr;
+
+/// Rewrites the `?` placeholders of a prepared statement.
+///
+/// Placeholders are identified by their offsets in the SQL text. All visited placeholders
+/// are recorded, and those with a bound value are replaced with it. The text of
+/// `INSERT ... VALUES` is not parsed into the AST, so its placeholders are rewritten
+/// textually.
+pub struct PlaceholderRewriter {
+    sql_len: usize,
+    values: HashMap<usize, Expr>,
+    placeholders: Vec<usize>,
+}
+
+impl PlaceholderRewriter {
+    pub fn new(sql: &str, values: HashMap<usize, Expr>) -> Self {
+        PlaceholderRewriter {
+            sql_len: sql.len(),
+            values,
+            placeholders: vec![],
+        }
+    }
+
+    /// Offsets of the visited placeholders, in the order of parameters.
+    pub fn placeholders(mut self) -> Vec<usize> {
+        self.placeholders.sort_unstable();
+        self.placeholders.dedup();
+        self.placeholders
+    }
+
+    fn rewrite_values(&mut self, rest_str: &mut String) {
+        // `rest_str` always spans to the end of the SQL text.
+        let base = self.sql_len - rest_str.len();
+        let spans = Tokenizer::new(rest_str)
+            .take_while(|token| token.is_ok())
+            .map(|token| token.unwrap())
+            .filter(|token| token.kind == TokenKind::Placeholder)
+            .map(|token| token.span)
+            .collect::<Vec<_>>();
+
+        // Replace from the back so that the spans in front stay valid.
+        for span in spans.into_iter().rev() {
+            let offset = base + span.start;
+            self.placeholders.push(offset);
+            if let Some(value) = self.values.get(&offset) {
+                rest_str.replace_range(span.start..span.end, &value.to_string());
+            }
+        }
+    }
+}
+
+impl VisitorMut for PlaceholderRewriter {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Placeholder { span: Some(span) } = expr {
+            let offset = span.start;
+            self.placeholders.push(offset);
+            if let Some(value) = self.values.get(&offset) {
+                *expr = value.clone();
+            }
+            return;
+        }
+        walk_expr_mut(self, expr);
+    }
+
+    fn visit_insert(&mut self, insert: &mut InsertStmt) {
+        self.visit_insert_source(&mut insert.source);
+    }
+
+    fn visit_replace(&mut self, replace: &mut ReplaceStmt) {
+        self.visit_insert_source(&mut replace.source);
+    }
+
+    fn visit_insert_source(&mut self, insert_source: &mut InsertSource) {
+        match insert_source {
+            InsertSource::Values { rest_str } => self.rewrite_values(rest_str),
+            InsertSource::Select { query } => self.visit_query(query),
+            _ => {}
+        }
+    }
+
+    fn visit_delete(
+        &mut self,
+        _table_reference: &mut TableReference,
+        selection: &mut Option<Expr>,
+    ) {
+        if let Some(selection) = selection {
+            self.visit_expr(selection);
+        }
+    }
+
+    fn visit_update(&mut self, update: &mut UpdateStmt) {
+        for update_expr in update.update_list.iter_mut() {
+            self.visit_expr(&mut update_expr.expr);
+        }
+        if let Some(selection) = &mut update.selection {
+            self.visit_expr(selection);
+        }
+    }
+}
+
+/// Substitutes the values of the placeholders in a plan bound with typed placeholders.
+///
+/// A placeholder is bound as a `NULL` constant spanning the `?` in the SQL text, cast to
+/// the type of the parameter. The constants are replaced with the values, keeping the cast,
+/// so the plan has the same types whatever the values are.
+pub struct PlaceholderBinder<'a> {
+    values: &'a HashMap<usize, Scalar>,
+    placeholders: BTreeSet<usize>,
+}
+
+impl<'a> PlaceholderBinder<'a> {
+    pub fn new(values: &'a HashMap<usize, Scalar>) -> Self {
+        PlaceholderBinder {
+            values,
+            placeholders: BTreeSet::new(),
+        }
+    }
+
+    /// Offsets of the placeholders found in the plan.
+    pub fn placeholders(self) -> BTreeSet<usize> {
+        self.placeholders
+    }
+
+    pub fn bind_s_expr(&mut self, s_expr: &SExpr) -> SExpr {
+        let mut plan = s_expr.plan().clone();
+        self.bind_operator(&mut plan);
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| Arc::new(self.bind_s_expr(child)))
+            .collect::<Vec<_>>();
+        s_expr
+            .replace_plan(Arc::new(plan))
+            .replace_children(children)
+    }
+
+    fn bind_operator(&mut self, operator: &mut RelOperator) {
+        match operator {
+            RelOperator::Scan(scan) => {
+                for predicate in scan.push_down_predicates.iter_mut().flatten() {
+                    self.bind_scalar(predicate);
+                }
+                if let Some(prewhere) = &mut scan.prewhere {
+                    for predicate in prewhere.predicates.iter_mut() {
+                        self.bind_scalar(predicate);
+                    }
+                }
+            }
+            RelOperator::Join(join) => {
+                for condition in join
+                    .left_conditions
+                    .iter_mut()
+                    .chain(join.right_conditions.iter_mut())
+                    .chain(join.non_equi_conditions.iter_mut())
+                {
+                    self.bind_scalar(condition);
+                }
+            }
+            RelOperator::EvalScalar(eval_scalar) => {
+                for item in eval_scalar.items.iter_mut() {
+                    self.bind_scalar(&mut item.scalar);
+                }
+            }
+            RelOperator::Filter(filter) => {
+                for predicate in filter.predicates.iter_mut() {
+                    self.bind_scalar(predicate);
+                }
+            }
+            RelOperator::Aggregate(aggregate) => {
+                for item in aggregate
+                    .group_items
+                    .iter_mut()
+                    .chain(aggregate.aggregate_functions.iter_mut())
+                {
+                    self.bind_scalar(&mut item.scalar);
+                }
+            }
+            RelOperator::Window(window) => {
+                self.bind_window_func(&mut window.function);
+                for item in window
+                    .arguments
+                    .iter_mut()
+                    .chain(window.partition_by.iter_mut())
+                {
+                    self.bind_scalar(&mut item.scalar);
+                }
+                for order_by in window.order_by.iter_mut() {
+                    self.bind_scalar(&mut order_by.order_by_item.scalar);
+                }
+            }
+            RelOperator::ProjectSet(project_set) => {
+                for srf in project_set.srfs.iter_mut() {
+                    self.bind_scalar(&mut srf.scalar);
+                }
+            }
+            RelOperator::RuntimeFilterSource(source) => {
+                for filter in source
+                    .left_runtime_filters
+                    .values_mut()
+                    .chain(source.right_runtime_filters.values_mut())
+                {
+                    self.bind_scalar(filter);
+                }
+            }
+            RelOperator::Sort(_)
+            | RelOperator::Limit(_)
+            | RelOperator::Exchange(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::DummyTableScan(_)
+            | RelOperator::Pattern(_) => {}
+        }
+    }
+
+    fn bind_window_func(&mut self, func: &mut WindowFuncType) {
+        match func {
+            WindowFuncType::Aggregate(aggregate) => {
+                for arg in aggregate.args.iter_mut() {
+                    self.bind_scalar(arg);
+                }
+            }
+            WindowFuncType::LagLead(lag_lead) => {
+                self.bind_scalar(&mut lag_lead.arg);
+                if let Some(default) = &mut lag_lead.default {
+                    self.bind_scalar(default);
+                }
+            }
+            WindowFuncType::NthValue(nth_value) => self.bind_scalar(&mut nth_value.arg),
+            _ => {}
+        }
+    }
+
+    fn bind_scalar(&mut self, scalar: &mut ScalarExpr) {
+        match scalar {
+            ScalarExpr::ConstantExpr(constant) => {
+                if let Some(span) = constant.span {
+                    if let Some(value) = self.values.get(&span.start) {
+                        constant.value = value.clone();
+                        self.placeholders.insert(span.start);
+                    }
+                }
+            }
+            ScalarExpr::BoundColumnRef(_) => {}
+            ScalarExpr::WindowFunction(window) => {
+                self.bind_window_func(&mut window.func);
+                for arg in window.partition_by.iter_mut() {
+                    self.bind_scalar(arg);
+                }
+                for order_by in window.order_by.iter_mut() {
+                    self.bind_scalar(&mut order_by.expr);
+                }
+            }
+            ScalarExpr::AggregateFunction(aggregate) => {
+                for arg in aggregate.args.iter_mut() {
+                    self.bind_scalar(arg);
+                }
+            }
+            ScalarExpr::FunctionCall(func) => {
+                for arg in func.arguments.iter_mut() {
+                    self.bind_scalar(arg);
+                }
+            }
+            ScalarExpr::CastExpr(cast) => self.bind_scalar(&mut cast.argument),
+            ScalarExpr::SubqueryExpr(subquery) => {
+                if let Some(child_expr) = &mut subquery.child_expr {
+                    self.bind_scalar(child_expr);
+                }
+                subquery.subquery = Box::new(self.bind_s_expr(&subquery.subquery));
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_ast::ast::Identifier;
+use common_ast::ast::TableReference;
+use common_ast::ast::With;
+use common_ast::walk_cte_mut;
+use common_ast::walk_table_reference_mut;
+use common_ast::VisitorMut;
+
+/// Qualify the table references without database with the given database,
+/// so that the query can be bound in a session using a different database.
+#[derive(Debug, Clone)]
+pub struct TableDatabaseQualifier {
+    database: String,
+    cte_names: HashSet<String>,
+}
+
+impl TableDatabaseQualifier {
+    pub fn new(database: String) -> Self {
+        Self {
+            database,
+            cte_names: HashSet::new(),
+        }
+    }
+}
+
+impl VisitorMut for TableDatabaseQualifier {
+    fn visit_with(&mut self, with: &mut With) {
+        for cte in with.ctes.iter_mut() {
+            self.cte_names.insert(cte.alias.name.name.clone());
+        }
+        for cte in with.ctes.iter_mut() {
+            walk_cte_mut(self, cte);
+        }
+    }
+
+    fn visit_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            database, table, ..
+        } = table_ref
+        {
+            if database.is_none() && !self.cte_names.contains(&table.name) {
+                let quote = if self
+                    .database
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                {
+                    None
+                } else {
+                    Some('"')
+                };
+                *database = Some(Identifier {
+                    name: self.database.clone(),
+                    quote,
+                    span: None,
+                });
+            }
+        }
+        walk_table_reference_mut(self, table_ref);
+    }
+}
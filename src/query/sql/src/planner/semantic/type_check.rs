@@ -614,6 +614,40 @@ impl<'a> TypeChecker<'a> {
                 Box::new((ConstantExpr { span: *span, value }.into(), data_type))
             }
 
+            Expr::Placeholder { span } => {
+                let param_type = {
+                    let metadata = self.metadata.read();
+                    if !metadata.placeholders_allowed() {
+                        return Err(ErrorCode::SemanticError(
+                            "placeholder `?` can only be used in prepared statements".to_string(),
+                        )
+                        .set_span(*span));
+                    }
+                    span.and_then(|span| metadata.placeholder_param_type(span.start).cloned())
+                };
+                // The actual value is substituted at execution, see `Planner::plan_prepared`.
+                let null = ConstantExpr {
+                    span: *span,
+                    value: Scalar::Null,
+                };
+                match param_type {
+                    Some(data_type) => {
+                        let target_type = data_type.wrap_nullable();
+                        Box::new((
+                            CastExpr {
+                                span: *span,
+                                is_try: false,
+                                argument: Box::new(null.into()),
+                                target_type: Box::new(target_type.clone()),
+                            }
+                            .into(),
+                            target_type,
+                        ))
+                    }
+                    None => Box::new((null.into(), DataType::Null)),
+                }
+            }
+
             Expr::FunctionCall {
                 span,
                 distinct,
@@ -1530,6 +1564,10 @@ impl<'a> TypeChecker<'a> {
             arg_types.push(arg_type);
         }
 
+        if arguments.len() == 2 {
+            self.infer_placeholder_types(arguments, &arg_types);
+        }
+
         // rewrite substr('xx', 0, xx) -> substr('xx', 1, xx)
         if (func_name == "substr" || func_name == "substring")
             && self
@@ -1673,6 +1711,21 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Infer the type of a placeholder from the other operand of a binary function,
+    /// e.g. the placeholder in `a = ?` takes the type of `a`.
+    fn infer_placeholder_types(&self, arguments: &[&Expr], arg_types: &[DataType]) {
+        for (argument, other_type) in arguments.iter().zip(arg_types.iter().rev()) {
+            if let Expr::Placeholder { span: Some(span) } = argument {
+                let data_type = other_type.remove_nullable();
+                if data_type != DataType::Null {
+                    self.metadata
+                        .write()
+                        .set_placeholder_type(span.start, data_type);
+                }
+            }
+        }
+    }
+
     /// Resolve unary expressions.
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
@@ -3042,7 +3095,7 @@ impl<'a> TypeChecker<'a> {
         &self,
         expr: &common_expression::Expr<Index>,
     ) -> Option<Box<(ScalarExpr, DataType)>> {
-        if expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        if expr.is_deterministic(&BUILTIN_FUNCTIONS) && !self.contains_placeholder(expr) {
             if let (common_expression::Expr::Constant { scalar, .. }, _) =
                 ConstantFolder::fold(expr, &self.func_ctx, &BUILTIN_FUNCTIONS)
             {
@@ -3061,6 +3114,27 @@ impl<'a> TypeChecker<'a> {
 
         None
     }
+
+    /// Whether the expression has a placeholder bound as a typed parameter, it's not folded
+    /// since its value is substituted on each execution of the prepared statement.
+    fn contains_placeholder<Index: ColumnIndex>(
+        &self,
+        expr: &common_expression::Expr<Index>,
+    ) -> bool {
+        match expr {
+            common_expression::Expr::Constant { span, .. } => span.map_or(false, |span| {
+                self.metadata
+                    .read()
+                    .placeholder_param_type(span.start)
+                    .is_some()
+            }),
+            common_expression::Expr::ColumnRef { .. } => false,
+            common_expression::Expr::Cast { expr, .. } => self.contains_placeholder(expr),
+            common_expression::Expr::FunctionCall { args, .. } => {
+                args.iter().any(|arg| self.contains_placeholder(arg))
+            }
+        }
+    }
 }
 
 pub fn resolve_type_name_by_str(name: &str) -> Result<TableDataType> {
@@ -3099,8 +3173,10 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             })?)
         }
         TypeName::String => TableDataType::String,
+        TypeName::Binary => TableDataType::Binary,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::Array(item_type) => TableDataType::Array(Box::new(resolve_type_name(item_type)?)),
         TypeName::Map { key_type, val_type } => {
             let key_type = resolve_type_name(key_type)?;
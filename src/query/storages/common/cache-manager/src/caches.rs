@@ -28,6 +28,7 @@ use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_cache::NamedCache;
 use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::InvertedIndexTerms;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
@@ -49,6 +50,8 @@ pub type TableSnapshotStatisticCache = NamedCache<InMemoryItemCacheHolder<TableS
 pub type BloomIndexFilterCache = NamedCache<InMemoryItemCacheHolder<Xor8Filter>>;
 /// In memory object cache of parquet FileMetaData of bloom index data
 pub type BloomIndexMetaCache = NamedCache<InMemoryItemCacheHolder<BloomIndexMeta>>;
+/// In memory object cache of the terms of inverted index files
+pub type InvertedIndexTermsCache = NamedCache<InMemoryItemCacheHolder<InvertedIndexTerms>>;
 /// In memory object cache of parquet FileMetaData of external parquet files
 pub type FileMetaDataCache = NamedCache<InMemoryItemCacheHolder<FileMetaData>>;
 
@@ -128,6 +131,13 @@ impl CachedObject<Xor8Filter> for Xor8Filter {
     }
 }
 
+impl CachedObject<InvertedIndexTerms> for InvertedIndexTerms {
+    type Cache = InvertedIndexTermsCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_inverted_index_terms_cache()
+    }
+}
+
 impl CachedObject<FileMetaData> for FileMetaData {
     type Cache = FileMetaDataCache;
     fn cache() -> Option<Self::Cache> {
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -23,12 +24,15 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
 use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
@@ -138,6 +142,34 @@ pub struct BloomIndex {
     pub column_distinct_count: HashMap<FieldIndex, usize>,
 }
 
+/// NgramArgs describes an ngram bloom filter of a string column.
+///
+/// The filter contains the digests of all the `gram_size` bytes long substrings of the column,
+/// it is used to prune the blocks for `LIKE '%abc%'` and substring search functions.
+#[derive(Clone, Debug)]
+pub struct NgramArgs {
+    /// The offset of the column in the source data block.
+    pub index: FieldIndex,
+    pub field: TableField,
+    pub gram_size: usize,
+}
+
+impl NgramArgs {
+    pub fn new(index: FieldIndex, field: TableField, gram_size: usize) -> Self {
+        Self {
+            index,
+            field,
+            gram_size,
+        }
+    }
+
+    /// The ngram filter will be stored with field name 'Ngram(column_id,gram_size)',
+    /// so that the filters built with another gram size are never used.
+    pub fn filter_column_name(&self) -> String {
+        format!("Ngram({},{})", self.field.column_id(), self.gram_size)
+    }
+}
+
 /// FilterExprEvalResult represents the evaluation result of an expression by a filter.
 ///
 /// For example, expression of 'age = 12' should return false is the filter are sure
@@ -176,6 +208,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -279,6 +312,18 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for arg in ngram_args {
+            if let Some(filter) =
+                Self::build_ngram_filter(&func_ctx, data_blocks_tobe_indexed, arg)?
+            {
+                filter_fields.push(TableField::new(
+                    &arg.filter_column_name(),
+                    TableDataType::String,
+                ));
+                filters.push(Arc::new(filter));
+            }
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -294,6 +339,52 @@ impl BloomIndex {
         }))
     }
 
+    /// Build the ngram filter of a string column, returns None if there is no ngram at all,
+    /// e.g. all the values are shorter than the gram size.
+    fn build_ngram_filter(
+        func_ctx: &FunctionContext,
+        data_blocks_tobe_indexed: &[&DataBlock],
+        arg: &NgramArgs,
+    ) -> Result<Option<Xor8Filter>> {
+        let mut ngrams = HashSet::new();
+        for block in data_blocks_tobe_indexed {
+            let entry = block.get_by_offset(arg.index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows())
+                .remove_nullable();
+            let column = StringType::try_downcast_column(&column).ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Unsupported data type for ngram index: {}",
+                    entry.data_type
+                ))
+            })?;
+            // Null values are stored as empty strings, they contribute no ngrams.
+            for value in column.iter() {
+                ngrams.extend(value.windows(arg.gram_size).map(|gram| gram.to_vec()));
+            }
+        }
+        if ngrams.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder = StringColumnBuilder::with_capacity(ngrams.len(), 0);
+        for gram in ngrams.iter() {
+            builder.put_slice(gram);
+            builder.commit_row();
+        }
+        let column = Self::calculate_column_digest(
+            func_ctx,
+            &Column::String(builder.build()),
+            &DataType::String,
+            &DataType::Number(NumberDataType::UInt64),
+        )?;
+        let column = UInt64Type::try_downcast_column(&column).unwrap();
+        let mut filter_builder = Xor8Builder::create();
+        filter_builder.add_digests(column.deref());
+        Ok(Some(filter_builder.build()?))
+    }
+
     pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
         let fields = self.filter_schema.fields();
         let mut filter_columns = Vec::with_capacity(fields.len());
@@ -315,8 +406,22 @@ impl BloomIndex {
         &self,
         mut expr: Expr<String>,
         scalar_map: &HashMap<Scalar, u64>,
+        ngram_args: &[NgramArgs],
         data_schema: TableSchemaRef,
     ) -> Result<FilterEvalResult> {
+        if !ngram_args.is_empty() {
+            visit_expr_column_contains_constant(
+                &mut expr,
+                &mut |col_name, needles| match ngram_args
+                    .iter()
+                    .find(|arg| arg.field.name() == col_name)
+                {
+                    Some(arg) => self.find_ngrams(arg, needles, scalar_map),
+                    None => Ok(FilterEvalResult::Uncertain),
+                },
+            )?;
+        }
+
         visit_expr_column_eq_constant(
             &mut expr,
             &mut |span, col_name, scalar, ty, return_type| {
@@ -405,6 +510,32 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all the ngram columns that match the pattern of `col LIKE <constant>` or
+    /// `position(<constant>, col)`, returns the ngrams that need to be looked up in the filter.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        ngram_args: &[NgramArgs],
+    ) -> Result<Vec<(NgramArgs, Vec<Scalar>)>> {
+        let mut cols: Vec<(NgramArgs, Vec<Scalar>)> = Vec::new();
+        visit_expr_column_contains_constant(&mut expr.clone(), &mut |col_name, needles| {
+            if let Some(arg) = ngram_args.iter().find(|arg| arg.field.name() == col_name) {
+                let ngrams = needles
+                    .iter()
+                    .flat_map(|needle| needle.windows(arg.gram_size))
+                    .map(|gram| Scalar::String(gram.to_vec()))
+                    .collect::<Vec<_>>();
+                if !ngrams.is_empty() {
+                    match cols.iter_mut().find(|(a, _)| a.field.name() == col_name) {
+                        Some((_, scalars)) => scalars.extend(ngrams),
+                        None => cols.push((arg.clone(), ngrams)),
+                    }
+                }
+            }
+            Ok(FilterEvalResult::Uncertain)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -455,6 +586,33 @@ impl BloomIndex {
         }
     }
 
+    /// Returns `MustFalse` if some ngram of the needles doesn't show up in the ngram filter.
+    fn find_ngrams(
+        &self,
+        arg: &NgramArgs,
+        needles: &[Vec<u8>],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        let filter_column = arg.filter_column_name();
+        if !self.filter_schema.has_field(&filter_column) {
+            // The block doesn't have an ngram filter of the column.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(&filter_column)?;
+        let filter = &self.filters[idx];
+        for needle in needles {
+            for gram in needle.windows(arg.gram_size) {
+                if let Some(digest) = scalar_map.get(&Scalar::String(gram.to_vec())) {
+                    if !filter.contains_digest(*digest) {
+                        return Ok(FilterEvalResult::MustFalse);
+                    }
+                }
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let mut data_type = DataType::from(data_type);
         if let DataType::Map(box inner_ty) = data_type {
@@ -558,6 +716,107 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+fn visit_expr_column_contains_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &[Vec<u8>]) -> Result<FilterEvalResult>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`, `position(<constant>, Column)`,
+    // `locate(<constant>, Column[, pos])` or `instr(Column, <constant>)`.
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let name = function.signature.name.as_str();
+        let (column, needle, absent) = match (name, args.as_slice()) {
+            ("like", [column, needle]) => (column, needle, Scalar::Boolean(false)),
+            ("instr", [column, needle]) => {
+                (column, needle, Scalar::Number(NumberScalar::UInt64(0)))
+            }
+            ("position" | "locate", [needle, column] | [needle, column, _]) => {
+                (column, needle, Scalar::Number(NumberScalar::UInt64(0)))
+            }
+            _ => return visit_sub_exprs(expr, visitor),
+        };
+        if let (
+            Expr::ColumnRef { id, data_type, .. },
+            Expr::Constant {
+                scalar: Scalar::String(pattern),
+                ..
+            },
+        ) = (column, needle)
+        {
+            if data_type.remove_nullable() == DataType::String {
+                let needles = if name == "like" {
+                    like_pattern_needles(pattern)
+                } else {
+                    vec![pattern.clone()]
+                };
+                // If the needles must not show up in the column, the function returns false
+                // for `LIKE` or 0 for the substring search functions.
+                if visitor(id, &needles)? == FilterEvalResult::MustFalse {
+                    let new_expr = Expr::Constant {
+                        span: *span,
+                        scalar: absent,
+                        data_type: return_type.clone(),
+                    };
+                    *expr = new_expr;
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    visit_sub_exprs(expr, visitor)
+}
+
+fn visit_sub_exprs(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &[Vec<u8>]) -> Result<FilterEvalResult>,
+) -> Result<()> {
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_contains_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_contains_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Split a LIKE pattern into the literal substrings that every matched value must contain.
+fn like_pattern_needles(pattern: &[u8]) -> Vec<Vec<u8>> {
+    let mut needles = vec![];
+    let mut needle = vec![];
+    let mut i = 0;
+    while i < pattern.len() {
+        match pattern[i] {
+            b'%' | b'_' => {
+                if !needle.is_empty() {
+                    needles.push(std::mem::take(&mut needle));
+                }
+            }
+            b'\\' if i + 1 < pattern.len() => {
+                i += 1;
+                needle.push(pattern[i]);
+            }
+            c => needle.push(c),
+        }
+        i += 1;
+    }
+    if !needle.is_empty() {
+        needles.push(needle);
+    }
+    needles
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
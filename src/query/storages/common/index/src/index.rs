@@ -23,6 +23,7 @@ pub trait Index {
             DataType::Number(_)
                 | DataType::Date
                 | DataType::Timestamp
+                | DataType::Interval
                 | DataType::String
                 | DataType::Decimal(_)
         )
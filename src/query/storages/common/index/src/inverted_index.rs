@@ -0,0 +1,271 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::array::ArrayColumn;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt32Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnId;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::tokenize;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::FilterEvalResult;
+
+/// InvertedIndex maps the terms of the indexed string columns of a block to the rows
+/// containing them, the terms are produced by the same analyzer as the `match` function.
+///
+/// The index of a block is stored as a data block with one row per (column, term):
+/// ```
+///         +--column_id--+--term---+--rows---+
+///         |      1      | "error" | [0, 3]  |
+///         |      1      | "disk"  | [3]     |
+///         +-------------+---------+---------+
+/// ```
+pub struct InvertedIndex {
+    pub func_ctx: FunctionContext,
+
+    pub terms: Arc<InvertedIndexTerms>,
+}
+
+/// The terms of each indexed column of a block, the posting lists are not needed for pruning.
+pub struct InvertedIndexTerms {
+    pub terms: HashMap<ColumnId, HashSet<String>>,
+}
+
+impl InvertedIndexTerms {
+    /// Load the terms from an index block.
+    pub fn from_index_block(index_block: &DataBlock) -> Result<Self> {
+        let num_rows = index_block.num_rows();
+        let invalid = || ErrorCode::Internal("invalid inverted index block");
+        if index_block.num_columns() < 2 {
+            return Err(invalid());
+        }
+
+        let entry = index_block.get_by_offset(0);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let column_ids = UInt32Type::try_downcast_column(&column).ok_or_else(invalid)?;
+        let entry = index_block.get_by_offset(1);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let terms = StringType::try_downcast_column(&column).ok_or_else(invalid)?;
+
+        let mut index_terms = HashMap::<ColumnId, HashSet<String>>::new();
+        for (column_id, term) in column_ids.iter().zip(terms.iter()) {
+            index_terms
+                .entry(*column_id)
+                .or_default()
+                .insert(String::from_utf8_lossy(term).into_owned());
+        }
+        Ok(Self { terms: index_terms })
+    }
+}
+
+impl InvertedIndex {
+    /// The schema of the index block.
+    pub fn schema() -> TableSchemaRef {
+        Arc::new(TableSchema::new(vec![
+            TableField::new("column_id", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new("term", TableDataType::String),
+            TableField::new(
+                "rows",
+                TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+        ]))
+    }
+
+    /// Build the index block of the columns `(offset in block, column id)` from source data.
+    pub fn build_index_block(
+        data_block: &DataBlock,
+        columns: &[(FieldIndex, ColumnId)],
+    ) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let mut column_ids = vec![];
+        let mut terms = vec![];
+        let mut rows = vec![];
+        let mut offsets = vec![0u64];
+        for (index, column_id) in columns {
+            let entry = data_block.get_by_offset(*index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+                .remove_nullable();
+            let column = StringType::try_downcast_column(&column).ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "inverted index column {} must be of type String, but is {}",
+                    column_id, entry.data_type
+                ))
+            })?;
+
+            // Null rows are stored as empty strings, so they contribute no terms.
+            let mut postings = BTreeMap::<String, Vec<u32>>::new();
+            for (row, text) in column.iter().enumerate() {
+                for term in tokenize(text) {
+                    let posting = postings.entry(term).or_default();
+                    if posting.last() != Some(&(row as u32)) {
+                        posting.push(row as u32);
+                    }
+                }
+            }
+
+            for (term, posting) in postings {
+                column_ids.push(*column_id);
+                terms.push(term);
+                rows.extend(posting);
+                offsets.push(rows.len() as u64);
+            }
+        }
+
+        let terms = terms.iter().map(|t| t.as_str()).collect::<Vec<_>>();
+        Ok(DataBlock::new_from_columns(vec![
+            UInt32Type::from_data(column_ids),
+            StringType::from_data(terms),
+            Column::Array(Box::new(ArrayColumn {
+                values: UInt32Type::from_data(rows),
+                offsets: offsets.into(),
+            })),
+        ]))
+    }
+
+    /// Load the terms from an index block, the posting lists are not needed for pruning.
+    pub fn from_index_block(func_ctx: FunctionContext, index_block: &DataBlock) -> Result<Self> {
+        let terms = InvertedIndexTerms::from_index_block(index_block)?;
+        Ok(Self::from_terms(func_ctx, Arc::new(terms)))
+    }
+
+    pub fn from_terms(func_ctx: FunctionContext, terms: Arc<InvertedIndexTerms>) -> Self {
+        Self { func_ctx, terms }
+    }
+
+    /// Apply the predicate expression, rewrite each `match(column, '<query>')` to false
+    /// if some term of the query doesn't show up in the column, then return `MustFalse`
+    /// if the whole expression becomes false.
+    #[tracing::instrument(level = "debug", name = "inverted_index_eval", skip_all)]
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        data_schema: TableSchemaRef,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_match(&mut expr, &mut |span, col_name, query, return_type| {
+            let column_id = data_schema.field_with_name(col_name)?.column_id();
+            let terms = match self.terms.terms.get(&column_id) {
+                Some(terms) => terms,
+                // The column is not indexed by this index.
+                None => return Ok(None),
+            };
+            let query_terms = tokenize(query);
+            if query_terms.is_empty() || query_terms.iter().any(|t| !terms.contains(t)) {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Find the names of the columns that match the pattern of `match(col, <constant>)`.
+    pub fn find_match_columns(expr: &Expr<String>) -> Result<Vec<String>> {
+        let mut cols = Vec::new();
+        visit_expr_match(&mut expr.clone(), &mut |_, col_name, _, _| {
+            if !cols.iter().any(|c| c == col_name) {
+                cols.push(col_name.to_string());
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+}
+
+fn visit_expr_match(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[u8], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "match" {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                // If the visitor returns a new expression, replace the current expression.
+                if let Some(new_expr) = visitor(*span, id, query, return_type)? {
+                    *expr = new_expr;
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_match(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_match(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
@@ -18,12 +18,16 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
 mod page_index;
 mod range_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
+pub use bloom_index::NgramArgs;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
+pub use inverted_index::InvertedIndexTerms;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
@@ -18,6 +18,7 @@ use common_expression::types::number::SimpleDomain;
 use common_expression::types::string::StringDomain;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -196,6 +197,10 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                     min: DateType::try_downcast_scalar(&stat.min.as_ref()).unwrap(),
                     max: DateType::try_downcast_scalar(&stat.max.as_ref()).unwrap(),
                 }),
+                DataType::Interval => IntervalType::upcast_domain(SimpleDomain {
+                    min: IntervalType::try_downcast_scalar(&stat.min.as_ref()).unwrap(),
+                    max: IntervalType::try_downcast_scalar(&stat.max.as_ref()).unwrap(),
+                }),
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
@@ -48,6 +48,7 @@ use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
 use storages_common_index::Index;
+use storages_common_index::NgramArgs;
 use storages_common_table_meta::meta::Versioned;
 
 #[test]
@@ -118,6 +119,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -235,6 +237,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
     assert_eq!(
@@ -251,6 +254,98 @@ fn test_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("s", TableDataType::String),
+        TableField::new(
+            "n",
+            TableDataType::Nullable(Box::new(TableDataType::String)),
+        ),
+    ]));
+    let block = DataBlock::new_from_columns(vec![
+        StringType::from_data(vec!["databend cloud", "hello world"]),
+        StringType::from_data_with_validity(vec!["", "abcdef"], vec![false, true]),
+    ]);
+    let ngram_args = vec![
+        NgramArgs::new(0, schema.field(0).clone(), 3),
+        NgramArgs::new(1, schema.field(1).clone(), 3),
+    ];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &[&block],
+        BTreeMap::new(),
+        &ngram_args,
+    )?
+    .unwrap();
+
+    let s_ty = DataType::String;
+    let n_ty = DataType::Nullable(Box::new(DataType::String));
+    let like = |col: &str, ty: &DataType, pattern: &str| {
+        let args = [column_ref(col, ty), string_constant(pattern)];
+        check_function(None, "like", &[], &args, &BUILTIN_FUNCTIONS).unwrap()
+    };
+    let search = |name: &str, args: &[Expr<String>]| {
+        let func = check_function(None, name, &[], args, &BUILTIN_FUNCTIONS).unwrap();
+        let zero = Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::UInt64(0)),
+            data_type: DataType::Number(NumberDataType::UInt64),
+        };
+        check_function(None, "gt", &[], &[func, zero], &BUILTIN_FUNCTIONS).unwrap()
+    };
+
+    let cases = vec![
+        (like("s", &s_ty, "%base%"), FilterEvalResult::MustFalse),
+        (like("s", &s_ty, "%bend%"), FilterEvalResult::Uncertain),
+        (like("s", &s_ty, "hel%wor%"), FilterEvalResult::Uncertain),
+        (like("s", &s_ty, "hel%word%"), FilterEvalResult::MustFalse),
+        // needles shorter than the gram size can't be pruned.
+        (like("s", &s_ty, "%xy%"), FilterEvalResult::Uncertain),
+        // the escaped wildcard is a part of the needle.
+        (like("s", &s_ty, "%cloud\\%%"), FilterEvalResult::MustFalse),
+        (like("n", &n_ty, "abc%"), FilterEvalResult::Uncertain),
+        (like("n", &n_ty, "%xyz%"), FilterEvalResult::MustFalse),
+        (
+            search("position", &[
+                string_constant("world"),
+                column_ref("s", &s_ty),
+            ]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            search("position", &[
+                string_constant("earth"),
+                column_ref("s", &s_ty),
+            ]),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            search("instr", &[column_ref("n", &n_ty), string_constant("bcd")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            search("locate", &[string_constant("bcx"), column_ref("n", &n_ty)]),
+            FilterEvalResult::MustFalse,
+        ),
+    ];
+    for (expr, expected) in cases {
+        assert_eq!(
+            expected,
+            eval_ngram_index(&index, expr, &ngram_args, schema.clone())
+        );
+    }
+
+    // The filters built with another gram size are not used.
+    let other_args = vec![NgramArgs::new(0, schema.field(0).clone(), 4)];
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_ngram_index(&index, like("s", &s_ty, "%base%"), &other_args, schema)
+    );
+    Ok(())
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -291,7 +386,7 @@ fn eval_index(
         }
     }
 
-    index.apply(expr, &scalar_map, schema).unwrap()
+    index.apply(expr, &scalar_map, &[], schema).unwrap()
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -352,7 +447,48 @@ fn eval_map_index(
         }
     }
 
-    index.apply(expr, &scalar_map, schema).unwrap()
+    index.apply(expr, &scalar_map, &[], schema).unwrap()
+}
+
+fn eval_ngram_index(
+    index: &BloomIndex,
+    expr: Expr<String>,
+    ngram_args: &[NgramArgs],
+    schema: Arc<TableSchema>,
+) -> FilterEvalResult {
+    let ngram_cols = BloomIndex::find_ngram_columns(&expr, ngram_args).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, scalars) in ngram_cols.iter() {
+        for scalar in scalars {
+            if !scalar_map.contains_key(scalar) {
+                let digest =
+                    BloomIndex::calculate_scalar_digest(&func_ctx, scalar, &DataType::String)
+                        .unwrap();
+                scalar_map.insert(scalar.clone(), digest);
+            }
+        }
+    }
+
+    index.apply(expr, &scalar_map, ngram_args, schema).unwrap()
+}
+
+fn column_ref(col_name: &str, ty: &DataType) -> Expr<String> {
+    Expr::ColumnRef {
+        span: None,
+        id: col_name.to_string(),
+        data_type: ty.clone(),
+        display_name: col_name.to_string(),
+    }
+}
+
+fn string_constant(value: &str) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::String(value.as_bytes().to_vec()),
+        data_type: DataType::String,
+    }
 }
 
 fn bloom_columns_map(
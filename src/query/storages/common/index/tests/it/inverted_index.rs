@@ -0,0 +1,206 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt8Type;
+use common_expression::types::ArrayType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("msg", TableDataType::String),
+        TableField::new(
+            "note",
+            TableDataType::Nullable(Box::new(TableDataType::String)),
+        ),
+    ]));
+
+    let block = DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec!["Disk error on node-1", "all good", "ERROR: disk full"]),
+        StringType::from_data_with_validity(vec!["backup", "", "restore"], vec![true, false, true]),
+    ]);
+
+    let columns = vec![
+        (1, schema.field(1).column_id()),
+        (2, schema.field(2).column_id()),
+    ];
+    let index_block = InvertedIndex::build_index_block(&block, &columns)?;
+
+    // msg: 1, all, disk, error, full, good, node, on; note: backup, restore
+    assert_eq!(index_block.num_rows(), 10);
+    let entry = index_block.get_by_offset(1);
+    let terms = StringType::try_downcast_column(
+        &entry
+            .value
+            .convert_to_full_column(&entry.data_type, index_block.num_rows()),
+    )
+    .unwrap();
+    let error_row = terms.iter().position(|t| t == b"error").unwrap();
+    let entry = index_block.get_by_offset(2);
+    let rows = entry
+        .value
+        .convert_to_full_column(&entry.data_type, index_block.num_rows());
+    let rows = ArrayType::<UInt32Type>::try_downcast_column(&rows).unwrap();
+    let posting = rows.index(error_row).unwrap();
+    assert_eq!(posting.as_slice(), &[0, 2]);
+
+    let index = InvertedIndex::from_index_block(FunctionContext::default(), &index_block)?;
+
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_match(&index, schema.clone(), "msg", "disk ERROR")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_match(&index, schema.clone(), "msg", "disk network")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_match(&index, schema.clone(), "msg", "backup")
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_match(&index, schema.clone(), "note", "Backup")
+    );
+    // a query without terms matches nothing.
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_match(&index, schema.clone(), "msg", "!!")
+    );
+
+    // `not match(..)` can't be pruned by the absence of terms.
+    let expr = check_function(
+        None,
+        "not",
+        &[],
+        &[match_expr(&schema, "msg", "network")],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        index.apply(fold(&expr), schema.clone())?
+    );
+
+    // other predicates are kept as is.
+    let id_expr = check_function(
+        None,
+        "eq",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: "id".to_string(),
+                data_type: DataType::Number(NumberDataType::UInt8),
+                display_name: "id".to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::Number(NumberScalar::UInt8(1)),
+                data_type: DataType::Number(NumberDataType::UInt8),
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    let expr = check_function(
+        None,
+        "or",
+        &[],
+        &[match_expr(&schema, "msg", "network"), id_expr.clone()],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        index.apply(fold(&expr), schema.clone())?
+    );
+    let expr = check_function(
+        None,
+        "and",
+        &[],
+        &[match_expr(&schema, "msg", "network"), id_expr],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        index.apply(fold(&expr), schema.clone())?
+    );
+
+    assert_eq!(InvertedIndex::find_match_columns(&fold(&expr))?, vec![
+        "msg".to_string()
+    ]);
+
+    Ok(())
+}
+
+fn eval_match(
+    index: &InvertedIndex,
+    schema: TableSchemaRef,
+    col_name: &str,
+    query: &str,
+) -> FilterEvalResult {
+    let expr = match_expr(&schema, col_name, query);
+    index.apply(fold(&expr), schema).unwrap()
+}
+
+fn match_expr(schema: &TableSchemaRef, col_name: &str, query: &str) -> Expr<String> {
+    let field = schema.field_with_name(col_name).unwrap();
+    check_function(
+        None,
+        "match",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::from(field.data_type()),
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(query.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap()
+}
+
+fn fold(expr: &Expr<String>) -> Expr<String> {
+    let (expr, _) = ConstantFolder::fold(expr, &FunctionContext::default(), &BUILTIN_FUNCTIONS);
+    expr
+}
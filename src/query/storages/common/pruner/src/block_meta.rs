@@ -37,6 +37,8 @@ pub struct BlockMetaIndex {
     pub block_location: String,
     pub segment_location: String,
     pub snapshot_location: Option<String>,
+    /// The block is removed from the table, set when reading the changes of a stream.
+    pub is_deleted: bool,
 }
 
 #[typetag::serde(name = "block_meta_index")]
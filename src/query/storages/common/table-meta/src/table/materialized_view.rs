@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_MATERIALIZED_VIEWS;
+
+/// A materialized view refreshed from a table, registered in the option
+/// [`OPT_KEY_MATERIALIZED_VIEWS`] of the table.
+///
+/// The view is looked up by name, and ignored if the table found is not the registered one,
+/// e.g. the view is dropped or renamed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedViewRef {
+    pub table_id: u64,
+    pub database: String,
+    pub name: String,
+}
+
+impl MaterializedViewRef {
+    /// The materialized views registered in the table options, an invalid registry is
+    /// taken as empty.
+    pub fn list(options: &BTreeMap<String, String>) -> Vec<MaterializedViewRef> {
+        options
+            .get(OPT_KEY_MATERIALIZED_VIEWS)
+            .and_then(|views| serde_json::from_str(views).ok())
+            .unwrap_or_default()
+    }
+
+    /// Add `view` to the materialized views registered in the table options, returns the
+    /// new value of the option.
+    pub fn register(
+        options: &BTreeMap<String, String>,
+        view: MaterializedViewRef,
+    ) -> Result<String> {
+        let mut views = Self::list(options);
+        views.retain(|v| v.table_id != view.table_id);
+        views.push(view);
+        serde_json::to_string(&views)
+            .map_err(|e| ErrorCode::Internal(format!("Cannot serialize materialized views: {e}")))
+    }
+}
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod materialized_view;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
+pub use materialized_view::MaterializedViewRef;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
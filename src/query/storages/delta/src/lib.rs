@@ -0,0 +1,38 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This is the Delta Lake table engine of databend, it's read-only.
+//!
+//! A delta table is created on the root directory of the table, the one
+//! holding `_delta_log`, and takes its schema from the latest version:
+//!
+//! ```sql
+//! CREATE TABLE t ENGINE = DELTA LOCATION = 's3://bkt/path/to/table/' CONNECTION = ( ... );
+//! ```
+//!
+//! Each scan replays the log to find the active data files of the latest
+//! version, prunes them with the partition values and fills the partition
+//! columns, which are not stored in the data files.
+//!
+//! Column mapping and deletion vectors are not supported yet.
+
+mod log;
+mod partition;
+mod partition_filler;
+mod pruning;
+mod schema;
+mod table;
+mod table_source;
+
+pub use table::DeltaTable;
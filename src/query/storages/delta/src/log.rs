@@ -0,0 +1,315 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay of the delta transaction log.
+//!
+//! The state of a table is the result of applying the actions of the commits
+//! in `_delta_log` in order. A checkpoint holds the state up to its version,
+//! so the replay starts from the latest complete checkpoint and applies the
+//! newer commits on top of it.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use arrow_json::writer::record_batches_to_json_rows;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Operator;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::table_source::parquet_error;
+
+pub const DELTA_LOG_DIR: &str = "_delta_log";
+
+/// Readers of a higher version must support features that we don't have.
+const MAX_READER_VERSION: i32 = 3;
+/// Reader features that need no special handling when reading.
+const SUPPORTED_READER_FEATURES: &[&str] = &["timestampNtz"];
+const COLUMN_MAPPING_MODE: &str = "delta.columnMapping.mode";
+
+/// A data file added to the table.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFile {
+    /// URI encoded path relative to the table root, or an absolute URI.
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: HashMap<String, Option<String>>,
+    pub size: i64,
+    /// File statistics encoded as a JSON string.
+    #[serde(default)]
+    pub stats: Option<String>,
+    #[serde(default)]
+    pub deletion_vector: Option<Value>,
+}
+
+impl AddFile {
+    /// The number of records of the file, if recorded in the statistics.
+    pub fn num_records(&self) -> Option<u64> {
+        let stats: Value = serde_json::from_str(self.stats.as_ref()?).ok()?;
+        stats.get("numRecords")?.as_u64()
+    }
+}
+
+/// A data file removed from the table.
+#[derive(Deserialize, Clone, Debug)]
+pub struct RemoveFile {
+    pub path: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// The table schema encoded as a JSON string.
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: HashMap<String, Option<String>>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Protocol {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+/// An action of a commit, the kinds not affecting reads are ignored.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    add: Option<AddFile>,
+    remove: Option<RemoveFile>,
+    meta_data: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+/// The state of a table at a version.
+pub struct DeltaSnapshot {
+    pub version: u64,
+    pub metadata: Metadata,
+    /// The active data files, ordered by path.
+    pub files: Vec<AddFile>,
+}
+
+#[derive(Default)]
+struct LogReplay {
+    metadata: Option<Metadata>,
+    protocol: Option<Protocol>,
+    files: BTreeMap<String, AddFile>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, action: Action) {
+        if let Some(add) = action.add {
+            self.files.insert(add.path.clone(), add);
+        }
+        if let Some(remove) = action.remove {
+            self.files.remove(&remove.path);
+        }
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(protocol) = action.protocol {
+            self.protocol = Some(protocol);
+        }
+    }
+
+    fn finish(self, version: u64) -> Result<DeltaSnapshot> {
+        let protocol = self.protocol.ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Cannot find the protocol of delta table")
+        })?;
+        if protocol.min_reader_version > MAX_READER_VERSION {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Delta reader version {} is not supported yet",
+                protocol.min_reader_version
+            )));
+        }
+        for feature in protocol.reader_features.iter().flatten() {
+            if !SUPPORTED_READER_FEATURES.contains(&feature.as_str()) {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Delta reader feature {feature} is not supported yet"
+                )));
+            }
+        }
+
+        let metadata = self.metadata.ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Cannot find the metadata of delta table")
+        })?;
+        match metadata.configuration.get(COLUMN_MAPPING_MODE) {
+            None | Some(None) => {}
+            Some(Some(mode)) if mode == "none" => {}
+            Some(Some(mode)) => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Delta column mapping mode {mode} is not supported yet"
+                )));
+            }
+        }
+
+        let files = self.files.into_values().collect::<Vec<_>>();
+        if let Some(file) = files.iter().find(|f| f.deletion_vector.is_some()) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Deletion vectors of delta table are not supported yet, found in {}",
+                file.path
+            )));
+        }
+        Ok(DeltaSnapshot {
+            version,
+            metadata,
+            files,
+        })
+    }
+}
+
+enum LogFile {
+    Commit(u64),
+    /// A checkpoint, or a part of a multi-part checkpoint.
+    Checkpoint {
+        version: u64,
+        parts: u64,
+    },
+}
+
+/// Parse `{version}.json`, `{version}.checkpoint.parquet` and
+/// `{version}.checkpoint.{part}.{parts}.parquet`, the versions are zero padded.
+fn parse_log_file(name: &str) -> Option<LogFile> {
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 {
+        return None;
+    }
+    let version = version.parse().ok()?;
+    match rest.split('.').collect::<Vec<_>>().as_slice() {
+        ["json"] => Some(LogFile::Commit(version)),
+        ["checkpoint", "parquet"] => Some(LogFile::Checkpoint { version, parts: 1 }),
+        ["checkpoint", _, parts, "parquet"] => Some(LogFile::Checkpoint {
+            version,
+            parts: parts.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+/// Load the latest snapshot of the table rooted at `op`.
+#[async_backtrace::framed]
+pub async fn load_snapshot(op: &Operator) -> Result<DeltaSnapshot> {
+    let mut commits = BTreeMap::new();
+    let mut checkpoints: BTreeMap<u64, (u64, Vec<String>)> = BTreeMap::new();
+    let mut lister = op.list(&format!("{DELTA_LOG_DIR}/")).await?;
+    while let Some(entry) = lister.try_next().await? {
+        match parse_log_file(entry.name()) {
+            Some(LogFile::Commit(version)) => {
+                commits.insert(version, entry.path().to_string());
+            }
+            Some(LogFile::Checkpoint { version, parts }) => {
+                let (_, paths) = checkpoints.entry(version).or_insert((parts, vec![]));
+                paths.push(entry.path().to_string());
+            }
+            None => {}
+        }
+    }
+
+    // Writers may crash when writing a multi-part checkpoint.
+    let checkpoint = checkpoints
+        .into_iter()
+        .rev()
+        .find(|(_, (parts, paths))| paths.len() as u64 == *parts);
+
+    let mut replay = LogReplay::default();
+    let mut version = None;
+    if let Some((checkpoint_version, (_, mut paths))) = checkpoint {
+        paths.sort();
+        for path in &paths {
+            for action in read_checkpoint(op, path).await? {
+                replay.apply(action);
+            }
+        }
+        version = Some(checkpoint_version);
+    }
+
+    for (commit_version, path) in commits {
+        if version.map_or(false, |v| commit_version <= v) {
+            continue;
+        }
+        let expected = version.map_or(0, |v| v + 1);
+        if commit_version != expected {
+            return Err(ErrorCode::ReadTableDataError(format!(
+                "Delta log is not contiguous, expect version {expected} but got {commit_version}"
+            )));
+        }
+        let bytes = op.read(&path).await?;
+        for line in bytes.split(|b| *b == b'\n') {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            replay.apply(serde_json::from_slice(line)?);
+        }
+        version = Some(commit_version);
+    }
+
+    match version {
+        Some(version) => replay.finish(version),
+        None => Err(ErrorCode::ReadTableDataError(format!(
+            "Cannot find any delta log in {DELTA_LOG_DIR}"
+        ))),
+    }
+}
+
+/// Read the actions of a checkpoint file.
+#[async_backtrace::framed]
+async fn read_checkpoint(op: &Operator, path: &str) -> Result<Vec<Action>> {
+    let reader = op.reader(path).await?;
+    let mut stream = ParquetRecordBatchStreamBuilder::new(reader)
+        .await
+        .map_err(parquet_error)?
+        .build()
+        .map_err(parquet_error)?;
+    let mut actions = vec![];
+    while let Some(batch) = stream.try_next().await.map_err(parquet_error)? {
+        let rows = record_batches_to_json_rows(&[&batch]).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot read delta checkpoint {path}: {e}"))
+        })?;
+        for row in rows {
+            actions.push(checkpoint_action(row)?);
+        }
+    }
+    Ok(actions)
+}
+
+/// A checkpoint row has a column for each kind of action, one of them is set.
+fn checkpoint_action(mut row: Map<String, Value>) -> Result<Action> {
+    // Tombstones are only kept for vacuum, the files they refer to are not
+    // in the checkpoint.
+    row.remove("remove");
+    // Unset actions may be converted to objects without their required keys.
+    for (kind, key) in [
+        ("add", "path"),
+        ("metaData", "schemaString"),
+        ("protocol", "minReaderVersion"),
+    ] {
+        if row
+            .get(kind)
+            .and_then(|action| action.get(key))
+            .map_or(true, Value::is_null)
+        {
+            row.remove(kind);
+        }
+    }
+    Ok(serde_json::from_value(Value::Object(row))?)
+}
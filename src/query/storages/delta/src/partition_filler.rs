@@ -0,0 +1,150 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize::read_decimal_with_size;
+use common_expression::serialize::uniform_date;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::Value;
+
+use crate::partition::DeltaPartInfo;
+
+/// Partition columns are not stored in the data files, their values are
+/// filled from the delta log.
+#[derive(Debug, Clone)]
+pub struct DeltaPartitionFiller {
+    /// The schema of the blocks to output.
+    output_schema: DataSchemaRef,
+}
+
+impl DeltaPartitionFiller {
+    pub fn create(output_schema: DataSchemaRef) -> Self {
+        DeltaPartitionFiller { output_schema }
+    }
+
+    /// Arrange the columns of `data_block` of `schema`, read from the data
+    /// file of `part`, as the output schema, with the partition columns filled.
+    pub fn fill_data(
+        &self,
+        data_block: DataBlock,
+        schema: &DataSchema,
+        part: &DeltaPartInfo,
+    ) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let mut columns = Vec::with_capacity(self.output_schema.num_fields());
+        for field in self.output_schema.fields() {
+            let data_type = field.data_type();
+            let partition_value = part
+                .partition_values
+                .iter()
+                .find(|(name, _)| name == field.name());
+            let value = match partition_value {
+                Some((_, value)) => {
+                    Value::Scalar(partition_value_to_scalar(value.as_deref(), data_type)?)
+                }
+                None => match schema.index_of(field.name()) {
+                    Ok(i) => {
+                        let entry = data_block.get_by_offset(i);
+                        if data_type.is_nullable() && !entry.data_type.is_nullable() {
+                            entry.value.clone().wrap_nullable(None)
+                        } else {
+                            entry.value.clone()
+                        }
+                    }
+                    // The column is added after the file was written.
+                    Err(_) => Value::Scalar(Scalar::Null),
+                },
+            };
+            columns.push(BlockEntry::new(data_type.clone(), value));
+        }
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
+
+/// Parse a partition value serialized in the delta log.
+///
+/// Both a missing value and an empty string stand for null.
+pub fn partition_value_to_scalar(value: Option<&str>, data_type: &DataType) -> Result<Scalar> {
+    let value = match value {
+        Some(value) if !value.is_empty() => value,
+        _ => return Ok(Scalar::Null),
+    };
+    let invalid = || {
+        ErrorCode::ReadTableDataError(format!(
+            "Invalid delta partition value {value} of type {data_type}"
+        ))
+    };
+    let scalar = match data_type {
+        DataType::Nullable(inner) => return partition_value_to_scalar(Some(value), inner),
+        DataType::Boolean => Scalar::Boolean(value.parse().map_err(|_| invalid())?),
+        DataType::String => Scalar::String(value.as_bytes().to_vec()),
+        DataType::Number(num_ty) => Scalar::Number(match num_ty {
+            NumberDataType::Int8 => NumberScalar::Int8(value.parse().map_err(|_| invalid())?),
+            NumberDataType::Int16 => NumberScalar::Int16(value.parse().map_err(|_| invalid())?),
+            NumberDataType::Int32 => NumberScalar::Int32(value.parse().map_err(|_| invalid())?),
+            NumberDataType::Int64 => NumberScalar::Int64(value.parse().map_err(|_| invalid())?),
+            NumberDataType::Float32 => {
+                NumberScalar::Float32(F32::from(value.parse::<f32>().map_err(|_| invalid())?))
+            }
+            NumberDataType::Float64 => {
+                NumberScalar::Float64(F64::from(value.parse::<f64>().map_err(|_| invalid())?))
+            }
+            _ => return Err(invalid()),
+        }),
+        DataType::Date => {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| invalid())?;
+            Scalar::Date(uniform_date(date))
+        }
+        // Written as `{year}-{month}-{day} {hour}:{minute}:{second}[.{microsecond}]`,
+        // or in ISO 8601 by some writers.
+        DataType::Timestamp => {
+            let micros = match NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f") {
+                Ok(ts) => ts.timestamp_micros(),
+                Err(_) => DateTime::parse_from_rfc3339(value)
+                    .map_err(|_| invalid())?
+                    .timestamp_micros(),
+            };
+            Scalar::Timestamp(micros)
+        }
+        DataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            let (n, n_read) = read_decimal_with_size::<i128>(value.as_bytes(), *size, true)
+                .map_err(|_| invalid())?;
+            if n_read != value.len() {
+                return Err(invalid());
+            }
+            Scalar::Decimal(DecimalScalar::Decimal128(n, *size))
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Delta partition column of type {data_type} is not supported yet"
+            )));
+        }
+    };
+    Ok(scalar)
+}
@@ -0,0 +1,100 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Data file pruning with the partition values kept in the delta log.
+//!
+//! The partition values of a file are turned into [`StatisticsOfColumns`]
+//! whose min and max are the same, so that the range pruner shared with the
+//! other storages can evaluate the filter.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnId;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::log::AddFile;
+use crate::partition_filler::partition_value_to_scalar;
+
+pub struct DeltaPartitionPruner {
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    /// The partition columns, (name, column id, type).
+    columns: Vec<(String, ColumnId, DataType)>,
+}
+
+impl DeltaPartitionPruner {
+    /// Create a pruner if the table is partitioned and there is a filter to prune with.
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        partition_columns: &[String],
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Option<Self>> {
+        let filter = match push_downs.as_ref().and_then(|p| p.filter.as_ref()) {
+            Some(filter) if !partition_columns.is_empty() => filter.as_expr(&BUILTIN_FUNCTIONS),
+            _ => return Ok(None),
+        };
+        let range_pruner = RangePrunerCreator::try_create(func_ctx, schema, Some(&filter))?;
+
+        let mut columns = Vec::with_capacity(partition_columns.len());
+        for name in partition_columns {
+            if let Ok(field) = schema.field_with_name(name) {
+                let column_ids = schema.leaf_columns_of(name);
+                if column_ids.len() == 1 {
+                    columns.push((name.clone(), column_ids[0], field.data_type().into()));
+                }
+            }
+        }
+
+        Ok(Some(Self {
+            range_pruner,
+            columns,
+        }))
+    }
+
+    /// Returns false if no row of `file` can match the filter.
+    pub fn should_keep(&self, file: &AddFile) -> bool {
+        let mut stats = StatisticsOfColumns::new();
+        for (name, column_id, data_type) in &self.columns {
+            let value = file.partition_values.get(name).cloned().flatten();
+            // Keep the file if the value can't be understood.
+            let scalar = match partition_value_to_scalar(value.as_deref(), data_type) {
+                Ok(scalar) => scalar,
+                Err(_) => continue,
+            };
+            let null_count = match scalar {
+                Scalar::Null if !data_type.is_nullable() => continue,
+                Scalar::Null => file.num_records().unwrap_or(1),
+                _ => 0,
+            };
+            stats.insert(*column_id, ColumnStatistics {
+                min: scalar.clone(),
+                max: scalar,
+                null_count,
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+        self.range_pruner.should_keep(&stats, None)
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A field of a delta struct type.
+#[derive(Deserialize, Clone, Debug)]
+pub struct StructField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: Value,
+    #[serde(default)]
+    pub nullable: bool,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+struct StructType {
+    fields: Vec<StructField>,
+}
+
+/// Convert the `schemaString` of delta metadata to the table schema exposed to databend.
+pub fn to_table_schema(schema_string: &str) -> Result<TableSchema> {
+    let schema: StructType = serde_json::from_str(schema_string)?;
+    let fields = schema
+        .fields
+        .iter()
+        .map(|f| {
+            let ty = to_table_type(&f.field_type, f.nullable)?;
+            Ok(TableField::new(&f.name, ty))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(TableSchema::new(fields))
+}
+
+fn to_table_type(ty: &Value, nullable: bool) -> Result<TableDataType> {
+    let unsupported = || ErrorCode::Unimplemented(format!("Delta type {ty} is not supported yet"));
+    let table_type = match ty {
+        Value::String(ty) => match ty.as_str() {
+            "boolean" => TableDataType::Boolean,
+            "byte" => TableDataType::Number(NumberDataType::Int8),
+            "short" => TableDataType::Number(NumberDataType::Int16),
+            "integer" => TableDataType::Number(NumberDataType::Int32),
+            "long" => TableDataType::Number(NumberDataType::Int64),
+            "float" => TableDataType::Number(NumberDataType::Float32),
+            "double" => TableDataType::Number(NumberDataType::Float64),
+            "date" => TableDataType::Date,
+            "timestamp" | "timestamp_ntz" => TableDataType::Timestamp,
+            "string" | "binary" => TableDataType::String,
+            ty if ty.starts_with("decimal(") => {
+                let (precision, scale) = parse_decimal(ty).ok_or_else(unsupported)?;
+                TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                    precision,
+                    scale,
+                }))
+            }
+            _ => return Err(unsupported()),
+        },
+        Value::Object(obj) => match obj.get("type").and_then(Value::as_str) {
+            Some("struct") => {
+                let fields: Vec<StructField> = obj
+                    .get("fields")
+                    .cloned()
+                    .map(serde_json::from_value)
+                    .transpose()
+                    .map_err(|_| unsupported())?
+                    .unwrap_or_default();
+                let mut fields_name = Vec::with_capacity(fields.len());
+                let mut fields_type = Vec::with_capacity(fields.len());
+                for f in fields {
+                    fields_type.push(to_table_type(&f.field_type, f.nullable)?);
+                    fields_name.push(f.name);
+                }
+                TableDataType::Tuple {
+                    fields_name,
+                    fields_type,
+                }
+            }
+            Some("array") => {
+                let element = obj.get("elementType").ok_or_else(unsupported)?;
+                let contains_null = obj
+                    .get("containsNull")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(true);
+                TableDataType::Array(Box::new(to_table_type(element, contains_null)?))
+            }
+            Some("map") => {
+                let key = obj.get("keyType").ok_or_else(unsupported)?;
+                let value = obj.get("valueType").ok_or_else(unsupported)?;
+                let value_contains_null = obj
+                    .get("valueContainsNull")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(true);
+                TableDataType::Map(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["key".to_string(), "value".to_string()],
+                    fields_type: vec![
+                        to_table_type(key, false)?,
+                        to_table_type(value, value_contains_null)?,
+                    ],
+                }))
+            }
+            _ => return Err(unsupported()),
+        },
+        _ => return Err(unsupported()),
+    };
+
+    if nullable {
+        Ok(table_type.wrap_nullable())
+    } else {
+        Ok(table_type)
+    }
+}
+
+/// Parse `decimal(p,s)` to `(p, s)`.
+fn parse_decimal(ty: &str) -> Option<(u8, u8)> {
+    let args = ty.strip_prefix("decimal(")?.strip_suffix(')')?;
+    let (precision, scale) = args.split_once(',')?;
+    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+}
@@ -0,0 +1,219 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+use common_storage::init_operator;
+use opendal::Operator;
+use percent_encoding::percent_decode_str;
+
+use crate::log::load_snapshot;
+use crate::partition::DeltaPartInfo;
+use crate::partition_filler::DeltaPartitionFiller;
+use crate::pruning::DeltaPartitionPruner;
+use crate::schema::to_table_schema;
+use crate::table_source::DeltaTableSource;
+
+/// A read-only delta lake table.
+///
+/// The schema is taken when the table is created, while the log is replayed
+/// on every scan to read the latest version.
+pub struct DeltaTable {
+    info: TableInfo,
+    op: Operator,
+}
+
+impl DeltaTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let op = match &info.meta.storage_params {
+            Some(sp) => init_operator(sp)?,
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Delta table {} has no location",
+                    info.name
+                )));
+            }
+        };
+        Ok(Box::new(Self { info, op }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "DELTA".to_string(),
+            comment: "DELTA Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// The schema of the latest version of the delta table at `sp`.
+    #[async_backtrace::framed]
+    pub async fn infer_schema(sp: &StorageParams) -> Result<TableSchema> {
+        let op = init_operator(sp)?;
+        let snapshot = load_snapshot(&op).await?;
+        to_table_schema(&snapshot.metadata.schema_string)
+    }
+
+    pub fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        // The schema of the data block `read_data` output.
+        let output_schema: Arc<DataSchema> = Arc::new(plan.schema().into());
+        let partition_filler = DeltaPartitionFiller::create(output_schema);
+
+        let mut source_builder = SourcePipeBuilder::create();
+        for _ in 0..std::cmp::max(1, max_threads) {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                DeltaTableSource::create(
+                    ctx.clone(),
+                    self.op.clone(),
+                    output,
+                    partition_filler.clone(),
+                )?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, ctx, push_downs))]
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let snapshot = load_snapshot(&self.op).await?;
+        let total_files = snapshot.files.len();
+
+        let pruner = DeltaPartitionPruner::try_create(
+            ctx.get_function_context()?,
+            &self.info.schema(),
+            &snapshot.metadata.partition_columns,
+            &push_downs,
+        )?;
+        let files = match pruner {
+            Some(pruner) => snapshot
+                .files
+                .into_iter()
+                .filter(|f| pruner.should_keep(f))
+                .collect(),
+            None => snapshot.files,
+        };
+
+        let (read_rows, read_bytes) = files.iter().fold((0, 0), |(rows, bytes), f| {
+            (
+                rows + f.num_records().unwrap_or_default() as usize,
+                bytes + f.size as usize,
+            )
+        });
+        let statistics =
+            PartStatistics::new_estimated(None, read_rows, read_bytes, files.len(), total_files);
+
+        let partitions = files
+            .into_iter()
+            .map(|f| {
+                let mut partition_values = f.partition_values.into_iter().collect::<Vec<_>>();
+                partition_values.sort();
+                Ok(Arc::new(Box::new(DeltaPartInfo {
+                    path: data_file_path(&f.path)?,
+                    size: f.size as u64,
+                    partition_values,
+                }) as Box<dyn PartInfo>))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Seq, partitions),
+        ))
+    }
+}
+
+/// The path of a data file relative to the table root.
+fn data_file_path(path: &str) -> Result<String> {
+    if path.contains("://") {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Delta data file of absolute path {path} is not supported yet"
+        )));
+    }
+    Ok(percent_decode_str(path).decode_utf8()?.into_owned())
+}
+
+#[async_trait]
+impl Table for DeltaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn name(&self) -> &str {
+        &self.get_table_info().name
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+}
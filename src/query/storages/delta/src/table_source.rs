@@ -0,0 +1,190 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use common_base::base::Progress;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use futures::StreamExt;
+use opendal::Operator;
+use opendal::Reader;
+use parquet::arrow::async_reader::ParquetRecordBatchStream;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+
+use crate::partition::DeltaPartInfo;
+use crate::partition_filler::DeltaPartitionFiller;
+
+pub struct DeltaTableSource {
+    state: State,
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    _scan_progress: Arc<Progress>,
+    output: Arc<OutputPort>,
+
+    /// Arrange the blocks read as the output schema.
+    partition_filler: DeltaPartitionFiller,
+}
+
+enum State {
+    /// Read parquet file meta data
+    ReadMeta(Option<PartInfoPtr>),
+
+    /// Read data from parquet file.
+    ///
+    /// `Option<RecordBatch>` means there are data blocks ready for push.
+    ReadData(
+        PartInfoPtr,
+        ParquetRecordBatchStream<Reader>,
+        Option<RecordBatch>,
+    ),
+
+    Finish,
+}
+
+impl DeltaTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        output: Arc<OutputPort>,
+        partition_filler: DeltaPartitionFiller,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        Ok(ProcessorPtr::create(Box::new(DeltaTableSource {
+            ctx,
+            dal,
+            output,
+            _scan_progress: scan_progress,
+            state: State::ReadMeta(None),
+            partition_filler,
+        })))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for DeltaTableSource {
+    fn name(&self) -> String {
+        "DeltaEngineSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if matches!(self.state, State::ReadMeta(None)) {
+            match self.ctx.get_partition() {
+                None => self.state = State::Finish,
+                Some(part_info) => {
+                    self.state = State::ReadMeta(Some(part_info));
+                }
+            }
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if matches!(self.state, State::ReadData(_, _, _)) {
+            if let State::ReadData(part, ps, mut data) =
+                std::mem::replace(&mut self.state, State::Finish)
+            {
+                if let Some(arrow_block) = data.take() {
+                    let (data_block, schema) =
+                        DataBlock::from_record_batch(&arrow_block).map_err(|err| {
+                            ErrorCode::ReadTableDataError(format!(
+                                "Cannot convert arrow record batch to data block: {err:?}"
+                            ))
+                        })?;
+                    let data_block = self.partition_filler.fill_data(
+                        data_block,
+                        &schema,
+                        DeltaPartInfo::from_part(&part)?,
+                    )?;
+                    if !data_block.is_empty() {
+                        self.output.push_data(Ok(data_block));
+                    }
+                }
+
+                // Let's fetch more data.
+                self.state = State::ReadData(part, ps, None);
+
+                return Ok(Event::Async);
+            }
+        }
+
+        match self.state {
+            State::Finish => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            State::ReadMeta(_) => Ok(Event::Async),
+            State::ReadData(_, _, _) => Ok(Event::Async),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        Err(ErrorCode::Internal(
+            "It's a bug for DeltaTableSource to go into Event::Sync.",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::ReadMeta(Some(part)) => {
+                let delta_part = DeltaPartInfo::from_part(&part)?;
+                let r = self.dal.reader(&delta_part.path).await?;
+                let s = ParquetRecordBatchStreamBuilder::new(r)
+                    .await
+                    .map_err(parquet_error)?
+                    .build()
+                    .map_err(parquet_error)?;
+                self.state = State::ReadData(part, s, None);
+                Ok(())
+            }
+            State::ReadData(part, mut stream, None) => match stream.next().await {
+                None => {
+                    self.state = State::ReadMeta(None);
+                    Ok(())
+                }
+                Some(data) => {
+                    let data = data.map_err(parquet_error)?;
+                    self.state = State::ReadData(part, stream, Some(data));
+                    Ok(())
+                }
+            },
+            _ => Err(ErrorCode::Internal(
+                "It's a bug for DeltaTableSource to async_process current state.",
+            )),
+        }
+    }
+}
+
+pub(crate) fn parquet_error(err: parquet::errors::ParquetError) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("Cannot read delta parquet file: {err}"))
+}
@@ -28,9 +28,12 @@ use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use common_meta_app::schema::TableIndex;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
+use storages_common_index::NgramArgs;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
@@ -110,6 +113,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -117,6 +121,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            ngram_args,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -142,11 +147,56 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &str,
+        index: &TableIndex,
+    ) -> Result<Option<Self>> {
+        // columns dropped after the index is created are skipped.
+        let columns = index
+            .column_ids
+            .iter()
+            .filter_map(|column_id| {
+                source_schema
+                    .fields()
+                    .iter()
+                    .position(|f| f.column_id() == *column_id)
+                    .map(|offset| (offset, *column_id))
+            })
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return Ok(None);
+        }
+
+        let index_block = InvertedIndex::build_index_block(block, &columns)?;
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+        blocks_to_parquet(
+            &InvertedIndex::schema(),
+            vec![index_block],
+            &mut data,
+            TableCompression::Zstd,
+        )?;
+        let location = TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+            block_location,
+            &index.version,
+        );
+        Ok(Some(Self { data, location }))
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub inverted_index_states: Vec<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -157,6 +207,8 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_args: Vec<NgramArgs>,
+    pub inverted_indexes: Vec<TableIndex>,
 }
 
 impl BlockBuilder {
@@ -172,11 +224,24 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            &self.ngram_args,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let mut inverted_index_states = Vec::with_capacity(self.inverted_indexes.len());
+        for index in &self.inverted_indexes {
+            if let Some(state) = InvertedIndexState::try_create(
+                &self.source_schema,
+                &data_block,
+                &block_location.0,
+                index,
+            )? {
+                inverted_index_states.push(state);
+            }
+        }
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -212,6 +277,7 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            inverted_index_states,
         };
         Ok(serialized)
     }
@@ -27,6 +27,7 @@ pub mod io;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
+mod stream_table;
 pub mod table_functions;
 
 mod metrics;
@@ -42,6 +43,7 @@ pub use fuse_table::FuseStorageFormat;
 pub use fuse_table::FuseTable;
 pub use io::MergeIOReadResult;
 pub use pruning::SegmentLocation;
+pub use stream_table::*;
 
 mod sessions {
     pub use common_catalog::table_context::TableContext;
@@ -0,0 +1,330 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::block_id_in_segment;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::InternalColumnMeta;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+use siphasher::sip128;
+use siphasher::sip128::Hasher128;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::SegmentsIO;
+use crate::io::SnapshotsIO;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Read the rows changed in the blocks of `plan`, see [`FuseTable::changed_block_metas`].
+    ///
+    /// The whole blocks are read and diffed row by row: a row both removed and added, e.g. kept
+    /// by a block rewrite, is not a change. The id of a changed row is derived from its values,
+    /// so it is kept by the rewrites of the row's block.
+    pub fn do_read_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let mut changes_plan = plan.clone();
+        changes_plan.output_schema = self.schema();
+        changes_plan.push_downs = None;
+        changes_plan.query_internal_columns = true;
+        changes_plan.data_mask_policy = None;
+        self.do_read_data(ctx.clone(), &changes_plan, pipeline)?;
+
+        // The rows removed and added may be read by different sources.
+        pipeline.resize(1)?;
+        let query_internal_columns = plan.query_internal_columns;
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                TransformChanges {
+                    query_internal_columns,
+                    blocks: vec![],
+                },
+            )))
+        })?;
+
+        self.apply_data_mask_policy_if_needed(ctx, plan, pipeline)
+    }
+
+    /// Get the blocks changed between the snapshot at `base_location` and the latest snapshot.
+    ///
+    /// The blocks only referenced by the latest snapshot are added ones, the blocks only
+    /// referenced by the base snapshot are removed ones, which are marked by `is_deleted`.
+    ///
+    /// A block rewritten by DELETE, UPDATE, REPLACE or compaction is a removed block plus an
+    /// added block, the rows it keeps unchanged are dropped by [`FuseTable::do_read_changes`].
+    #[async_backtrace::framed]
+    pub async fn changed_block_metas(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_location: Option<String>,
+    ) -> Result<Vec<(Option<BlockMetaIndex>, Arc<BlockMeta>)>> {
+        let latest_location = self.snapshot_loc().await?;
+        if latest_location == base_location {
+            return Ok(vec![]);
+        }
+
+        let latest = self.read_table_snapshot().await?;
+        let base = match &base_location {
+            Some(location) => {
+                let (snapshot, _) =
+                    SnapshotsIO::read_snapshot(location.clone(), self.get_operator()).await?;
+                Some(snapshot)
+            }
+            None => None,
+        };
+
+        // The segments referenced by both snapshots have no changes.
+        let latest_segments: HashSet<&Location> =
+            latest.iter().flat_map(|s| s.segments.iter()).collect();
+        let base_segments: HashSet<&Location> =
+            base.iter().flat_map(|s| s.segments.iter()).collect();
+
+        let mut added = self
+            .blocks_of_segments(
+                ctx.clone(),
+                latest.as_ref(),
+                latest_location,
+                &base_segments,
+                false,
+            )
+            .await?;
+        let mut removed = self
+            .blocks_of_segments(ctx, base.as_ref(), base_location, &latest_segments, true)
+            .await?;
+
+        // The blocks moved into other segments (e.g. by segment compaction) have no changes either.
+        let added_blocks: HashSet<String> = added
+            .iter()
+            .map(|(_, block)| block.location.0.clone())
+            .collect();
+        let removed_blocks: HashSet<String> = removed
+            .iter()
+            .map(|(_, block)| block.location.0.clone())
+            .collect();
+        added.retain(|(_, block)| !removed_blocks.contains(&block.location.0));
+        removed.retain(|(_, block)| !added_blocks.contains(&block.location.0));
+
+        added.extend(removed);
+        Ok(added)
+    }
+
+    #[async_backtrace::framed]
+    async fn blocks_of_segments(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot: Option<&Arc<TableSnapshot>>,
+        snapshot_location: Option<String>,
+        excluded_segments: &HashSet<&Location>,
+        is_deleted: bool,
+    ) -> Result<Vec<(Option<BlockMetaIndex>, Arc<BlockMeta>)>> {
+        let snapshot = match snapshot {
+            Some(snapshot) => snapshot,
+            None => return Ok(vec![]),
+        };
+
+        let segments = snapshot
+            .segments
+            .iter()
+            .enumerate()
+            .filter(|(_, location)| !excluded_segments.contains(location))
+            .map(|(idx, location)| (idx, location.clone()))
+            .collect::<Vec<_>>();
+        let locations = segments
+            .iter()
+            .map(|(_, location)| location.clone())
+            .collect::<Vec<_>>();
+
+        let segments_io =
+            SegmentsIO::create(ctx, self.get_operator(), Arc::new(snapshot.schema.clone()));
+        let segment_infos = segments_io
+            .read_segments::<SegmentInfo>(&locations, true)
+            .await?;
+
+        let mut block_metas = vec![];
+        for ((segment_idx, segment_location), segment_info) in
+            segments.into_iter().zip(segment_infos.into_iter())
+        {
+            let segment_info = segment_info?;
+            let block_num = segment_info.blocks.len();
+            for (block_idx, block_meta) in segment_info.blocks.iter().enumerate() {
+                let block_meta_index = BlockMetaIndex {
+                    segment_idx,
+                    block_idx,
+                    range: None,
+                    page_size: block_meta.page_size() as usize,
+                    block_id: block_id_in_segment(block_num, block_idx),
+                    block_location: block_meta.location.0.clone(),
+                    segment_location: segment_location.0.clone(),
+                    snapshot_location: snapshot_location.clone(),
+                    is_deleted,
+                };
+                block_metas.push((Some(block_meta_index), block_meta.clone()));
+            }
+        }
+        Ok(block_metas)
+    }
+}
+
+struct TransformChanges {
+    query_internal_columns: bool,
+    blocks: Vec<(InternalColumnMeta, DataBlock)>,
+}
+
+impl AccumulatingTransform for TransformChanges {
+    const NAME: &'static str = "TransformChanges";
+
+    fn transform(&mut self, mut data: DataBlock) -> Result<Vec<DataBlock>> {
+        let meta = data
+            .take_meta()
+            .and_then(InternalColumnMeta::downcast_from)
+            .ok_or_else(|| ErrorCode::Internal("The changes must be read with their block meta"))?;
+        self.blocks.push((meta, data));
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, _output: bool) -> Result<Vec<DataBlock>> {
+        let blocks = std::mem::take(&mut self.blocks);
+        let columns = blocks
+            .iter()
+            .map(|(_, block)| {
+                block
+                    .columns()
+                    .iter()
+                    .map(|entry| {
+                        entry
+                            .value
+                            .convert_to_full_column(&entry.data_type, block.num_rows())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let hashes = blocks
+            .iter()
+            .zip(columns.iter())
+            .map(|((_, block), columns)| {
+                (0..block.num_rows())
+                    .map(|row| row_hash(columns, row))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Bucket the added rows by hash, then drop every removed row with an equal added row.
+        let mut added: HashMap<u128, Vec<(usize, usize)>> = HashMap::new();
+        for (idx, (meta, _)) in blocks.iter().enumerate() {
+            if !meta.is_deleted {
+                for (row, hash) in hashes[idx].iter().enumerate() {
+                    added.entry(*hash).or_default().push((idx, row));
+                }
+            }
+        }
+        let mut dropped = blocks
+            .iter()
+            .map(|(_, block)| vec![false; block.num_rows()])
+            .collect::<Vec<_>>();
+        for (idx, (meta, _)) in blocks.iter().enumerate() {
+            if !meta.is_deleted {
+                continue;
+            }
+            for (row, hash) in hashes[idx].iter().enumerate() {
+                if let Some(candidates) = added.get_mut(hash) {
+                    let matched = candidates.iter().position(|(added_idx, added_row)| {
+                        rows_equal(&columns[idx], row, &columns[*added_idx], *added_row)
+                    });
+                    if let Some(pos) = matched {
+                        let (added_idx, added_row) = candidates.swap_remove(pos);
+                        dropped[added_idx][added_row] = true;
+                        dropped[idx][row] = true;
+                    }
+                }
+            }
+        }
+
+        // Equal rows changed by the same action get ids told apart by their occurrence.
+        let mut occurrences: HashMap<(bool, u128), usize> = HashMap::new();
+        let mut output = Vec::with_capacity(blocks.len());
+        for (idx, (mut meta, block)) in blocks.into_iter().enumerate() {
+            let kept = (0..block.num_rows())
+                .filter(|row| !dropped[idx][*row])
+                .collect::<Vec<_>>();
+            if kept.is_empty() {
+                continue;
+            }
+
+            let row_ids = kept
+                .iter()
+                .map(|row| {
+                    let hash = hashes[idx][*row];
+                    let occurrence = occurrences.entry((meta.is_deleted, hash)).or_default();
+                    *occurrence += 1;
+                    format!("{:032x}{:06x}", hash, *occurrence - 1)
+                })
+                .collect::<Vec<_>>();
+            meta.offsets = Some(match &meta.offsets {
+                Some(offsets) => kept.iter().map(|row| offsets[*row]).collect(),
+                None => kept.clone(),
+            });
+            meta.change_row_ids = Some(row_ids);
+
+            let indices = kept.iter().map(|row| *row as u32).collect::<Vec<_>>();
+            let block = block.take(&indices)?;
+            if self.query_internal_columns {
+                output.push(block.add_meta(Some(Box::new(meta)))?);
+            } else {
+                output.push(block);
+            }
+        }
+        Ok(output)
+    }
+}
+
+fn row_hash(columns: &[Column], row: usize) -> u128 {
+    let mut sip = sip128::SipHasher24::new();
+    for column in columns {
+        let value = column.index(row).unwrap();
+        // `ScalarRef::Null` hashes nothing, so is a NULL told apart by a flag.
+        sip.write_u8(matches!(value, ScalarRef::Null) as u8);
+        value.hash(&mut sip);
+    }
+    sip.finish128().as_u128()
+}
+
+fn rows_equal(left: &[Column], left_row: usize, right: &[Column], right_row: usize) -> bool {
+    left.iter()
+        .zip(right.iter())
+        .all(|(left, right)| left.index(left_row) == right.index(right_row))
+}
@@ -27,6 +27,7 @@ use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::MatchSeq;
@@ -212,10 +213,41 @@ impl FuseTable {
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
         };
 
-        // 3. let's roll
-        let reply = catalog.update_table_meta(table_info, req).await;
+        // 3. inside a transaction, the update is buffered and committed with the others
+        // by `COMMIT`, or dropped by `ROLLBACK`.
+        // The offsets of the streams consumed by the statement are advanced along with
+        // the table.
+        let stream_updates = ctx.get_stream_updates();
+        if ctx.txn_mgr().lock().is_active() {
+            info!(
+                "buffering table meta update of table {}, {} in transaction",
+                table_info.desc, table_info.ident
+            );
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            txn_mgr.update_table_meta(table_info.catalog(), req);
+            for stream_update in stream_updates {
+                txn_mgr.update_table_meta(table_info.catalog(), stream_update);
+            }
+            ctx.clear_stream_updates();
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        // 4. let's roll
+        let reply = if stream_updates.is_empty() {
+            catalog.update_table_meta(table_info, req).await.map(|_| ())
+        } else {
+            let mut update_table_metas = vec![req];
+            update_table_metas.extend(stream_updates);
+            catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+                .map(|_| ())
+        };
         match reply {
             Ok(_) => {
+                ctx.clear_stream_updates();
                 TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
                 // try keep a hit file of last snapshot
                 Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
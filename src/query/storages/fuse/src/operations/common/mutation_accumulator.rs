@@ -85,6 +85,7 @@ pub enum MutationKind {
     Replace,
     Recluster,
     Insert,
+    MergeInto,
 }
 
 pub struct MutationAccumulator {
@@ -284,7 +284,8 @@ where F: SnapshotGenerator + Send + 'static
                 .await
                 {
                     Ok(_) => {
-                        if self.transient {
+                        // The historical data is still referenced until the transaction commits.
+                        if self.transient && !self.ctx.txn_mgr().lock().is_active() {
                             // Removes historical data, if table is transient
                             let latest = self.table.refresh(self.ctx.as_ref()).await?;
                             let tbl = FuseTable::try_from_table(latest.as_ref())?;
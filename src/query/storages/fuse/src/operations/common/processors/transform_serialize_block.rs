@@ -27,6 +27,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
+use storages_common_index::NgramArgs;
 
 use crate::io::write_data;
 use crate::io::BlockBuilder;
@@ -83,6 +84,12 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_args = table
+            .ngram_index_cols
+            .ngram_index_fields(source_schema.clone())?
+            .into_iter()
+            .map(|(index, field, gram_size)| NgramArgs::new(index, field, gram_size))
+            .collect();
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -90,6 +97,8 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_args,
+            inverted_indexes: table.table_info.meta.indexes.values().cloned().collect(),
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -271,6 +280,10 @@ impl Processor for TransformSerializeBlock {
                     }
                 }
 
+                for index_state in serialized.inverted_index_states {
+                    write_data(index_state.data, &self.dal, &index_state.location).await?;
+                }
+
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::Replaced {
                         index,
@@ -301,6 +301,8 @@ impl FuseTable {
             self.table_info.schema(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
+            &self.table_info.meta.indexes,
         )?;
 
         let segment_locations = create_segment_location_vector(segment_locations, None);
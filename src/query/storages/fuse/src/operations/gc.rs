@@ -26,6 +26,7 @@ use storages_common_cache::CacheAccessor;
 use storages_common_cache::LoadParams;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::InvertedIndexTerms;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -444,12 +445,20 @@ impl FuseTable {
         blooms_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
-        // 1. Try to purge block file chunks.
+        // 1. Try to purge block file chunks, and the inverted index files of the blocks.
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
             counter.blocks += blocks_count;
+            let inverted_indexes_to_be_purged = self.inverted_index_locations(&blocks_to_be_purged);
             self.try_purge_location_files(ctx.clone(), blocks_to_be_purged)
                 .await?;
+            if !inverted_indexes_to_be_purged.is_empty() {
+                self.try_purge_location_files_and_cache::<InvertedIndexTerms, _, _>(
+                    ctx.clone(),
+                    inverted_indexes_to_be_purged,
+                )
+                .await?;
+            }
         }
 
         // 2. Try to purge bloom index file chunks.
@@ -521,6 +530,23 @@ impl FuseTable {
         Ok(())
     }
 
+    // The inverted index files of the blocks, one for each index of the table. The blocks
+    // written before an index is created have no file of it, removing them is a no-op.
+    fn inverted_index_locations(&self, block_locations: &HashSet<String>) -> HashSet<String> {
+        let mut locations = HashSet::new();
+        for index in self.table_info.meta.indexes.values() {
+            for block_location in block_locations {
+                locations.insert(
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                        block_location,
+                        &index.version,
+                    ),
+                );
+            }
+        }
+        locations
+    }
+
     // Purge file by location chunks.
     #[async_backtrace::framed]
     pub async fn try_purge_location_files(
@@ -0,0 +1,115 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIndex;
+use storages_common_table_meta::meta::SegmentInfo;
+
+use crate::io::write_data;
+use crate::io::InvertedIndexState;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Write the files of the inverted index `index` for all the blocks of the current snapshot.
+    ///
+    /// Blocks appended later write their index files along with the block, so once the
+    /// index is committed to the table meta, every block of the table has its index files.
+    #[async_backtrace::framed]
+    pub async fn build_inverted_index(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        index: &TableIndex,
+    ) -> Result<()> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let schema = self.schema();
+        let field_indices = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| index.column_ids.contains(&f.column_id()))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if field_indices.is_empty() {
+            return Ok(());
+        }
+        let projected_schema = Arc::new(schema.project(&field_indices));
+        let block_reader =
+            self.create_block_reader(Projection::Columns(field_indices), false, ctx.clone())?;
+        let block_reader = block_reader.as_ref();
+        let storage_format = self.storage_format;
+        let settings = &ReadSettings::from_ctx(ctx)?;
+
+        let start = Instant::now();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), schema);
+        let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
+        let number_segments = snapshot.segments.len();
+        let mut read_segment_count = 0;
+        for chunk in snapshot.segments.chunks(max_io_requests) {
+            let segments = segments_io
+                .read_segments::<Arc<SegmentInfo>>(chunk, false)
+                .await?;
+            for segment in segments {
+                let segment = segment?;
+                for blocks in segment.blocks.chunks(max_io_requests) {
+                    // build the index files of the blocks in parallel.
+                    let mut task_futures = Vec::with_capacity(blocks.len());
+                    for block in blocks {
+                        let projected_schema = projected_schema.clone();
+                        task_futures.push(async move {
+                            let data_block = block_reader
+                                .read_by_meta(settings, block.as_ref(), &storage_format)
+                                .await?;
+                            if let Some(state) = InvertedIndexState::try_create(
+                                &projected_schema,
+                                &data_block,
+                                &block.location.0,
+                                index,
+                            )? {
+                                write_data(state.data, &self.operator, &state.location).await?;
+                            }
+                            Ok::<_, ErrorCode>(())
+                        });
+                    }
+                    futures::future::try_join_all(task_futures).await?;
+                }
+            }
+
+            // Status.
+            {
+                read_segment_count += chunk.len();
+                let status = format!(
+                    "create inverted index: processed segment files:{}/{}, cost:{} sec",
+                    read_segment_count,
+                    number_segments,
+                    start.elapsed().as_secs()
+                );
+                ctx.set_status_info(&status);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::plan::block_id_in_segment;
+use common_catalog::plan::block_idx_in_segment;
+use common_catalog::plan::split_prefix;
+use common_catalog::plan::split_row_id;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PruningStatistics;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::ROW_ID_COL_NAME;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_sources::BlocksSource;
+use common_pipeline_transforms::processors::transforms::create_dummy_item;
+use parking_lot::Mutex;
+use storages_common_cache::LoadParams;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::MetaReaders;
+use crate::operations::common::MutationKind;
+use crate::operations::common::TransformSerializeBlock;
+use crate::operations::mutation::Mutation;
+use crate::operations::mutation::MutationPartInfo;
+use crate::pipelines::Pipeline;
+use crate::FuseTable;
+
+impl FuseTable {
+    // The pipeline going to be constructed
+    //
+    //  ┌─────────────────┐        ┌─────────────────┐         ┌─────────────────┐
+    //  │ MutationSource1 ├───────►│ SerializeBlock1 ├────────►│  DummyTransform ├─────────┐
+    //  └─────────────────┘        └─────────────────┘         └─────────────────┘         │
+    //  ┌─────────────────┐        ┌─────────────────┐         ┌─────────────────┐         │
+    //  │ MutationSourceN ├───────►│ SerializeBlockN ├────────►│  DummyTransform ├─────────┤
+    //  └─────────────────┘        └─────────────────┘         └─────────────────┘         │
+    //                                                         ┌─────────────────┐         ├─────┐
+    //                                                         │   BlocksSource  ├─────────┘     │
+    //                                                         └─────────────────┘               │
+    //                                                                                           │
+    //       ┌───────────────────────────────────────────────────────────────────────────────────┘
+    //       │
+    //       │      ┌───────────────────┐       ┌───────────────────────┐         ┌───────────────────┐
+    //       └─────►│ResizeProcessor(1) ├──────►│TableMutationAggregator├────────►│     CommitSink    │
+    //              └───────────────────┘       └───────────────────────┘         └───────────────────┘
+    //
+    // `deleted_row_ids` are the `_row_id`s of the base snapshot, which are removed by MERGE INTO
+    // (the updated rows are deleted, and the new versions are appended). `append_logs` are the
+    // mutation logs of the segments written by the append pipeline of MERGE INTO.
+    #[async_backtrace::framed]
+    pub async fn build_merge_into_pipeline(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        deleted_row_ids: Vec<u64>,
+        append_logs: Vec<DataBlock>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let base_snapshot = self.read_table_snapshot().await?.unwrap_or_else(|| {
+            Arc::new(TableSnapshot::new_empty_snapshot(schema.as_ref().clone()))
+        });
+
+        // 1. the deletion of the matched rows, the blocks are filtered by `_row_id`
+        if !deleted_row_ids.is_empty() {
+            let parts = self
+                .merge_into_mutation_parts(&base_snapshot, &deleted_row_ids)
+                .await?;
+            let filter = Self::row_id_filter(deleted_row_ids)?;
+            self.add_deletion_source(
+                ctx.clone(),
+                &filter,
+                self.all_column_indices(),
+                true,
+                pipeline,
+                parts,
+            )?;
+
+            let cluster_stats_gen =
+                self.get_cluster_stats_gen(ctx.clone(), 0, self.get_block_thresholds())?;
+            pipeline.add_transform(|input, output| {
+                let proc = TransformSerializeBlock::try_create(
+                    ctx.clone(),
+                    input,
+                    output,
+                    self,
+                    cluster_stats_gen.clone(),
+                )?;
+                proc.into_processor()
+            })?;
+        }
+
+        // 2. the mutation logs of the appended segments
+        if !append_logs.is_empty() {
+            let append_output = OutputPort::create();
+            let append_source = BlocksSource::create(
+                ctx.clone(),
+                append_output.clone(),
+                Arc::new(Mutex::new(append_logs.into())),
+            )?;
+            let output_len = pipeline.output_len();
+            let mut items = Vec::with_capacity(output_len + 1);
+            items.extend((0..output_len).map(|_| create_dummy_item()));
+            items.push(PipeItem::create(append_source, vec![], vec![append_output]));
+            pipeline.add_pipe(Pipe::create(output_len, output_len + 1, items));
+        }
+
+        // 3. connect with mutation pipes, the TableMutationAggregator, then CommitSink
+        self.chain_mutation_pipes(&ctx, pipeline, base_snapshot, MutationKind::MergeInto)
+    }
+
+    // The mutation parts of the blocks which contain the rows identified by `row_ids`.
+    #[async_backtrace::framed]
+    async fn merge_into_mutation_parts(
+        &self,
+        base_snapshot: &TableSnapshot,
+        row_ids: &[u64],
+    ) -> Result<Partitions> {
+        // number of the matched rows, grouped by the row id prefix (segment id, block id)
+        let mut matched_rows: BTreeMap<u64, usize> = BTreeMap::new();
+        for row_id in row_ids {
+            let (prefix, _) = split_row_id(*row_id);
+            *matched_rows.entry(prefix).or_default() += 1;
+        }
+
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.operator.clone(), self.table_info.schema());
+        let mut block_metas = Vec::with_capacity(matched_rows.len());
+        let mut whole_block_mutations = Vec::with_capacity(matched_rows.len());
+        for (prefix, num_matched) in matched_rows {
+            let (segment_id, block_id) = split_prefix(prefix);
+            let segment_idx = segment_id as usize;
+            let (location, ver) = base_snapshot
+                .segments
+                .get(segment_idx)
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "unexpected, segment (idx {}) not found, during applying merge into",
+                        segment_idx
+                    ))
+                })?
+                .clone();
+            let compact_segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.clone(),
+                    len_hint: None,
+                    ver,
+                    put_cache: true,
+                })
+                .await?;
+            let blocks = compact_segment_info.block_metas()?;
+            let block_idx = block_idx_in_segment(blocks.len(), block_id as usize);
+            let block_meta = blocks[block_idx].clone();
+
+            whole_block_mutations.push(num_matched == block_meta.row_count as usize);
+            block_metas.push((
+                Some(BlockMetaIndex {
+                    segment_idx,
+                    block_idx,
+                    range: None,
+                    page_size: block_meta.page_size() as usize,
+                    block_id: block_id_in_segment(blocks.len(), block_idx),
+                    block_location: block_meta.location.0.clone(),
+                    segment_location: location,
+                    snapshot_location: None,
+                    is_deleted: false,
+                }),
+                block_meta,
+            ));
+        }
+
+        let (_, inner_parts) = self.read_partitions_with_metas(
+            self.table_info.schema(),
+            None,
+            &block_metas,
+            None,
+            base_snapshot.summary.block_count as usize,
+            PruningStatistics::default(),
+        )?;
+
+        Ok(Partitions::create_nolazy(
+            PartitionsShuffleKind::Mod,
+            block_metas
+                .into_iter()
+                .zip(whole_block_mutations)
+                .zip(inner_parts.partitions)
+                .map(
+                    |(((block_meta_index, block_meta), whole_block_mutation), part)| {
+                        let part_info_ptr: PartInfoPtr = Arc::new(Box::new(
+                            Mutation::MutationPartInfo(MutationPartInfo::create(
+                                block_meta_index.unwrap(),
+                                block_meta.cluster_stats.clone(),
+                                part,
+                                whole_block_mutation,
+                            )),
+                        ));
+                        part_info_ptr
+                    },
+                )
+                .collect(),
+        ))
+    }
+
+    // `contains([row ids], _row_id)`, the same filter as DELETE with subquery.
+    fn row_id_filter(row_ids: Vec<u64>) -> Result<RemoteExpr<String>> {
+        let row_id_type = DataType::Number(NumberDataType::UInt64);
+        let filter = check_function(
+            None,
+            "contains",
+            &[],
+            &[
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Array(UInt64Type::from_data(row_ids)),
+                    data_type: DataType::Array(Box::new(row_id_type.clone())),
+                },
+                Expr::ColumnRef {
+                    span: None,
+                    id: ROW_ID_COL_NAME.to_string(),
+                    data_type: row_id_type,
+                    display_name: ROW_ID_COL_NAME.to_string(),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        Ok(filter.as_remote_expr())
+    }
+}
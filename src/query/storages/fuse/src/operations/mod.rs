@@ -15,11 +15,14 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod changes;
 mod commit;
 pub mod common;
 mod compact;
 mod delete;
 mod gc;
+mod inverted_index;
+mod merge;
 mod mutation;
 mod navigate;
 mod read;
@@ -195,6 +195,8 @@ impl Processor for MutationSource {
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: "".to_string(),
                             offsets: None,
+                            is_deleted: false,
+                            change_row_ids: None,
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
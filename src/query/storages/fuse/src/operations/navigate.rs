@@ -154,6 +154,10 @@ impl FuseTable {
 
         assert!(root_snapshot.timestamp.is_some());
         let mut time_point = root_snapshot.timestamp.unwrap() - retention;
+        // Keep the offset snapshots of the streams on this table.
+        if let Some(offset) = self.oldest_stream_offset(ctx).await? {
+            time_point = std::cmp::min(offset, time_point);
+        }
 
         let (location, files) = match instant {
             Some(NavigationPoint::TimePoint(point)) => {
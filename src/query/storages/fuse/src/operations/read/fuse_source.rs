@@ -289,6 +289,8 @@ pub(crate) fn fill_internal_column_meta(
         segment_location: block_meta.segment_location.clone(),
         snapshot_location: block_meta.snapshot_location.as_ref().unwrap().clone(),
         offsets,
+        is_deleted: block_meta.is_deleted,
+        change_row_ids: None,
     };
 
     let meta: Option<BlockMetaInfoPtr> = Some(Box::new(internal_column_meta));
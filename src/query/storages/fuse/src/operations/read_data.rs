@@ -81,7 +81,7 @@ impl FuseTable {
         }
     }
 
-    fn apply_data_mask_policy_if_needed(
+    pub(crate) fn apply_data_mask_policy_if_needed(
         &self,
         ctx: Arc<dyn TableContext>,
         plan: &DataSourcePlan,
@@ -172,6 +172,8 @@ impl FuseTable {
                 table_info.schema(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
+                &table_info.meta.indexes,
             )?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
@@ -184,6 +186,8 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
+                &table_info.meta.indexes,
             )?
         };
 
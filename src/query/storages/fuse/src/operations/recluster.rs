@@ -77,6 +77,8 @@ impl FuseTable {
             schema,
             &push_downs,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
+            &self.table_info.meta.indexes,
         )?;
         let block_metas = pruner.read_pruning(segment_locations).await?;
 
@@ -196,6 +198,7 @@ impl FuseTable {
             final_block_size,
             None,
             false,
+            None,
         )?;
 
         assert_eq!(pipeline.output_len(), 1);
@@ -43,7 +43,7 @@ pub fn row_hash_of_columns(columns: &[&Column], row_idx: usize) -> u128 {
             },
             ScalarRef::Timestamp(v) => sip.write_i64(v),
             ScalarRef::String(v) => sip.write(v),
-            ScalarRef::Bitmap(v) => sip.write(v),
+            ScalarRef::Bitmap(v) | ScalarRef::Binary(v) => sip.write(v),
             ScalarRef::Decimal(v) => match v {
                 DecimalScalar::Decimal128(i, DecimalSize { precision, scale }) => {
                     sip.write_i128(i);
@@ -59,6 +59,7 @@ pub fn row_hash_of_columns(columns: &[&Column], row_idx: usize) -> u128 {
             },
             ScalarRef::Boolean(v) => sip.write_u8(v as u8),
             ScalarRef::Date(d) => sip.write_i32(d),
+            ScalarRef::Interval(i) => sip.write_i64(i),
             _ => {
                 let string = value.to_string();
                 sip.write(string.as_bytes());
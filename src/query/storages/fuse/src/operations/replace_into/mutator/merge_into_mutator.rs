@@ -426,6 +426,9 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        for index_state in serialized.inverted_index_states {
+            write_data(index_state.data, &data_accessor, &index_state.location).await?;
+        }
 
         // generate log
         let mutation = MutationLogEntry::Replaced {
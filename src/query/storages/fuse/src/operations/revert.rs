@@ -55,7 +55,16 @@ impl FuseTable {
             deduplicated_label: None,
         };
 
-        // 4. let's roll
+        // 4. inside a transaction, the update is buffered and committed with the others
+        // by `COMMIT`, or dropped by `ROLLBACK`.
+        if ctx.txn_mgr().lock().is_active() {
+            ctx.txn_mgr()
+                .lock()
+                .update_table_meta(self.table_info.catalog(), req);
+            return Ok(());
+        }
+
+        // 5. let's roll
         let reply = catalog.update_table_meta(&self.table_info, req).await;
         if reply.is_ok() {
             // try keep the snapshot hit
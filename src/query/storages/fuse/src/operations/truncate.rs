@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
@@ -31,6 +32,14 @@ impl FuseTable {
     #[inline]
     #[async_backtrace::framed]
     pub async fn do_truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
+        // the purged files can not be restored by `ROLLBACK`.
+        let in_txn = ctx.txn_mgr().lock().is_active();
+        if in_txn && purge {
+            return Err(ErrorCode::Unimplemented(
+                "TRUNCATE TABLE ... PURGE is not supported in a transaction",
+            ));
+        }
+
         if let Some(prev_snapshot) = self.read_table_snapshot().await? {
             // 1. prepare new snapshot
             let prev_id = prev_snapshot.snapshot_id;
@@ -68,19 +77,27 @@ impl FuseTable {
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog())?;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            };
+
+            // inside a transaction, the update is buffered and the copied files are
+            // removed after `COMMIT`.
+            if in_txn {
+                let mut txn_mgr = ctx.txn_mgr().lock();
+                txn_mgr.update_table_meta(self.table_info.catalog(), req);
+                txn_mgr.truncate_table(self.table_info.catalog(), &self.table_info);
+                return Ok(());
+            }
 
             // commit table meta to meta server.
             // `truncate_table` is not supposed to be retry-able, thus we use
             // `update_data_table_meta` directly.
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                    copied_files: None,
-                    deduplicated_label: None,
-                })
-                .await?;
+            catalog.update_table_meta(&self.table_info, req).await?;
 
             // best effort to remove the table's copied files.
             catalog
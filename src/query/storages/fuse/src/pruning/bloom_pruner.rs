@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::Expr;
 use common_expression::FunctionContext;
@@ -25,9 +26,11 @@ use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_sql::BloomIndexColumns;
+use common_sql::NgramIndexColumns;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
+use storages_common_index::NgramArgs;
 use storages_common_table_meta::meta::Location;
 
 use crate::io::BloomBlockFilterReader;
@@ -49,6 +52,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// ngram filters that should be loaded from filter block
+    ngram_args: Vec<NgramArgs>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -69,6 +75,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: NgramIndexColumns,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
@@ -76,7 +83,14 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_args = ngram_index_cols
+                .ngram_index_fields(schema.clone())?
+                .into_iter()
+                .map(|(index, field, gram_size)| NgramArgs::new(index, field, gram_size))
+                .collect::<Vec<_>>();
+            let substring_query_cols = BloomIndex::find_ngram_columns(expr, &ngram_args)?;
+
+            if !point_query_cols.is_empty() || !substring_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -88,9 +102,26 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                // the ngrams are digested in the same way as the string values
+                let mut ngram_args = Vec::with_capacity(substring_query_cols.len());
+                for (arg, ngrams) in substring_query_cols.into_iter() {
+                    ngram_args.push(arg);
+                    for ngram in ngrams {
+                        if let Entry::Vacant(e) = scalar_map.entry(ngram.clone()) {
+                            let digest = BloomIndex::calculate_scalar_digest(
+                                &func_ctx,
+                                &ngram,
+                                &DataType::String,
+                            )?;
+                            e.insert(digest);
+                        }
+                    }
+                }
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_args,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -113,7 +144,7 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
+        let mut index_columns = self.index_fields.iter().try_fold(
             Vec::with_capacity(self.index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
@@ -122,6 +153,11 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for arg in &self.ngram_args {
+            if column_ids_of_indexed_block.contains(&arg.field.column_id()) {
+                index_columns.push(arg.filter_column_name());
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
@@ -137,6 +173,7 @@ impl BloomPrunerCreator {
             .apply(
                 self.filter_expression.clone(),
                 &self.scalar_map,
+                &self.ngram_args,
                 self.data_schema.clone(),
             )? != FilterEvalResult::MustFalse),
             Err(e) if e.code() == ErrorCode::DEPRECATED_INDEX_FORMAT => {
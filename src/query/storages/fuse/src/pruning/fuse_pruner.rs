@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::sync::Arc;
@@ -28,8 +29,10 @@ use common_expression::RemoteExpr;
 use common_expression::TableSchemaRef;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIndex;
 use common_sql::field_default_value;
 use common_sql::BloomIndexColumns;
+use common_sql::NgramIndexColumns;
 use opendal::Operator;
 use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
@@ -54,6 +57,7 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
 
 pub struct PruningContext {
@@ -65,6 +69,7 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
@@ -104,6 +109,8 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: NgramIndexColumns,
+        table_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<Self> {
         Self::create_with_pages(
             ctx,
@@ -113,10 +120,13 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_index_cols,
+            table_indexes,
         )
     }
 
     // Create fuse pruner with pages.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -125,6 +135,8 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: NgramIndexColumns,
+        table_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<Self> {
         let func_ctx = ctx.get_function_context()?;
 
@@ -178,6 +190,17 @@ impl FusePruner {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
+        )?;
+
+        // Inverted index pruner.
+        // None will be returned, if there is no `match` predicate on the indexed columns.
+        let inverted_index_pruner = InvertedIndexPruner::try_create(
+            func_ctx.clone(),
+            &table_schema,
+            dal.clone(),
+            filter_expr.as_ref(),
+            table_indexes,
         )?;
 
         // Page pruner, used in native format
@@ -225,6 +248,7 @@ impl FusePruner {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            inverted_index_pruner,
             page_pruner,
             internal_column_pruner,
             pruning_stats,
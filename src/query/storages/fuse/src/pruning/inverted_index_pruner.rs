@@ -0,0 +1,173 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read as pread;
+use common_arrow::infer_schema_with_extension;
+use common_arrow::parquet::read::read_metadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::schema::TableIndex;
+use opendal::Operator;
+use storages_common_cache::InMemoryItemCacheReader;
+use storages_common_cache::LoadParams;
+use storages_common_cache::Loader;
+use storages_common_cache_manager::CachedObject;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_index::InvertedIndexTerms;
+
+use crate::io::TableMetaLocationGenerator;
+
+/// Prune blocks by the inverted indexes covering the columns of the `match` predicates.
+pub struct InvertedIndexPruner {
+    func_ctx: FunctionContext,
+
+    /// the indexes that cover at least one column used by `match`
+    indexes: Vec<TableIndex>,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// the data accessor
+    dal: Operator,
+
+    /// the schema of data being indexed
+    data_schema: TableSchemaRef,
+}
+
+impl InvertedIndexPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+        table_indexes: &BTreeMap<String, TableIndex>,
+    ) -> Result<Option<Arc<InvertedIndexPruner>>> {
+        let expr = match filter_expr {
+            Some(expr) if !table_indexes.is_empty() => expr,
+            _ => return Ok(None),
+        };
+
+        let mut column_ids = vec![];
+        for col_name in InvertedIndex::find_match_columns(expr)? {
+            if let Ok(field) = schema.field_with_name(&col_name) {
+                column_ids.push(field.column_id());
+            }
+        }
+        let indexes = table_indexes
+            .values()
+            .filter(|index| index.column_ids.iter().any(|id| column_ids.contains(id)))
+            .cloned()
+            .collect::<Vec<_>>();
+        if indexes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Arc::new(InvertedIndexPruner {
+            func_ctx,
+            indexes,
+            filter_expression: expr.clone(),
+            dal,
+            data_schema: schema.clone(),
+        })))
+    }
+
+    // returns true, if target should NOT be pruned
+    #[async_backtrace::framed]
+    pub async fn should_keep(&self, block_location: &str) -> bool {
+        for index in &self.indexes {
+            match self.apply(index, block_location).await {
+                Ok(true) => {}
+                Ok(false) => return false,
+                // blocks written by a query started before the index is created have no index file.
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {}
+                Err(e) => {
+                    // swallow exceptions intentionally,
+                    // corrupted index should not prevent execution
+                    tracing::warn!(
+                        "failed to apply inverted index pruner, returning true. {}",
+                        e
+                    );
+                }
+            }
+        }
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn apply(&self, index: &TableIndex, block_location: &str) -> Result<bool> {
+        let location = TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+            block_location,
+            &index.version,
+        );
+        let reader =
+            InMemoryItemCacheReader::new(InvertedIndexTerms::cache(), InvertedIndexTermsLoader {
+                operator: self.dal.clone(),
+            });
+        let terms = reader
+            .read(&LoadParams {
+                location,
+                len_hint: None,
+                ver: 0,
+                put_cache: true,
+            })
+            .await?;
+        let inverted_index = InvertedIndex::from_terms(self.func_ctx.clone(), terms);
+        Ok(
+            inverted_index.apply(self.filter_expression.clone(), self.data_schema.clone())?
+                != FilterEvalResult::MustFalse,
+        )
+    }
+}
+
+/// Loader that reads the terms of an inverted index file.
+pub struct InvertedIndexTermsLoader {
+    pub operator: Operator,
+}
+
+#[async_trait::async_trait]
+impl Loader<InvertedIndexTerms> for InvertedIndexTermsLoader {
+    #[async_backtrace::framed]
+    async fn load(&self, params: &LoadParams) -> Result<InvertedIndexTerms> {
+        let data = self.operator.read(&params.location).await?;
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let arrow_schema = infer_schema_with_extension(&meta)?;
+        let schema = DataSchema::from(&TableSchema::from(&arrow_schema));
+
+        let chunks =
+            pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+        let mut blocks = vec![];
+        for chunk in chunks {
+            blocks.push(DataBlock::from_arrow_chunk(&chunk?, &schema)?);
+        }
+        let index_block = if blocks.is_empty() {
+            // the indexed columns of the block have no terms at all.
+            DataBlock::empty_with_schema(Arc::new(schema))
+        } else {
+            DataBlock::concat(&blocks)?
+        };
+        InvertedIndexTerms::from_index_block(&index_block)
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::InternalColumn;
+use common_catalog::plan::InternalColumnType;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PruningStatistics;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::StreamTableInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::CHANGE_ACTION_COL_NAME;
+use common_expression::CHANGE_ROW_ID_COL_NAME;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::EmptySource;
+use parking_lot::RwLock;
+
+use crate::io::SnapshotsIO;
+use crate::FuseTable;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+pub const OPT_KEY_TABLE_DATABASE: &str = "table_database";
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+/// The snapshot of the table the stream is consumed to, absent if the table had no data then.
+pub const OPT_KEY_OFFSET_SNAPSHOT_LOCATION: &str = "offset_snapshot_location";
+
+/// A stream exposes the rows changed in a fuse table since its offset snapshot.
+///
+/// Reading a stream diffs the rows of the offset snapshot and the latest snapshot of the table,
+/// see [`FuseTable::do_read_changes`], the offset is advanced to the latest snapshot when the
+/// query reading it commits. The offset snapshot is kept by the purge of the table.
+pub struct StreamTable {
+    stream_info: TableInfo,
+
+    table_database: String,
+    table_name: String,
+    table_id: u64,
+    offset_location: Option<String>,
+
+    // The table read by `read_partitions`, whose info is the data source of the read plan.
+    source: RwLock<Option<Arc<FuseTable>>>,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let table_database = options.get(OPT_KEY_TABLE_DATABASE).cloned();
+        let table_name = options.get(OPT_KEY_TABLE_NAME).cloned();
+        let table_id = options
+            .get(OPT_KEY_TABLE_ID)
+            .and_then(|id| id.parse::<u64>().ok());
+        let offset_location = options.get(OPT_KEY_OFFSET_SNAPSHOT_LOCATION).cloned();
+
+        match (table_database, table_name, table_id) {
+            (Some(table_database), Some(table_name), Some(table_id)) => Ok(Box::new(StreamTable {
+                stream_info: table_info,
+                table_database,
+                table_name,
+                table_id,
+                offset_location,
+                source: RwLock::new(None),
+            })),
+            _ => Err(ErrorCode::Internal(
+                "Need `table_database`, `table_name` and `table_id` when creating StreamTable",
+            )),
+        }
+    }
+
+    pub fn from_info(info: &StreamTableInfo) -> Result<Arc<dyn Table>> {
+        let stream = StreamTable::try_create(info.stream_info.clone())?;
+        let stream = stream.as_any().downcast_ref::<StreamTable>().unwrap();
+        let source = FuseTable::do_create(info.source_info.clone())?;
+        Ok(Arc::new(StreamTable {
+            stream_info: stream.stream_info.clone(),
+            table_database: stream.table_database.clone(),
+            table_name: stream.table_name.clone(),
+            table_id: stream.table_id,
+            offset_location: stream.offset_location.clone(),
+            source: RwLock::new(Some(Arc::new(*source))),
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (CHANGES OF A FUSE TABLE)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn offset_location(&self) -> Option<&String> {
+        self.offset_location.as_ref()
+    }
+
+    pub fn table_id(&self) -> u64 {
+        self.table_id
+    }
+
+    #[async_backtrace::framed]
+    async fn source_table(&self, ctx: &Arc<dyn TableContext>) -> Result<Arc<FuseTable>> {
+        let table = ctx
+            .get_table(
+                self.stream_info.catalog(),
+                &self.table_database,
+                &self.table_name,
+            )
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        if fuse_table.get_id() != self.table_id
+            || fuse_table.schema() != self.stream_info.meta.schema
+        {
+            return Err(ErrorCode::IllegalStream(format!(
+                "table {}.{} of stream {} has been recreated or altered, please recreate the stream",
+                self.table_database, self.table_name, self.stream_info.name
+            )));
+        }
+        Ok(Arc::new(fuse_table.clone()))
+    }
+}
+
+impl FuseTable {
+    /// The timestamp of the oldest offset snapshot of the streams on this table, which must be
+    /// kept by the purge for the streams to read their changes.
+    #[async_backtrace::framed]
+    pub async fn oldest_stream_offset(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        let table_id = self.get_id().to_string();
+
+        let mut oldest = None;
+        for database in catalog.list_databases(&tenant).await? {
+            for table in database.list_tables().await? {
+                let options = table.options();
+                if table.engine() != STREAM_ENGINE
+                    || options.get(OPT_KEY_TABLE_ID) != Some(&table_id)
+                {
+                    continue;
+                }
+                if let Some(location) = options.get(OPT_KEY_OFFSET_SNAPSHOT_LOCATION) {
+                    let (snapshot, _) =
+                        SnapshotsIO::read_snapshot(location.clone(), self.get_operator()).await?;
+                    oldest = match (oldest, snapshot.timestamp) {
+                        (Some(oldest), Some(timestamp)) => Some(std::cmp::min(oldest, timestamp)),
+                        (oldest, timestamp) => oldest.or(timestamp),
+                    };
+                }
+            }
+        }
+        Ok(oldest)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.stream_info
+    }
+
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        // The changes are read from the table resolved by `read_partitions`.
+        match self.source.read().as_ref() {
+            Some(source) => DataSourceInfo::StreamSource(StreamTableInfo {
+                stream_info: self.stream_info.clone(),
+                source_info: source.get_table_info().clone(),
+            }),
+            None => DataSourceInfo::TableSource(self.stream_info.clone()),
+        }
+    }
+
+    fn change_columns(&self) -> Vec<InternalColumn> {
+        vec![
+            InternalColumn::new(CHANGE_ACTION_COL_NAME, InternalColumnType::ChangeAction),
+            InternalColumn::new(CHANGE_ROW_ID_COL_NAME, InternalColumnType::ChangeRowId),
+        ]
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let source = self.source_table(&ctx).await?;
+        let latest_location = source.snapshot_loc().await?;
+        let block_metas = source
+            .changed_block_metas(ctx.clone(), self.offset_location.clone())
+            .await?;
+
+        // Advance the offset to the latest snapshot, which takes effect only if the
+        // query commits, e.g. a DML consuming the stream.
+        if latest_location != self.offset_location {
+            let mut new_table_meta = self.stream_info.meta.clone();
+            match &latest_location {
+                Some(location) => {
+                    new_table_meta.options.insert(
+                        OPT_KEY_OFFSET_SNAPSHOT_LOCATION.to_string(),
+                        location.clone(),
+                    );
+                }
+                None => {
+                    new_table_meta
+                        .options
+                        .remove(OPT_KEY_OFFSET_SNAPSHOT_LOCATION);
+                }
+            }
+            ctx.add_stream_update(UpdateTableMetaReq {
+                table_id: self.stream_info.ident.table_id,
+                seq: MatchSeq::Exact(self.stream_info.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            });
+        }
+
+        *self.source.write() = Some(source.clone());
+
+        // The changes are diffed on all the columns, so are the whole blocks read, see
+        // `FuseTable::do_read_changes`.
+        let partitions_total = block_metas.len();
+        source.read_partitions_with_metas(
+            self.schema(),
+            None,
+            &block_metas,
+            None,
+            partitions_total,
+            PruningStatistics::default(),
+        )
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let source = self.source.read().clone();
+        match source {
+            Some(source) => source.do_read_changes(ctx, plan, pipeline),
+            // No partitions are read, e.g. the filter is always false.
+            None => pipeline.add_source(EmptySource::create, 1),
+        }
+    }
+}
@@ -79,6 +79,8 @@ use opendal::Metakey;
 
 use crate::context::ICEBERG_CONTEXT;
 use crate::database::IcebergDatabase;
+use crate::metadata::create_metadata;
+use crate::metadata::table_location;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
 
@@ -237,8 +239,39 @@ impl Catalog for IcebergCatalog {
     }
 
     #[async_backtrace::framed]
-    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
-        unimplemented!()
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let db_name = &req.name_ident.db_name;
+        let table_name = &req.name_ident.table_name;
+        // make sure the database exists
+        self.get_database(&req.name_ident.tenant, db_name).await?;
+
+        let rel_path = format!("{db_name}/{table_name}/");
+        if self.operator.operator().is_exist(&rel_path).await? {
+            return if req.if_not_exists {
+                Ok(CreateTableReply {
+                    table_id: 0,
+                    new_table: false,
+                })
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "Table {db_name}.{table_name} already exists"
+                )))
+            };
+        }
+
+        // storage params for table
+        let table_sp = self
+            .operator
+            .params()
+            .map_root(|root| format!("{root}{rel_path}"));
+        let location = table_location(&table_sp)?;
+        let tbl_root = DataOperator::try_create(&table_sp).await?;
+        create_metadata(&tbl_root.operator(), &location, &req.table_meta.schema).await?;
+
+        Ok(CreateTableReply {
+            table_id: 0,
+            new_table: true,
+        })
     }
 
     #[async_backtrace::framed]
@@ -423,6 +456,10 @@ impl Catalog for IcebergCatalog {
 
     // Get table engines
     fn get_table_engines(&self) -> Vec<StorageDescription> {
-        unimplemented!()
+        vec![StorageDescription {
+            engine_name: "ICEBERG".to_string(),
+            comment: "ICEBERG Storage Engine".to_string(),
+            ..Default::default()
+        }]
     }
 }
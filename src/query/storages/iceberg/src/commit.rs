@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use opendal::Operator;
+
+use crate::manifest::read_manifest;
+use crate::manifest::read_manifest_list;
+use crate::manifest::write_manifest;
+use crate::manifest::write_manifest_list;
+use crate::manifest::DataFile;
+use crate::manifest::ManifestEntry;
+use crate::manifest::ManifestFile;
+use crate::manifest::CONTENT_DATA;
+use crate::manifest::MANIFEST_STATUS_ADDED;
+use crate::manifest::MANIFEST_STATUS_DELETED;
+use crate::metadata::commit_metadata;
+use crate::metadata::load_metadata;
+use crate::metadata::Snapshot;
+use crate::metadata::TableMetadata;
+use crate::metadata::METADATA_DIR;
+use crate::writer::IcebergDataFiles;
+
+/// Collect the data files written by the upstream writers and commit them as a
+/// new snapshot once all of them are done.
+pub struct IcebergCommitSink {
+    op: Operator,
+    /// The schema the data files are written with.
+    schema_id: i32,
+    overwrite: bool,
+    files: Vec<DataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        op: Operator,
+        schema_id: i32,
+        overwrite: bool,
+    ) -> Result<ProcessorPtr> {
+        let sinker = AsyncSinker::create(input, IcebergCommitSink {
+            op,
+            schema_id,
+            overwrite,
+            files: vec![],
+        });
+        Ok(ProcessorPtr::create(sinker))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.files.is_empty() && !self.overwrite {
+            return Ok(());
+        }
+        let files = std::mem::take(&mut self.files);
+        commit_data_files(&self.op, self.schema_id, files, self.overwrite).await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(IcebergDataFiles::downcast_from)
+        {
+            self.files.extend(meta.files);
+        }
+        Ok(false)
+    }
+}
+
+/// Commit `files` as a new snapshot of the table rooted at `op`.
+///
+/// Manifests and the manifest list are written first, the snapshot only
+/// becomes visible once the new metadata file is published.
+#[async_backtrace::framed]
+pub async fn commit_data_files(
+    op: &Operator,
+    schema_id: i32,
+    files: Vec<DataFile>,
+    overwrite: bool,
+) -> Result<()> {
+    let base = load_metadata(op).await?;
+    base.check_writable()?;
+    let schema = base.current_schema()?;
+    if schema.schema_id != schema_id {
+        return Err(ErrorCode::TableVersionMismatched(format!(
+            "Iceberg table schema has changed from {} to {} during writing",
+            schema_id, schema.schema_id
+        )));
+    }
+
+    let parent = base.current_snapshot()?;
+    let previous_manifests = match &parent {
+        Some(parent) => read_snapshot_manifests(op, &base, parent).await?,
+        None => vec![],
+    };
+
+    let commit_id = uuid::Uuid::new_v4();
+    let mut snapshot = Snapshot {
+        snapshot_id: (commit_id.as_u128() as i64) & i64::MAX,
+        parent_snapshot_id: parent.as_ref().map(|s| s.snapshot_id),
+        sequence_number: base.last_sequence_number() + 1,
+        timestamp_ms: chrono::Utc::now().timestamp_millis(),
+        manifest_list: None,
+        summary: BTreeMap::new(),
+        schema_id: Some(schema.schema_id),
+    };
+
+    let spec_id = base.default_spec_id();
+    let mut manifests = vec![];
+    let added_entries = files
+        .iter()
+        .map(|file| ManifestEntry {
+            status: MANIFEST_STATUS_ADDED,
+            snapshot_id: Some(snapshot.snapshot_id),
+            sequence_number: None,
+            file_sequence_number: None,
+            data_file: file.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    // Overwrite marks every live data file of the parent snapshot as deleted.
+    let mut deleted_entries = vec![];
+    if overwrite {
+        for manifest in previous_manifests
+            .iter()
+            .filter(|m| m.content == CONTENT_DATA)
+        {
+            let bytes = op.read(&base.rel_path(&manifest.manifest_path)?).await?;
+            for entry in read_manifest(&bytes, manifest)? {
+                if entry.status == MANIFEST_STATUS_DELETED {
+                    continue;
+                }
+                deleted_entries.push(ManifestEntry {
+                    status: MANIFEST_STATUS_DELETED,
+                    snapshot_id: Some(snapshot.snapshot_id),
+                    ..entry
+                });
+            }
+        }
+    } else {
+        manifests.extend(previous_manifests);
+    }
+
+    for entries in [&added_entries, &deleted_entries] {
+        if entries.is_empty() {
+            continue;
+        }
+        let path = format!("{METADATA_DIR}/{commit_id}-m{}.avro", manifests.len());
+        let bytes = write_manifest(&schema, spec_id, entries)?;
+        let length = bytes.len();
+        op.write(&path, bytes).await?;
+        manifests.push(ManifestFile::create(
+            base.abs_path(&path)?,
+            length,
+            spec_id,
+            &snapshot,
+            entries,
+        ));
+    }
+
+    snapshot.summary = summary(parent.as_ref(), &added_entries, &deleted_entries, overwrite);
+    let path = format!(
+        "{METADATA_DIR}/snap-{}-1-{commit_id}.avro",
+        snapshot.snapshot_id
+    );
+    op.write(&path, write_manifest_list(&snapshot, &manifests)?)
+        .await?;
+    snapshot.manifest_list = Some(base.abs_path(&path)?);
+
+    commit_metadata(op, &base, base.with_snapshot(&snapshot)?).await
+}
+
+/// Read the manifest list of `snapshot`.
+#[async_backtrace::framed]
+pub async fn read_snapshot_manifests(
+    op: &Operator,
+    metadata: &TableMetadata,
+    snapshot: &Snapshot,
+) -> Result<Vec<ManifestFile>> {
+    let manifest_list = snapshot.manifest_list.as_ref().ok_or_else(|| {
+        ErrorCode::Unimplemented(format!(
+            "Iceberg snapshot {} without manifest list is not supported",
+            snapshot.snapshot_id
+        ))
+    })?;
+    let bytes = op.read(&metadata.rel_path(manifest_list)?).await?;
+    read_manifest_list(&bytes)
+}
+
+fn summary(
+    parent: Option<&Snapshot>,
+    added: &[ManifestEntry],
+    deleted: &[ManifestEntry],
+    overwrite: bool,
+) -> BTreeMap<String, String> {
+    let count = |entries: &[ManifestEntry]| {
+        let records = entries
+            .iter()
+            .map(|e| e.data_file.record_count)
+            .sum::<i64>();
+        let size = entries
+            .iter()
+            .map(|e| e.data_file.file_size_in_bytes)
+            .sum::<i64>();
+        (entries.len() as i64, records, size)
+    };
+    let (added_files, added_records, added_size) = count(added);
+    let (deleted_files, deleted_records, deleted_size) = count(deleted);
+    // The parent totals don't apply after an overwrite.
+    let total = |key: &str| match parent {
+        Some(parent) if !overwrite => parent.summary_count(key),
+        _ => 0,
+    };
+
+    let operation = if overwrite { "overwrite" } else { "append" };
+    let mut summary = BTreeMap::from([("operation".to_string(), operation.to_string())]);
+    let counters = [
+        ("added-data-files", added_files),
+        ("added-records", added_records),
+        ("added-files-size", added_size),
+        ("deleted-data-files", deleted_files),
+        ("deleted-records", deleted_records),
+        ("removed-files-size", deleted_size),
+        ("total-data-files", total("total-data-files") + added_files),
+        ("total-records", total("total-records") + added_records),
+        ("total-files-size", total("total-files-size") + added_size),
+        ("total-delete-files", total("total-delete-files")),
+        ("total-position-deletes", total("total-position-deletes")),
+        ("total-equality-deletes", total("total-equality-deletes")),
+    ];
+    for (key, value) in counters {
+        summary.insert(key.to_string(), value.to_string());
+    }
+    summary
+}
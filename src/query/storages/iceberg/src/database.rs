@@ -81,7 +81,7 @@ impl Database for IcebergDatabase {
         let path = format!("{table_name}/");
         let op = self.db_root.operator();
         // check existence first
-        if !op.stat(&path).await?.mode().is_dir() {
+        if !op.is_exist(&path).await? || !op.stat(&path).await?.mode().is_dir() {
             return Err(ErrorCode::UnknownTable(format!(
                 "table {table_name} does not exist or is not a valid table"
             )));
@@ -0,0 +1,260 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Row-level deletes of iceberg v2 tables.
+//!
+//! Delete files are matched with data files when planning, following the
+//! sequence number and partition rules of the spec, and the matched ones are
+//! carried by [`IcebergPartInfo`] so that the source can filter out the
+//! deleted rows by position or by equality key.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use futures::StreamExt;
+use icelake::io::parquet::ParquetStreamBuilder;
+use opendal::Operator;
+
+use crate::manifest::DataFile;
+use crate::manifest::CONTENT_EQUALITY_DELETES;
+use crate::manifest::CONTENT_POSITION_DELETES;
+use crate::partition::IcebergPartInfo;
+use crate::table_source::parse_icelake_error;
+
+/// Reserved field id of the `file_path` column of position delete files.
+const POSITION_DELETE_FILE_PATH_ID: i32 = 2147483546;
+const POSITION_DELETE_FILE_PATH: &str = "file_path";
+const POSITION_DELETE_POS: &str = "pos";
+
+/// A delete file to apply when reading a data file.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct IcebergDeleteFile {
+    /// Path relative to the table root.
+    pub path: String,
+    pub content: i32,
+    /// Names of the equality key columns, only set for equality deletes.
+    pub equality_columns: Vec<String>,
+}
+
+/// The live delete files of a snapshot, to look up the ones applying to a data file.
+#[derive(Default)]
+pub struct DeleteFileIndex {
+    files: Vec<IndexedDeleteFile>,
+}
+
+struct IndexedDeleteFile {
+    sequence_number: i64,
+    spec_id: i32,
+    /// Equality deletes of an unpartitioned spec apply to all the partitions.
+    global: bool,
+    partition: Vec<(String, Option<Vec<u8>>)>,
+    /// Range of the data file paths referenced by a position delete file.
+    path_bounds: Option<(Vec<u8>, Vec<u8>)>,
+    file: IcebergDeleteFile,
+}
+
+impl DeleteFileIndex {
+    /// Add the delete file `file` of spec `spec_id` and data sequence number `sequence_number`.
+    pub fn add(
+        &mut self,
+        sequence_number: i64,
+        spec_id: i32,
+        unpartitioned: bool,
+        file: &DataFile,
+        delete_file: IcebergDeleteFile,
+    ) {
+        let bound = |bounds: &[(i32, Vec<u8>)]| {
+            bounds
+                .iter()
+                .find(|(id, _)| *id == POSITION_DELETE_FILE_PATH_ID)
+                .map(|(_, v)| v.clone())
+        };
+        let path_bounds = match delete_file.content {
+            CONTENT_POSITION_DELETES => bound(&file.lower_bounds).zip(bound(&file.upper_bounds)),
+            _ => None,
+        };
+        self.files.push(IndexedDeleteFile {
+            sequence_number,
+            spec_id,
+            global: unpartitioned && delete_file.content == CONTENT_EQUALITY_DELETES,
+            partition: file.partition.clone(),
+            path_bounds,
+            file: delete_file,
+        });
+    }
+
+    /// The delete files applying to the data file `file` of spec `spec_id`
+    /// and data sequence number `sequence_number`.
+    pub fn for_data_file(
+        &self,
+        sequence_number: i64,
+        spec_id: i32,
+        file: &DataFile,
+    ) -> Vec<IcebergDeleteFile> {
+        self.files
+            .iter()
+            .filter(|d| {
+                let same_partition = d.spec_id == spec_id && d.partition == file.partition;
+                match d.file.content {
+                    // Position deletes may be committed along with the data
+                    // files they delete from.
+                    CONTENT_POSITION_DELETES => {
+                        d.sequence_number >= sequence_number
+                            && same_partition
+                            && d.path_bounds.as_ref().map_or(true, |(lower, upper)| {
+                                let path = file.file_path.as_bytes();
+                                lower.as_slice() <= path && path <= upper.as_slice()
+                            })
+                    }
+                    _ => d.sequence_number > sequence_number && (d.global || same_partition),
+                }
+            })
+            .map(|d| d.file.clone())
+            .collect()
+    }
+}
+
+/// Filter out the deleted rows of a data file.
+pub struct DeleteFilter {
+    /// Deleted row positions.
+    positions: HashSet<i64>,
+    /// Deleted keys, by the key columns.
+    equalities: HashMap<Vec<String>, HashSet<Vec<Scalar>>>,
+}
+
+impl DeleteFilter {
+    /// Load the delete files of `part`, returns `None` if there is none.
+    #[async_backtrace::framed]
+    pub async fn try_create(op: &Operator, part: &IcebergPartInfo) -> Result<Option<Self>> {
+        if part.deletes.is_empty() {
+            return Ok(None);
+        }
+        let mut positions = HashSet::new();
+        let mut equalities: HashMap<Vec<String>, HashSet<Vec<Scalar>>> = HashMap::new();
+        for delete_file in &part.deletes {
+            let blocks = read_delete_file(op, &delete_file.path).await?;
+            if delete_file.content == CONTENT_POSITION_DELETES {
+                for (block, schema) in blocks {
+                    let file_path =
+                        block.get_by_offset(schema.index_of(POSITION_DELETE_FILE_PATH)?);
+                    let pos = block.get_by_offset(schema.index_of(POSITION_DELETE_POS)?);
+                    for row in 0..block.num_rows() {
+                        match (file_path.value.index(row), pos.value.index(row)) {
+                            (
+                                Some(ScalarRef::String(path)),
+                                Some(ScalarRef::Number(NumberScalar::Int64(pos))),
+                            ) if path == part.file_path.as_bytes() => {
+                                positions.insert(pos);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            } else {
+                let keys = equalities
+                    .entry(delete_file.equality_columns.clone())
+                    .or_default();
+                for (block, schema) in blocks {
+                    let columns = delete_file
+                        .equality_columns
+                        .iter()
+                        .map(|name| schema.index_of(name))
+                        .collect::<Result<Vec<_>>>()?;
+                    for row in 0..block.num_rows() {
+                        keys.insert(row_key(&block, &columns, row));
+                    }
+                }
+            }
+        }
+        Ok(Some(Self {
+            positions,
+            equalities,
+        }))
+    }
+
+    /// Filter `block` of `schema`, whose first row is at `offset` of the data file.
+    pub fn filter(
+        &self,
+        block: DataBlock,
+        schema: &DataSchema,
+        offset: usize,
+    ) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let mut keep = MutableBitmap::from_len_set(num_rows);
+        if !self.positions.is_empty() {
+            for row in 0..num_rows {
+                if self.positions.contains(&((offset + row) as i64)) {
+                    keep.set(row, false);
+                }
+            }
+        }
+        for (names, keys) in &self.equalities {
+            // Columns missing in the data file are nulls.
+            let columns = names
+                .iter()
+                .map(|name| schema.index_of(name).ok())
+                .collect::<Vec<_>>();
+            for row in 0..num_rows {
+                let key = columns
+                    .iter()
+                    .map(|column| column.map_or(Scalar::Null, |i| value_at(&block, i, row)))
+                    .collect::<Vec<_>>();
+                if keys.contains(&key) {
+                    keep.set(row, false);
+                }
+            }
+        }
+        block.filter_with_bitmap(&keep.into())
+    }
+}
+
+/// The values of `columns` at `row`.
+fn row_key(block: &DataBlock, columns: &[usize], row: usize) -> Vec<Scalar> {
+    columns.iter().map(|i| value_at(block, *i, row)).collect()
+}
+
+fn value_at(block: &DataBlock, column: usize, row: usize) -> Scalar {
+    block
+        .get_by_offset(column)
+        .value
+        .index(row)
+        .map_or(Scalar::Null, |v| v.to_owned())
+}
+
+#[async_backtrace::framed]
+async fn read_delete_file(op: &Operator, path: &str) -> Result<Vec<(DataBlock, DataSchema)>> {
+    let reader = op.reader(path).await?;
+    let mut stream = ParquetStreamBuilder::new(reader)
+        .build()
+        .await
+        .map_err(parse_icelake_error)?;
+    let mut blocks = vec![];
+    while let Some(batch) = stream.next().await {
+        let batch = batch.map_err(parse_icelake_error)?;
+        blocks.push(DataBlock::from_record_batch(&batch).map_err(|err| {
+            ErrorCode::ReadTableDataError(format!(
+                "Cannot convert arrow record batch of iceberg delete file {path}: {err:?}"
+            ))
+        })?);
+    }
+    Ok(blocks)
+}
@@ -90,11 +90,21 @@
 #![feature(lazy_cell)]
 
 mod catalog;
+mod commit;
 mod context;
 mod database;
+mod delete;
+mod manifest;
+mod metadata;
 mod partition;
+mod pruning;
 mod table;
+mod table_functions;
 mod table_source;
+mod writer;
 
 pub use catalog::IcebergCatalog;
 pub use catalog::ICEBERG_CATALOG;
+pub use table::IcebergTable;
+pub use table_functions::IcebergManifestsTable;
+pub use table_functions::IcebergSnapshotsTable;
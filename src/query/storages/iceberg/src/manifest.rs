@@ -0,0 +1,622 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Iceberg manifest lists and manifests, both are avro files.
+//!
+//! Reading accepts both v1 and v2 layouts, writing always produces v2.
+
+use std::collections::HashMap;
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Codec;
+use apache_avro::Reader;
+use apache_avro::Schema as AvroSchema;
+use apache_avro::Writer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::metadata::Schema;
+use crate::metadata::Snapshot;
+
+pub const MANIFEST_STATUS_EXISTING: i32 = 0;
+pub const MANIFEST_STATUS_ADDED: i32 = 1;
+pub const MANIFEST_STATUS_DELETED: i32 = 2;
+
+/// Content of both manifests and data files.
+pub const CONTENT_DATA: i32 = 0;
+/// Content of manifests tracking delete files.
+pub const CONTENT_DELETES: i32 = 1;
+/// Content of delete files.
+pub const CONTENT_POSITION_DELETES: i32 = 1;
+pub const CONTENT_EQUALITY_DELETES: i32 = 2;
+
+const MANIFEST_ENTRY_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "manifest_entry",
+  "fields": [
+    {"name": "status", "type": "int", "field-id": 0},
+    {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+    {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+    {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+    {"name": "data_file", "field-id": 2, "type": {
+      "type": "record",
+      "name": "r2",
+      "fields": [
+        {"name": "content", "type": "int", "field-id": 134},
+        {"name": "file_path", "type": "string", "field-id": 100},
+        {"name": "file_format", "type": "string", "field-id": 101},
+        {"name": "partition", "field-id": 102,
+         "type": {"type": "record", "name": "r102", "fields": []}},
+        {"name": "record_count", "type": "long", "field-id": 103},
+        {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+        {"name": "column_sizes", "default": null, "field-id": 108, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k117_v118", "fields": [
+              {"name": "key", "type": "int", "field-id": 117},
+              {"name": "value", "type": "long", "field-id": 118}]}}]},
+        {"name": "value_counts", "default": null, "field-id": 109, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k119_v120", "fields": [
+              {"name": "key", "type": "int", "field-id": 119},
+              {"name": "value", "type": "long", "field-id": 120}]}}]},
+        {"name": "null_value_counts", "default": null, "field-id": 110, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k121_v122", "fields": [
+              {"name": "key", "type": "int", "field-id": 121},
+              {"name": "value", "type": "long", "field-id": 122}]}}]},
+        {"name": "nan_value_counts", "default": null, "field-id": 137, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k138_v139", "fields": [
+              {"name": "key", "type": "int", "field-id": 138},
+              {"name": "value", "type": "long", "field-id": 139}]}}]},
+        {"name": "lower_bounds", "default": null, "field-id": 125, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k126_v127", "fields": [
+              {"name": "key", "type": "int", "field-id": 126},
+              {"name": "value", "type": "bytes", "field-id": 127}]}}]},
+        {"name": "upper_bounds", "default": null, "field-id": 128, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k129_v130", "fields": [
+              {"name": "key", "type": "int", "field-id": 129},
+              {"name": "value", "type": "bytes", "field-id": 130}]}}]},
+        {"name": "key_metadata", "type": ["null", "bytes"], "default": null, "field-id": 131},
+        {"name": "split_offsets", "default": null, "field-id": 132,
+         "type": ["null", {"type": "array", "items": "long", "element-id": 133}]},
+        {"name": "equality_ids", "default": null, "field-id": 135,
+         "type": ["null", {"type": "array", "items": "int", "element-id": 136}]},
+        {"name": "sort_order_id", "type": ["null", "int"], "default": null, "field-id": 140}
+      ]
+    }}
+  ]
+}"#;
+
+const MANIFEST_FILE_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "manifest_file",
+  "fields": [
+    {"name": "manifest_path", "type": "string", "field-id": 500},
+    {"name": "manifest_length", "type": "long", "field-id": 501},
+    {"name": "partition_spec_id", "type": "int", "field-id": 502},
+    {"name": "content", "type": "int", "field-id": 517},
+    {"name": "sequence_number", "type": "long", "field-id": 515},
+    {"name": "min_sequence_number", "type": "long", "field-id": 516},
+    {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+    {"name": "added_files_count", "type": "int", "field-id": 504},
+    {"name": "existing_files_count", "type": "int", "field-id": 505},
+    {"name": "deleted_files_count", "type": "int", "field-id": 506},
+    {"name": "added_rows_count", "type": "long", "field-id": 512},
+    {"name": "existing_rows_count", "type": "long", "field-id": 513},
+    {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+    {"name": "partitions", "default": null, "field-id": 507, "type": ["null", {
+      "type": "array", "element-id": 508, "items": {
+        "type": "record", "name": "r508", "fields": [
+          {"name": "contains_null", "type": "boolean", "field-id": 509},
+          {"name": "contains_nan", "type": ["null", "boolean"], "default": null, "field-id": 518},
+          {"name": "lower_bound", "type": ["null", "bytes"], "default": null, "field-id": 510},
+          {"name": "upper_bound", "type": ["null", "bytes"], "default": null, "field-id": 511}
+        ]}}]},
+    {"name": "key_metadata", "type": ["null", "bytes"], "default": null, "field-id": 519}
+  ]
+}"#;
+
+/// A data file tracked by a manifest, with the column metrics keyed by field id.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DataFile {
+    pub content: i32,
+    /// Absolute path of the file.
+    pub file_path: String,
+    pub file_format: String,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub column_sizes: Vec<(i32, i64)>,
+    pub value_counts: Vec<(i32, i64)>,
+    pub null_value_counts: Vec<(i32, i64)>,
+    pub nan_value_counts: Vec<(i32, i64)>,
+    pub lower_bounds: Vec<(i32, Vec<u8>)>,
+    pub upper_bounds: Vec<(i32, Vec<u8>)>,
+    /// Partition values keyed by partition field name, in the single-value
+    /// binary serialization of iceberg, same as the bounds.
+    pub partition: Vec<(String, Option<Vec<u8>>)>,
+    /// Field ids of the equality delete key, only set for equality delete files.
+    pub equality_ids: Vec<i32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestEntry {
+    pub status: i32,
+    pub snapshot_id: Option<i64>,
+    pub sequence_number: Option<i64>,
+    pub file_sequence_number: Option<i64>,
+    pub data_file: DataFile,
+}
+
+/// An entry of the manifest list.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ManifestFile {
+    /// Absolute path of the manifest.
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    pub content: i32,
+    pub sequence_number: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+}
+
+impl ManifestFile {
+    /// Describe a manifest holding `entries` written by `snapshot`.
+    pub fn create(
+        manifest_path: String,
+        manifest_length: usize,
+        partition_spec_id: i32,
+        snapshot: &Snapshot,
+        entries: &[ManifestEntry],
+    ) -> Self {
+        let mut manifest = ManifestFile {
+            manifest_path,
+            manifest_length: manifest_length as i64,
+            partition_spec_id,
+            content: CONTENT_DATA,
+            sequence_number: snapshot.sequence_number,
+            min_sequence_number: snapshot.sequence_number,
+            added_snapshot_id: snapshot.snapshot_id,
+            ..Default::default()
+        };
+        for entry in entries {
+            let rows = entry.data_file.record_count;
+            match entry.status {
+                MANIFEST_STATUS_ADDED => {
+                    manifest.added_files_count += 1;
+                    manifest.added_rows_count += rows;
+                }
+                MANIFEST_STATUS_DELETED => {
+                    manifest.deleted_files_count += 1;
+                    manifest.deleted_rows_count += rows;
+                }
+                _ => {
+                    manifest.existing_files_count += 1;
+                    manifest.existing_rows_count += rows;
+                }
+            }
+            if let Some(seq) = entry.sequence_number {
+                manifest.min_sequence_number = manifest.min_sequence_number.min(seq);
+            }
+        }
+        manifest
+    }
+}
+
+/// Read the manifests listed by a snapshot.
+pub fn read_manifest_list(bytes: &[u8]) -> Result<Vec<ManifestFile>> {
+    let reader = Reader::new(bytes).map_err(read_error)?;
+    let mut manifests = vec![];
+    for value in reader {
+        let record = AvroRecord::try_from(value.map_err(read_error)?)?;
+        manifests.push(ManifestFile {
+            manifest_path: record.string("manifest_path")?,
+            manifest_length: record.long(&["manifest_length"]).unwrap_or_default(),
+            partition_spec_id: record.long(&["partition_spec_id"]).unwrap_or_default() as i32,
+            content: record.long(&["content"]).unwrap_or_default() as i32,
+            sequence_number: record.long(&["sequence_number"]).unwrap_or_default(),
+            min_sequence_number: record.long(&["min_sequence_number"]).unwrap_or_default(),
+            added_snapshot_id: record.long(&["added_snapshot_id"]).unwrap_or_default(),
+            added_files_count: record
+                .long(&["added_files_count", "added_data_files_count"])
+                .unwrap_or_default() as i32,
+            existing_files_count: record
+                .long(&["existing_files_count", "existing_data_files_count"])
+                .unwrap_or_default() as i32,
+            deleted_files_count: record
+                .long(&["deleted_files_count", "deleted_data_files_count"])
+                .unwrap_or_default() as i32,
+            added_rows_count: record.long(&["added_rows_count"]).unwrap_or_default(),
+            existing_rows_count: record.long(&["existing_rows_count"]).unwrap_or_default(),
+            deleted_rows_count: record.long(&["deleted_rows_count"]).unwrap_or_default(),
+        });
+    }
+    Ok(manifests)
+}
+
+/// Read the entries of `manifest`, inheriting snapshot id and sequence numbers from it.
+pub fn read_manifest(bytes: &[u8], manifest: &ManifestFile) -> Result<Vec<ManifestEntry>> {
+    let reader = Reader::new(bytes).map_err(read_error)?;
+    let mut entries = vec![];
+    for value in reader {
+        let record = AvroRecord::try_from(value.map_err(read_error)?)?;
+        let status = record.long(&["status"]).unwrap_or_default() as i32;
+        let inherit_sequence_number = |seq: Option<i64>| match seq {
+            None if status == MANIFEST_STATUS_ADDED => Some(manifest.sequence_number),
+            seq => seq,
+        };
+        let data_file = record.record("data_file")?;
+        entries.push(ManifestEntry {
+            status,
+            snapshot_id: record
+                .long(&["snapshot_id"])
+                .or(Some(manifest.added_snapshot_id)),
+            sequence_number: inherit_sequence_number(record.long(&["sequence_number"])),
+            file_sequence_number: inherit_sequence_number(record.long(&["file_sequence_number"])),
+            data_file: DataFile {
+                content: data_file.long(&["content"]).unwrap_or_default() as i32,
+                file_path: data_file.string("file_path")?,
+                file_format: data_file.string("file_format")?,
+                record_count: data_file.long(&["record_count"]).unwrap_or_default(),
+                file_size_in_bytes: data_file.long(&["file_size_in_bytes"]).unwrap_or_default(),
+                column_sizes: data_file.int_map("column_sizes", AvroRecord::as_long),
+                value_counts: data_file.int_map("value_counts", AvroRecord::as_long),
+                null_value_counts: data_file.int_map("null_value_counts", AvroRecord::as_long),
+                nan_value_counts: data_file.int_map("nan_value_counts", AvroRecord::as_long),
+                lower_bounds: data_file.int_map("lower_bounds", AvroRecord::as_bytes),
+                upper_bounds: data_file.int_map("upper_bounds", AvroRecord::as_bytes),
+                partition: data_file.record("partition")?.partition_values(),
+                equality_ids: data_file.int_array("equality_ids"),
+            },
+        });
+    }
+    Ok(entries)
+}
+
+/// Write a v2 manifest of an unpartitioned table.
+pub fn write_manifest(
+    schema: &Schema,
+    partition_spec_id: i32,
+    entries: &[ManifestEntry],
+) -> Result<Vec<u8>> {
+    let avro_schema = AvroSchema::parse_str(MANIFEST_ENTRY_SCHEMA).map_err(write_error)?;
+    let mut writer = Writer::with_codec(&avro_schema, Vec::new(), Codec::Deflate);
+    let metadata = [
+        ("schema", schema.to_json().to_string()),
+        ("schema-id", schema.schema_id.to_string()),
+        ("partition-spec", "[]".to_string()),
+        ("partition-spec-id", partition_spec_id.to_string()),
+        ("format-version", "2".to_string()),
+        ("content", "data".to_string()),
+    ];
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(write_error)?;
+    }
+
+    for entry in entries {
+        let file = &entry.data_file;
+        let data_file = AvroValue::Record(vec![
+            ("content".to_string(), AvroValue::Int(file.content)),
+            (
+                "file_path".to_string(),
+                AvroValue::String(file.file_path.clone()),
+            ),
+            (
+                "file_format".to_string(),
+                AvroValue::String(file.file_format.clone()),
+            ),
+            ("partition".to_string(), AvroValue::Record(vec![])),
+            (
+                "record_count".to_string(),
+                AvroValue::Long(file.record_count),
+            ),
+            (
+                "file_size_in_bytes".to_string(),
+                AvroValue::Long(file.file_size_in_bytes),
+            ),
+            (
+                "column_sizes".to_string(),
+                int_map(&file.column_sizes, |v| AvroValue::Long(*v)),
+            ),
+            (
+                "value_counts".to_string(),
+                int_map(&file.value_counts, |v| AvroValue::Long(*v)),
+            ),
+            (
+                "null_value_counts".to_string(),
+                int_map(&file.null_value_counts, |v| AvroValue::Long(*v)),
+            ),
+            (
+                "nan_value_counts".to_string(),
+                int_map(&file.nan_value_counts, |v| AvroValue::Long(*v)),
+            ),
+            (
+                "lower_bounds".to_string(),
+                int_map(&file.lower_bounds, |v| AvroValue::Bytes(v.clone())),
+            ),
+            (
+                "upper_bounds".to_string(),
+                int_map(&file.upper_bounds, |v| AvroValue::Bytes(v.clone())),
+            ),
+            ("key_metadata".to_string(), optional(None)),
+            ("split_offsets".to_string(), optional(None)),
+            ("equality_ids".to_string(), int_array(&file.equality_ids)),
+            ("sort_order_id".to_string(), optional(None)),
+        ]);
+        let value = AvroValue::Record(vec![
+            ("status".to_string(), AvroValue::Int(entry.status)),
+            (
+                "snapshot_id".to_string(),
+                optional(entry.snapshot_id.map(AvroValue::Long)),
+            ),
+            (
+                "sequence_number".to_string(),
+                optional(entry.sequence_number.map(AvroValue::Long)),
+            ),
+            (
+                "file_sequence_number".to_string(),
+                optional(entry.file_sequence_number.map(AvroValue::Long)),
+            ),
+            ("data_file".to_string(), data_file),
+        ]);
+        writer.append(value).map_err(write_error)?;
+    }
+    writer.into_inner().map_err(write_error)
+}
+
+/// Write the v2 manifest list of `snapshot`.
+pub fn write_manifest_list(snapshot: &Snapshot, manifests: &[ManifestFile]) -> Result<Vec<u8>> {
+    let avro_schema = AvroSchema::parse_str(MANIFEST_FILE_SCHEMA).map_err(write_error)?;
+    let mut writer = Writer::with_codec(&avro_schema, Vec::new(), Codec::Deflate);
+    let parent_snapshot_id = snapshot
+        .parent_snapshot_id
+        .map_or_else(|| "null".to_string(), |id| id.to_string());
+    let metadata = [
+        ("snapshot-id", snapshot.snapshot_id.to_string()),
+        ("parent-snapshot-id", parent_snapshot_id),
+        ("sequence-number", snapshot.sequence_number.to_string()),
+        ("format-version", "2".to_string()),
+    ];
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(write_error)?;
+    }
+
+    for manifest in manifests {
+        let value = AvroValue::Record(vec![
+            (
+                "manifest_path".to_string(),
+                AvroValue::String(manifest.manifest_path.clone()),
+            ),
+            (
+                "manifest_length".to_string(),
+                AvroValue::Long(manifest.manifest_length),
+            ),
+            (
+                "partition_spec_id".to_string(),
+                AvroValue::Int(manifest.partition_spec_id),
+            ),
+            ("content".to_string(), AvroValue::Int(manifest.content)),
+            (
+                "sequence_number".to_string(),
+                AvroValue::Long(manifest.sequence_number),
+            ),
+            (
+                "min_sequence_number".to_string(),
+                AvroValue::Long(manifest.min_sequence_number),
+            ),
+            (
+                "added_snapshot_id".to_string(),
+                AvroValue::Long(manifest.added_snapshot_id),
+            ),
+            (
+                "added_files_count".to_string(),
+                AvroValue::Int(manifest.added_files_count),
+            ),
+            (
+                "existing_files_count".to_string(),
+                AvroValue::Int(manifest.existing_files_count),
+            ),
+            (
+                "deleted_files_count".to_string(),
+                AvroValue::Int(manifest.deleted_files_count),
+            ),
+            (
+                "added_rows_count".to_string(),
+                AvroValue::Long(manifest.added_rows_count),
+            ),
+            (
+                "existing_rows_count".to_string(),
+                AvroValue::Long(manifest.existing_rows_count),
+            ),
+            (
+                "deleted_rows_count".to_string(),
+                AvroValue::Long(manifest.deleted_rows_count),
+            ),
+            ("partitions".to_string(), optional(None)),
+            ("key_metadata".to_string(), optional(None)),
+        ]);
+        writer.append(value).map_err(write_error)?;
+    }
+    writer.into_inner().map_err(write_error)
+}
+
+fn optional(value: Option<AvroValue>) -> AvroValue {
+    match value {
+        Some(v) => AvroValue::Union(1, Box::new(v)),
+        None => AvroValue::Union(0, Box::new(AvroValue::Null)),
+    }
+}
+
+fn int_array(values: &[i32]) -> AvroValue {
+    if values.is_empty() {
+        return optional(None);
+    }
+    let items = values.iter().map(|v| AvroValue::Int(*v)).collect();
+    optional(Some(AvroValue::Array(items)))
+}
+
+/// Iceberg encodes maps with int keys as arrays of key-value records.
+fn int_map<T>(entries: &[(i32, T)], f: impl Fn(&T) -> AvroValue) -> AvroValue {
+    if entries.is_empty() {
+        return optional(None);
+    }
+    let items = entries
+        .iter()
+        .map(|(k, v)| {
+            AvroValue::Record(vec![
+                ("key".to_string(), AvroValue::Int(*k)),
+                ("value".to_string(), f(v)),
+            ])
+        })
+        .collect();
+    optional(Some(AvroValue::Array(items)))
+}
+
+fn read_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("Cannot read iceberg manifest: {e}"))
+}
+
+fn write_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::Internal(format!("Cannot write iceberg manifest: {e}"))
+}
+
+/// A decoded avro record, fields are looked up by name so that both v1 and v2
+/// layouts can be read.
+struct AvroRecord(HashMap<String, AvroValue>);
+
+impl AvroRecord {
+    fn try_from(value: AvroValue) -> Result<Self> {
+        match value {
+            AvroValue::Record(fields) => Ok(Self(fields.into_iter().collect())),
+            v => Err(ErrorCode::ReadTableDataError(format!(
+                "Invalid iceberg manifest, expect record but got {v:?}"
+            ))),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&AvroValue> {
+        let value = match self.0.get(name)? {
+            AvroValue::Union(_, v) => v.as_ref(),
+            v => v,
+        };
+        Some(value).filter(|v| !matches!(v, AvroValue::Null))
+    }
+
+    fn long(&self, names: &[&str]) -> Option<i64> {
+        names
+            .iter()
+            .find_map(|name| self.get(name).and_then(Self::as_long))
+    }
+
+    fn string(&self, name: &str) -> Result<String> {
+        match self.get(name) {
+            Some(AvroValue::String(v)) => Ok(v.clone()),
+            v => Err(ErrorCode::ReadTableDataError(format!(
+                "Invalid iceberg manifest field {name}: {v:?}"
+            ))),
+        }
+    }
+
+    fn record(&self, name: &str) -> Result<AvroRecord> {
+        match self.get(name) {
+            Some(v) => Self::try_from(v.clone()),
+            None => Err(ErrorCode::ReadTableDataError(format!(
+                "Invalid iceberg manifest, field {name} is missing"
+            ))),
+        }
+    }
+
+    fn int_map<T>(&self, name: &str, f: impl Fn(&AvroValue) -> Option<T>) -> Vec<(i32, T)> {
+        match self.get(name) {
+            Some(AvroValue::Array(items)) => items
+                .iter()
+                .filter_map(|item| match item {
+                    AvroValue::Record(kv) => {
+                        let key = kv.iter().find(|(k, _)| k == "key")?;
+                        let value = kv.iter().find(|(k, _)| k == "value")?;
+                        Some((Self::as_long(&key.1)? as i32, f(&value.1)?))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn int_array(&self, name: &str) -> Vec<i32> {
+        match self.get(name) {
+            Some(AvroValue::Array(items)) => items
+                .iter()
+                .filter_map(|v| Self::as_long(v).map(|v| v as i32))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Serialize the fields of a partition record like the bounds are, sorted by name.
+    fn partition_values(&self) -> Vec<(String, Option<Vec<u8>>)> {
+        let mut values = self
+            .0
+            .keys()
+            .map(|name| {
+                let value = self.get(name).and_then(|v| match v {
+                    AvroValue::Boolean(v) => Some(vec![*v as u8]),
+                    AvroValue::Int(v) | AvroValue::Date(v) | AvroValue::TimeMillis(v) => {
+                        Some(v.to_le_bytes().to_vec())
+                    }
+                    AvroValue::Long(v)
+                    | AvroValue::TimeMicros(v)
+                    | AvroValue::TimestampMillis(v)
+                    | AvroValue::TimestampMicros(v) => Some(v.to_le_bytes().to_vec()),
+                    AvroValue::Float(v) => Some(v.to_le_bytes().to_vec()),
+                    AvroValue::Double(v) => Some(v.to_le_bytes().to_vec()),
+                    AvroValue::String(v) => Some(v.as_bytes().to_vec()),
+                    AvroValue::Decimal(v) => Vec::<u8>::try_from(v).ok(),
+                    v => Self::as_bytes(v),
+                });
+                (name.clone(), value)
+            })
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| a.0.cmp(&b.0));
+        values
+    }
+
+    fn as_long(value: &AvroValue) -> Option<i64> {
+        match value {
+            AvroValue::Long(v) => Some(*v),
+            AvroValue::Int(v) => Some(*v as i64),
+            _ => None,
+        }
+    }
+
+    fn as_bytes(value: &AvroValue) -> Option<Vec<u8>> {
+        match value {
+            AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,696 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Iceberg table metadata (`*.metadata.json`).
+//!
+//! The metadata is kept as raw json, so fields we don't understand survive
+//! a commit untouched. Only the parts needed for reading and appending are
+//! exposed as typed views.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_meta_app::storage::StorageParams;
+use dashmap::DashMap;
+use futures::lock::Mutex;
+use futures::TryStreamExt;
+use once_cell::sync::Lazy;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+pub const METADATA_DIR: &str = "metadata";
+const VERSION_HINT_PATH: &str = "metadata/version-hint.text";
+const METADATA_FILE_SUFFIX: &str = ".metadata.json";
+
+/// A field of an iceberg schema, the type is kept as raw json since it may be nested.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SchemaField {
+    pub id: i32,
+    pub name: String,
+    pub required: bool,
+    #[serde(rename = "type")]
+    pub field_type: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Schema {
+    #[serde(rename = "schema-id", default)]
+    pub schema_id: i32,
+    pub fields: Vec<SchemaField>,
+}
+
+impl Schema {
+    /// Convert to the table schema exposed to databend.
+    pub fn to_table_schema(&self) -> Result<TableSchema> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| {
+                let ty = to_table_type(&f.field_type, f.required)?;
+                Ok(TableField::new(&f.name, ty))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TableSchema::new(fields))
+    }
+
+    /// The json representation used in metadata files and manifest headers.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "type": "struct",
+            "schema-id": self.schema_id,
+            "fields": self.fields,
+        })
+    }
+}
+
+/// A field of a partition spec, deriving the partition value from the
+/// source column with `transform`, e.g. `identity`, `bucket[16]` or `day`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionField {
+    pub source_id: i32,
+    /// Absent in v1 tables.
+    #[serde(default)]
+    pub field_id: Option<i32>,
+    pub name: String,
+    pub transform: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    /// Only absent for v1 tables listing manifests inline, which are not supported.
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+    #[serde(default)]
+    pub summary: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+}
+
+impl Snapshot {
+    /// Read a counter of the snapshot summary, e.g. `total-records`.
+    pub fn summary_count(&self, key: &str) -> i64 {
+        self.summary
+            .get(key)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// A loaded `*.metadata.json` file.
+#[derive(Clone, Debug)]
+pub struct TableMetadata {
+    /// Version parsed from the file name.
+    pub version: u64,
+    /// Path of the metadata file, relative to the table root.
+    pub path: String,
+    /// Whether the table is tracked by `metadata/version-hint.text`.
+    version_hint: bool,
+    json: Map<String, Value>,
+}
+
+impl TableMetadata {
+    pub fn parse(version: u64, path: String, version_hint: bool, bytes: &[u8]) -> Result<Self> {
+        let json = serde_json::from_slice::<Map<String, Value>>(bytes).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse metadata {path}: {e}"))
+        })?;
+        Ok(Self {
+            version,
+            path,
+            version_hint,
+            json,
+        })
+    }
+
+    pub fn format_version(&self) -> i64 {
+        self.json
+            .get("format-version")
+            .and_then(Value::as_i64)
+            .unwrap_or(1)
+    }
+
+    pub fn location(&self) -> Result<&str> {
+        self.json
+            .get("location")
+            .and_then(Value::as_str)
+            .map(|v| v.trim_end_matches('/'))
+            .ok_or_else(|| self.invalid("location is missing"))
+    }
+
+    pub fn current_schema(&self) -> Result<Schema> {
+        match self.json.get("current-schema-id").and_then(Value::as_i64) {
+            Some(id) => self.schema(id as i32),
+            // v1 tables may only have the single `schema` field.
+            None => {
+                let schema = self
+                    .json
+                    .get("schema")
+                    .ok_or_else(|| self.invalid("current schema is missing"))?;
+                serde_json::from_value(schema.clone()).map_err(|e| self.invalid(e))
+            }
+        }
+    }
+
+    /// The schema a snapshot was written with, falling back to the current one.
+    pub fn snapshot_schema(&self, snapshot: &Snapshot) -> Result<Schema> {
+        match snapshot.schema_id {
+            Some(id) => self.schema(id),
+            None => self.current_schema(),
+        }
+    }
+
+    fn schema(&self, id: i32) -> Result<Schema> {
+        let schema = self
+            .json
+            .get("schemas")
+            .and_then(Value::as_array)
+            .and_then(|schemas| {
+                schemas
+                    .iter()
+                    .find(|s| s.get("schema-id").and_then(Value::as_i64) == Some(id as i64))
+            })
+            .ok_or_else(|| self.invalid(format!("schema {id} is missing")))?;
+        serde_json::from_value(schema.clone()).map_err(|e| self.invalid(e))
+    }
+
+    /// Only unpartitioned v2 tables can be written for now.
+    pub fn check_writable(&self) -> Result<()> {
+        if self.format_version() < 2 {
+            return Err(ErrorCode::Unimplemented(
+                "Writing to iceberg table of format version 1 is not supported yet",
+            ));
+        }
+        if !self.partition_spec(self.default_spec_id())?.is_empty() {
+            return Err(ErrorCode::Unimplemented(
+                "Writing to partitioned iceberg table is not supported yet",
+            ));
+        }
+        Ok(())
+    }
+
+    /// The fields of partition spec `spec_id`.
+    pub fn partition_spec(&self, spec_id: i32) -> Result<Vec<PartitionField>> {
+        let spec = self
+            .json
+            .get("partition-specs")
+            .and_then(Value::as_array)
+            .and_then(|specs| {
+                specs
+                    .iter()
+                    .find(|s| s.get("spec-id").and_then(Value::as_i64) == Some(spec_id as i64))
+            })
+            .and_then(|s| s.get("fields"));
+        // v1 tables may only have the single `partition-spec` field.
+        let spec = match spec {
+            None if spec_id == self.default_spec_id() => self.json.get("partition-spec"),
+            spec => spec,
+        };
+        match spec {
+            Some(fields) => serde_json::from_value(fields.clone()).map_err(|e| self.invalid(e)),
+            None => Ok(vec![]),
+        }
+    }
+
+    pub fn default_spec_id(&self) -> i32 {
+        self.json
+            .get("default-spec-id")
+            .and_then(Value::as_i64)
+            .unwrap_or_default() as i32
+    }
+
+    pub fn last_sequence_number(&self) -> i64 {
+        self.json
+            .get("last-sequence-number")
+            .and_then(Value::as_i64)
+            .unwrap_or_default()
+    }
+
+    pub fn current_snapshot_id(&self) -> Option<i64> {
+        self.json
+            .get("current-snapshot-id")
+            .and_then(Value::as_i64)
+            .filter(|id| *id != -1)
+    }
+
+    pub fn snapshots(&self) -> Result<Vec<Snapshot>> {
+        match self.json.get("snapshots") {
+            None | Some(Value::Null) => Ok(vec![]),
+            Some(v) => serde_json::from_value(v.clone()).map_err(|e| self.invalid(e)),
+        }
+    }
+
+    pub fn current_snapshot(&self) -> Result<Option<Snapshot>> {
+        match self.current_snapshot_id() {
+            None => Ok(None),
+            Some(id) => match self.snapshot(id)? {
+                Some(s) => Ok(Some(s)),
+                None => Err(self.invalid(format!("current snapshot {id} is missing"))),
+            },
+        }
+    }
+
+    pub fn snapshot(&self, id: i64) -> Result<Option<Snapshot>> {
+        Ok(self.snapshots()?.into_iter().find(|s| s.snapshot_id == id))
+    }
+
+    /// The snapshot that was current at `timestamp_ms`, according to the
+    /// `snapshot-log`.
+    pub fn snapshot_at(&self, timestamp_ms: i64) -> Result<Option<Snapshot>> {
+        let id = self
+            .json
+            .get("snapshot-log")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let ts = entry.get("timestamp-ms").and_then(Value::as_i64)?;
+                let id = entry.get("snapshot-id").and_then(Value::as_i64)?;
+                Some((ts, id))
+            })
+            .filter(|(ts, _)| *ts <= timestamp_ms)
+            .max_by_key(|(ts, _)| *ts)
+            .map(|(_, id)| id);
+        match id {
+            Some(id) => self.snapshot(id),
+            None => Ok(None),
+        }
+    }
+
+    /// Make a path written in iceberg metadata relative to the table root.
+    pub fn rel_path(&self, path: &str) -> Result<String> {
+        let location = self.location()?;
+        path.strip_prefix(location)
+            .map(|p| p.trim_start_matches('/').to_string())
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Path {path} is not under iceberg table location {location}"
+                ))
+            })
+    }
+
+    /// Make a path relative to the table root absolute, as iceberg requires.
+    pub fn abs_path(&self, rel_path: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.location()?, rel_path))
+    }
+
+    /// Build the metadata of the next version with `snapshot` as the current snapshot.
+    pub fn with_snapshot(&self, snapshot: &Snapshot) -> Result<Map<String, Value>> {
+        let mut json = self.json.clone();
+        let snapshot_value = serde_json::to_value(snapshot).map_err(|e| self.invalid(e))?;
+
+        let previous_updated_ms = json
+            .get("last-updated-ms")
+            .and_then(Value::as_i64)
+            .unwrap_or(snapshot.timestamp_ms);
+        let metadata_file = self.abs_path(&self.path)?;
+
+        json.insert("last-updated-ms".to_string(), json!(snapshot.timestamp_ms));
+        json.insert(
+            "last-sequence-number".to_string(),
+            json!(snapshot.sequence_number),
+        );
+        json.insert(
+            "current-snapshot-id".to_string(),
+            json!(snapshot.snapshot_id),
+        );
+        push_array(&mut json, "snapshots", snapshot_value);
+        push_array(
+            &mut json,
+            "snapshot-log",
+            json!({
+                "timestamp-ms": snapshot.timestamp_ms,
+                "snapshot-id": snapshot.snapshot_id,
+            }),
+        );
+        push_array(
+            &mut json,
+            "metadata-log",
+            json!({
+                "timestamp-ms": previous_updated_ms,
+                "metadata-file": metadata_file,
+            }),
+        );
+        let refs = json
+            .entry("refs")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(refs) = refs {
+            refs.insert(
+                "main".to_string(),
+                json!({"snapshot-id": snapshot.snapshot_id, "type": "branch"}),
+            );
+        }
+        Ok(json)
+    }
+
+    fn invalid(&self, reason: impl ToString) -> ErrorCode {
+        ErrorCode::ReadTableDataError(format!(
+            "Invalid iceberg metadata {}: {}",
+            self.path,
+            reason.to_string()
+        ))
+    }
+}
+
+fn push_array(json: &mut Map<String, Value>, key: &str, value: Value) {
+    let array = json.entry(key).or_insert_with(|| Value::Array(vec![]));
+    match array {
+        Value::Array(array) => array.push(value),
+        other => *other = Value::Array(vec![value]),
+    }
+}
+
+/// Parse the version of a metadata file name, both `v3.metadata.json` and
+/// `00003-<uuid>.metadata.json` are accepted.
+fn parse_metadata_version(name: &str) -> Option<u64> {
+    let stem = name.strip_suffix(METADATA_FILE_SUFFIX)?;
+    let stem = stem.strip_prefix('v').unwrap_or(stem);
+    stem.split('-').next()?.parse().ok()
+}
+
+/// Find the path of the latest metadata file, returns `(version, path, version_hint)`.
+///
+/// The version hint may be read while it's being rewritten by a commit, the metadata
+/// files are listed in that case, as the hint is written after the metadata it points to.
+async fn current_metadata_path(op: &Operator) -> Result<(u64, String, bool)> {
+    let version_hint = op.is_exist(VERSION_HINT_PATH).await?;
+    if version_hint {
+        let hint = op.read(VERSION_HINT_PATH).await?;
+        if let Ok(version) = String::from_utf8_lossy(&hint).trim().parse::<u64>() {
+            let path = format!("{METADATA_DIR}/v{version}{METADATA_FILE_SUFFIX}");
+            return Ok((version, path, true));
+        }
+    }
+
+    let mut latest: Option<(u64, String)> = None;
+    let mut lister = op.list(&format!("{METADATA_DIR}/")).await?;
+    while let Some(entry) = lister.try_next().await? {
+        if let Some(version) = parse_metadata_version(entry.name()) {
+            if latest.as_ref().map_or(true, |(v, _)| version > *v) {
+                latest = Some((version, entry.path().to_string()));
+            }
+        }
+    }
+    match latest {
+        Some((version, path)) => Ok((version, path, version_hint)),
+        None => Err(ErrorCode::ReadTableDataError(
+            "Cannot find any iceberg metadata file",
+        )),
+    }
+}
+
+/// Load the latest metadata of the table rooted at `op`.
+#[async_backtrace::framed]
+pub async fn load_metadata(op: &Operator) -> Result<TableMetadata> {
+    let (version, path, version_hint) = current_metadata_path(op).await?;
+    let bytes = op.read(&path).await?;
+    TableMetadata::parse(version, path, version_hint, &bytes)
+}
+
+/// Locks serializing the commits of a table within this process, keyed by the storage
+/// and root of the table.
+static COMMIT_LOCKS: Lazy<DashMap<String, Arc<Mutex<()>>>> = Lazy::new(DashMap::new);
+
+fn commit_lock(op: &Operator) -> Arc<Mutex<()>> {
+    let info = op.info();
+    let key = format!("{}://{}{}", info.scheme(), info.name(), info.root());
+    COMMIT_LOCKS.entry(key).or_default().clone()
+}
+
+/// Publish `json` as the next version of `base`.
+///
+/// Plain object storages offer no compare-and-swap, so a table supports a single
+/// writing query node only. The commits of the node are serialized by a lock,
+/// which is held from checking the latest version until the new version is
+/// published, and fail if the table has moved forward since `base` was loaded.
+/// The lock lives in memory, so the commits of different processes, or of other
+/// iceberg engines, are not isolated from each other: the version check is a best
+/// effort and a racing commit of another process may be overwritten.
+#[async_backtrace::framed]
+pub async fn commit_metadata(
+    op: &Operator,
+    base: &TableMetadata,
+    json: Map<String, Value>,
+) -> Result<()> {
+    let lock = commit_lock(op);
+    let _guard = lock.lock().await;
+
+    let (latest, _, _) = current_metadata_path(op).await?;
+    if latest != base.version {
+        return Err(ErrorCode::TableVersionMismatched(format!(
+            "Iceberg table has been updated concurrently, expect version {} but got {}",
+            base.version, latest
+        )));
+    }
+
+    let version = base.version + 1;
+    let bytes = serde_json::to_vec_pretty(&json)
+        .map_err(|e| ErrorCode::Internal(format!("Cannot serialize iceberg metadata: {e}")))?;
+    if base.version_hint {
+        let path = format!("{METADATA_DIR}/v{version}{METADATA_FILE_SUFFIX}");
+        if op.is_exist(&path).await? {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Iceberg metadata {path} already exists"
+            )));
+        }
+        op.write(&path, bytes).await?;
+        op.write(VERSION_HINT_PATH, version.to_string().into_bytes())
+            .await?;
+    } else {
+        let path = format!(
+            "{METADATA_DIR}/{version:05}-{}{METADATA_FILE_SUFFIX}",
+            uuid::Uuid::new_v4()
+        );
+        op.write(&path, bytes).await?;
+    }
+    Ok(())
+}
+
+/// Write the first metadata of a new table rooted at `op`.
+#[async_backtrace::framed]
+pub async fn create_metadata(op: &Operator, location: &str, schema: &TableSchema) -> Result<()> {
+    let mut next_id = 0;
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| {
+            next_id += 1;
+            let (field_type, required) = to_iceberg_type(f.data_type())?;
+            Ok(SchemaField {
+                id: next_id,
+                name: f.name().clone(),
+                required,
+                field_type,
+                doc: None,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let schema = Schema {
+        schema_id: 0,
+        fields,
+    };
+
+    let json = json!({
+        "format-version": 2,
+        "table-uuid": uuid::Uuid::new_v4().to_string(),
+        "location": location.trim_end_matches('/'),
+        "last-sequence-number": 0,
+        "last-updated-ms": chrono::Utc::now().timestamp_millis(),
+        "last-column-id": next_id,
+        "current-schema-id": 0,
+        "schemas": [schema.to_json()],
+        "default-spec-id": 0,
+        "partition-specs": [{"spec-id": 0, "fields": []}],
+        "last-partition-id": 999,
+        "default-sort-order-id": 0,
+        "sort-orders": [{"order-id": 0, "fields": []}],
+        "properties": {},
+        "current-snapshot-id": -1,
+        "refs": {},
+        "snapshots": [],
+        "snapshot-log": [],
+        "metadata-log": [],
+    });
+    let bytes = serde_json::to_vec_pretty(&json)
+        .map_err(|e| ErrorCode::Internal(format!("Cannot serialize iceberg metadata: {e}")))?;
+    op.write(&format!("{METADATA_DIR}/v1{METADATA_FILE_SUFFIX}"), bytes)
+        .await?;
+    op.write(VERSION_HINT_PATH, b"1".to_vec()).await?;
+    Ok(())
+}
+
+/// The absolute location of a table, iceberg metadata always refers files by it.
+pub fn table_location(params: &StorageParams) -> Result<String> {
+    fn join(prefix: String, root: &str) -> String {
+        format!("{prefix}/{}", root.trim_matches('/'))
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    let location = match params {
+        StorageParams::Fs(v) => format!("file://{}", v.root.trim_end_matches('/')),
+        StorageParams::S3(v) => join(format!("s3://{}", v.bucket), &v.root),
+        StorageParams::Gcs(v) => join(format!("gs://{}", v.bucket), &v.root),
+        StorageParams::Oss(v) => join(format!("oss://{}", v.bucket), &v.root),
+        StorageParams::Obs(v) => join(format!("obs://{}", v.bucket), &v.root),
+        StorageParams::Cos(v) => join(format!("cos://{}", v.bucket), &v.root),
+        StorageParams::Azblob(v) => join(format!("azblob://{}", v.container), &v.root),
+        v => {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "Cannot create iceberg table on storage {v}"
+            )));
+        }
+    };
+    Ok(location)
+}
+
+fn to_table_type(ty: &Value, required: bool) -> Result<TableDataType> {
+    let unsupported =
+        || ErrorCode::Unimplemented(format!("Iceberg type {ty} is not supported yet"));
+    let table_type = match ty {
+        Value::String(ty) => match ty.as_str() {
+            "boolean" => TableDataType::Boolean,
+            "int" => TableDataType::Number(NumberDataType::Int32),
+            "long" => TableDataType::Number(NumberDataType::Int64),
+            "float" => TableDataType::Number(NumberDataType::Float32),
+            "double" => TableDataType::Number(NumberDataType::Float64),
+            "date" => TableDataType::Date,
+            "timestamp" | "timestamptz" => TableDataType::Timestamp,
+            "string" | "uuid" | "binary" => TableDataType::String,
+            ty if ty.starts_with("fixed[") => TableDataType::String,
+            ty if ty.starts_with("decimal(") => {
+                let (precision, scale) = parse_decimal(ty).ok_or_else(unsupported)?;
+                TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                    precision,
+                    scale,
+                }))
+            }
+            _ => return Err(unsupported()),
+        },
+        Value::Object(obj) => match obj.get("type").and_then(Value::as_str) {
+            Some("struct") => {
+                let fields: Vec<SchemaField> = obj
+                    .get("fields")
+                    .cloned()
+                    .map(serde_json::from_value)
+                    .transpose()
+                    .map_err(|_| unsupported())?
+                    .unwrap_or_default();
+                let mut fields_name = Vec::with_capacity(fields.len());
+                let mut fields_type = Vec::with_capacity(fields.len());
+                for f in fields {
+                    fields_type.push(to_table_type(&f.field_type, f.required)?);
+                    fields_name.push(f.name);
+                }
+                TableDataType::Tuple {
+                    fields_name,
+                    fields_type,
+                }
+            }
+            Some("list") => {
+                let element = obj.get("element").ok_or_else(unsupported)?;
+                let element_required = obj
+                    .get("element-required")
+                    .and_then(Value::as_bool)
+                    .unwrap_or_default();
+                TableDataType::Array(Box::new(to_table_type(element, element_required)?))
+            }
+            Some("map") => {
+                let key = obj.get("key").ok_or_else(unsupported)?;
+                let value = obj.get("value").ok_or_else(unsupported)?;
+                let value_required = obj
+                    .get("value-required")
+                    .and_then(Value::as_bool)
+                    .unwrap_or_default();
+                TableDataType::Map(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["key".to_string(), "value".to_string()],
+                    fields_type: vec![
+                        to_table_type(key, true)?,
+                        to_table_type(value, value_required)?,
+                    ],
+                }))
+            }
+            _ => return Err(unsupported()),
+        },
+        _ => return Err(unsupported()),
+    };
+
+    if required {
+        Ok(table_type)
+    } else {
+        Ok(table_type.wrap_nullable())
+    }
+}
+
+pub fn parse_decimal(ty: &str) -> Option<(u8, u8)> {
+    let args = ty.strip_prefix("decimal(")?.strip_suffix(')')?;
+    let (precision, scale) = args.split_once(',')?;
+    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+}
+
+/// Returns the iceberg type of a top-level column and whether it's required.
+fn to_iceberg_type(ty: &TableDataType) -> Result<(Value, bool)> {
+    let (ty, required) = match ty {
+        TableDataType::Nullable(inner) => (inner.as_ref(), false),
+        ty => (ty, true),
+    };
+    let iceberg_type = match ty {
+        TableDataType::Boolean => "boolean".to_string(),
+        TableDataType::Number(num) => match num {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16 => "int".to_string(),
+            NumberDataType::Int64 | NumberDataType::UInt32 | NumberDataType::UInt64 => {
+                "long".to_string()
+            }
+            NumberDataType::Float32 => "float".to_string(),
+            NumberDataType::Float64 => "double".to_string(),
+        },
+        TableDataType::Decimal(decimal) if decimal.precision() <= 38 => {
+            format!("decimal({}, {})", decimal.precision(), decimal.scale())
+        }
+        TableDataType::Date => "date".to_string(),
+        TableDataType::Timestamp => "timestamp".to_string(),
+        TableDataType::String => "string".to_string(),
+        TableDataType::Binary => "binary".to_string(),
+        ty => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Data type {ty} is not supported by iceberg tables yet"
+            )));
+        }
+    };
+    Ok((Value::String(iceberg_type), required))
+}
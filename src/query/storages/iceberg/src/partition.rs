@@ -22,6 +22,8 @@ use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::delete::IcebergDeleteFile;
+
 /// # TODO
 ///
 /// - we should support different format.
@@ -29,6 +31,10 @@ use common_exception::Result;
 pub struct IcebergPartInfo {
     pub path: String,
     pub size: u64,
+    /// Absolute path of the data file, as referenced by position deletes.
+    pub file_path: String,
+    /// The delete files to apply.
+    pub deletes: Vec<IcebergDeleteFile>,
 }
 
 impl IcebergPartInfo {
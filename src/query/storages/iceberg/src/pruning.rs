@@ -0,0 +1,637 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Data file pruning with the metrics and partition values kept in manifests.
+//!
+//! Both are turned into [`StatisticsOfColumns`] of the source columns, so that
+//! the range pruner shared with the other storages can evaluate the filter.
+//! Bucket partitions have no order, they are only checked against the
+//! `column = constant` conjuncts of the filter.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use common_catalog::plan::PushDownInfo;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::ColumnId;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use serde_json::Value;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::manifest::DataFile;
+use crate::metadata::parse_decimal;
+use crate::metadata::PartitionField;
+use crate::metadata::Schema;
+use crate::writer::decimal_bytes;
+
+const MICROS_PER_HOUR: i64 = 3_600_000_000;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
+pub struct IcebergPruner {
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    /// Top level primitive columns, iceberg field id -> (column id, iceberg type).
+    columns: HashMap<i32, (ColumnId, String)>,
+    /// Constants of the `column = constant` conjuncts, by iceberg field id.
+    equalities: HashMap<i32, Scalar>,
+}
+
+impl IcebergPruner {
+    /// Create a pruner if there is a filter to prune with.
+    ///
+    /// `schema` is the table schema converted from `iceberg_schema`.
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        iceberg_schema: &Schema,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Option<Self>> {
+        let filter = match push_downs.as_ref().and_then(|p| p.filter.as_ref()) {
+            Some(filter) => filter.as_expr(&BUILTIN_FUNCTIONS),
+            None => return Ok(None),
+        };
+        let range_pruner = RangePrunerCreator::try_create(func_ctx, schema, Some(&filter))?;
+
+        let mut columns = HashMap::new();
+        for field in &iceberg_schema.fields {
+            if let Value::String(ty) = &field.field_type {
+                let column_ids = schema.leaf_columns_of(&field.name);
+                if column_ids.len() == 1 {
+                    columns.insert(field.id, (column_ids[0], ty.clone()));
+                }
+            }
+        }
+
+        let mut equalities = HashMap::new();
+        find_eq_constants(&filter, &mut |name, scalar| {
+            if let Some(field) = iceberg_schema.fields.iter().find(|f| f.name == name) {
+                equalities.insert(field.id, scalar.clone());
+            }
+        });
+
+        Ok(Some(Self {
+            range_pruner,
+            columns,
+            equalities,
+        }))
+    }
+
+    /// Returns false if no row of `file` can match the filter.
+    pub fn should_keep(&self, file: &DataFile, spec: &[PartitionField]) -> bool {
+        self.range_pruner
+            .should_keep(&self.metric_statistics(file), None)
+            && self
+                .range_pruner
+                .should_keep(&self.partition_statistics(file, spec), None)
+            && self.bucket_matches(file, spec)
+    }
+
+    fn metric_statistics(&self, file: &DataFile) -> StatisticsOfColumns {
+        let mut stats = StatisticsOfColumns::new();
+        for (id, (column_id, ty)) in &self.columns {
+            let null_count = find(&file.null_value_counts, *id).copied();
+            let value_count = find(&file.value_counts, *id)
+                .copied()
+                .unwrap_or(file.record_count);
+            let (min, max) = if value_count > 0 && null_count == Some(value_count) {
+                (Scalar::Null, Scalar::Null)
+            } else {
+                let lower = find(&file.lower_bounds, *id).and_then(|v| decode_value(ty, v));
+                let upper = find(&file.upper_bounds, *id).and_then(|v| decode_value(ty, v));
+                match (lower, upper) {
+                    (Some(min), Some(max)) if !is_nan(&min) && !is_nan(&max) => {
+                        // NaN is excluded from the bounds, but sorts after
+                        // any other value in databend.
+                        let nan_count = find(&file.nan_value_counts, *id).copied();
+                        match nan_count {
+                            Some(0) => (min, max),
+                            _ => (min, nan_of(ty).unwrap_or(max)),
+                        }
+                    }
+                    _ => continue,
+                }
+            };
+            stats.insert(*column_id, ColumnStatistics {
+                min,
+                max,
+                // Unknown null count must not rule out nulls.
+                null_count: null_count.unwrap_or(value_count) as u64,
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+        stats
+    }
+
+    /// The range of the source columns implied by the partition values.
+    fn partition_statistics(
+        &self,
+        file: &DataFile,
+        spec: &[PartitionField],
+    ) -> StatisticsOfColumns {
+        let mut stats = StatisticsOfColumns::new();
+        for field in spec {
+            let (column_id, ty) = match self.columns.get(&field.source_id) {
+                Some(column) => column,
+                None => continue,
+            };
+            if stats.contains_key(column_id) {
+                continue;
+            }
+            let value = match file.partition.iter().find(|(name, _)| name == &field.name) {
+                Some((_, value)) => value,
+                None => continue,
+            };
+            // All the transforms keep nulls as nulls.
+            let (range, null_count) = match value {
+                Some(v) => (transform_range(&field.transform, ty, v), 0),
+                None => (Some((Scalar::Null, Scalar::Null)), file.record_count as u64),
+            };
+            if let Some((min, max)) = range {
+                stats.insert(*column_id, ColumnStatistics {
+                    min,
+                    max,
+                    null_count,
+                    in_memory_size: 0,
+                    distinct_of_values: None,
+                });
+            }
+        }
+        stats
+    }
+
+    fn bucket_matches(&self, file: &DataFile, spec: &[PartitionField]) -> bool {
+        spec.iter().all(|field| {
+            let num_buckets = match transform_arg(&field.transform, "bucket") {
+                Some(n) if n > 0 => n,
+                _ => return true,
+            };
+            let (constant, ty) = match (
+                self.equalities.get(&field.source_id),
+                self.columns.get(&field.source_id),
+            ) {
+                (Some(constant), Some((_, ty))) => (constant, ty),
+                _ => return true,
+            };
+            match file.partition.iter().find(|(name, _)| name == &field.name) {
+                Some((_, Some(bucket))) => match (bucket_input(ty, constant), le_i32(bucket)) {
+                    (Some(input), Some(bucket)) => {
+                        (murmur3_32(&input) & i32::MAX) % num_buckets as i32 == bucket
+                    }
+                    _ => true,
+                },
+                // Only nulls in the file, which are never equal to the constant.
+                Some((_, None)) => constant.is_null(),
+                None => true,
+            }
+        })
+    }
+}
+
+/// Collect the `column = constant` conjuncts of `expr`.
+fn find_eq_constants(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scalar)) {
+    match expr {
+        Expr::Cast { expr, .. } => find_eq_constants(expr, visitor),
+        Expr::FunctionCall { function, args, .. } => match function.signature.name.as_str() {
+            "eq" => match args.as_slice() {
+                [Expr::ColumnRef { id, .. }, Expr::Constant { scalar, .. }]
+                | [Expr::Constant { scalar, .. }, Expr::ColumnRef { id, .. }] => {
+                    visitor(id, scalar)
+                }
+                _ => {}
+            },
+            "and" | "and_filters" | "is_true" => {
+                for arg in args {
+                    find_eq_constants(arg, visitor)
+                }
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+fn find<T>(entries: &[(i32, T)], id: i32) -> Option<&T> {
+    entries.iter().find(|(k, _)| *k == id).map(|(_, v)| v)
+}
+
+/// Decode a value in the iceberg single-value binary serialization.
+fn decode_value(ty: &str, bytes: &[u8]) -> Option<Scalar> {
+    let scalar = match ty {
+        "boolean" => Scalar::Boolean(*bytes.first()? != 0),
+        "int" => Scalar::Number(NumberScalar::Int32(le_i32(bytes)?)),
+        // Files written before promoting int to long / float to double
+        // keep the narrower bounds.
+        "long" => Scalar::Number(NumberScalar::Int64(match bytes.len() {
+            4 => le_i32(bytes)? as i64,
+            _ => le_i64(bytes)?,
+        })),
+        "float" => Scalar::Number(NumberScalar::Float32(F32::from(f32::from_le_bytes(
+            bytes.try_into().ok()?,
+        )))),
+        "double" => Scalar::Number(NumberScalar::Float64(F64::from(match bytes.len() {
+            4 => f32::from_le_bytes(bytes.try_into().ok()?) as f64,
+            _ => f64::from_le_bytes(bytes.try_into().ok()?),
+        }))),
+        "date" => Scalar::Date(le_i32(bytes)?),
+        "timestamp" | "timestamptz" => Scalar::Timestamp(le_i64(bytes)?),
+        "string" => Scalar::String(bytes.to_vec()),
+        ty if ty.starts_with("decimal(") => {
+            let (precision, scale) = parse_decimal(ty)?;
+            Scalar::Decimal(DecimalScalar::Decimal128(be_i128(bytes)?, DecimalSize {
+                precision,
+                scale,
+            }))
+        }
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+/// The range of source values mapped to partition `value` by `transform`.
+fn transform_range(transform: &str, ty: &str, value: &[u8]) -> Option<(Scalar, Scalar)> {
+    match transform {
+        "identity" => {
+            let v = decode_value(ty, value)?;
+            Some((v.clone(), v))
+        }
+        "year" | "month" | "day" | "hour" => {
+            let (start, end) = time_range(transform, le_i32(value)? as i64)?;
+            match ty {
+                "date" if transform != "hour" => Some((
+                    Scalar::Date((start / MICROS_PER_DAY) as i32),
+                    Scalar::Date((end / MICROS_PER_DAY - 1) as i32),
+                )),
+                "timestamp" | "timestamptz" => {
+                    Some((Scalar::Timestamp(start), Scalar::Timestamp(end - 1)))
+                }
+                _ => None,
+            }
+        }
+        transform => {
+            // Truncated strings only have a lower bound, they are not used.
+            let width = transform_arg(transform, "truncate")?;
+            match decode_value(ty, value)? {
+                Scalar::Number(NumberScalar::Int32(v)) => Some((
+                    Scalar::Number(NumberScalar::Int32(v)),
+                    Scalar::Number(NumberScalar::Int32(v.checked_add(width as i32 - 1)?)),
+                )),
+                Scalar::Number(NumberScalar::Int64(v)) => Some((
+                    Scalar::Number(NumberScalar::Int64(v)),
+                    Scalar::Number(NumberScalar::Int64(v.checked_add(width - 1)?)),
+                )),
+                Scalar::Decimal(DecimalScalar::Decimal128(v, size)) => Some((
+                    Scalar::Decimal(DecimalScalar::Decimal128(v, size)),
+                    Scalar::Decimal(DecimalScalar::Decimal128(
+                        v.checked_add(width as i128 - 1)?,
+                        size,
+                    )),
+                )),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// The `[start, end)` micros since epoch covered by the `ordinal`-th
+/// year/month/day/hour since epoch.
+fn time_range(transform: &str, ordinal: i64) -> Option<(i64, i64)> {
+    let unit = match transform {
+        "hour" => MICROS_PER_HOUR,
+        "day" => MICROS_PER_DAY,
+        _ => {
+            let months = if transform == "year" { 12 } else { 1 };
+            let start = month_start_micros(ordinal * months)?;
+            let end = month_start_micros((ordinal + 1) * months)?;
+            return Some((start, end));
+        }
+    };
+    Some((ordinal.checked_mul(unit)?, (ordinal + 1).checked_mul(unit)?))
+}
+
+fn month_start_micros(months_since_epoch: i64) -> Option<i64> {
+    let year = 1970 + months_since_epoch.div_euclid(12);
+    let month = months_since_epoch.rem_euclid(12) + 1;
+    let date = NaiveDate::from_ymd_opt(year.try_into().ok()?, month as u32, 1)?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+    (date - epoch).num_days().checked_mul(MICROS_PER_DAY)
+}
+
+/// The argument of a parameterized transform like `bucket[16]`.
+fn transform_arg(transform: &str, name: &str) -> Option<i64> {
+    transform
+        .strip_prefix(name)?
+        .strip_prefix('[')?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// The bytes hashed by the bucket transform.
+fn bucket_input(ty: &str, value: &Scalar) -> Option<Vec<u8>> {
+    let long = match (ty, value) {
+        ("int" | "long", Scalar::Number(n)) => match n {
+            NumberScalar::Int8(v) => *v as i64,
+            NumberScalar::Int16(v) => *v as i64,
+            NumberScalar::Int32(v) => *v as i64,
+            NumberScalar::Int64(v) => *v,
+            NumberScalar::UInt8(v) => *v as i64,
+            NumberScalar::UInt16(v) => *v as i64,
+            NumberScalar::UInt32(v) => *v as i64,
+            NumberScalar::UInt64(v) => i64::try_from(*v).ok()?,
+            _ => return None,
+        },
+        ("date", Scalar::Date(v)) => *v as i64,
+        ("timestamp" | "timestamptz", Scalar::Timestamp(v)) => *v,
+        ("string", Scalar::String(v)) => return Some(v.clone()),
+        (ty, Scalar::Decimal(DecimalScalar::Decimal128(v, size)))
+            if parse_decimal(ty).map(|(_, scale)| scale) == Some(size.scale) =>
+        {
+            return Some(decimal_bytes(*v));
+        }
+        _ => return None,
+    };
+    Some(long.to_le_bytes().to_vec())
+}
+
+/// The 32 bits murmur3 hash (x86 variant, seed 0) used by iceberg buckets.
+fn murmur3_32(data: &[u8]) -> i32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+    let mut h = 0u32;
+    let chunks = data.chunks_exact(4);
+    let tail = chunks.remainder();
+    for chunk in chunks {
+        let k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        h ^= k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+    if !tail.is_empty() {
+        let k = tail
+            .iter()
+            .enumerate()
+            .fold(0u32, |k, (i, b)| k ^ ((*b as u32) << (8 * i)));
+        h ^= k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+    }
+    h ^= data.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    h as i32
+}
+
+fn is_nan(value: &Scalar) -> bool {
+    match value {
+        Scalar::Number(NumberScalar::Float32(v)) => v.is_nan(),
+        Scalar::Number(NumberScalar::Float64(v)) => v.is_nan(),
+        _ => false,
+    }
+}
+
+fn nan_of(ty: &str) -> Option<Scalar> {
+    match ty {
+        "float" => Some(Scalar::Number(NumberScalar::Float32(F32::from(f32::NAN)))),
+        "double" => Some(Scalar::Number(NumberScalar::Float64(F64::from(f64::NAN)))),
+        _ => None,
+    }
+}
+
+fn le_i32(bytes: &[u8]) -> Option<i32> {
+    Some(i32::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn le_i64(bytes: &[u8]) -> Option<i64> {
+    Some(i64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// Big-endian two's-complement of any width up to 16 bytes.
+fn be_i128(bytes: &[u8]) -> Option<i128> {
+    if bytes.is_empty() || bytes.len() > 16 {
+        return None;
+    }
+    let mut buf = if bytes[0] & 0x80 != 0 {
+        [0xff; 16]
+    } else {
+        [0; 16]
+    };
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    Some(i128::from_be_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use common_exception::Result;
+    use common_expression::types::decimal::DecimalScalar;
+    use common_expression::types::decimal::DecimalSize;
+    use common_expression::types::number::NumberScalar;
+    use common_expression::FunctionContext;
+    use common_expression::Scalar;
+    use common_expression::TableSchemaRefExt;
+    use storages_common_pruner::RangePrunerCreator;
+
+    use super::bucket_input;
+    use super::murmur3_32;
+    use super::transform_range;
+    use super::IcebergPruner;
+    use super::MICROS_PER_DAY;
+    use super::MICROS_PER_HOUR;
+    use crate::manifest::DataFile;
+    use crate::metadata::PartitionField;
+
+    fn int(v: i32) -> Scalar {
+        Scalar::Number(NumberScalar::Int32(v))
+    }
+
+    fn long(v: i64) -> Scalar {
+        Scalar::Number(NumberScalar::Int64(v))
+    }
+
+    // The hash test vectors of the iceberg spec, appendix B.
+    #[test]
+    fn test_murmur3_32() {
+        let decimal = Scalar::Decimal(DecimalScalar::Decimal128(1420, DecimalSize {
+            precision: 9,
+            scale: 2,
+        }));
+        for (ty, value, hash) in [
+            ("int", int(34), 2017239379),
+            ("long", long(34), 2017239379),
+            ("decimal(9,2)", decimal, -500754589),
+            // 2017-11-16
+            ("date", Scalar::Date(17486), -653330422),
+            // 2017-11-16T22:31:08
+            (
+                "timestamp",
+                Scalar::Timestamp(1510871468000000),
+                -2047944441,
+            ),
+            // 2017-11-16T14:31:08-08:00
+            (
+                "timestamptz",
+                Scalar::Timestamp(1510871468000000),
+                -2047944441,
+            ),
+            ("string", Scalar::String(b"iceberg".to_vec()), 1210000089),
+        ] {
+            let input = bucket_input(ty, &value).unwrap();
+            assert_eq!(murmur3_32(&input), hash, "{ty}");
+        }
+        assert_eq!(murmur3_32(&[0, 1, 2, 3]), -188683207);
+    }
+
+    #[test]
+    fn test_transform_range() {
+        let date = Scalar::Date;
+        let ts = Scalar::Timestamp;
+        for (transform, ty, value, expected) in [
+            (
+                "identity",
+                "int",
+                7i32.to_le_bytes().to_vec(),
+                Some((int(7), int(7))),
+            ),
+            (
+                "truncate[10]",
+                "int",
+                30i32.to_le_bytes().to_vec(),
+                Some((int(30), int(39))),
+            ),
+            (
+                "truncate[10]",
+                "long",
+                (-10i64).to_le_bytes().to_vec(),
+                Some((long(-10), long(-1))),
+            ),
+            ("truncate[3]", "string", b"ice".to_vec(), None),
+            ("bucket[4]", "int", 3i32.to_le_bytes().to_vec(), None),
+            // 2023 of dates
+            (
+                "year",
+                "date",
+                53i32.to_le_bytes().to_vec(),
+                Some((date(19358), date(19722))),
+            ),
+            // 2023-07 of timestamps
+            (
+                "month",
+                "timestamp",
+                642i32.to_le_bytes().to_vec(),
+                Some((ts(19539 * MICROS_PER_DAY), ts(19570 * MICROS_PER_DAY - 1))),
+            ),
+            // 1969-12 of dates
+            (
+                "month",
+                "date",
+                (-1i32).to_le_bytes().to_vec(),
+                Some((date(-31), date(-1))),
+            ),
+            (
+                "day",
+                "date",
+                19358i32.to_le_bytes().to_vec(),
+                Some((date(19358), date(19358))),
+            ),
+            (
+                "day",
+                "timestamptz",
+                19358i32.to_le_bytes().to_vec(),
+                Some((ts(19358 * MICROS_PER_DAY), ts(19359 * MICROS_PER_DAY - 1))),
+            ),
+            (
+                "hour",
+                "timestamp",
+                465344i32.to_le_bytes().to_vec(),
+                Some((
+                    ts(465344 * MICROS_PER_HOUR),
+                    ts(465345 * MICROS_PER_HOUR - 1),
+                )),
+            ),
+            ("hour", "date", 465344i32.to_le_bytes().to_vec(), None),
+        ] {
+            assert_eq!(
+                transform_range(transform, ty, &value),
+                expected,
+                "{transform} of {ty}"
+            );
+        }
+
+        let size = DecimalSize {
+            precision: 9,
+            scale: 2,
+        };
+        let decimal = |v| Scalar::Decimal(DecimalScalar::Decimal128(v, size));
+        assert_eq!(
+            transform_range("truncate[50]", "decimal(9,2)", &[0x03, 0xe8]),
+            Some((decimal(1000), decimal(1049)))
+        );
+    }
+
+    #[test]
+    fn test_bucket_matches() -> Result<()> {
+        let schema = TableSchemaRefExt::create(vec![]);
+        let pruner = IcebergPruner {
+            range_pruner: RangePrunerCreator::try_create(
+                FunctionContext::default(),
+                &schema,
+                None,
+            )?,
+            columns: HashMap::from([(1, (0, "int".to_string()))]),
+            // id = 34, which is in bucket 3 of 4
+            equalities: HashMap::from([(1, int(34))]),
+        };
+        let spec = vec![PartitionField {
+            source_id: 1,
+            field_id: Some(1000),
+            name: "id_bucket".to_string(),
+            transform: "bucket[4]".to_string(),
+        }];
+        let file = |bucket: Option<i32>| DataFile {
+            partition: vec![(
+                "id_bucket".to_string(),
+                bucket.map(|v| v.to_le_bytes().to_vec()),
+            )],
+            ..Default::default()
+        };
+
+        assert!(pruner.bucket_matches(&file(Some(3)), &spec));
+        assert!(!pruner.bucket_matches(&file(Some(0)), &spec));
+        // only nulls
+        assert!(!pruner.bucket_matches(&file(None), &spec));
+        // written with another spec
+        assert!(pruner.bucket_matches(&DataFile::default(), &spec));
+        // no equality on the source column
+        let unbound = IcebergPruner {
+            equalities: HashMap::new(),
+            ..pruner
+        };
+        assert!(unbound.bucket_matches(&file(Some(0)), &spec));
+        Ok(())
+    }
+}
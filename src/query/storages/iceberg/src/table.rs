@@ -13,19 +13,19 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use arrow_schema::Schema as ArrowSchema;
 use async_trait::async_trait;
 use chrono::Utc;
-use common_arrow::arrow::datatypes::Field as Arrow2Field;
-use common_arrow::arrow::datatypes::Schema as Arrow2Schema;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::TableContext;
@@ -33,33 +33,54 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRefExt;
-use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_core::SourcePipeBuilder;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
 use common_storage::DataOperator;
 
+use crate::commit::read_snapshot_manifests;
+use crate::commit::IcebergCommitSink;
+use crate::context::ICEBERG_CONTEXT;
+use crate::delete::DeleteFileIndex;
+use crate::delete::IcebergDeleteFile;
+use crate::manifest::read_manifest;
+use crate::manifest::DataFile;
+use crate::manifest::CONTENT_DATA;
+use crate::manifest::CONTENT_DELETES;
+use crate::manifest::CONTENT_EQUALITY_DELETES;
+use crate::manifest::MANIFEST_STATUS_DELETED;
+use crate::metadata::load_metadata;
+use crate::metadata::PartitionField;
+use crate::metadata::Schema;
+use crate::metadata::Snapshot;
+use crate::metadata::TableMetadata;
 use crate::partition::IcebergPartInfo;
+use crate::pruning::IcebergPruner;
 use crate::table_source::IcebergTableSource;
+use crate::writer::IcebergDataFileWriter;
 
 /// accessor wrapper as a table
-///
-/// TODO: we should use icelake Table instead.
 pub struct IcebergTable {
     info: TableInfo,
     op: opendal::Operator,
 
-    table: icelake::Table,
+    /// The metadata loaded when the table is opened.
+    metadata: TableMetadata,
+    /// The snapshot to read, the current one unless navigated to another.
+    snapshot: Option<Snapshot>,
+    /// The iceberg schema of `snapshot`.
+    schema: Schema,
 }
 
 impl IcebergTable {
     /// create a new table on the table directory
-    ///
-    /// TODO: we should use icelake Table instead.
     #[async_backtrace::framed]
     pub async fn try_create(
         catalog: &str,
@@ -68,38 +89,10 @@ impl IcebergTable {
         tbl_root: DataOperator,
     ) -> Result<IcebergTable> {
         let op = tbl_root.operator();
-        let mut table = icelake::Table::new(op.clone());
-        table
-            .load()
-            .await
-            .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot load metadata: {e:?}")))?;
-
-        let meta = table.current_table_metadata().map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current table metadata: {e:?}"))
-        })?;
-
-        // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta
-            .schemas
-            .last()
-            .ok_or_else(|| {
-                ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
-            })?
-            .clone()
-            .try_into()
-            .map_err(|e| {
-                ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-            })?;
-
-        // Build arrow2 schema from arrow schema.
-        let fields: Vec<Arrow2Field> = arrow_schema
-            .fields()
-            .into_iter()
-            .map(|f| f.into())
-            .collect();
-        let arrow2_schema = Arrow2Schema::from(fields);
-
-        let table_schema = TableSchema::from(&arrow2_schema);
+        let metadata = load_metadata(&op).await?;
+        let schema = metadata.current_schema()?;
+        let table_schema = schema.to_table_schema()?;
+        let snapshot = metadata.current_snapshot()?;
 
         // construct table info
         let info = TableInfo {
@@ -117,7 +110,30 @@ impl IcebergTable {
             ..Default::default()
         };
 
-        Ok(Self { info, op, table })
+        Ok(Self {
+            info,
+            op,
+            metadata,
+            snapshot,
+            schema,
+        })
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&IcebergTable> {
+        tbl.as_any().downcast_ref::<IcebergTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine ICEBERG, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    pub(crate) fn operator(&self) -> &opendal::Operator {
+        &self.op
+    }
+
+    pub(crate) fn metadata(&self) -> &TableMetadata {
+        &self.metadata
     }
 
     pub fn do_read_data(
@@ -167,38 +183,152 @@ impl IcebergTable {
         Ok(())
     }
 
-    #[tracing::instrument(level = "info", skip(self, _ctx))]
+    #[tracing::instrument(level = "info", skip(self, ctx, push_downs))]
     #[async_backtrace::framed]
     async fn do_read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        let data_files = self.table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let (data_files, deletes) = match &self.snapshot {
+            Some(snapshot) => self.scan_files(snapshot).await?,
+            None => (vec![], DeleteFileIndex::default()),
+        };
+        let total_files = data_files.len();
+
+        let pruner = IcebergPruner::try_create(
+            ctx.get_function_context()?,
+            &self.info.schema(),
+            &self.schema,
+            &push_downs,
+        )?;
+        let data_files = match pruner {
+            Some(pruner) => {
+                let mut specs: HashMap<i32, Vec<PartitionField>> = HashMap::new();
+                let mut kept = Vec::with_capacity(data_files.len());
+                for (spec_id, sequence_number, file) in data_files {
+                    if !specs.contains_key(&spec_id) {
+                        specs.insert(spec_id, self.metadata.partition_spec(spec_id)?);
+                    }
+                    if pruner.should_keep(&file, &specs[&spec_id]) {
+                        kept.push((spec_id, sequence_number, file));
+                    }
+                }
+                kept
+            }
+            None => data_files,
+        };
+
+        let (read_rows, read_bytes) = data_files.iter().fold((0, 0), |(rows, bytes), (_, _, f)| {
+            (
+                rows + f.record_count as usize,
+                bytes + f.file_size_in_bytes as usize,
+            )
+        });
+        let statistics = PartStatistics::new_estimated(
+            None,
+            read_rows,
+            read_bytes,
+            data_files.len(),
+            total_files,
+        );
 
         let partitions = data_files
             .into_iter()
-            .map(|v: icelake::types::DataFile| match v.file_format {
-                icelake::types::DataFileFormat::Parquet => Arc::new(Box::new(IcebergPartInfo {
-                    path: self
-                        .table
-                        .rel_path(&v.file_path)
-                        .expect("file path must be rel to table"),
-                    size: v.file_size_in_bytes as u64,
-                })
-                    as Box<dyn PartInfo>),
-                _ => {
-                    unimplemented!("Only parquet format is supported for iceberg table")
-                }
-            })
-            .collect();
+            .map(
+                |(spec_id, sequence_number, v)| match v.file_format.to_uppercase().as_str() {
+                    "PARQUET" => Ok(Arc::new(Box::new(IcebergPartInfo {
+                        path: self.metadata.rel_path(&v.file_path)?,
+                        size: v.file_size_in_bytes as u64,
+                        deletes: deletes.for_data_file(sequence_number, spec_id, &v),
+                        file_path: v.file_path,
+                    }) as Box<dyn PartInfo>)),
+                    format => Err(ErrorCode::Unimplemented(format!(
+                        "Only parquet format is supported for iceberg table, but got {format}"
+                    ))),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
 
         Ok((
-            PartStatistics::default(),
+            statistics,
             Partitions::create_nolazy(PartitionsShuffleKind::Seq, partitions),
         ))
     }
+
+    /// The live data files of `snapshot` with the id of their partition spec
+    /// and their data sequence number, and the live delete files.
+    #[async_backtrace::framed]
+    async fn scan_files(
+        &self,
+        snapshot: &Snapshot,
+    ) -> Result<(Vec<(i32, i64, DataFile)>, DeleteFileIndex)> {
+        let manifests = read_snapshot_manifests(&self.op, &self.metadata, snapshot).await?;
+        let mut data_files = vec![];
+        let mut deletes = DeleteFileIndex::default();
+        for manifest in &manifests {
+            let spec_id = manifest.partition_spec_id;
+            let bytes = self
+                .op
+                .read(&self.metadata.rel_path(&manifest.manifest_path)?)
+                .await?;
+            let entries = read_manifest(&bytes, manifest)?
+                .into_iter()
+                .filter(|e| e.status != MANIFEST_STATUS_DELETED);
+            match manifest.content {
+                CONTENT_DATA => data_files.extend(
+                    entries
+                        .filter(|e| e.data_file.content == CONTENT_DATA)
+                        .map(|e| (spec_id, e.sequence_number.unwrap_or_default(), e.data_file)),
+                ),
+                CONTENT_DELETES => {
+                    let unpartitioned = self.metadata.partition_spec(spec_id)?.is_empty();
+                    for entry in entries {
+                        let delete_file = self.delete_file(&entry.data_file)?;
+                        deletes.add(
+                            entry.sequence_number.unwrap_or_default(),
+                            spec_id,
+                            unpartitioned,
+                            &entry.data_file,
+                            delete_file,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok((data_files, deletes))
+    }
+
+    fn delete_file(&self, file: &DataFile) -> Result<IcebergDeleteFile> {
+        if file.file_format.to_uppercase() != "PARQUET" {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Only parquet format is supported for iceberg delete file, but got {}",
+                file.file_format
+            )));
+        }
+        let equality_columns = match file.content {
+            CONTENT_EQUALITY_DELETES => file
+                .equality_ids
+                .iter()
+                .map(|id| {
+                    let field = self.schema.fields.iter().find(|f| f.id == *id);
+                    field.map(|f| f.name.clone()).ok_or_else(|| {
+                        ErrorCode::Unimplemented(format!(
+                            "Iceberg equality deletes on dropped column {id} are not supported: {}",
+                            file.file_path
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => vec![],
+        };
+        Ok(IcebergDeleteFile {
+            path: self.metadata.rel_path(&file.file_path)?,
+            content: file.content,
+            equality_columns,
+        })
+    }
 }
 
 #[async_trait]
@@ -223,12 +353,11 @@ impl Table for IcebergTable {
     async fn read_partitions(
         &self,
         ctx: Arc<dyn TableContext>,
-        // TODO: we will support push down later.
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
         // TODO: we will support dry run later.
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
-        self.do_read_partitions(ctx).await
+        self.do_read_partitions(ctx, push_downs).await
     }
 
     fn read_data(
@@ -240,6 +369,76 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let snapshot = match point {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                let id = snapshot_id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid iceberg snapshot id {snapshot_id}, expecting an integer"
+                    ))
+                })?;
+                self.metadata.snapshot(id)?
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                self.metadata.snapshot_at(time_point.timestamp_millis())?
+            }
+        };
+        let snapshot = snapshot.ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point {point:?}"
+            ))
+        })?;
+
+        // Tables are looked up by `desc` when building the pipeline, so the
+        // navigated table needs its own entry.
+        let mut info = self.info.clone();
+        info.desc = format!("{}@{}", info.desc, snapshot.snapshot_id);
+        let schema = self.metadata.snapshot_schema(&snapshot)?;
+        info.meta.schema = Arc::new(schema.to_table_schema()?);
+        let table = Arc::new(IcebergTable {
+            info,
+            op: self.op.clone(),
+            metadata: self.metadata.clone(),
+            snapshot: Some(snapshot),
+            schema,
+        }) as Arc<dyn Table>;
+        ICEBERG_CONTEXT.insert(&table.get_table_info().desc, table.clone());
+        Ok(table)
+    }
+
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        self.metadata.check_writable()?;
+        let schema = self.metadata.current_schema()?;
+        let location = self.metadata.location()?.to_string();
+        pipeline.add_transform(|input, output| {
+            let writer =
+                IcebergDataFileWriter::try_create(self.op.clone(), location.clone(), &schema)?;
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, writer,
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        overwrite: bool,
+    ) -> Result<()> {
+        let schema_id = self.metadata.current_schema()?.schema_id;
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            IcebergCommitSink::try_create(input, self.op.clone(), schema_id, overwrite)
+        })
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -0,0 +1,302 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::number::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+
+use super::IcebergTableArgs;
+use crate::commit::read_snapshot_manifests;
+use crate::manifest::ManifestFile;
+use crate::manifest::CONTENT_DATA;
+use crate::table::IcebergTable;
+
+const ICEBERG_FUNC_MANIFESTS: &str = "iceberg_manifests";
+
+/// `iceberg_manifests([catalog,] database, table)` or
+/// `iceberg_manifests(catalog, database, table, snapshot_id)` lists the
+/// manifests of an iceberg snapshot, the current one by default.
+pub struct IcebergManifestsTable {
+    table_info: TableInfo,
+    table_args: TableArgs,
+    args: IcebergTableArgs,
+}
+
+impl IcebergManifestsTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = IcebergTableArgs::parse(&table_args, ICEBERG_FUNC_MANIFESTS, 1)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: ICEBERG_FUNC_MANIFESTS.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(IcebergManifestsTable {
+            table_info,
+            table_args,
+            args,
+        }))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("snapshot_id", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("manifest_path", TableDataType::String),
+            TableField::new(
+                "manifest_length",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "partition_spec_id",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new("content", TableDataType::String),
+            TableField::new(
+                "sequence_number",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "added_snapshot_id",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "added_files_count",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new(
+                "existing_files_count",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new(
+                "deleted_files_count",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new(
+                "added_rows_count",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "existing_rows_count",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "deleted_rows_count",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+        ])
+    }
+
+    fn to_block(snapshot_id: i64, manifests: &[ManifestFile]) -> DataBlock {
+        let len = manifests.len();
+        let mut paths = Vec::with_capacity(len);
+        let mut lengths = Vec::with_capacity(len);
+        let mut spec_ids = Vec::with_capacity(len);
+        let mut contents = Vec::with_capacity(len);
+        let mut sequence_numbers = Vec::with_capacity(len);
+        let mut added_snapshot_ids = Vec::with_capacity(len);
+        let mut added_files = Vec::with_capacity(len);
+        let mut existing_files = Vec::with_capacity(len);
+        let mut deleted_files = Vec::with_capacity(len);
+        let mut added_rows = Vec::with_capacity(len);
+        let mut existing_rows = Vec::with_capacity(len);
+        let mut deleted_rows = Vec::with_capacity(len);
+        for m in manifests {
+            paths.push(m.manifest_path.as_bytes().to_vec());
+            lengths.push(m.manifest_length);
+            spec_ids.push(m.partition_spec_id);
+            let content = if m.content == CONTENT_DATA {
+                "data"
+            } else {
+                "deletes"
+            };
+            contents.push(content.as_bytes().to_vec());
+            sequence_numbers.push(m.sequence_number);
+            added_snapshot_ids.push(m.added_snapshot_id);
+            added_files.push(m.added_files_count);
+            existing_files.push(m.existing_files_count);
+            deleted_files.push(m.deleted_files_count);
+            added_rows.push(m.added_rows_count);
+            existing_rows.push(m.existing_rows_count);
+            deleted_rows.push(m.deleted_rows_count);
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int64Type::from_data(vec![snapshot_id; len]),
+            StringType::from_data(paths),
+            Int64Type::from_data(lengths),
+            Int32Type::from_data(spec_ids),
+            StringType::from_data(contents),
+            Int64Type::from_data(sequence_numbers),
+            Int64Type::from_data(added_snapshot_ids),
+            Int32Type::from_data(added_files),
+            Int32Type::from_data(existing_files),
+            Int32Type::from_data(deleted_files),
+            Int64Type::from_data(added_rows),
+            Int64Type::from_data(existing_rows),
+            Int64Type::from_data(deleted_rows),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for IcebergManifestsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(self.table_args.clone())
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| IcebergManifestsSource::create(ctx.clone(), output, self.args.clone()),
+            1,
+        )
+    }
+}
+
+impl TableFunction for IcebergManifestsTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct IcebergManifestsSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    args: IcebergTableArgs,
+}
+
+impl IcebergManifestsSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        args: IcebergTableArgs,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergManifestsSource {
+            finish: false,
+            ctx,
+            args,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergManifestsSource {
+    const NAME: &'static str = "iceberg_manifests";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tbl = self.args.get_table(&self.ctx).await?;
+        let tbl = IcebergTable::try_from_table(tbl.as_ref())?;
+        let metadata = tbl.metadata();
+        let snapshot = match self.args.extra.first() {
+            Some(snapshot_id) => {
+                let id = snapshot_id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid iceberg snapshot id {snapshot_id}, expecting an integer"
+                    ))
+                })?;
+                let snapshot = metadata.snapshot(id)?.ok_or_else(|| {
+                    ErrorCode::TableHistoricalDataNotFound(format!(
+                        "Iceberg snapshot {id} not found"
+                    ))
+                })?;
+                Some(snapshot)
+            }
+            None => metadata.current_snapshot()?,
+        };
+
+        let block = match snapshot {
+            Some(snapshot) => {
+                let manifests =
+                    read_snapshot_manifests(tbl.operator(), metadata, &snapshot).await?;
+                IcebergManifestsTable::to_block(snapshot.snapshot_id, &manifests)
+            }
+            None => DataBlock::empty_with_schema(Arc::new(IcebergManifestsTable::schema().into())),
+        };
+        Ok(Some(block))
+    }
+}
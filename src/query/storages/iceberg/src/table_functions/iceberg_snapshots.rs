@@ -0,0 +1,262 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::number::Int64Type;
+use common_expression::types::BooleanType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+
+use super::IcebergTableArgs;
+use crate::metadata::TableMetadata;
+use crate::table::IcebergTable;
+
+const ICEBERG_FUNC_SNAPSHOTS: &str = "iceberg_snapshots";
+
+/// `iceberg_snapshots([catalog,] database, table)` lists the snapshots of an
+/// iceberg table.
+pub struct IcebergSnapshotsTable {
+    table_info: TableInfo,
+    table_args: TableArgs,
+    args: IcebergTableArgs,
+}
+
+impl IcebergSnapshotsTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = IcebergTableArgs::parse(&table_args, ICEBERG_FUNC_SNAPSHOTS, 0)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: ICEBERG_FUNC_SNAPSHOTS.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(IcebergSnapshotsTable {
+            table_info,
+            table_args,
+            args,
+        }))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("snapshot_id", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new(
+                "parent_snapshot_id",
+                TableDataType::Number(NumberDataType::Int64).wrap_nullable(),
+            ),
+            TableField::new(
+                "sequence_number",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new("timestamp", TableDataType::Timestamp),
+            TableField::new("operation", TableDataType::String.wrap_nullable()),
+            TableField::new("manifest_list", TableDataType::String.wrap_nullable()),
+            TableField::new(
+                "schema_id",
+                TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+            ),
+            TableField::new("is_current", TableDataType::Boolean),
+            TableField::new(
+                "added_data_files",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "added_records",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "total_data_files",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "total_records",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+        ])
+    }
+
+    fn to_block(metadata: &TableMetadata) -> Result<DataBlock> {
+        let snapshots = metadata.snapshots()?;
+        let current = metadata.current_snapshot_id();
+
+        let len = snapshots.len();
+        let mut snapshot_ids = Vec::with_capacity(len);
+        let mut parent_ids = Vec::with_capacity(len);
+        let mut sequence_numbers = Vec::with_capacity(len);
+        let mut timestamps = Vec::with_capacity(len);
+        let mut operations = Vec::with_capacity(len);
+        let mut manifest_lists = Vec::with_capacity(len);
+        let mut schema_ids = Vec::with_capacity(len);
+        let mut is_current = Vec::with_capacity(len);
+        let mut added_data_files = Vec::with_capacity(len);
+        let mut added_records = Vec::with_capacity(len);
+        let mut total_data_files = Vec::with_capacity(len);
+        let mut total_records = Vec::with_capacity(len);
+        for s in snapshots {
+            snapshot_ids.push(s.snapshot_id);
+            parent_ids.push(s.parent_snapshot_id);
+            sequence_numbers.push(s.sequence_number);
+            timestamps.push(s.timestamp_ms * 1000);
+            operations.push(s.summary.get("operation").map(|v| v.as_bytes().to_vec()));
+            manifest_lists.push(s.manifest_list.as_ref().map(|v| v.as_bytes().to_vec()));
+            schema_ids.push(s.schema_id);
+            is_current.push(current == Some(s.snapshot_id));
+            added_data_files.push(s.summary_count("added-data-files"));
+            added_records.push(s.summary_count("added-records"));
+            total_data_files.push(s.summary_count("total-data-files"));
+            total_records.push(s.summary_count("total-records"));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            Int64Type::from_data(snapshot_ids),
+            Int64Type::from_opt_data(parent_ids),
+            Int64Type::from_data(sequence_numbers),
+            TimestampType::from_data(timestamps),
+            StringType::from_opt_data(operations),
+            StringType::from_opt_data(manifest_lists),
+            Int32Type::from_opt_data(schema_ids),
+            BooleanType::from_data(is_current),
+            Int64Type::from_data(added_data_files),
+            Int64Type::from_data(added_records),
+            Int64Type::from_data(total_data_files),
+            Int64Type::from_data(total_records),
+        ]))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for IcebergSnapshotsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(self.table_args.clone())
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| IcebergSnapshotsSource::create(ctx.clone(), output, self.args.clone()),
+            1,
+        )
+    }
+}
+
+impl TableFunction for IcebergSnapshotsTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct IcebergSnapshotsSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    args: IcebergTableArgs,
+}
+
+impl IcebergSnapshotsSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        args: IcebergTableArgs,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergSnapshotsSource {
+            finish: false,
+            ctx,
+            args,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergSnapshotsSource {
+    const NAME: &'static str = "iceberg_snapshots";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tbl = self.args.get_table(&self.ctx).await?;
+        let tbl = IcebergTable::try_from_table(tbl.as_ref())?;
+        Ok(Some(IcebergSnapshotsTable::to_block(tbl.metadata())?))
+    }
+}
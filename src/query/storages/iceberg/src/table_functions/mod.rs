@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod iceberg_manifests;
+mod iceberg_snapshots;
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Scalar;
+pub use iceberg_manifests::IcebergManifestsTable;
+pub use iceberg_snapshots::IcebergSnapshotsTable;
+
+/// Arguments of the iceberg table functions, in the form of
+/// `([catalog,] database, table, extra...)`.
+///
+/// The catalog can only be omitted if there are no extra arguments, the
+/// current catalog is used then.
+#[derive(Clone, Debug)]
+pub(crate) struct IcebergTableArgs {
+    catalog: Option<String>,
+    database: String,
+    table: String,
+    extra: Vec<String>,
+}
+
+impl IcebergTableArgs {
+    pub fn parse(table_args: &TableArgs, func_name: &str, max_extra: usize) -> Result<Self> {
+        let args = table_args
+            .expect_all_positioned(func_name, None)?
+            .iter()
+            .map(|arg| match arg {
+                Scalar::String(v) => String::from_utf8(v.clone())
+                    .map_err(|e| ErrorCode::BadArguments(format!("invalid string. {}", e))),
+                _ => Err(ErrorCode::BadArguments(format!(
+                    "{func_name} expects string literals as arguments, but got {arg:?}"
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        match args.len() {
+            2 => Ok(Self {
+                catalog: None,
+                database: args[0].clone(),
+                table: args[1].clone(),
+                extra: vec![],
+            }),
+            n if n >= 3 && n - 3 <= max_extra => Ok(Self {
+                catalog: Some(args[0].clone()),
+                database: args[1].clone(),
+                table: args[2].clone(),
+                extra: args[3..].to_vec(),
+            }),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "{func_name} expects <catalog>, <database> and <table_name> (as string literals) \
+                 with at most {max_extra} more arguments, but got {:?}",
+                args
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_table(&self, ctx: &Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let catalog = match &self.catalog {
+            Some(catalog) => catalog.clone(),
+            None => ctx.get_current_catalog(),
+        };
+        ctx.get_catalog(&catalog)?
+            .get_table(ctx.get_tenant().as_str(), &self.database, &self.table)
+            .await
+    }
+}
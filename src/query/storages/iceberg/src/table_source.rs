@@ -32,6 +32,7 @@ use icelake::io::parquet::ParquetStream;
 use icelake::io::parquet::ParquetStreamBuilder;
 use opendal::Operator;
 
+use crate::delete::DeleteFilter;
 use crate::partition::IcebergPartInfo;
 
 pub struct IcebergTableSource {
@@ -41,6 +42,11 @@ pub struct IcebergTableSource {
     _scan_progress: Arc<Progress>,
     output: Arc<OutputPort>,
 
+    /// The deletes of the data file being read.
+    deletes: Option<DeleteFilter>,
+    /// Position of the next row in the data file being read.
+    row_offset: usize,
+
     /// The schema before output. Some fields might be removed when outputting.
     _source_schema: DataSchemaRef,
     /// The final output schema
@@ -73,6 +79,8 @@ impl IcebergTableSource {
             dal,
             output,
             _scan_progress: scan_progress,
+            deletes: None,
+            row_offset: 0,
             state: State::ReadMeta(None),
             _source_schema: source_schema,
             _output_schema: output_schema,
@@ -112,13 +120,20 @@ impl Processor for IcebergTableSource {
             if let State::ReadData(ps, mut data) = std::mem::replace(&mut self.state, State::Finish)
             {
                 if let Some(arrow_block) = data.take() {
-                    let (data_block, _) =
-                        DataBlock::from_record_batch(&arrow_block).map_err(|err| {
+                    let (mut data_block, schema) = DataBlock::from_record_batch(&arrow_block)
+                        .map_err(|err| {
                             ErrorCode::ReadTableDataError(format!(
                                 "Cannot convert arrow record batch to data block: {err:?}"
                             ))
                         })?;
-                    self.output.push_data(Ok(data_block));
+                    let num_rows = data_block.num_rows();
+                    if let Some(deletes) = &self.deletes {
+                        data_block = deletes.filter(data_block, &schema, self.row_offset)?;
+                    }
+                    self.row_offset += num_rows;
+                    if !data_block.is_empty() {
+                        self.output.push_data(Ok(data_block));
+                    }
                 }
 
                 // Let's fetch more data.
@@ -149,6 +164,8 @@ impl Processor for IcebergTableSource {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::ReadMeta(Some(part)) => {
                 let part = IcebergPartInfo::from_part(&part)?;
+                self.deletes = DeleteFilter::try_create(&self.dal, part).await?;
+                self.row_offset = 0;
                 let r = self.dal.reader(&part.path).await?;
                 let s = ParquetStreamBuilder::new(r)
                     .build()
@@ -175,6 +192,6 @@ impl Processor for IcebergTableSource {
     }
 }
 
-fn parse_icelake_error(err: icelake::Error) -> ErrorCode {
+pub(crate) fn parse_icelake_error(err: icelake::Error) -> ErrorCode {
     ErrorCode::ReadTableDataError(format!("icelake operation failed: {:?}", err))
 }
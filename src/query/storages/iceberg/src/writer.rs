@@ -0,0 +1,353 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::LargeBinaryArray;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_array::TimestampMicrosecondArray;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field as ArrowField;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::TimeUnit;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalDomain;
+use common_expression::types::number::NumberDomain;
+use common_expression::types::string::StringDomain;
+use common_expression::BlockMetaInfo;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Domain;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransform;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use serde_json::Value;
+
+use crate::manifest::DataFile;
+use crate::manifest::CONTENT_DATA;
+use crate::metadata::parse_decimal;
+use crate::metadata::Schema;
+use crate::metadata::SchemaField;
+
+/// Blocks are buffered until they take this much memory, then written out as one data file.
+const DATA_FILE_BUFFER_SIZE: usize = 256 * 1024 * 1024;
+
+const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+
+/// Data files written by [`IcebergDataFileWriter`], waiting for being committed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct IcebergDataFiles {
+    pub files: Vec<DataFile>,
+}
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFiles {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match info.as_any().downcast_ref::<IcebergDataFiles>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Write the incoming blocks as parquet data files under `data/` of the table,
+/// and output the written files as [`IcebergDataFiles`].
+pub struct IcebergDataFileWriter {
+    op: Operator,
+    /// Absolute location of the table.
+    location: String,
+    arrow_schema: Arc<ArrowSchema>,
+    field_ids: Vec<i32>,
+
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+}
+
+impl IcebergDataFileWriter {
+    pub fn try_create(op: Operator, location: String, schema: &Schema) -> Result<Self> {
+        let fields = schema
+            .fields
+            .iter()
+            .map(arrow_field)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            op,
+            location,
+            arrow_schema: Arc::new(ArrowSchema::new(fields)),
+            field_ids: schema.fields.iter().map(|f| f.id).collect(),
+            blocks: vec![],
+            buffered_bytes: 0,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_empty() {
+            return Ok(None);
+        }
+        let blocks = std::mem::take(&mut self.blocks);
+        self.buffered_bytes = 0;
+
+        let mut stats = vec![ColumnStats::default(); self.field_ids.len()];
+        let mut record_count = 0;
+        let mut buf = vec![];
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, self.arrow_schema.clone(), Some(props))
+            .map_err(parquet_error)?;
+        for block in blocks {
+            let block = block.convert_to_full();
+            record_count += block.num_rows();
+            let mut arrays = Vec::with_capacity(block.num_columns());
+            for (i, entry) in block.columns().iter().enumerate() {
+                let column = entry.value.as_column().unwrap();
+                let field = self.arrow_schema.field(i);
+                arrays.push(to_arrow_array(column.clone(), field.data_type())?);
+                stats[i].update(column);
+            }
+            let batch = RecordBatch::try_new(self.arrow_schema.clone(), arrays).map_err(|e| {
+                ErrorCode::Internal(format!("Cannot build iceberg record batch: {e}"))
+            })?;
+            writer.write(&batch).map_err(parquet_error)?;
+        }
+        let file_meta = writer.close().map_err(parquet_error)?;
+
+        let mut column_sizes = vec![0; self.field_ids.len()];
+        for row_group in &file_meta.row_groups {
+            for (size, column) in column_sizes.iter_mut().zip(&row_group.columns) {
+                if let Some(meta) = &column.meta_data {
+                    *size += meta.total_compressed_size;
+                }
+            }
+        }
+
+        let mut data_file = DataFile {
+            content: CONTENT_DATA,
+            file_path: String::new(),
+            file_format: "PARQUET".to_string(),
+            record_count: record_count as i64,
+            file_size_in_bytes: buf.len() as i64,
+            ..Default::default()
+        };
+        for ((id, size), stats) in self.field_ids.iter().zip(column_sizes).zip(stats) {
+            data_file.column_sizes.push((*id, size));
+            data_file.value_counts.push((*id, record_count as i64));
+            data_file.null_value_counts.push((*id, stats.null_count));
+            if let Some((lower, upper)) = stats.domain.as_ref().and_then(domain_bounds) {
+                data_file.lower_bounds.push((*id, lower));
+                data_file.upper_bounds.push((*id, upper));
+            }
+        }
+
+        let path = format!("data/{}.parquet", uuid::Uuid::new_v4());
+        self.op.write(&path, buf).await?;
+        data_file.file_path = format!("{}/{}", self.location, path);
+
+        Ok(Some(DataBlock::empty_with_meta(Box::new(
+            IcebergDataFiles {
+                files: vec![data_file],
+            },
+        ))))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for IcebergDataFileWriter {
+    const NAME: &'static str = "IcebergDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+        self.buffered_bytes += data.memory_size();
+        self.blocks.push(data);
+        if self.buffered_bytes >= DATA_FILE_BUFFER_SIZE {
+            return self.flush().await;
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.flush().await
+    }
+}
+
+fn parquet_error(e: parquet::errors::ParquetError) -> ErrorCode {
+    ErrorCode::Internal(format!("Cannot write iceberg data file: {e}"))
+}
+
+/// The arrow field written to parquet, carrying the iceberg field id.
+fn arrow_field(field: &SchemaField) -> Result<ArrowField> {
+    let unsupported = || {
+        ErrorCode::Unimplemented(format!(
+            "Writing iceberg column {} of type {} is not supported yet",
+            field.name, field.field_type
+        ))
+    };
+    let data_type = match &field.field_type {
+        Value::String(ty) => match ty.as_str() {
+            "boolean" => ArrowDataType::Boolean,
+            "int" => ArrowDataType::Int32,
+            "long" => ArrowDataType::Int64,
+            "float" => ArrowDataType::Float32,
+            "double" => ArrowDataType::Float64,
+            "date" => ArrowDataType::Date32,
+            "timestamp" => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+            "timestamptz" => ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into())),
+            "string" => ArrowDataType::Utf8,
+            "binary" => ArrowDataType::LargeBinary,
+            ty => {
+                let (precision, scale) = parse_decimal(ty).ok_or_else(unsupported)?;
+                ArrowDataType::Decimal128(precision, scale as i8)
+            }
+        },
+        _ => return Err(unsupported()),
+    };
+    let metadata = HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), field.id.to_string())]);
+    Ok(ArrowField::new(&field.name, data_type, !field.required).with_metadata(metadata))
+}
+
+/// Convert a column to the arrow array expected by the iceberg schema.
+fn to_arrow_array(column: Column, data_type: &ArrowDataType) -> Result<ArrayRef> {
+    let array = column
+        .into_arrow_rs()
+        .map_err(|e| ErrorCode::Internal(format!("Cannot convert column to arrow: {e}")))?;
+    if array.data_type() == data_type {
+        return Ok(array);
+    }
+    match (array.data_type(), data_type) {
+        (ArrowDataType::LargeBinary, ArrowDataType::Utf8) => {
+            let binary = array.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+            let strings = binary
+                .iter()
+                .map(|v| v.map(std::str::from_utf8).transpose())
+                .collect::<std::result::Result<StringArray, _>>()?;
+            Ok(Arc::new(strings))
+        }
+        (
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, _),
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, tz),
+        ) => {
+            let timestamps = array
+                .as_any()
+                .downcast_ref::<TimestampMicrosecondArray>()
+                .unwrap()
+                .clone()
+                .with_timezone_opt(tz.clone());
+            Ok(Arc::new(timestamps))
+        }
+        (from, to) => Err(ErrorCode::Unimplemented(format!(
+            "Cannot write arrow type {from} as iceberg column of arrow type {to}"
+        ))),
+    }
+}
+
+/// Null count and value range of a column across the blocks of a data file.
+#[derive(Clone, Default)]
+struct ColumnStats {
+    null_count: i64,
+    domain: Option<Domain>,
+}
+
+impl ColumnStats {
+    fn update(&mut self, column: &Column) {
+        let column = match column {
+            Column::Nullable(c) => {
+                let nulls = c.validity.unset_bits();
+                self.null_count += nulls as i64;
+                if nulls == c.len() {
+                    return;
+                }
+                if nulls > 0 {
+                    c.column.filter(&c.validity)
+                } else {
+                    c.column.clone()
+                }
+            }
+            c => c.clone(),
+        };
+        if column.len() == 0 {
+            return;
+        }
+        let domain = column.domain();
+        self.domain = Some(match self.domain.take() {
+            Some(d) => d.merge(&domain),
+            None => domain,
+        });
+    }
+}
+
+/// Encode the bounds of a domain as iceberg single-value binaries.
+fn domain_bounds(domain: &Domain) -> Option<(Vec<u8>, Vec<u8>)> {
+    match domain {
+        Domain::Boolean(d) => Some((vec![!d.has_false as u8], vec![d.has_true as u8])),
+        Domain::Number(NumberDomain::Int32(d)) | Domain::Date(d) => {
+            Some((d.min.to_le_bytes().to_vec(), d.max.to_le_bytes().to_vec()))
+        }
+        Domain::Number(NumberDomain::Int64(d)) | Domain::Timestamp(d) => {
+            Some((d.min.to_le_bytes().to_vec(), d.max.to_le_bytes().to_vec()))
+        }
+        // NaN must not be used as bound.
+        Domain::Number(NumberDomain::Float32(d)) if !d.min.is_nan() && !d.max.is_nan() => Some((
+            d.min.0.to_le_bytes().to_vec(),
+            d.max.0.to_le_bytes().to_vec(),
+        )),
+        Domain::Number(NumberDomain::Float64(d)) if !d.min.is_nan() && !d.max.is_nan() => Some((
+            d.min.0.to_le_bytes().to_vec(),
+            d.max.0.to_le_bytes().to_vec(),
+        )),
+        Domain::String(StringDomain {
+            min,
+            max: Some(max),
+        }) => Some((min.clone(), max.clone())),
+        Domain::Decimal(DecimalDomain::Decimal128(d, _)) => {
+            Some((decimal_bytes(d.min), decimal_bytes(d.max)))
+        }
+        _ => None,
+    }
+}
+
+/// Big-endian two's-complement with the minimum number of bytes.
+pub(crate) fn decimal_bytes(v: i128) -> Vec<u8> {
+    let bytes = v.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1 {
+        let (b, next) = (bytes[start], bytes[start + 1]);
+        if (b == 0x00 && next & 0x80 == 0) || (b == 0xff && next & 0x80 != 0) {
+            start += 1;
+        } else {
+            break;
+        }
+    }
+    bytes[start..].to_vec()
+}
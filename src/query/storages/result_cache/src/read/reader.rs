@@ -15,8 +15,8 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use common_arrow::arrow::io::parquet::read::infer_schema;
 use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::infer_schema_with_extension;
 use common_arrow::parquet::read::read_metadata;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
@@ -111,7 +111,7 @@ impl ResultCacheReader {
         let data = self.operator.read(location).await?;
         let mut reader = Cursor::new(data);
         let meta = read_metadata(&mut reader)?;
-        let arrow_schema = infer_schema(&meta)?;
+        let arrow_schema = infer_schema_with_extension(&meta)?;
         let schema = DataSchema::from(&TableSchema::from(&arrow_schema));
 
         // Read the parquet file into one block.
@@ -135,7 +135,7 @@ impl ResultCacheReader {
         let data = operator.read(location).await?;
         let mut reader = Cursor::new(data.clone());
         let meta = read_metadata(&mut reader)?;
-        let arrow_schema = infer_schema(&meta)?;
+        let arrow_schema = infer_schema_with_extension(&meta)?;
         let table_schema = TableSchema::from(&arrow_schema);
 
         Ok((table_schema, data))
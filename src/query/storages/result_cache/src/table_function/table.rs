@@ -16,8 +16,8 @@ use std::any::Any;
 use std::io::Cursor;
 use std::sync::Arc;
 
-use common_arrow::arrow::io::parquet::read::infer_schema;
 use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::infer_schema_with_extension;
 use common_arrow::parquet::read::read_metadata;
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
@@ -131,7 +131,7 @@ impl Table for ResultScan {
         } else {
             let mut reader = Cursor::new(self.block_raw_data.clone());
             let meta = read_metadata(&mut reader)?;
-            let arrow_schema = infer_schema(&meta)?;
+            let arrow_schema = infer_schema_with_extension(&meta)?;
             let table_schema = TableSchema::from(&arrow_schema);
             let schema = DataSchema::from(&table_schema);
 
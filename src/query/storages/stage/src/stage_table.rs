@@ -33,18 +33,27 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
+use common_formats::FileFormatOptionsExt;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableInfo;
 use common_pipeline_core::Pipeline;
 use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::OrcSplitMeta;
 use common_pipeline_sources::input_formats::SplitInfo;
 use common_storage::init_stage_operator;
 use common_storage::StageFileInfo;
 use dashmap::DashMap;
 use opendal::Operator;
 use parking_lot::Mutex;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::parquet_file::append_data_to_parquet_files;
 use crate::row_based_file::append_data_to_row_based_files;
@@ -110,7 +119,7 @@ impl Table for StageTable {
     async fn read_partitions(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
         let stage_info = &self.table_info;
@@ -130,6 +139,25 @@ impl Table for StageTable {
                 &ctx.get_settings(),
             )
             .await?;
+        let splits = match (
+            &stage_info.stage_info.file_format_params,
+            push_downs.as_ref().and_then(|p| p.filter.as_ref()),
+        ) {
+            (FileFormatParams::Orc(_), Some(filter)) => {
+                let filter = filter.as_expr(&BUILTIN_FUNCTIONS);
+                // The columns are matched by name like the input context of the read does.
+                let options_ext =
+                    FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+                prune_orc_splits(
+                    ctx.get_function_context()?,
+                    &stage_info.schema,
+                    &filter,
+                    options_ext.ident_case_sensitive,
+                    splits,
+                )?
+            }
+            _ => splits,
+        };
 
         let partitions = splits
             .into_iter()
@@ -239,7 +267,12 @@ impl Table for StageTable {
         let uuid = uuid::Uuid::new_v4().to_string();
         let group_id = AtomicUsize::new(0);
         match fmt {
-            FileFormatParams::Parquet(_) => append_data_to_parquet_files(
+            // Arrow IPC, Avro and ORC files need a header or footer too,
+            // so they are written out as a whole like parquet.
+            FileFormatParams::Parquet(_)
+            | FileFormatParams::Arrow(_)
+            | FileFormatParams::Avro(_)
+            | FileFormatParams::Orc(_) => append_data_to_parquet_files(
                 pipeline,
                 ctx.clone(),
                 self.table_info.clone(),
@@ -282,6 +315,54 @@ impl Table for StageTable {
     }
 }
 
+/// Drop the ORC stripes whose statistics show that no row can match the filter.
+fn prune_orc_splits(
+    func_ctx: FunctionContext,
+    schema: &TableSchemaRef,
+    filter: &Expr<String>,
+    case_sensitive: bool,
+    splits: Vec<Arc<SplitInfo>>,
+) -> Result<Vec<Arc<SplitInfo>>> {
+    let pruner = RangePrunerCreator::try_create(func_ctx, schema, Some(filter))?;
+    let num_splits = splits.len();
+    let splits = splits
+        .into_iter()
+        .filter(|split| {
+            let meta = match split
+                .format_info
+                .as_ref()
+                .and_then(|m| m.as_any().downcast_ref::<OrcSplitMeta>())
+            {
+                Some(meta) => meta,
+                None => return true,
+            };
+            let mut stats = StatisticsOfColumns::new();
+            for field in schema.fields() {
+                let column_ids = schema.leaf_columns_of(field.name());
+                if column_ids.len() != 1 {
+                    continue;
+                }
+                if let Some(s) = meta.column_statistics(field.name(), case_sensitive) {
+                    stats.insert(column_ids[0], ColumnStatistics {
+                        min: s.min,
+                        max: s.max,
+                        null_count: s.null_count,
+                        in_memory_size: 0,
+                        distinct_of_values: None,
+                    });
+                }
+            }
+            pruner.should_keep(&stats, None)
+        })
+        .collect::<Vec<_>>();
+    tracing::info!(
+        "prune orc stripes by statistics: {} of {} remain",
+        splits.len(),
+        num_splits
+    );
+    Ok(splits)
+}
+
 pub fn unload_path(
     stage_table_info: &StageTableInfo,
     uuid: &str,
@@ -49,6 +49,8 @@ mod stages_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod task_history_table;
+mod tasks_table;
 mod tracing_table;
 mod users_table;
 mod util;
@@ -94,5 +96,7 @@ pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::TasksTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::ListBackgroundTasksReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The runs of tasks, a run is kept until its background task record expires.
+pub struct TaskHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TaskHistoryTable {
+    const NAME: &'static str = "system.task_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let runs = meta_api
+            .list_background_tasks(ListBackgroundTasksReq { tenant })
+            .await?
+            .into_iter()
+            .filter(|(_, _, task)| task.task_type == BackgroundTaskType::SQL)
+            .collect::<Vec<_>>();
+        let mut names = Vec::with_capacity(runs.len());
+        let mut run_ids = Vec::with_capacity(runs.len());
+        let mut states = Vec::with_capacity(runs.len());
+        let mut messages = Vec::with_capacity(runs.len());
+        let mut triggers = Vec::with_capacity(runs.len());
+        let mut create_timestamps = Vec::with_capacity(runs.len());
+        let mut update_timestamps = Vec::with_capacity(runs.len());
+        for (_, run_id, task) in runs {
+            // The creator of a run is the background job of its task.
+            names.push(
+                task.creator
+                    .map(|c| c.name)
+                    .unwrap_or_default()
+                    .as_bytes()
+                    .to_vec(),
+            );
+            run_ids.push(run_id.as_bytes().to_vec());
+            states.push(task.task_state.to_string().as_bytes().to_vec());
+            messages.push(task.message.as_bytes().to_vec());
+            triggers.push(
+                task.manual_trigger
+                    .map(|t| t.trigger.to_string().as_bytes().to_vec()),
+            );
+            create_timestamps.push(task.created_at.timestamp_micros());
+            update_timestamps.push(task.last_updated.unwrap_or_default().timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(run_ids),
+            StringType::from_data(states),
+            StringType::from_data(messages),
+            StringType::from_opt_data(triggers),
+            TimestampType::from_data(create_timestamps),
+            TimestampType::from_data(update_timestamps),
+        ]))
+    }
+}
+
+impl TaskHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("run_id", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("message", TableDataType::String),
+            TableField::new("trigger", TableDataType::String.wrap_nullable()),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'task_history'".to_string(),
+            name: "task_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTaskHistory".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::ListBackgroundJobsReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TasksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TasksTable {
+    const NAME: &'static str = "system.tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let tasks = meta_api
+            .list_background_jobs(ListBackgroundJobsReq { tenant })
+            .await?
+            .into_iter()
+            .filter(|(_, _, job)| job.task_type == BackgroundTaskType::SQL)
+            .collect::<Vec<_>>();
+        let mut names = Vec::with_capacity(tasks.len());
+        let mut schedules = Vec::with_capacity(tasks.len());
+        let mut states = Vec::with_capacity(tasks.len());
+        let mut definitions = Vec::with_capacity(tasks.len());
+        let mut owners = Vec::with_capacity(tasks.len());
+        let mut creators = Vec::with_capacity(tasks.len());
+        let mut last_task_ids = Vec::with_capacity(tasks.len());
+        let mut last_run_times = Vec::with_capacity(tasks.len());
+        let mut next_scheduled_times = Vec::with_capacity(tasks.len());
+        let mut create_timestamps = Vec::with_capacity(tasks.len());
+        for (_, name, task) in tasks {
+            names.push(name.as_bytes().to_vec());
+            schedules.push(
+                task.job_params
+                    .map(|p| p.scheduled_job_cron)
+                    .unwrap_or_default()
+                    .as_bytes()
+                    .to_vec(),
+            );
+            states.push(
+                task.job_status
+                    .as_ref()
+                    .map(|s| s.job_state.to_string().as_bytes().to_vec()),
+            );
+            definitions.push(task.sql.unwrap_or_default().as_bytes().to_vec());
+            owners.push(task.owner_role.map(|r| r.as_bytes().to_vec()));
+            creators.push(task.creator.map(|c| c.to_string().as_bytes().to_vec()));
+            last_task_ids.push(
+                task.job_status
+                    .as_ref()
+                    .and_then(|s| s.last_task_id.clone().map(|id| id.as_bytes().to_vec())),
+            );
+            last_run_times.push(
+                task.job_status
+                    .as_ref()
+                    .and_then(|s| s.last_task_run_at.map(|t| t.timestamp_micros())),
+            );
+            next_scheduled_times.push(
+                task.job_status
+                    .as_ref()
+                    .and_then(|s| s.next_task_scheduled_time.map(|t| t.timestamp_micros())),
+            );
+            create_timestamps.push(task.created_at.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(schedules),
+            StringType::from_opt_data(states),
+            StringType::from_data(definitions),
+            StringType::from_opt_data(owners),
+            StringType::from_opt_data(creators),
+            StringType::from_opt_data(last_task_ids),
+            TimestampType::from_opt_data(last_run_times),
+            TimestampType::from_opt_data(next_scheduled_times),
+            TimestampType::from_data(create_timestamps),
+        ]))
+    }
+}
+
+impl TasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("schedule", TableDataType::String),
+            TableField::new("state", TableDataType::String.wrap_nullable()),
+            TableField::new("definition", TableDataType::String),
+            TableField::new("owner", TableDataType::String.wrap_nullable()),
+            TableField::new("creator", TableDataType::String.wrap_nullable()),
+            TableField::new("last_task_id", TableDataType::String.wrap_nullable()),
+            TableField::new("last_run_time", TableDataType::Timestamp.wrap_nullable()),
+            TableField::new(
+                "next_scheduled_time",
+                TableDataType::Timestamp.wrap_nullable(),
+            ),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tasks'".to_string(),
+            name: "tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTasks".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,53 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists test_arrow" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_arrow
+(
+    a VARCHAR NULL,
+    b float,
+    c int
+);" | $MYSQL_CLIENT_CONNECT
+
+echo "insert into test_arrow values ('a\"b', 1.5, 1), (null, 2.5, 2)" | $MYSQL_CLIENT_CONNECT
+
+test_format() {
+	# unload clickhouse
+	curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_arrow order by c FORMAT ${1}" > /tmp/test_arrow.arrow
+
+	echo "truncate table test_arrow" | $MYSQL_CLIENT_CONNECT
+
+	# load streaming
+	curl -sH "insert_sql:insert into test_arrow file_format = (type = arrow)" \
+	-F "upload=@/tmp/test_arrow.arrow" \
+	-u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -c "SUCCESS"
+	echo "select * from test_arrow order by c" | $MYSQL_CLIENT_CONNECT
+
+	echo "truncate table test_arrow" | $MYSQL_CLIENT_CONNECT
+
+	# copy into table
+	echo "copy into test_arrow from 'fs:///tmp/test_arrow.arrow' file_format = (type = arrow);" | $MYSQL_CLIENT_CONNECT
+	echo "select * from test_arrow order by c" | $MYSQL_CLIENT_CONNECT
+	rm /tmp/test_arrow.arrow
+}
+
+test_format "Arrow"
+test_format "ArrowStream"
+
+# unload to stage and load back
+rm -rf /tmp/test_arrow_fs
+echo "drop stage if exists data_arrow_fs;" | $MYSQL_CLIENT_CONNECT
+echo "create stage data_arrow_fs url = 'fs:///tmp/test_arrow_fs/' FILE_FORMAT = (type = arrow);" | $MYSQL_CLIENT_CONNECT
+echo "copy into @data_arrow_fs from test_arrow;" | $MYSQL_CLIENT_CONNECT
+ls /tmp/test_arrow_fs/ | grep -c "\.arrow$"
+echo "truncate table test_arrow" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_arrow from @data_arrow_fs;" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_arrow order by c" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage if exists data_arrow_fs;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_arrow" | $MYSQL_CLIENT_CONNECT
+rm -rf /tmp/test_arrow_fs
@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists test_unload" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_unload
+(
+    a VARCHAR NULL,
+    b double,
+    c int
+);" | $MYSQL_CLIENT_CONNECT
+
+echo "insert into test_unload values ('a\"b', 1.5, 1), (null, 2.5, 2)" | $MYSQL_CLIENT_CONNECT
+
+# unload to stage and load back
+test_format() {
+	rm -rf /tmp/test_unload_fs
+	echo "drop stage if exists data_unload_fs;" | $MYSQL_CLIENT_CONNECT
+	echo "create stage data_unload_fs url = 'fs:///tmp/test_unload_fs/' FILE_FORMAT = (type = ${1} compression = ${2});" | $MYSQL_CLIENT_CONNECT
+	echo "copy into @data_unload_fs from test_unload;" | $MYSQL_CLIENT_CONNECT
+	ls /tmp/test_unload_fs/ | grep -c "\.${1}$"
+	echo "truncate table test_unload" | $MYSQL_CLIENT_CONNECT
+	echo "copy into test_unload from @data_unload_fs;" | $MYSQL_CLIENT_CONNECT
+	echo "select * from test_unload order by c" | $MYSQL_CLIENT_CONNECT
+}
+
+test_format "avro" "none"
+test_format "avro" "zstd"
+test_format "orc" "none"
+test_format "orc" "snappy"
+
+echo "drop stage if exists data_unload_fs;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_unload" | $MYSQL_CLIENT_CONNECT
+rm -rf /tmp/test_unload_fs
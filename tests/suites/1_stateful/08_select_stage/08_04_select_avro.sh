@@ -0,0 +1,44 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+DATADIR_PATH="$CURDIR/../../../data/"
+
+echo "drop stage if exists data_avro;" | $MYSQL_CLIENT_CONNECT
+echo "create stage data_avro url = 'fs://$DATADIR_PATH' FILE_FORMAT = (type = AVRO)" | $MYSQL_CLIENT_CONNECT
+
+echo "---simple"
+echo "select \$1 from @data_avro (files=>('sample.avro')) order by \$1:a_id;" | $MYSQL_CLIENT_CONNECT
+
+echo "---path"
+echo "select \$1:b_name as b from @data_avro (files=>('sample.avro')) order by b;" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => '@data_avro/sample.avro');" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy"
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "create table t (a_id int, b_name string, c_score double null)" | $MYSQL_CLIENT_CONNECT
+echo "copy into t from @data_avro files=('sample.avro');" | $MYSQL_CLIENT_CONNECT
+echo "select * from t order by a_id" | $MYSQL_CLIENT_CONNECT
+echo "drop table t;" | $MYSQL_CLIENT_CONNECT
+
+echo "---split"
+echo "create table t (id bigint, name string)" | $MYSQL_CLIENT_CONNECT
+# the file holds 34 small blocks, the splits end inside blocks and sync markers
+for split_size in 7 16 50 97 256; do
+	echo "truncate table t;" | $MYSQL_CLIENT_CONNECT
+	echo "copy into t from @data_avro files=('sample_blocks.avro') split_size = $split_size force = true;" | $MYSQL_CLIENT_CONNECT
+	echo "select count(), sum(id), count(distinct id), count(distinct name) from t" | $MYSQL_CLIENT_CONNECT
+done
+echo "drop table t;" | $MYSQL_CLIENT_CONNECT
+
+echo "---types_infer_schema"
+echo "select * from infer_schema(location => '@data_avro/sample_types.avro');" | $MYSQL_CLIENT_CONNECT
+
+echo "---types"
+echo "create table t (id int, opt bigint null, choice variant, tags map(string, bigint), point tuple(x double, y double), items array(string), price decimal(10, 2), big decimal(40, 5), ts_ms timestamp, ts_us timestamp, d date)" | $MYSQL_CLIENT_CONNECT
+echo "copy into t from @data_avro files=('sample_types.avro');" | $MYSQL_CLIENT_CONNECT
+echo "select * from t order by id" | $MYSQL_CLIENT_CONNECT
+echo "drop table t;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage data_avro;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+DATADIR_PATH="$CURDIR/../../../data/"
+
+# sample.orc holds two stripes: id 1 to 3 and id 4 to 6.
+echo "drop stage if exists data_orc;" | $MYSQL_CLIENT_CONNECT
+echo "create stage data_orc url = 'fs://$DATADIR_PATH' FILE_FORMAT = (type = ORC)" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => '@data_orc/sample.orc');" | $MYSQL_CLIENT_CONNECT
+
+echo "---select"
+echo "select * from @data_orc (files=>('sample.orc')) order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "---projection"
+echo "select score, id from @data_orc (files=>('sample.orc')) order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "---filter"
+echo "select id, \"Name\" from @data_orc (files=>('sample.orc')) where id > 4 order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select id from @data_orc (files=>('sample.orc')) where big < 20;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from @data_orc (files=>('sample.orc')) where id > 6;" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy"
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "create table t (id int, big bigint, score double null, name string)" | $MYSQL_CLIENT_CONNECT
+echo "copy into t from @data_orc files=('sample.orc');" | $MYSQL_CLIENT_CONNECT
+echo "select * from t order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy_subset"
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "create table t (name string, id int, missing int null)" | $MYSQL_CLIENT_CONNECT
+echo "copy into t from @data_orc files=('sample.orc');" | $MYSQL_CLIENT_CONNECT
+echo "select * from t order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy_type_mismatch"
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "create table t (id string)" | $MYSQL_CLIENT_CONNECT
+echo "copy into t from @data_orc files=('sample.orc');" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "column id in file is"
+
+echo "drop table t;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage data_orc;" | $MYSQL_CLIENT_CONNECT